@@ -161,6 +161,10 @@ import UIKit
             result(nil)
           }
 
+        case "request_keyframe":
+          self.hevcDumper.requestKeyframe()
+          result(nil)
+
         default:
           result(FlutterMethodNotImplemented)
         }
@@ -112,6 +112,9 @@ final class HevcDumper: NSObject, FlutterStreamHandler {
   private var audioOutputFormat: AVAudioFormat?
   private var audioConverterError: HevcDumperError?
   private var audioOnlyMode = false
+  /// Set by `requestKeyframe`; read and cleared by the capture delegate, which runs
+  /// on `sessionQueue`.
+  private var forceNextKeyframe = false
 
   let audioStreamHandler: HevcAudioStreamHandler
 
@@ -252,6 +255,12 @@ final class HevcDumper: NSObject, FlutterStreamHandler {
     }
   }
 
+  func requestKeyframe() {
+    sessionQueue.async {
+      self.forceNextKeyframe = true
+    }
+  }
+
   func setBitrate(bitrate: Int, completion: @escaping (Error?) -> Void) {
     sessionQueue.async {
       guard bitrate > 0 else {
@@ -934,12 +943,17 @@ extension HevcDumper: AVCaptureVideoDataOutputSampleBufferDelegate, AVCaptureAud
     }
 
     let presentationTimeStamp = CMSampleBufferGetPresentationTimeStamp(sampleBuffer)
+    var frameProperties: CFDictionary?
+    if forceNextKeyframe {
+      forceNextKeyframe = false
+      frameProperties = [kVTEncodeFrameOptionKey_ForceKeyFrame: kCFBooleanTrue] as CFDictionary
+    }
     let status = VTCompressionSessionEncodeFrame(
       compressionSession,
       imageBuffer: imageBuffer,
       presentationTimeStamp: presentationTimeStamp,
       duration: CMTime.invalid,
-      frameProperties: nil,
+      frameProperties: frameProperties,
       sourceFrameRefcon: nil,
       infoFlagsOut: nil
     )
@@ -26,12 +26,6 @@ const String _sankakuBindHost = '[::]';
 const String _sankakuReceiverBindAddr = '$_sankakuBindHost:$_sankakuUdpPort';
 const String _manualDialPrefsKey = 'broadcast.manual_destination';
 const String _manualDialPlaceholder = '<Your_Public_IP>:$_sankakuUdpPort';
-const int _audioCodecDebugReport = 0x7E;
-const List<int> _debugReportMagic = <int>[0x4E, 0x52, 0x50, 0x54]; // "NRPT"
-const int _debugReportProtocolVersion = 1;
-const int _debugReportPacketBegin = 0x01;
-const int _debugReportPacketChunk = 0x02;
-const int _debugReportPacketEnd = 0x03;
 
 Future<void> main() async {
   WidgetsFlutterBinding.ensureInitialized();
@@ -599,7 +593,7 @@ class _ReceiverScreenState extends State<ReceiverScreen>
       return;
     }
 
-    event.when(
+    event.maybeWhen(
       log: (msg) {
         final remoteReportPath = _extractRemoteReportPath(msg);
         setState(() {
@@ -710,6 +704,7 @@ class _ReceiverScreenState extends State<ReceiverScreen>
           _statusLog = 'ERROR: $msg';
         });
       },
+      orElse: () {},
     );
   }
 
@@ -1156,63 +1151,6 @@ class _BroadcastScreenState extends State<BroadcastScreen> {
     }
   }
 
-  Uint8List _u16le(int value) {
-    final ByteData data = ByteData(2)..setUint16(0, value, Endian.little);
-    return data.buffer.asUint8List();
-  }
-
-  Uint8List _u32le(int value) {
-    final ByteData data = ByteData(4)..setUint32(0, value, Endian.little);
-    return data.buffer.asUint8List();
-  }
-
-  Uint8List _buildDebugReportBeginPacket({
-    required int reportId,
-    required String filename,
-    required int totalChunks,
-    required int totalBytes,
-  }) {
-    final Uint8List filenameBytes = Uint8List.fromList(utf8.encode(filename));
-    final BytesBuilder builder = BytesBuilder(copy: false)
-      ..add(_debugReportMagic)
-      ..add(<int>[_debugReportProtocolVersion, _debugReportPacketBegin])
-      ..add(_u32le(reportId))
-      ..add(_u32le(totalChunks))
-      ..add(_u32le(totalBytes))
-      ..add(_u16le(filenameBytes.length))
-      ..add(filenameBytes);
-    return builder.toBytes();
-  }
-
-  Uint8List _buildDebugReportChunkPacket({
-    required int reportId,
-    required int sequence,
-    required Uint8List chunkBytes,
-  }) {
-    final BytesBuilder builder = BytesBuilder(copy: false)
-      ..add(_debugReportMagic)
-      ..add(<int>[_debugReportProtocolVersion, _debugReportPacketChunk])
-      ..add(_u32le(reportId))
-      ..add(_u32le(sequence))
-      ..add(_u16le(chunkBytes.length))
-      ..add(chunkBytes);
-    return builder.toBytes();
-  }
-
-  Uint8List _buildDebugReportEndPacket({
-    required int reportId,
-    required int totalChunks,
-    required int totalBytes,
-  }) {
-    final BytesBuilder builder = BytesBuilder(copy: false)
-      ..add(_debugReportMagic)
-      ..add(<int>[_debugReportProtocolVersion, _debugReportPacketEnd])
-      ..add(_u32le(reportId))
-      ..add(_u32le(totalChunks))
-      ..add(_u32le(totalBytes));
-    return builder.toBytes();
-  }
-
   Future<void> _sendDebugReportToReceiver() async {
     if (_isBusy || !_isBroadcasting) {
       if (!mounted) {
@@ -1251,72 +1189,17 @@ class _BroadcastScreenState extends State<BroadcastScreen> {
     final Uint8List fileBytes = Uint8List.fromList(
       utf8.encode('${reportLines.join('\n')}\n'),
     );
-    const int chunkPayloadBytes = 800;
-    final int totalChunks = fileBytes.isEmpty
-        ? 0
-        : (fileBytes.length / chunkPayloadBytes).ceil();
-    final int reportId = now.microsecondsSinceEpoch & 0xFFFFFFFF;
-
-    int packetCount = 0;
-    final basePtsUs = now.microsecondsSinceEpoch;
 
     try {
-      await pushAudioFrame(
-        frameBytes: _buildDebugReportBeginPacket(
-          reportId: reportId,
-          filename: filename,
-          totalChunks: totalChunks,
-          totalBytes: fileBytes.length,
-        ),
-        pts: BigInt.from(basePtsUs + packetCount),
-        codec: _audioCodecDebugReport,
-        framesPerPacket: 0,
-      );
-      packetCount += 1;
-
-      for (int sequence = 0; sequence < totalChunks; sequence++) {
-        final int start = sequence * chunkPayloadBytes;
-        final int end = math.min(fileBytes.length, start + chunkPayloadBytes);
-        final Uint8List chunkBytes = Uint8List.sublistView(
-          fileBytes,
-          start,
-          end,
-        );
-
-        await pushAudioFrame(
-          frameBytes: _buildDebugReportChunkPacket(
-            reportId: reportId,
-            sequence: sequence,
-            chunkBytes: chunkBytes,
-          ),
-          pts: BigInt.from(basePtsUs + packetCount),
-          codec: _audioCodecDebugReport,
-          framesPerPacket: 0,
-        );
-        packetCount += 1;
-      }
-
-      await pushAudioFrame(
-        frameBytes: _buildDebugReportEndPacket(
-          reportId: reportId,
-          totalChunks: totalChunks,
-          totalBytes: fileBytes.length,
-        ),
-        pts: BigInt.from(basePtsUs + packetCount),
-        codec: _audioCodecDebugReport,
-        framesPerPacket: 0,
-      );
-      packetCount += 1;
-
+      await sendDebugReport(name: filename, report: fileBytes);
       _debugLog(
-        'DEBUG: Sender debug report file sent to receiver (file=$filename bytes=${fileBytes.length} chunks=$totalChunks packets=$packetCount)',
+        'DEBUG: Sender debug report file sent to receiver (file=$filename bytes=${fileBytes.length})',
       );
       if (!mounted) {
         return;
       }
       setState(() {
-        _statusLog =
-            'Debug report file sent ($packetCount packets, ${fileBytes.length} bytes).';
+        _statusLog = 'Debug report file sent (${fileBytes.length} bytes).';
       });
     } catch (error) {
       _debugLog('DEBUG: Sender debug report send failed: $error');
@@ -1724,12 +1607,21 @@ class _BroadcastScreenState extends State<BroadcastScreen> {
     }
 
     int? bitrateToApply;
+    bool keyframeRequested = false;
     setState(() {
-      event.when(
+      event.maybeWhen(
         log: (msg) {
           _appendDebugLineInSetState(msg);
           _statusLog = msg;
         },
+        controlMessageReceived: (message) {
+          if (message is ControlMessage_KeyframeRequest) {
+            keyframeRequested = !_isAudioOnlyCall;
+            _debugLog(
+              'DEBUG: Receiver requested a keyframe on stream ${message.streamId}',
+            );
+          }
+        },
         connectionState: (state, detail) {
           _statusLog = 'Connection [$state]: $detail';
         },
@@ -1790,6 +1682,7 @@ class _BroadcastScreenState extends State<BroadcastScreen> {
           }
           _statusLog = 'ERROR: $msg';
         },
+        orElse: () {},
       );
     });
 
@@ -1805,6 +1698,14 @@ class _BroadcastScreenState extends State<BroadcastScreen> {
         }),
       );
     }
+
+    if (keyframeRequested && _isBroadcasting) {
+      unawaited(
+        _hevcService.requestKeyframe().catchError((Object error) {
+          _debugLog('DEBUG: Keyframe request failed: $error');
+        }),
+      );
+    }
   }
 
   String? _destinationFromService(nsd.Service service) {
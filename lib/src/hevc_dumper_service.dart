@@ -84,6 +84,15 @@ class HevcDumperService {
     await _channel.invokeMethod<void>('set_bitrate', bitrate);
   }
 
+  /// Makes the encoder emit a keyframe for the next captured frame.
+  Future<void> requestKeyframe() async {
+    if (!isSupported) {
+      return;
+    }
+
+    await _channel.invokeMethod<void>('request_keyframe');
+  }
+
   Future<void> _ensureStreamSubscriptions() async {
     if (_hevcStreamSubscription != null) {
       // already active
@@ -150,6 +159,7 @@ class HevcDumperService {
         isKeyframe: frameEvent.isKeyframe,
         pts: BigInt.from(frameEvent.ptsUs),
         codec: frameEvent.codec,
+        nalLengthSize: 0,
       ).catchError((Object error, StackTrace stackTrace) {
         _log('pushVideoFrame failed: $error');
       }),
@@ -8,22 +8,63 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'simple.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `announce_sender_handshake_if_needed`, `audio_frame_tx_slot`, `clear_audio_frame_tx`, `clear_hevc_frame_tx`, `emit_quic_network_telemetry`, `emit_remote_report_text_lines`, `handle_remote_debug_report_payload`, `hevc_frame_tx_slot`, `install_audio_frame_tx`, `install_hevc_frame_tx`, `make_client_endpoint`, `make_server_endpoint`, `new`, `parse_u16_le`, `parse_u32_le`, `run_receiver_loop`, `run_sender_loop`, `sanitize_debug_report_filename`, `save_remote_debug_report_file`, `send_sender_frame`, `sink_event`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FrameIngressGuard`, `ReceiverRunGuard`, `RemoteDebugReportAssembly`, `SenderRunGuard`, `SkipServerVerification`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `drop`, `drop`, `drop`, `fmt`, `from`, `supported_verify_schemes`, `verify_server_cert`, `verify_tls12_signature`, `verify_tls13_signature`
+// These functions are ignored because they are not marked as `pub`: `active_video_ingress_queue`, `allows`, `announce_sender_handshake_if_needed`, `apply_audio_redundancy`, `apply_lip_sync`, `as_str`, `audio_codec_list`, `audio_frame_tx_slot`, `budget`, `buffer_or_emit_frame`, `check_frame`, `check_sender_audio`, `clear_audio_frame_tx`, `clear_receiver_parameter_sets`, `clear_video_ingress_queue`, `codec_list`, `command`, `complete_with_cached_parameter_sets`, `conceal_gap`, `current_video_congestion`, `data_channels_slot`, `deliver`, `emit_audio_codec_negotiated`, `emit_audio_gap`, `emit_codec_declined`, `emit_codec_negotiated`, `emit_concealment_placeholders`, `emit_congestion`, `emit_file_transfer_finished`, `emit_file_transfer_progress`, `emit_file_transfer_started`, `emit_frame_arrival`, `emit_frame_loss_telemetry`, `emit_issued_command_timeout`, `emit_jitter_buffer_telemetry`, `emit_lip_sync_telemetry`, `emit_quic_network_telemetry`, `emit_send_delay_telemetry`, `emit_stale_frame_telemetry`, `emit_telemetry`, `emit_telemetry`, `emit_telemetry`, `emit_telemetry`, `emit_temporal_layer_telemetry`, `emit_temporal_layers`, `emit_video_queue_drops`, `emit_video_queue_telemetry`, `emit_video_stream_format`, `expire_issued_commands`, `expire_remote_commands`, `fail_issued_commands`, `file_reply_error`, `file_stream_stopped`, `file_transfer_config_slot`, `flush`, `fold_mute_state`, `frame_duration_us`, `handle_control_message`, `handle_data_datagram`, `handle_peer`, `handle_remote_command`, `inspect`, `install_audio_frame_tx`, `install_video_ingress_queue`, `local_media_state_slot`, `make_client_config`, `make_client_endpoint`, `make_server_endpoint`, `new`, `new`, `new`, `new`, `new`, `new`, `new`, `next_control_message`, `next_data_datagram`, `next_file_stream_item`, `next_file_stream`, `next_playout_deadline`, `offered`, `offers`, `push_stamped_access_units`, `push`, `read_incoming_file`, `receive_debug_report`, `receiver_audio_codecs_slot`, `receiver_control_slot`, `receiver_datagrams_slot`, `receiver_file_commands_slot`, `receiver_issued_commands_slot`, `receiver_jitter_buffer_config_slot`, `receiver_jitter_buffer_config`, `receiver_parameter_sets_slot`, `receiver_video_codecs_slot`, `receiver_video_framing_slot`, `reject_incoming_file`, `release_jitter_buffer`, `remote_command_from_control`, `remote_command_policy_slot`, `reply`, `reset_video_bytes_ingress`, `reset`, `resolve`, `run_incoming_file`, `run_outgoing_file`, `run_receiver_loop`, `run_sender_loop`, `send_local_media_state`, `send_receiver_reports`, `send_sender_audio`, `send_sender_frame`, `sender_audio_codecs_slot`, `sender_control_slot`, `sender_datagrams_slot`, `sender_file_commands_slot`, `sender_keyframe_detection_slot`, `sender_latency_budget`, `sender_latency_budgets_slot`, `sender_pending_commands_slot`, `sender_video_codecs_slot`, `sender_video_queue_config_slot`, `set_session_slot`, `settle_issued_command`, `signal`, `sink_event`, `sleep_until_playout`, `stamp`, `start_control_channel`, `start_datagram_channel`, `start`, `start`, `stream_file`, `stream_opened`, `submit`, `take_supplied_pts`, `validate_audio_codecs`, `validate_video_codecs`, `video_bytes_ingress_slot`, `video_ingress_queue_slot`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AudioInspection`, `FileCommand`, `FileReply`, `FileTransferConfig`, `FileTransfers`, `FrameIngressGuard`, `IncomingFileEvent`, `IncomingFileSignal`, `IncomingTransfer`, `KeyframeCheck`, `LatencyBudgets`, `OutgoingTransfer`, `PcmPlayout`, `QueuedAudioFrame`, `ReceiverRunGuard`, `SenderAudioCodecs`, `SenderAudioState`, `SenderRunGuard`, `SkipServerVerification`, `SoftwareVideoDecode`, `VideoBytesIngress`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `drop`, `drop`, `drop`, `drop`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `supported_verify_schemes`, `verify_server_cert`, `verify_tls12_signature`, `verify_tls13_signature`
 
-Future<void> pushVideoFrame({
+/// Queues one access unit for sending. `codec` is `VIDEO_CODEC_HEVC` (also used
+/// for 0), `VIDEO_CODEC_H264` or `VIDEO_CODEC_AV1`, and must match the codec
+/// negotiated for the session. `nal_length_size` declares how the caller framed
+/// its NAL units: 0 for Annex B start codes, or 1, 2 or 4 for hvcC/avcC-style
+/// length prefixes of that size. NAL-based frames are normalised to Annex B before
+/// sending; AV1 temporal units must use 0 and are sent as-is. Returns the sender's
+/// congestion state after queueing.
+Future<CongestionState> pushVideoFrame({
   required List<int> frameBytes,
   required bool isKeyframe,
   required BigInt pts,
   required int codec,
+  required int nalLengthSize,
 }) => RustLib.instance.api.crateApiSimplePushVideoFrame(
   frameBytes: frameBytes,
   isKeyframe: isKeyframe,
   pts: pts,
   codec: codec,
+  nalLengthSize: nalLengthSize,
 );
 
+/// Feeds an arbitrary chunk of an Annex B HEVC elementary stream. Access units are
+/// reassembled across chunks and queued like `push_video_frame` once complete.
+/// Only HEVC streams can be split this way.
+/// When `pts` is `None`, pts are synthesised at the rate set by
+/// `configure_video_bytes_frame_rate`; a supplied pts applies to the access unit
+/// whose first byte is in this chunk, even if it completes on a later push.
+/// Fails without consuming the chunk while the sender is not active.
+Future<CongestionState> pushVideoBytes({
+  required List<int> chunk,
+  BigInt? pts,
+  required int codec,
+}) => RustLib.instance.api.crateApiSimplePushVideoBytes(
+  chunk: chunk,
+  pts: pts,
+  codec: codec,
+);
+
+/// Flushes the access unit still buffered by `push_video_bytes`, e.g. at end of file.
+Future<CongestionState> flushVideoBytes({required int codec}) =>
+    RustLib.instance.api.crateApiSimpleFlushVideoBytes(codec: codec);
+
+/// Sets the frame rate used to synthesise pts for `push_video_bytes`.
+Future<void> configureVideoBytesFrameRate({
+  required int frameRateNum,
+  required int frameRateDen,
+}) => RustLib.instance.api.crateApiSimpleConfigureVideoBytesFrameRate(
+  frameRateNum: frameRateNum,
+  frameRateDen: frameRateDen,
+);
+
+/// Queues one audio frame for the sender. `codec` 0 stands for the audio codec
+/// negotiated for the session.
 Future<void> pushAudioFrame({
   required List<int> frameBytes,
   required BigInt pts,
@@ -36,6 +77,322 @@ Future<void> pushAudioFrame({
   framesPerPacket: framesPerPacket,
 );
 
+/// Bounds the sender's video queue to `capacity` frames and selects what happens
+/// when it is full. Keyframe-aware policies use the keyframe flag resolved by
+/// `configure_sender_keyframe_detection`. Applies to the next sender session.
+Future<void> configureSenderVideoQueue({
+  required int capacity,
+  required VideoQueuePolicy policy,
+}) => RustLib.instance.api.crateApiSimpleConfigureSenderVideoQueue(
+  capacity: capacity,
+  policy: policy,
+);
+
+/// Sets the sender's default video latency budget, used by every stream without
+/// a budget of its own. A dequeued delta frame whose pts trails the newest pushed
+/// pts by more than `budget_ms` is discarded instead of sent, along with the delta
+/// frames that depend on it up to the next keyframe. Keyframes are always sent.
+/// Disabled by default; `enabled = false` turns it off again. Takes effect
+/// immediately, including for a running session.
+Future<void> configureSenderVideoLatencyBudget({
+  required bool enabled,
+  required int budgetMs,
+}) => RustLib.instance.api.crateApiSimpleConfigureSenderVideoLatencyBudget(
+  enabled: enabled,
+  budgetMs: budgetMs,
+);
+
+/// Gives one stream of the running sender session its own latency budget,
+/// overriding the default from `configure_sender_video_latency_budget`;
+/// `enabled = false` disables discarding for that stream only. Stream ids are
+/// reported by the `stream_id` telemetry at session start, and the overrides
+/// are cleared when the next session starts.
+Future<void> configureSenderStreamLatencyBudget({
+  required int streamId,
+  required bool enabled,
+  required int budgetMs,
+}) => RustLib.instance.api.crateApiSimpleConfigureSenderStreamLatencyBudget(
+  streamId: streamId,
+  enabled: enabled,
+  budgetMs: budgetMs,
+);
+
+/// Repeats the previous `depth` Opus packets inside each new audio payload (RFC 2198
+/// style) for subsequent `start_sankaku_sender` sessions, so the receiver can
+/// rebuild packets lost in between. Costs roughly `depth` times the audio bitrate.
+/// `0` disables it. Only used when the receiver negotiated redundant audio; older
+/// receivers get plain Opus.
+Future<void> configureSenderAudioRedundancy({required int depth}) =>
+    RustLib.instance.api.crateApiSimpleConfigureSenderAudioRedundancy(
+      depth: depth,
+    );
+
+/// Selects how subsequent `start_sankaku_sender` sessions derive keyframe flags.
+/// Defaults to [`KeyframeDetectionMode::CrossCheck`].
+Future<void> configureSenderKeyframeDetection({
+  required KeyframeDetectionMode mode,
+}) => RustLib.instance.api.crateApiSimpleConfigureSenderKeyframeDetection(
+  mode: mode,
+);
+
+/// Enables the receiver jitter buffer for subsequent `start_sankaku_receiver` calls.
+/// Frames are reordered by pts and released after an adaptive playout delay bounded
+/// by `min_delay_ms..=max_delay_ms`. Passing `enabled = false` delivers frames as
+/// soon as they arrive.
+Future<void> configureReceiverJitterBuffer({
+  required bool enabled,
+  required int minDelayMs,
+  required int maxDelayMs,
+}) => RustLib.instance.api.crateApiSimpleConfigureReceiverJitterBuffer(
+  enabled: enabled,
+  minDelayMs: minDelayMs,
+  maxDelayMs: maxDelayMs,
+);
+
+/// Enables audio/video alignment for subsequent `start_sankaku_receiver` calls. The
+/// stream whose frames arrive ahead of the other is held back so equal pts are
+/// released together. `manual_correction_ms` is applied on top and may be changed
+/// while the receiver runs via `set_receiver_lip_sync_correction`; positive values
+/// delay audio relative to video.
+Future<void> configureReceiverLipSync({
+  required bool enabled,
+  required int manualCorrectionMs,
+}) => RustLib.instance.api.crateApiSimpleConfigureReceiverLipSync(
+  enabled: enabled,
+  manualCorrectionMs: manualCorrectionMs,
+);
+
+/// Makes subsequent `start_sankaku_receiver` sessions fill audio gaps with
+/// `AudioConcealment` placeholder events, one per missing packet and up to 200 ms
+/// (at least one packet), so the player can run packet loss concealment (e.g.
+/// decode with no input) and keep its timeline. `AudioGap` events are emitted
+/// either way.
+Future<void> configureReceiverAudioConcealment({required bool enabled}) =>
+    RustLib.instance.api.crateApiSimpleConfigureReceiverAudioConcealment(
+      enabled: enabled,
+    );
+
+/// Makes `start_sankaku_receiver` sessions decode received HEVC in software and
+/// send the pictures as `DecodedVideoFrame` events on `sink`, for desktop targets
+/// without a hardware decoder. Takes effect in running sessions from the next IRAP.
+/// Pictures larger than `max_width` x `max_height` are downscaled (zero means
+/// unbounded). Access units that waited too long for the decoder, or pictures that
+/// finish decoding too long after they arrived, are dropped and counted in
+/// `video.decode.dropped_late`; after a drop, decoding resumes at the next IRAP.
+/// `VideoFrameReceived` events are still emitted. Requires the `hevc-decode`
+/// cargo feature.
+Stream<UiEvent> startSoftwareVideoDecode({
+  required DecodedVideoFormat format,
+  required int maxWidth,
+  required int maxHeight,
+}) => RustLib.instance.api.crateApiSimpleStartSoftwareVideoDecode(
+  format: format,
+  maxWidth: maxWidth,
+  maxHeight: maxHeight,
+);
+
+/// Stops software decoding, including in running receiver sessions.
+Future<void> stopSoftwareVideoDecode() =>
+    RustLib.instance.api.crateApiSimpleStopSoftwareVideoDecode();
+
+/// Makes subsequent `start_sankaku_receiver` sessions decode Opus in Rust, for
+/// targets without a platform audio player. Decoded 48 kHz stereo audio arrives as
+/// `PcmAudioReceived` events, or is written as raw s16le to `output_path` (a file
+/// or a FIFO whose reader is already attached) when one is given. Writes happen on
+/// a separate thread; audio decoded while about a second is still unwritten is
+/// dropped and counted as `audio.pcm.output_dropped`. Gaps are always filled by
+/// the decoder's loss concealment, using in-band FEC where the sender provides it.
+/// Requires the `opus-decode` cargo feature.
+Future<void> configureReceiverOpusDecode({
+  required bool enabled,
+  String? outputPath,
+}) => RustLib.instance.api.crateApiSimpleConfigureReceiverOpusDecode(
+  enabled: enabled,
+  outputPath: outputPath,
+);
+
+Future<void> setReceiverLipSyncCorrection({required int manualCorrectionMs}) =>
+    RustLib.instance.api.crateApiSimpleSetReceiverLipSyncCorrection(
+      manualCorrectionMs: manualCorrectionMs,
+    );
+
+/// Selects the NAL framing of `VideoFrameReceived` payloads: 0 for Annex B start
+/// codes (the default), or 1, 2 or 4 for length prefixes of that size. Takes effect
+/// immediately, including on a running receiver.
+Future<void> configureReceiverVideoFormat({required int nalLengthSize}) =>
+    RustLib.instance.api.crateApiSimpleConfigureReceiverVideoFormat(
+      nalLengthSize: nalLengthSize,
+    );
+
+/// Returns the most recent decoder configuration seen on a receiver stream, so a
+/// decoder can be re-primed without waiting for the next keyframe: VPS/SPS/PPS
+/// (HEVC) or SPS/PPS (H.264) as an Annex B blob, or the AV1 sequence header OBU.
+/// Returns `None` until a complete set has been received.
+Future<Uint8List?> getCachedParameterSets({required int streamId}) =>
+    RustLib.instance.api.crateApiSimpleGetCachedParameterSets(
+      streamId: streamId,
+    );
+
+/// Sets the video codecs the sender offers at session start, most preferred
+/// first. Ids are `VIDEO_CODEC_HEVC`, `VIDEO_CODEC_H264` (0xC0) and
+/// `VIDEO_CODEC_AV1` (0xC1). Defaults to HEVC only; applies to the next session.
+Future<void> configureSenderVideoCodecs({required List<int> codecs}) =>
+    RustLib.instance.api.crateApiSimpleConfigureSenderVideoCodecs(
+      codecs: codecs,
+    );
+
+/// Sets the video codecs the receiver can decode. An offer sharing none of them is
+/// declined. Defaults to HEVC only; applies to the next session.
+Future<void> configureReceiverVideoCodecs({required List<int> codecs}) =>
+    RustLib.instance.api.crateApiSimpleConfigureReceiverVideoCodecs(
+      codecs: codecs,
+    );
+
+/// Sets the audio codecs the sender offers at session start, most preferred
+/// first. Ids are `AUDIO_CODEC_OPUS`, `AUDIO_CODEC_AAC_LC` (0xC0) and
+/// `AUDIO_CODEC_PCM_S16LE` (0xC1); AAC-LC and PCM also need
+/// `configure_sender_aac_config` or `configure_sender_pcm_format`. Defaults to
+/// Opus only; applies to the next session.
+Future<void> configureSenderAudioCodecs({required List<int> codecs}) =>
+    RustLib.instance.api.crateApiSimpleConfigureSenderAudioCodecs(
+      codecs: codecs,
+    );
+
+/// Sets the AAC AudioSpecificConfig sent with an AAC-LC offer, as produced by the
+/// platform encoder. Only the AAC-LC object type is accepted, at one of the
+/// standard sampling frequencies; explicit frequencies are rejected.
+Future<void> configureSenderAacConfig({
+  required List<int> audioSpecificConfig,
+}) => RustLib.instance.api.crateApiSimpleConfigureSenderAacConfig(
+  audioSpecificConfig: audioSpecificConfig,
+);
+
+/// Sets the sample rate and channel count of interleaved 16-bit little-endian PCM
+/// sent with a PCM offer.
+Future<void> configureSenderPcmFormat({
+  required int sampleRate,
+  required int channels,
+}) => RustLib.instance.api.crateApiSimpleConfigureSenderPcmFormat(
+  sampleRate: sampleRate,
+  channels: channels,
+);
+
+/// Sets the audio codecs the receiver can decode. An offer sharing none of them is
+/// declined. Defaults to Opus only; applies to the next session.
+Future<void> configureReceiverAudioCodecs({required List<int> codecs}) =>
+    RustLib.instance.api.crateApiSimpleConfigureReceiverAudioCodecs(
+      codecs: codecs,
+    );
+
+/// Sends `message` to the peer on the control stream of the running sender or
+/// receiver session (both, if both are running). Fails when no running session
+/// has a control stream, e.g. because the peer predates it.
+Future<void> sendControlMessage({required ControlMessage message}) =>
+    RustLib.instance.api.crateApiSimpleSendControlMessage(message: message);
+
+/// Sends a diagnostics report to the peer on the control stream of the running
+/// session (the sender's, if both are running). A sender whose receiver predates
+/// the control stream sends it on the audio stream in the older packet format
+/// instead. The peer saves it under `remote_reports` and logs
+/// `[RemoteReport] saved file path=...`. Reports larger than a control message keep
+/// their last 64 KiB or so, the most recent lines.
+Future<void> sendDebugReport({
+  required String name,
+  required List<int> report,
+}) => RustLib.instance.api.crateApiSimpleSendDebugReport(
+  name: name,
+  report: report,
+);
+
+/// Opens a named data channel for `send_data`. Channels stay open across sessions
+/// until closed; reopening one with a different delivery mode is an error.
+Future<void> openDataChannel({
+  required String label,
+  required DataChannelDelivery delivery,
+}) => RustLib.instance.api.crateApiSimpleOpenDataChannel(
+  label: label,
+  delivery: delivery,
+);
+
+Future<void> closeDataChannel({required String label}) =>
+    RustLib.instance.api.crateApiSimpleCloseDataChannel(label: label);
+
+/// Sends `data` on an open channel to the peer of the running sender or receiver
+/// session (both, if both are running). The peer receives it as
+/// `UiEvent::DataReceived`.
+Future<void> sendData({required String channel, required List<int> data}) =>
+    RustLib.instance.api.crateApiSimpleSendData(channel: channel, data: data);
+
+/// Enables receiving files into `receive_dir` (`None` refuses incoming offers)
+/// and sets the largest file accepted or sent. Applies to transfers offered after
+/// the call.
+Future<void> configureFileTransfer({
+  String? receiveDir,
+  required BigInt maxBytes,
+}) => RustLib.instance.api.crateApiSimpleConfigureFileTransfer(
+  receiveDir: receiveDir,
+  maxBytes: maxBytes,
+);
+
+/// Offers the file at `path` to the peer of the running session (the sender's,
+/// if both are running) and returns the transfer id used in file transfer
+/// events. The file is streamed from disk once the peer accepts.
+Future<int> sendFile({required String path}) =>
+    RustLib.instance.api.crateApiSimpleSendFile(path: path);
+
+/// Cancels a transfer; `incoming` selects between the peer's transfer and one
+/// started with `send_file`, whose ids may coincide.
+Future<void> cancelFileTransfer({
+  required int transferId,
+  required bool incoming,
+}) => RustLib.instance.api.crateApiSimpleCancelFileTransfer(
+  transferId: transferId,
+  incoming: incoming,
+);
+
+/// Sets which commands from the receiver reach the sender's app. Until this is
+/// called every command is denied.
+Future<void> configureRemoteCommandPolicy({
+  required RemoteCommandPolicy policy,
+}) => RustLib.instance.api.crateApiSimpleConfigureRemoteCommandPolicy(
+  policy: policy,
+);
+
+/// Asks the sender at the other end of the running receiver session to carry out
+/// `command`. Returns the id that its `UiEvent::RemoteCommandReply` will carry.
+/// Every command gets exactly one reply: `TimedOut` when the sender does not
+/// answer within 20 seconds or the control stream closes first.
+Future<int> sendRemoteCommand({required RemoteCommand command}) =>
+    RustLib.instance.api.crateApiSimpleSendRemoteCommand(command: command);
+
+/// Answers the `UiEvent::RemoteCommand` with `command_id`; each command takes
+/// exactly one reply.
+Future<void> replyRemoteCommand({
+  required int commandId,
+  required RemoteCommandStatus status,
+  required String detail,
+}) => RustLib.instance.api.crateApiSimpleReplyRemoteCommand(
+  commandId: commandId,
+  status: status,
+  detail: detail,
+);
+
+/// Records what the local side is deliberately not sending and tells the
+/// receiver of the running sender session, if any; later sessions announce it
+/// when they start. `audio_only` implies no video, and `screen_share` means video
+/// continues with the camera off.
+Future<void> announceMediaState({
+  required bool microphoneMuted,
+  required bool cameraOff,
+  required bool audioOnly,
+  required bool screenShare,
+}) => RustLib.instance.api.crateApiSimpleAnnounceMediaState(
+  microphoneMuted: microphoneMuted,
+  cameraOff: cameraOff,
+  audioOnly: audioOnly,
+  screenShare: screenShare,
+);
+
 Future<void> stopSankakuSender() =>
     RustLib.instance.api.crateApiSimpleStopSankakuSender();
 
@@ -60,6 +417,209 @@ Stream<UiEvent> startSankakuReceiver({
   graphBytes: graphBytes,
 );
 
+/// Recommended capture-side reaction to sender congestion, mildest first.
+enum CongestionAction {
+  none,
+  reduceBitrate,
+  reduceFrameRate,
+  skipFrame;
+
+  static Future<CongestionAction> default_() =>
+      RustLib.instance.api.crateApiSimpleCongestionActionDefault();
+}
+
+/// Sender video queue state returned by the video ingress calls, so the capture
+/// pipeline can adapt before the transport's bitrate estimate catches up. All
+/// zero while no sender is running.
+class CongestionState {
+  final int queueDepth;
+  final int queueCapacity;
+  /// Time to drain the queue at the current target bitrate.
+  final int estimatedSendDelayMs;
+  final CongestionAction recommendedAction;
+  /// False when the queue's overflow policy dropped a frame pushed by this call.
+  final bool frameQueued;
+
+  const CongestionState({
+    required this.queueDepth,
+    required this.queueCapacity,
+    required this.estimatedSendDelayMs,
+    required this.recommendedAction,
+    required this.frameQueued,
+  });
+
+  static Future<CongestionState> default_() =>
+      RustLib.instance.api.crateApiSimpleCongestionStateDefault();
+
+  @override
+  int get hashCode =>
+      queueDepth.hashCode ^
+      queueCapacity.hashCode ^
+      estimatedSendDelayMs.hashCode ^
+      recommendedAction.hashCode ^
+      frameQueued.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CongestionState &&
+          runtimeType == other.runtimeType &&
+          queueDepth == other.queueDepth &&
+          queueCapacity == other.queueCapacity &&
+          estimatedSendDelayMs == other.estimatedSendDelayMs &&
+          recommendedAction == other.recommendedAction &&
+          frameQueued == other.frameQueued;
+}
+
+@freezed
+sealed class ControlMessage with _$ControlMessage {
+  const ControlMessage._();
+
+  /// Asks the sender's encoder for a keyframe on `stream_id`. The sender's app
+  /// receives it as `ControlMessageReceived` and forwards it to its encoder.
+  const factory ControlMessage.keyframeRequest({required int streamId}) =
+      ControlMessage_KeyframeRequest;
+  /// A receiver reports it as `UiEvent::PeerMediaState`, with `video_muted` as
+  /// the camera being off; prefer `announce_media_state`.
+  const factory ControlMessage.muteState({
+    required bool audioMuted,
+    required bool videoMuted,
+  }) = ControlMessage_MuteState;
+  /// Sent before stopping a session so the peer can show why it ended.
+  const factory ControlMessage.hangup({required String reason}) =
+      ControlMessage_Hangup;
+  /// Codec ids and the largest picture the sending side can handle; zero
+  /// dimensions mean no limit.
+  const factory ControlMessage.capabilities({
+    required Uint8List videoCodecs,
+    required Uint8List audioCodecs,
+    required int maxWidth,
+    required int maxHeight,
+  }) = ControlMessage_Capabilities;
+  /// Sent by the receiver every second for each video stream. Counters are
+  /// cumulative for the session.
+  const factory ControlMessage.receiverReport({
+    required int streamId,
+    required BigInt highestFrameIndex,
+    required BigInt lostFrames,
+    required BigInt lateFrames,
+    required bool awaitingKeyframe,
+    required int jitterUs,
+  }) = ControlMessage_ReceiverReport;
+  /// Application-defined message; `kind` lets the app tell its messages apart.
+  const factory ControlMessage.custom({
+    required String kind,
+    required Uint8List payload,
+  }) = ControlMessage_Custom;
+}
+
+/// How messages on a data channel are delivered.
+enum DataChannelDelivery {
+  /// Delivered in order and retransmitted on loss, on the control stream. Up to
+  /// 64 KiB per message.
+  reliableOrdered,
+  /// Sent once as a QUIC datagram and possibly lost. Must fit in one datagram,
+  /// typically a little over 1100 bytes including the label.
+  unreliable,
+}
+
+/// Pixel layout of `DecodedVideoFrame` events.
+enum DecodedVideoFormat {
+  /// Packed 8-bit RGBA, `width * 4` bytes per row.
+  rgba,
+  /// The Y plane followed by the quarter-size U and V planes.
+  i420,
+}
+
+/// How a file transfer ended.
+enum FileTransferOutcome {
+  /// Incoming: saved after the checksum matched. Outgoing: the peer confirmed it.
+  completed,
+  /// The receiving side declined the offer.
+  rejected,
+  /// Cancelled locally or by the peer.
+  cancelled,
+  failed,
+}
+
+/// How the sender treats the `is_keyframe` flag passed to `push_video_frame`.
+enum KeyframeDetectionMode {
+  /// Use the caller's flag as-is without inspecting the bitstream.
+  trustCaller,
+  /// Use the caller's flag, but classify NAL unit types and count disagreements.
+  crossCheck,
+  /// Replace the caller's flag with the NAL-derived classification when available.
+  override,
+}
+
+@freezed
+sealed class RemoteCommand with _$RemoteCommand {
+  const RemoteCommand._();
+
+  const factory RemoteCommand.switchCamera() = RemoteCommand_SwitchCamera;
+  const factory RemoteCommand.setTorch({required bool enabled}) =
+      RemoteCommand_SetTorch;
+  const factory RemoteCommand.setBitrate({required int bitrateBps}) =
+      RemoteCommand_SetBitrate;
+  const factory RemoteCommand.startCapture() = RemoteCommand_StartCapture;
+  const factory RemoteCommand.stopCapture() = RemoteCommand_StopCapture;
+  const factory RemoteCommand.requestDebugReport() =
+      RemoteCommand_RequestDebugReport;
+}
+
+/// Which remote commands the sender passes on to the app. Commands not allowed
+/// here are answered with `Denied` without reaching Dart.
+class RemoteCommandPolicy {
+  final bool allowSwitchCamera;
+  final bool allowTorch;
+  final bool allowBitrate;
+  /// Covers both `StartCapture` and `StopCapture`.
+  final bool allowCapture;
+  final bool allowDebugReport;
+
+  const RemoteCommandPolicy({
+    required this.allowSwitchCamera,
+    required this.allowTorch,
+    required this.allowBitrate,
+    required this.allowCapture,
+    required this.allowDebugReport,
+  });
+
+  static Future<RemoteCommandPolicy> default_() =>
+      RustLib.instance.api.crateApiSimpleRemoteCommandPolicyDefault();
+
+  @override
+  int get hashCode =>
+      allowSwitchCamera.hashCode ^
+      allowTorch.hashCode ^
+      allowBitrate.hashCode ^
+      allowCapture.hashCode ^
+      allowDebugReport.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RemoteCommandPolicy &&
+          runtimeType == other.runtimeType &&
+          allowSwitchCamera == other.allowSwitchCamera &&
+          allowTorch == other.allowTorch &&
+          allowBitrate == other.allowBitrate &&
+          allowCapture == other.allowCapture &&
+          allowDebugReport == other.allowDebugReport;
+}
+
+enum RemoteCommandStatus {
+  done,
+  failed,
+  /// Not allowed by the sender's `RemoteCommandPolicy`.
+  denied,
+  /// The peer does not know the command, or is not a sender.
+  unsupported,
+  /// The sender's app did not answer within `REMOTE_COMMAND_TIMEOUT`, or no
+  /// reply reached the receiver in time.
+  timedOut,
+}
+
 @freezed
 sealed class UiEvent with _$UiEvent {
   const UiEvent._();
@@ -101,5 +661,179 @@ sealed class UiEvent with _$UiEvent {
     required BigInt pts,
     required int framesPerPacket,
   }) = UiEvent_AudioFrameReceived;
+  /// Stands in for a lost audio packet when concealment is enabled, in pts order
+  /// with `AudioFrameReceived`; the player should conceal `frames_per_packet`
+  /// frames at `pts`.
+  const factory UiEvent.audioConcealment({
+    required int streamId,
+    required BigInt pts,
+    required int framesPerPacket,
+  }) = UiEvent_AudioConcealment;
+  /// Received Opus decoded in Rust (`opus-decode` feature), delivered instead of
+  /// `AudioFrameReceived`. `samples` are interleaved 16-bit; `concealed` blocks
+  /// were synthesised by the decoder for lost packets.
+  const factory UiEvent.pcmAudioReceived({
+    required int streamId,
+    required BigInt pts,
+    required int sampleRate,
+    required int channels,
+    required int framesPerPacket,
+    required Int16List samples,
+    required bool concealed,
+  }) = UiEvent_PcmAudioReceived;
+  /// A received picture decoded in Rust (`hevc-decode` feature), sent only on the
+  /// stream passed to `start_software_video_decode`. `data` holds tightly packed
+  /// planes in `format`, ready to upload as a texture.
+  const factory UiEvent.decodedVideoFrame({
+    required int streamId,
+    required BigInt pts,
+    required int width,
+    required int height,
+    required DecodedVideoFormat format,
+    required Uint8List data,
+  }) = UiEvent_DecodedVideoFrame;
+  /// Emitted when a stream's parameter sets first appear or change. Frame rate is
+  /// `frame_rate_num / frame_rate_den`, both zero when the stream carries no timing info.
+  const factory UiEvent.streamFormat({
+    required int streamId,
+    required String codec,
+    required int width,
+    required int height,
+    required int profileIdc,
+    required bool highTier,
+    required int levelIdc,
+    required int chromaFormatIdc,
+    required int bitDepthLuma,
+    required int bitDepthChroma,
+    required int frameRateNum,
+    required int frameRateDen,
+  }) = UiEvent_StreamFormat;
+  /// Sender video queue state, emitted every second and whenever the recommended
+  /// action changes.
+  const factory UiEvent.congestion({
+    required int queueDepth,
+    required int queueCapacity,
+    required int estimatedSendDelayMs,
+    required String recommendedAction,
+  }) = UiEvent_Congestion;
+  /// HEVC temporal layers the sender currently transmits out of those seen in the
+  /// stream. Emitted when layers are dropped or restored to follow the bitrate
+  /// estimate, and when a new layer first appears.
+  const factory UiEvent.temporalLayers({
+    required int streamId,
+    required int activeLayers,
+    required int totalLayers,
+  }) = UiEvent_TemporalLayers;
+  /// Received audio skipped ahead of the pts expected from the previous packet's
+  /// `frames_per_packet`. `concealment_packets` `AudioConcealment` placeholders,
+  /// or concealed `PcmAudioReceived` blocks, were emitted when concealment is
+  /// enabled.
+  const factory UiEvent.audioGap({
+    required int streamId,
+    required BigInt expectedPts,
+    required BigInt missingUs,
+    required int missingPackets,
+    required int concealmentPackets,
+  }) = UiEvent_AudioGap;
+  /// Speaking/silent transitions of an Opus stream, derived from DTX and packet
+  /// bitrate. `local` is true for the sender's own microphone stream.
+  const factory UiEvent.audioActivity({
+    required int streamId,
+    required bool local,
+    required bool speaking,
+  }) = UiEvent_AudioActivity;
+  /// Outcome of codec negotiation at session start. `peer_negotiated` is false when
+  /// the peer predates negotiation and HEVC was assumed.
+  const factory UiEvent.codecNegotiated({
+    required int codec,
+    required String codecName,
+    required bool peerNegotiated,
+  }) = UiEvent_CodecNegotiated;
+  /// Audio codec agreed at session start, with what Dart needs to set up its
+  /// decoder: the AudioSpecificConfig for AAC-LC or the 5-byte PCM config (sample
+  /// rate u32 BE, channels). `channels` is zero for Opus, which signals it per
+  /// packet. `peer_negotiated` is false when the peer predates audio negotiation
+  /// and Opus was assumed.
+  const factory UiEvent.audioCodecNegotiated({
+    required int codec,
+    required String codecName,
+    required int sampleRate,
+    required int channels,
+    required Uint8List codecConfig,
+    required bool peerNegotiated,
+  }) = UiEvent_AudioCodecNegotiated;
+  /// A message the peer sent on the control stream.
+  const factory UiEvent.controlMessageReceived({
+    required ControlMessage message,
+  }) = UiEvent_ControlMessageReceived;
+  /// A message on one of the peer's data channels. Channels need not be opened
+  /// locally to receive on them.
+  const factory UiEvent.dataReceived({
+    required String channel,
+    required Uint8List data,
+    required bool reliable,
+  }) = UiEvent_DataReceived;
+  /// A file transfer began streaming: the peer accepted a file passed to
+  /// `send_file`, or an incoming offer was accepted under the
+  /// `configure_file_transfer` policy. `incoming` tells the two id spaces apart.
+  const factory UiEvent.fileTransferStarted({
+    required int transferId,
+    required bool incoming,
+    required String name,
+    required BigInt size,
+  }) = UiEvent_FileTransferStarted;
+  /// Bytes sent or received so far, at most every 250 ms and once at the end.
+  const factory UiEvent.fileTransferProgress({
+    required int transferId,
+    required bool incoming,
+    required BigInt bytes,
+    required BigInt size,
+  }) = UiEvent_FileTransferProgress;
+  /// Final event of every transfer. `path` is where an incoming file was saved
+  /// and is empty otherwise; `detail` explains any outcome but `Completed`.
+  const factory UiEvent.fileTransferFinished({
+    required int transferId,
+    required bool incoming,
+    required String name,
+    required FileTransferOutcome outcome,
+    required String path,
+    required String detail,
+  }) = UiEvent_FileTransferFinished;
+  /// Sender: the receiver asked for `command` and the policy set with
+  /// `configure_remote_command_policy` allows it. Answer it with
+  /// `reply_remote_command` and the same `command_id`.
+  const factory UiEvent.remoteCommand({
+    required int commandId,
+    required RemoteCommand command,
+  }) = UiEvent_RemoteCommand;
+  /// Receiver: the sender's answer to a `send_remote_command` call, or
+  /// `TimedOut` when none arrived in time.
+  const factory UiEvent.remoteCommandReply({
+    required int commandId,
+    required RemoteCommandStatus status,
+    required String detail,
+  }) = UiEvent_RemoteCommandReply;
+  /// Receiver: what the sender announced with `announce_media_state`, once when
+  /// the session starts and again on every change. Audio and video that stop
+  /// while muted or off are not reported as gaps or loss.
+  const factory UiEvent.peerMediaState({
+    required bool microphoneMuted,
+    required bool cameraOff,
+    required bool audioOnly,
+    required bool screenShare,
+  }) = UiEvent_PeerMediaState;
   const factory UiEvent.error({required String msg}) = UiEvent_Error;
 }
+
+/// What `push_video_frame` does when the sender's video queue is full.
+enum VideoQueuePolicy {
+  /// Evict the oldest queued delta frame to make room, with the delta frames
+  /// that reference it; if those run past the queue, incoming delta frames are
+  /// dropped until the caller's next keyframe.
+  dropOldestDeltaFrames,
+  /// Drop incoming delta frames until the caller's next keyframe, so the receiver
+  /// never sees a broken decoding chain.
+  dropUntilKeyframe,
+  /// Block the calling thread until the sender loop frees a slot.
+  block,
+}
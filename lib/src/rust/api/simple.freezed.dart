@@ -12,7 +12,7 @@ part of 'simple.dart';
 // dart format off
 T _$identity<T>(T value) => value;
 /// @nodoc
-mixin _$UiEvent {
+mixin _$ControlMessage {
 
 
 
@@ -20,7 +20,7 @@ mixin _$UiEvent {
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent);
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ControlMessage);
 }
 
 
@@ -29,20 +29,20 @@ int get hashCode => runtimeType.hashCode;
 
 @override
 String toString() {
-  return 'UiEvent()';
+  return 'ControlMessage()';
 }
 
 
 }
 
 /// @nodoc
-class $UiEventCopyWith<$Res>  {
-$UiEventCopyWith(UiEvent _, $Res Function(UiEvent) __);
+class $ControlMessageCopyWith<$Res>  {
+$ControlMessageCopyWith(ControlMessage _, $Res Function(ControlMessage) __);
 }
 
 
-/// Adds pattern-matching-related methods to [UiEvent].
-extension UiEventPatterns on UiEvent {
+/// Adds pattern-matching-related methods to [ControlMessage].
+extension ControlMessagePatterns on ControlMessage {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -55,22 +55,16 @@ extension UiEventPatterns on UiEvent {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( UiEvent_Log value)?  log,TResult Function( UiEvent_ConnectionState value)?  connectionState,TResult Function( UiEvent_HandshakeInitiated value)?  handshakeInitiated,TResult Function( UiEvent_HandshakeComplete value)?  handshakeComplete,TResult Function( UiEvent_Progress value)?  progress,TResult Function( UiEvent_Telemetry value)?  telemetry,TResult Function( UiEvent_FrameDrop value)?  frameDrop,TResult Function( UiEvent_Fault value)?  fault,TResult Function( UiEvent_BitrateChanged value)?  bitrateChanged,TResult Function( UiEvent_VideoFrameReceived value)?  videoFrameReceived,TResult Function( UiEvent_AudioFrameReceived value)?  audioFrameReceived,TResult Function( UiEvent_Error value)?  error,required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( ControlMessage_KeyframeRequest value)?  keyframeRequest,TResult Function( ControlMessage_MuteState value)?  muteState,TResult Function( ControlMessage_Hangup value)?  hangup,TResult Function( ControlMessage_Capabilities value)?  capabilities,TResult Function( ControlMessage_ReceiverReport value)?  receiverReport,TResult Function( ControlMessage_Custom value)?  custom,required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case UiEvent_Log() when log != null:
-return log(_that);case UiEvent_ConnectionState() when connectionState != null:
-return connectionState(_that);case UiEvent_HandshakeInitiated() when handshakeInitiated != null:
-return handshakeInitiated(_that);case UiEvent_HandshakeComplete() when handshakeComplete != null:
-return handshakeComplete(_that);case UiEvent_Progress() when progress != null:
-return progress(_that);case UiEvent_Telemetry() when telemetry != null:
-return telemetry(_that);case UiEvent_FrameDrop() when frameDrop != null:
-return frameDrop(_that);case UiEvent_Fault() when fault != null:
-return fault(_that);case UiEvent_BitrateChanged() when bitrateChanged != null:
-return bitrateChanged(_that);case UiEvent_VideoFrameReceived() when videoFrameReceived != null:
-return videoFrameReceived(_that);case UiEvent_AudioFrameReceived() when audioFrameReceived != null:
-return audioFrameReceived(_that);case UiEvent_Error() when error != null:
-return error(_that);case _:
+case ControlMessage_KeyframeRequest() when keyframeRequest != null:
+return keyframeRequest(_that);case ControlMessage_MuteState() when muteState != null:
+return muteState(_that);case ControlMessage_Hangup() when hangup != null:
+return hangup(_that);case ControlMessage_Capabilities() when capabilities != null:
+return capabilities(_that);case ControlMessage_ReceiverReport() when receiverReport != null:
+return receiverReport(_that);case ControlMessage_Custom() when custom != null:
+return custom(_that);case _:
   return orElse();
 
 }
@@ -88,22 +82,16 @@ return error(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( UiEvent_Log value)  log,required TResult Function( UiEvent_ConnectionState value)  connectionState,required TResult Function( UiEvent_HandshakeInitiated value)  handshakeInitiated,required TResult Function( UiEvent_HandshakeComplete value)  handshakeComplete,required TResult Function( UiEvent_Progress value)  progress,required TResult Function( UiEvent_Telemetry value)  telemetry,required TResult Function( UiEvent_FrameDrop value)  frameDrop,required TResult Function( UiEvent_Fault value)  fault,required TResult Function( UiEvent_BitrateChanged value)  bitrateChanged,required TResult Function( UiEvent_VideoFrameReceived value)  videoFrameReceived,required TResult Function( UiEvent_AudioFrameReceived value)  audioFrameReceived,required TResult Function( UiEvent_Error value)  error,}){
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( ControlMessage_KeyframeRequest value)  keyframeRequest,required TResult Function( ControlMessage_MuteState value)  muteState,required TResult Function( ControlMessage_Hangup value)  hangup,required TResult Function( ControlMessage_Capabilities value)  capabilities,required TResult Function( ControlMessage_ReceiverReport value)  receiverReport,required TResult Function( ControlMessage_Custom value)  custom,}){
 final _that = this;
 switch (_that) {
-case UiEvent_Log():
-return log(_that);case UiEvent_ConnectionState():
-return connectionState(_that);case UiEvent_HandshakeInitiated():
-return handshakeInitiated(_that);case UiEvent_HandshakeComplete():
-return handshakeComplete(_that);case UiEvent_Progress():
-return progress(_that);case UiEvent_Telemetry():
-return telemetry(_that);case UiEvent_FrameDrop():
-return frameDrop(_that);case UiEvent_Fault():
-return fault(_that);case UiEvent_BitrateChanged():
-return bitrateChanged(_that);case UiEvent_VideoFrameReceived():
-return videoFrameReceived(_that);case UiEvent_AudioFrameReceived():
-return audioFrameReceived(_that);case UiEvent_Error():
-return error(_that);}
+case ControlMessage_KeyframeRequest():
+return keyframeRequest(_that);case ControlMessage_MuteState():
+return muteState(_that);case ControlMessage_Hangup():
+return hangup(_that);case ControlMessage_Capabilities():
+return capabilities(_that);case ControlMessage_ReceiverReport():
+return receiverReport(_that);case ControlMessage_Custom():
+return custom(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
 ///
@@ -117,22 +105,16 @@ return error(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( UiEvent_Log value)?  log,TResult? Function( UiEvent_ConnectionState value)?  connectionState,TResult? Function( UiEvent_HandshakeInitiated value)?  handshakeInitiated,TResult? Function( UiEvent_HandshakeComplete value)?  handshakeComplete,TResult? Function( UiEvent_Progress value)?  progress,TResult? Function( UiEvent_Telemetry value)?  telemetry,TResult? Function( UiEvent_FrameDrop value)?  frameDrop,TResult? Function( UiEvent_Fault value)?  fault,TResult? Function( UiEvent_BitrateChanged value)?  bitrateChanged,TResult? Function( UiEvent_VideoFrameReceived value)?  videoFrameReceived,TResult? Function( UiEvent_AudioFrameReceived value)?  audioFrameReceived,TResult? Function( UiEvent_Error value)?  error,}){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( ControlMessage_KeyframeRequest value)?  keyframeRequest,TResult? Function( ControlMessage_MuteState value)?  muteState,TResult? Function( ControlMessage_Hangup value)?  hangup,TResult? Function( ControlMessage_Capabilities value)?  capabilities,TResult? Function( ControlMessage_ReceiverReport value)?  receiverReport,TResult? Function( ControlMessage_Custom value)?  custom,}){
 final _that = this;
 switch (_that) {
-case UiEvent_Log() when log != null:
-return log(_that);case UiEvent_ConnectionState() when connectionState != null:
-return connectionState(_that);case UiEvent_HandshakeInitiated() when handshakeInitiated != null:
-return handshakeInitiated(_that);case UiEvent_HandshakeComplete() when handshakeComplete != null:
-return handshakeComplete(_that);case UiEvent_Progress() when progress != null:
-return progress(_that);case UiEvent_Telemetry() when telemetry != null:
-return telemetry(_that);case UiEvent_FrameDrop() when frameDrop != null:
-return frameDrop(_that);case UiEvent_Fault() when fault != null:
-return fault(_that);case UiEvent_BitrateChanged() when bitrateChanged != null:
-return bitrateChanged(_that);case UiEvent_VideoFrameReceived() when videoFrameReceived != null:
-return videoFrameReceived(_that);case UiEvent_AudioFrameReceived() when audioFrameReceived != null:
-return audioFrameReceived(_that);case UiEvent_Error() when error != null:
-return error(_that);case _:
+case ControlMessage_KeyframeRequest() when keyframeRequest != null:
+return keyframeRequest(_that);case ControlMessage_MuteState() when muteState != null:
+return muteState(_that);case ControlMessage_Hangup() when hangup != null:
+return hangup(_that);case ControlMessage_Capabilities() when capabilities != null:
+return capabilities(_that);case ControlMessage_ReceiverReport() when receiverReport != null:
+return receiverReport(_that);case ControlMessage_Custom() when custom != null:
+return custom(_that);case _:
   return null;
 
 }
@@ -149,21 +131,15 @@ return error(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String msg)?  log,TResult Function( String state,  String detail)?  connectionState,TResult Function()?  handshakeInitiated,TResult Function( BigInt sessionId,  String bootstrapMode)?  handshakeComplete,TResult Function( int streamId,  BigInt frameIndex,  BigInt bytes,  BigInt frames)?  progress,TResult Function( String name,  BigInt value)?  telemetry,TResult Function( int streamId,  String reason)?  frameDrop,TResult Function( String code,  String message)?  fault,TResult Function( int bitrateBps)?  bitrateChanged,TResult Function( Uint8List data,  BigInt pts)?  videoFrameReceived,TResult Function( Uint8List data,  BigInt pts,  int framesPerPacket)?  audioFrameReceived,TResult Function( String msg)?  error,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( int streamId)?  keyframeRequest,TResult Function( bool audioMuted,  bool videoMuted)?  muteState,TResult Function( String reason)?  hangup,TResult Function( Uint8List videoCodecs,  Uint8List audioCodecs,  int maxWidth,  int maxHeight)?  capabilities,TResult Function( int streamId,  BigInt highestFrameIndex,  BigInt lostFrames,  BigInt lateFrames,  bool awaitingKeyframe,  int jitterUs)?  receiverReport,TResult Function( String kind,  Uint8List payload)?  custom,required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case UiEvent_Log() when log != null:
-return log(_that.msg);case UiEvent_ConnectionState() when connectionState != null:
-return connectionState(_that.state,_that.detail);case UiEvent_HandshakeInitiated() when handshakeInitiated != null:
-return handshakeInitiated();case UiEvent_HandshakeComplete() when handshakeComplete != null:
-return handshakeComplete(_that.sessionId,_that.bootstrapMode);case UiEvent_Progress() when progress != null:
-return progress(_that.streamId,_that.frameIndex,_that.bytes,_that.frames);case UiEvent_Telemetry() when telemetry != null:
-return telemetry(_that.name,_that.value);case UiEvent_FrameDrop() when frameDrop != null:
-return frameDrop(_that.streamId,_that.reason);case UiEvent_Fault() when fault != null:
-return fault(_that.code,_that.message);case UiEvent_BitrateChanged() when bitrateChanged != null:
-return bitrateChanged(_that.bitrateBps);case UiEvent_VideoFrameReceived() when videoFrameReceived != null:
-return videoFrameReceived(_that.data,_that.pts);case UiEvent_AudioFrameReceived() when audioFrameReceived != null:
-return audioFrameReceived(_that.data,_that.pts,_that.framesPerPacket);case UiEvent_Error() when error != null:
-return error(_that.msg);case _:
+case ControlMessage_KeyframeRequest() when keyframeRequest != null:
+return keyframeRequest(_that.streamId);case ControlMessage_MuteState() when muteState != null:
+return muteState(_that.audioMuted,_that.videoMuted);case ControlMessage_Hangup() when hangup != null:
+return hangup(_that.reason);case ControlMessage_Capabilities() when capabilities != null:
+return capabilities(_that.videoCodecs,_that.audioCodecs,_that.maxWidth,_that.maxHeight);case ControlMessage_ReceiverReport() when receiverReport != null:
+return receiverReport(_that.streamId,_that.highestFrameIndex,_that.lostFrames,_that.lateFrames,_that.awaitingKeyframe,_that.jitterUs);case ControlMessage_Custom() when custom != null:
+return custom(_that.kind,_that.payload);case _:
   return orElse();
 
 }
@@ -181,21 +157,15 @@ return error(_that.msg);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String msg)  log,required TResult Function( String state,  String detail)  connectionState,required TResult Function()  handshakeInitiated,required TResult Function( BigInt sessionId,  String bootstrapMode)  handshakeComplete,required TResult Function( int streamId,  BigInt frameIndex,  BigInt bytes,  BigInt frames)  progress,required TResult Function( String name,  BigInt value)  telemetry,required TResult Function( int streamId,  String reason)  frameDrop,required TResult Function( String code,  String message)  fault,required TResult Function( int bitrateBps)  bitrateChanged,required TResult Function( Uint8List data,  BigInt pts)  videoFrameReceived,required TResult Function( Uint8List data,  BigInt pts,  int framesPerPacket)  audioFrameReceived,required TResult Function( String msg)  error,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( int streamId)  keyframeRequest,required TResult Function( bool audioMuted,  bool videoMuted)  muteState,required TResult Function( String reason)  hangup,required TResult Function( Uint8List videoCodecs,  Uint8List audioCodecs,  int maxWidth,  int maxHeight)  capabilities,required TResult Function( int streamId,  BigInt highestFrameIndex,  BigInt lostFrames,  BigInt lateFrames,  bool awaitingKeyframe,  int jitterUs)  receiverReport,required TResult Function( String kind,  Uint8List payload)  custom,}) {final _that = this;
 switch (_that) {
-case UiEvent_Log():
-return log(_that.msg);case UiEvent_ConnectionState():
-return connectionState(_that.state,_that.detail);case UiEvent_HandshakeInitiated():
-return handshakeInitiated();case UiEvent_HandshakeComplete():
-return handshakeComplete(_that.sessionId,_that.bootstrapMode);case UiEvent_Progress():
-return progress(_that.streamId,_that.frameIndex,_that.bytes,_that.frames);case UiEvent_Telemetry():
-return telemetry(_that.name,_that.value);case UiEvent_FrameDrop():
-return frameDrop(_that.streamId,_that.reason);case UiEvent_Fault():
-return fault(_that.code,_that.message);case UiEvent_BitrateChanged():
-return bitrateChanged(_that.bitrateBps);case UiEvent_VideoFrameReceived():
-return videoFrameReceived(_that.data,_that.pts);case UiEvent_AudioFrameReceived():
-return audioFrameReceived(_that.data,_that.pts,_that.framesPerPacket);case UiEvent_Error():
-return error(_that.msg);}
+case ControlMessage_KeyframeRequest():
+return keyframeRequest(_that.streamId);case ControlMessage_MuteState():
+return muteState(_that.audioMuted,_that.videoMuted);case ControlMessage_Hangup():
+return hangup(_that.reason);case ControlMessage_Capabilities():
+return capabilities(_that.videoCodecs,_that.audioCodecs,_that.maxWidth,_that.maxHeight);case ControlMessage_ReceiverReport():
+return receiverReport(_that.streamId,_that.highestFrameIndex,_that.lostFrames,_that.lateFrames,_that.awaitingKeyframe,_that.jitterUs);case ControlMessage_Custom():
+return custom(_that.kind,_that.payload);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -209,21 +179,15 @@ return error(_that.msg);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String msg)?  log,TResult? Function( String state,  String detail)?  connectionState,TResult? Function()?  handshakeInitiated,TResult? Function( BigInt sessionId,  String bootstrapMode)?  handshakeComplete,TResult? Function( int streamId,  BigInt frameIndex,  BigInt bytes,  BigInt frames)?  progress,TResult? Function( String name,  BigInt value)?  telemetry,TResult? Function( int streamId,  String reason)?  frameDrop,TResult? Function( String code,  String message)?  fault,TResult? Function( int bitrateBps)?  bitrateChanged,TResult? Function( Uint8List data,  BigInt pts)?  videoFrameReceived,TResult? Function( Uint8List data,  BigInt pts,  int framesPerPacket)?  audioFrameReceived,TResult? Function( String msg)?  error,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( int streamId)?  keyframeRequest,TResult? Function( bool audioMuted,  bool videoMuted)?  muteState,TResult? Function( String reason)?  hangup,TResult? Function( Uint8List videoCodecs,  Uint8List audioCodecs,  int maxWidth,  int maxHeight)?  capabilities,TResult? Function( int streamId,  BigInt highestFrameIndex,  BigInt lostFrames,  BigInt lateFrames,  bool awaitingKeyframe,  int jitterUs)?  receiverReport,TResult? Function( String kind,  Uint8List payload)?  custom,}) {final _that = this;
 switch (_that) {
-case UiEvent_Log() when log != null:
-return log(_that.msg);case UiEvent_ConnectionState() when connectionState != null:
-return connectionState(_that.state,_that.detail);case UiEvent_HandshakeInitiated() when handshakeInitiated != null:
-return handshakeInitiated();case UiEvent_HandshakeComplete() when handshakeComplete != null:
-return handshakeComplete(_that.sessionId,_that.bootstrapMode);case UiEvent_Progress() when progress != null:
-return progress(_that.streamId,_that.frameIndex,_that.bytes,_that.frames);case UiEvent_Telemetry() when telemetry != null:
-return telemetry(_that.name,_that.value);case UiEvent_FrameDrop() when frameDrop != null:
-return frameDrop(_that.streamId,_that.reason);case UiEvent_Fault() when fault != null:
-return fault(_that.code,_that.message);case UiEvent_BitrateChanged() when bitrateChanged != null:
-return bitrateChanged(_that.bitrateBps);case UiEvent_VideoFrameReceived() when videoFrameReceived != null:
-return videoFrameReceived(_that.data,_that.pts);case UiEvent_AudioFrameReceived() when audioFrameReceived != null:
-return audioFrameReceived(_that.data,_that.pts,_that.framesPerPacket);case UiEvent_Error() when error != null:
-return error(_that.msg);case _:
+case ControlMessage_KeyframeRequest() when keyframeRequest != null:
+return keyframeRequest(_that.streamId);case ControlMessage_MuteState() when muteState != null:
+return muteState(_that.audioMuted,_that.videoMuted);case ControlMessage_Hangup() when hangup != null:
+return hangup(_that.reason);case ControlMessage_Capabilities() when capabilities != null:
+return capabilities(_that.videoCodecs,_that.audioCodecs,_that.maxWidth,_that.maxHeight);case ControlMessage_ReceiverReport() when receiverReport != null:
+return receiverReport(_that.streamId,_that.highestFrameIndex,_that.lostFrames,_that.lateFrames,_that.awaitingKeyframe,_that.jitterUs);case ControlMessage_Custom() when custom != null:
+return custom(_that.kind,_that.payload);case _:
   return null;
 
 }
@@ -234,43 +198,2475 @@ return error(_that.msg);case _:
 /// @nodoc
 
 
-class UiEvent_Log extends UiEvent {
-  const UiEvent_Log({required this.msg}): super._();
+class ControlMessage_KeyframeRequest extends ControlMessage {
+  const ControlMessage_KeyframeRequest({required this.streamId}): super._();
+  
+
+ final  int streamId;
+
+/// Create a copy of ControlMessage
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ControlMessage_KeyframeRequestCopyWith<ControlMessage_KeyframeRequest> get copyWith => _$ControlMessage_KeyframeRequestCopyWithImpl<ControlMessage_KeyframeRequest>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ControlMessage_KeyframeRequest&&(identical(other.streamId, streamId) || other.streamId == streamId));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,streamId);
+
+@override
+String toString() {
+  return 'ControlMessage.keyframeRequest(streamId: $streamId)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ControlMessage_KeyframeRequestCopyWith<$Res> implements $ControlMessageCopyWith<$Res> {
+  factory $ControlMessage_KeyframeRequestCopyWith(ControlMessage_KeyframeRequest value, $Res Function(ControlMessage_KeyframeRequest) _then) = _$ControlMessage_KeyframeRequestCopyWithImpl;
+@useResult
+$Res call({
+ int streamId
+});
+
+
+
+
+}
+/// @nodoc
+class _$ControlMessage_KeyframeRequestCopyWithImpl<$Res>
+    implements $ControlMessage_KeyframeRequestCopyWith<$Res> {
+  _$ControlMessage_KeyframeRequestCopyWithImpl(this._self, this._then);
+
+  final ControlMessage_KeyframeRequest _self;
+  final $Res Function(ControlMessage_KeyframeRequest) _then;
+
+/// Create a copy of ControlMessage
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? streamId = null,}) {
+  return _then(ControlMessage_KeyframeRequest(
+streamId: null == streamId ? _self.streamId : streamId // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ControlMessage_MuteState extends ControlMessage {
+  const ControlMessage_MuteState({required this.audioMuted, required this.videoMuted}): super._();
+  
+
+ final  bool audioMuted;
+ final  bool videoMuted;
+
+/// Create a copy of ControlMessage
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ControlMessage_MuteStateCopyWith<ControlMessage_MuteState> get copyWith => _$ControlMessage_MuteStateCopyWithImpl<ControlMessage_MuteState>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ControlMessage_MuteState&&(identical(other.audioMuted, audioMuted) || other.audioMuted == audioMuted)&&(identical(other.videoMuted, videoMuted) || other.videoMuted == videoMuted));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,audioMuted,videoMuted);
+
+@override
+String toString() {
+  return 'ControlMessage.muteState(audioMuted: $audioMuted, videoMuted: $videoMuted)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ControlMessage_MuteStateCopyWith<$Res> implements $ControlMessageCopyWith<$Res> {
+  factory $ControlMessage_MuteStateCopyWith(ControlMessage_MuteState value, $Res Function(ControlMessage_MuteState) _then) = _$ControlMessage_MuteStateCopyWithImpl;
+@useResult
+$Res call({
+ bool audioMuted, bool videoMuted
+});
+
+
+
+
+}
+/// @nodoc
+class _$ControlMessage_MuteStateCopyWithImpl<$Res>
+    implements $ControlMessage_MuteStateCopyWith<$Res> {
+  _$ControlMessage_MuteStateCopyWithImpl(this._self, this._then);
+
+  final ControlMessage_MuteState _self;
+  final $Res Function(ControlMessage_MuteState) _then;
+
+/// Create a copy of ControlMessage
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? audioMuted = null,Object? videoMuted = null,}) {
+  return _then(ControlMessage_MuteState(
+audioMuted: null == audioMuted ? _self.audioMuted : audioMuted // ignore: cast_nullable_to_non_nullable
+as bool,videoMuted: null == videoMuted ? _self.videoMuted : videoMuted // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ControlMessage_Hangup extends ControlMessage {
+  const ControlMessage_Hangup({required this.reason}): super._();
+  
+
+ final  String reason;
+
+/// Create a copy of ControlMessage
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ControlMessage_HangupCopyWith<ControlMessage_Hangup> get copyWith => _$ControlMessage_HangupCopyWithImpl<ControlMessage_Hangup>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ControlMessage_Hangup&&(identical(other.reason, reason) || other.reason == reason));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,reason);
+
+@override
+String toString() {
+  return 'ControlMessage.hangup(reason: $reason)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ControlMessage_HangupCopyWith<$Res> implements $ControlMessageCopyWith<$Res> {
+  factory $ControlMessage_HangupCopyWith(ControlMessage_Hangup value, $Res Function(ControlMessage_Hangup) _then) = _$ControlMessage_HangupCopyWithImpl;
+@useResult
+$Res call({
+ String reason
+});
+
+
+
+
+}
+/// @nodoc
+class _$ControlMessage_HangupCopyWithImpl<$Res>
+    implements $ControlMessage_HangupCopyWith<$Res> {
+  _$ControlMessage_HangupCopyWithImpl(this._self, this._then);
+
+  final ControlMessage_Hangup _self;
+  final $Res Function(ControlMessage_Hangup) _then;
+
+/// Create a copy of ControlMessage
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? reason = null,}) {
+  return _then(ControlMessage_Hangup(
+reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ControlMessage_Capabilities extends ControlMessage {
+  const ControlMessage_Capabilities({required this.videoCodecs, required this.audioCodecs, required this.maxWidth, required this.maxHeight}): super._();
+  
+
+ final  Uint8List videoCodecs;
+ final  Uint8List audioCodecs;
+ final  int maxWidth;
+ final  int maxHeight;
+
+/// Create a copy of ControlMessage
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ControlMessage_CapabilitiesCopyWith<ControlMessage_Capabilities> get copyWith => _$ControlMessage_CapabilitiesCopyWithImpl<ControlMessage_Capabilities>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ControlMessage_Capabilities&&const DeepCollectionEquality().equals(other.videoCodecs, videoCodecs)&&const DeepCollectionEquality().equals(other.audioCodecs, audioCodecs)&&(identical(other.maxWidth, maxWidth) || other.maxWidth == maxWidth)&&(identical(other.maxHeight, maxHeight) || other.maxHeight == maxHeight));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(videoCodecs),const DeepCollectionEquality().hash(audioCodecs),maxWidth,maxHeight);
+
+@override
+String toString() {
+  return 'ControlMessage.capabilities(videoCodecs: $videoCodecs, audioCodecs: $audioCodecs, maxWidth: $maxWidth, maxHeight: $maxHeight)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ControlMessage_CapabilitiesCopyWith<$Res> implements $ControlMessageCopyWith<$Res> {
+  factory $ControlMessage_CapabilitiesCopyWith(ControlMessage_Capabilities value, $Res Function(ControlMessage_Capabilities) _then) = _$ControlMessage_CapabilitiesCopyWithImpl;
+@useResult
+$Res call({
+ Uint8List videoCodecs, Uint8List audioCodecs, int maxWidth, int maxHeight
+});
+
+
+
+
+}
+/// @nodoc
+class _$ControlMessage_CapabilitiesCopyWithImpl<$Res>
+    implements $ControlMessage_CapabilitiesCopyWith<$Res> {
+  _$ControlMessage_CapabilitiesCopyWithImpl(this._self, this._then);
+
+  final ControlMessage_Capabilities _self;
+  final $Res Function(ControlMessage_Capabilities) _then;
+
+/// Create a copy of ControlMessage
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? videoCodecs = null,Object? audioCodecs = null,Object? maxWidth = null,Object? maxHeight = null,}) {
+  return _then(ControlMessage_Capabilities(
+videoCodecs: null == videoCodecs ? _self.videoCodecs : videoCodecs // ignore: cast_nullable_to_non_nullable
+as Uint8List,audioCodecs: null == audioCodecs ? _self.audioCodecs : audioCodecs // ignore: cast_nullable_to_non_nullable
+as Uint8List,maxWidth: null == maxWidth ? _self.maxWidth : maxWidth // ignore: cast_nullable_to_non_nullable
+as int,maxHeight: null == maxHeight ? _self.maxHeight : maxHeight // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ControlMessage_ReceiverReport extends ControlMessage {
+  const ControlMessage_ReceiverReport({required this.streamId, required this.highestFrameIndex, required this.lostFrames, required this.lateFrames, required this.awaitingKeyframe, required this.jitterUs}): super._();
+  
+
+ final  int streamId;
+ final  BigInt highestFrameIndex;
+ final  BigInt lostFrames;
+ final  BigInt lateFrames;
+ final  bool awaitingKeyframe;
+ final  int jitterUs;
+
+/// Create a copy of ControlMessage
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ControlMessage_ReceiverReportCopyWith<ControlMessage_ReceiverReport> get copyWith => _$ControlMessage_ReceiverReportCopyWithImpl<ControlMessage_ReceiverReport>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ControlMessage_ReceiverReport&&(identical(other.streamId, streamId) || other.streamId == streamId)&&(identical(other.highestFrameIndex, highestFrameIndex) || other.highestFrameIndex == highestFrameIndex)&&(identical(other.lostFrames, lostFrames) || other.lostFrames == lostFrames)&&(identical(other.lateFrames, lateFrames) || other.lateFrames == lateFrames)&&(identical(other.awaitingKeyframe, awaitingKeyframe) || other.awaitingKeyframe == awaitingKeyframe)&&(identical(other.jitterUs, jitterUs) || other.jitterUs == jitterUs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,streamId,highestFrameIndex,lostFrames,lateFrames,awaitingKeyframe,jitterUs);
+
+@override
+String toString() {
+  return 'ControlMessage.receiverReport(streamId: $streamId, highestFrameIndex: $highestFrameIndex, lostFrames: $lostFrames, lateFrames: $lateFrames, awaitingKeyframe: $awaitingKeyframe, jitterUs: $jitterUs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ControlMessage_ReceiverReportCopyWith<$Res> implements $ControlMessageCopyWith<$Res> {
+  factory $ControlMessage_ReceiverReportCopyWith(ControlMessage_ReceiverReport value, $Res Function(ControlMessage_ReceiverReport) _then) = _$ControlMessage_ReceiverReportCopyWithImpl;
+@useResult
+$Res call({
+ int streamId, BigInt highestFrameIndex, BigInt lostFrames, BigInt lateFrames, bool awaitingKeyframe, int jitterUs
+});
+
+
+
+
+}
+/// @nodoc
+class _$ControlMessage_ReceiverReportCopyWithImpl<$Res>
+    implements $ControlMessage_ReceiverReportCopyWith<$Res> {
+  _$ControlMessage_ReceiverReportCopyWithImpl(this._self, this._then);
+
+  final ControlMessage_ReceiverReport _self;
+  final $Res Function(ControlMessage_ReceiverReport) _then;
+
+/// Create a copy of ControlMessage
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? streamId = null,Object? highestFrameIndex = null,Object? lostFrames = null,Object? lateFrames = null,Object? awaitingKeyframe = null,Object? jitterUs = null,}) {
+  return _then(ControlMessage_ReceiverReport(
+streamId: null == streamId ? _self.streamId : streamId // ignore: cast_nullable_to_non_nullable
+as int,highestFrameIndex: null == highestFrameIndex ? _self.highestFrameIndex : highestFrameIndex // ignore: cast_nullable_to_non_nullable
+as BigInt,lostFrames: null == lostFrames ? _self.lostFrames : lostFrames // ignore: cast_nullable_to_non_nullable
+as BigInt,lateFrames: null == lateFrames ? _self.lateFrames : lateFrames // ignore: cast_nullable_to_non_nullable
+as BigInt,awaitingKeyframe: null == awaitingKeyframe ? _self.awaitingKeyframe : awaitingKeyframe // ignore: cast_nullable_to_non_nullable
+as bool,jitterUs: null == jitterUs ? _self.jitterUs : jitterUs // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ControlMessage_Custom extends ControlMessage {
+  const ControlMessage_Custom({required this.kind, required this.payload}): super._();
+  
+
+ final  String kind;
+ final  Uint8List payload;
+
+/// Create a copy of ControlMessage
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ControlMessage_CustomCopyWith<ControlMessage_Custom> get copyWith => _$ControlMessage_CustomCopyWithImpl<ControlMessage_Custom>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ControlMessage_Custom&&(identical(other.kind, kind) || other.kind == kind)&&const DeepCollectionEquality().equals(other.payload, payload));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,kind,const DeepCollectionEquality().hash(payload));
+
+@override
+String toString() {
+  return 'ControlMessage.custom(kind: $kind, payload: $payload)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ControlMessage_CustomCopyWith<$Res> implements $ControlMessageCopyWith<$Res> {
+  factory $ControlMessage_CustomCopyWith(ControlMessage_Custom value, $Res Function(ControlMessage_Custom) _then) = _$ControlMessage_CustomCopyWithImpl;
+@useResult
+$Res call({
+ String kind, Uint8List payload
+});
+
+
+
+
+}
+/// @nodoc
+class _$ControlMessage_CustomCopyWithImpl<$Res>
+    implements $ControlMessage_CustomCopyWith<$Res> {
+  _$ControlMessage_CustomCopyWithImpl(this._self, this._then);
+
+  final ControlMessage_Custom _self;
+  final $Res Function(ControlMessage_Custom) _then;
+
+/// Create a copy of ControlMessage
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? kind = null,Object? payload = null,}) {
+  return _then(ControlMessage_Custom(
+kind: null == kind ? _self.kind : kind // ignore: cast_nullable_to_non_nullable
+as String,payload: null == payload ? _self.payload : payload // ignore: cast_nullable_to_non_nullable
+as Uint8List,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$RemoteCommand {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RemoteCommand);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RemoteCommand()';
+}
+
+
+}
+
+/// @nodoc
+class $RemoteCommandCopyWith<$Res>  {
+$RemoteCommandCopyWith(RemoteCommand _, $Res Function(RemoteCommand) __);
+}
+
+
+/// Adds pattern-matching-related methods to [RemoteCommand].
+extension RemoteCommandPatterns on RemoteCommand {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( RemoteCommand_SwitchCamera value)?  switchCamera,TResult Function( RemoteCommand_SetTorch value)?  setTorch,TResult Function( RemoteCommand_SetBitrate value)?  setBitrate,TResult Function( RemoteCommand_StartCapture value)?  startCapture,TResult Function( RemoteCommand_StopCapture value)?  stopCapture,TResult Function( RemoteCommand_RequestDebugReport value)?  requestDebugReport,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case RemoteCommand_SwitchCamera() when switchCamera != null:
+return switchCamera(_that);case RemoteCommand_SetTorch() when setTorch != null:
+return setTorch(_that);case RemoteCommand_SetBitrate() when setBitrate != null:
+return setBitrate(_that);case RemoteCommand_StartCapture() when startCapture != null:
+return startCapture(_that);case RemoteCommand_StopCapture() when stopCapture != null:
+return stopCapture(_that);case RemoteCommand_RequestDebugReport() when requestDebugReport != null:
+return requestDebugReport(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( RemoteCommand_SwitchCamera value)  switchCamera,required TResult Function( RemoteCommand_SetTorch value)  setTorch,required TResult Function( RemoteCommand_SetBitrate value)  setBitrate,required TResult Function( RemoteCommand_StartCapture value)  startCapture,required TResult Function( RemoteCommand_StopCapture value)  stopCapture,required TResult Function( RemoteCommand_RequestDebugReport value)  requestDebugReport,}){
+final _that = this;
+switch (_that) {
+case RemoteCommand_SwitchCamera():
+return switchCamera(_that);case RemoteCommand_SetTorch():
+return setTorch(_that);case RemoteCommand_SetBitrate():
+return setBitrate(_that);case RemoteCommand_StartCapture():
+return startCapture(_that);case RemoteCommand_StopCapture():
+return stopCapture(_that);case RemoteCommand_RequestDebugReport():
+return requestDebugReport(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( RemoteCommand_SwitchCamera value)?  switchCamera,TResult? Function( RemoteCommand_SetTorch value)?  setTorch,TResult? Function( RemoteCommand_SetBitrate value)?  setBitrate,TResult? Function( RemoteCommand_StartCapture value)?  startCapture,TResult? Function( RemoteCommand_StopCapture value)?  stopCapture,TResult? Function( RemoteCommand_RequestDebugReport value)?  requestDebugReport,}){
+final _that = this;
+switch (_that) {
+case RemoteCommand_SwitchCamera() when switchCamera != null:
+return switchCamera(_that);case RemoteCommand_SetTorch() when setTorch != null:
+return setTorch(_that);case RemoteCommand_SetBitrate() when setBitrate != null:
+return setBitrate(_that);case RemoteCommand_StartCapture() when startCapture != null:
+return startCapture(_that);case RemoteCommand_StopCapture() when stopCapture != null:
+return stopCapture(_that);case RemoteCommand_RequestDebugReport() when requestDebugReport != null:
+return requestDebugReport(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  switchCamera,TResult Function( bool enabled)?  setTorch,TResult Function( int bitrateBps)?  setBitrate,TResult Function()?  startCapture,TResult Function()?  stopCapture,TResult Function()?  requestDebugReport,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case RemoteCommand_SwitchCamera() when switchCamera != null:
+return switchCamera();case RemoteCommand_SetTorch() when setTorch != null:
+return setTorch(_that.enabled);case RemoteCommand_SetBitrate() when setBitrate != null:
+return setBitrate(_that.bitrateBps);case RemoteCommand_StartCapture() when startCapture != null:
+return startCapture();case RemoteCommand_StopCapture() when stopCapture != null:
+return stopCapture();case RemoteCommand_RequestDebugReport() when requestDebugReport != null:
+return requestDebugReport();case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  switchCamera,required TResult Function( bool enabled)  setTorch,required TResult Function( int bitrateBps)  setBitrate,required TResult Function()  startCapture,required TResult Function()  stopCapture,required TResult Function()  requestDebugReport,}) {final _that = this;
+switch (_that) {
+case RemoteCommand_SwitchCamera():
+return switchCamera();case RemoteCommand_SetTorch():
+return setTorch(_that.enabled);case RemoteCommand_SetBitrate():
+return setBitrate(_that.bitrateBps);case RemoteCommand_StartCapture():
+return startCapture();case RemoteCommand_StopCapture():
+return stopCapture();case RemoteCommand_RequestDebugReport():
+return requestDebugReport();}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  switchCamera,TResult? Function( bool enabled)?  setTorch,TResult? Function( int bitrateBps)?  setBitrate,TResult? Function()?  startCapture,TResult? Function()?  stopCapture,TResult? Function()?  requestDebugReport,}) {final _that = this;
+switch (_that) {
+case RemoteCommand_SwitchCamera() when switchCamera != null:
+return switchCamera();case RemoteCommand_SetTorch() when setTorch != null:
+return setTorch(_that.enabled);case RemoteCommand_SetBitrate() when setBitrate != null:
+return setBitrate(_that.bitrateBps);case RemoteCommand_StartCapture() when startCapture != null:
+return startCapture();case RemoteCommand_StopCapture() when stopCapture != null:
+return stopCapture();case RemoteCommand_RequestDebugReport() when requestDebugReport != null:
+return requestDebugReport();case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class RemoteCommand_SwitchCamera extends RemoteCommand {
+  const RemoteCommand_SwitchCamera(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RemoteCommand_SwitchCamera);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RemoteCommand.switchCamera()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class RemoteCommand_SetTorch extends RemoteCommand {
+  const RemoteCommand_SetTorch({required this.enabled}): super._();
+  
+
+ final  bool enabled;
+
+/// Create a copy of RemoteCommand
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$RemoteCommand_SetTorchCopyWith<RemoteCommand_SetTorch> get copyWith => _$RemoteCommand_SetTorchCopyWithImpl<RemoteCommand_SetTorch>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RemoteCommand_SetTorch&&(identical(other.enabled, enabled) || other.enabled == enabled));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,enabled);
+
+@override
+String toString() {
+  return 'RemoteCommand.setTorch(enabled: $enabled)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $RemoteCommand_SetTorchCopyWith<$Res> implements $RemoteCommandCopyWith<$Res> {
+  factory $RemoteCommand_SetTorchCopyWith(RemoteCommand_SetTorch value, $Res Function(RemoteCommand_SetTorch) _then) = _$RemoteCommand_SetTorchCopyWithImpl;
+@useResult
+$Res call({
+ bool enabled
+});
+
+
+
+
+}
+/// @nodoc
+class _$RemoteCommand_SetTorchCopyWithImpl<$Res>
+    implements $RemoteCommand_SetTorchCopyWith<$Res> {
+  _$RemoteCommand_SetTorchCopyWithImpl(this._self, this._then);
+
+  final RemoteCommand_SetTorch _self;
+  final $Res Function(RemoteCommand_SetTorch) _then;
+
+/// Create a copy of RemoteCommand
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? enabled = null,}) {
+  return _then(RemoteCommand_SetTorch(
+enabled: null == enabled ? _self.enabled : enabled // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class RemoteCommand_SetBitrate extends RemoteCommand {
+  const RemoteCommand_SetBitrate({required this.bitrateBps}): super._();
+  
+
+ final  int bitrateBps;
+
+/// Create a copy of RemoteCommand
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$RemoteCommand_SetBitrateCopyWith<RemoteCommand_SetBitrate> get copyWith => _$RemoteCommand_SetBitrateCopyWithImpl<RemoteCommand_SetBitrate>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RemoteCommand_SetBitrate&&(identical(other.bitrateBps, bitrateBps) || other.bitrateBps == bitrateBps));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,bitrateBps);
+
+@override
+String toString() {
+  return 'RemoteCommand.setBitrate(bitrateBps: $bitrateBps)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $RemoteCommand_SetBitrateCopyWith<$Res> implements $RemoteCommandCopyWith<$Res> {
+  factory $RemoteCommand_SetBitrateCopyWith(RemoteCommand_SetBitrate value, $Res Function(RemoteCommand_SetBitrate) _then) = _$RemoteCommand_SetBitrateCopyWithImpl;
+@useResult
+$Res call({
+ int bitrateBps
+});
+
+
+
+
+}
+/// @nodoc
+class _$RemoteCommand_SetBitrateCopyWithImpl<$Res>
+    implements $RemoteCommand_SetBitrateCopyWith<$Res> {
+  _$RemoteCommand_SetBitrateCopyWithImpl(this._self, this._then);
+
+  final RemoteCommand_SetBitrate _self;
+  final $Res Function(RemoteCommand_SetBitrate) _then;
+
+/// Create a copy of RemoteCommand
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? bitrateBps = null,}) {
+  return _then(RemoteCommand_SetBitrate(
+bitrateBps: null == bitrateBps ? _self.bitrateBps : bitrateBps // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class RemoteCommand_StartCapture extends RemoteCommand {
+  const RemoteCommand_StartCapture(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RemoteCommand_StartCapture);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RemoteCommand.startCapture()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class RemoteCommand_StopCapture extends RemoteCommand {
+  const RemoteCommand_StopCapture(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RemoteCommand_StopCapture);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RemoteCommand.stopCapture()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class RemoteCommand_RequestDebugReport extends RemoteCommand {
+  const RemoteCommand_RequestDebugReport(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RemoteCommand_RequestDebugReport);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RemoteCommand.requestDebugReport()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+mixin _$UiEvent {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'UiEvent()';
+}
+
+
+}
+
+/// @nodoc
+class $UiEventCopyWith<$Res>  {
+$UiEventCopyWith(UiEvent _, $Res Function(UiEvent) __);
+}
+
+
+/// Adds pattern-matching-related methods to [UiEvent].
+extension UiEventPatterns on UiEvent {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( UiEvent_Log value)?  log,TResult Function( UiEvent_ConnectionState value)?  connectionState,TResult Function( UiEvent_HandshakeInitiated value)?  handshakeInitiated,TResult Function( UiEvent_HandshakeComplete value)?  handshakeComplete,TResult Function( UiEvent_Progress value)?  progress,TResult Function( UiEvent_Telemetry value)?  telemetry,TResult Function( UiEvent_FrameDrop value)?  frameDrop,TResult Function( UiEvent_Fault value)?  fault,TResult Function( UiEvent_BitrateChanged value)?  bitrateChanged,TResult Function( UiEvent_VideoFrameReceived value)?  videoFrameReceived,TResult Function( UiEvent_AudioFrameReceived value)?  audioFrameReceived,TResult Function( UiEvent_AudioConcealment value)?  audioConcealment,TResult Function( UiEvent_PcmAudioReceived value)?  pcmAudioReceived,TResult Function( UiEvent_DecodedVideoFrame value)?  decodedVideoFrame,TResult Function( UiEvent_StreamFormat value)?  streamFormat,TResult Function( UiEvent_Congestion value)?  congestion,TResult Function( UiEvent_TemporalLayers value)?  temporalLayers,TResult Function( UiEvent_AudioGap value)?  audioGap,TResult Function( UiEvent_AudioActivity value)?  audioActivity,TResult Function( UiEvent_CodecNegotiated value)?  codecNegotiated,TResult Function( UiEvent_AudioCodecNegotiated value)?  audioCodecNegotiated,TResult Function( UiEvent_ControlMessageReceived value)?  controlMessageReceived,TResult Function( UiEvent_DataReceived value)?  dataReceived,TResult Function( UiEvent_FileTransferStarted value)?  fileTransferStarted,TResult Function( UiEvent_FileTransferProgress value)?  fileTransferProgress,TResult Function( UiEvent_FileTransferFinished value)?  fileTransferFinished,TResult Function( UiEvent_RemoteCommand value)?  remoteCommand,TResult Function( UiEvent_RemoteCommandReply value)?  remoteCommandReply,TResult Function( UiEvent_PeerMediaState value)?  peerMediaState,TResult Function( UiEvent_Error value)?  error,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case UiEvent_Log() when log != null:
+return log(_that);case UiEvent_ConnectionState() when connectionState != null:
+return connectionState(_that);case UiEvent_HandshakeInitiated() when handshakeInitiated != null:
+return handshakeInitiated(_that);case UiEvent_HandshakeComplete() when handshakeComplete != null:
+return handshakeComplete(_that);case UiEvent_Progress() when progress != null:
+return progress(_that);case UiEvent_Telemetry() when telemetry != null:
+return telemetry(_that);case UiEvent_FrameDrop() when frameDrop != null:
+return frameDrop(_that);case UiEvent_Fault() when fault != null:
+return fault(_that);case UiEvent_BitrateChanged() when bitrateChanged != null:
+return bitrateChanged(_that);case UiEvent_VideoFrameReceived() when videoFrameReceived != null:
+return videoFrameReceived(_that);case UiEvent_AudioFrameReceived() when audioFrameReceived != null:
+return audioFrameReceived(_that);case UiEvent_AudioConcealment() when audioConcealment != null:
+return audioConcealment(_that);case UiEvent_PcmAudioReceived() when pcmAudioReceived != null:
+return pcmAudioReceived(_that);case UiEvent_DecodedVideoFrame() when decodedVideoFrame != null:
+return decodedVideoFrame(_that);case UiEvent_StreamFormat() when streamFormat != null:
+return streamFormat(_that);case UiEvent_Congestion() when congestion != null:
+return congestion(_that);case UiEvent_TemporalLayers() when temporalLayers != null:
+return temporalLayers(_that);case UiEvent_AudioGap() when audioGap != null:
+return audioGap(_that);case UiEvent_AudioActivity() when audioActivity != null:
+return audioActivity(_that);case UiEvent_CodecNegotiated() when codecNegotiated != null:
+return codecNegotiated(_that);case UiEvent_AudioCodecNegotiated() when audioCodecNegotiated != null:
+return audioCodecNegotiated(_that);case UiEvent_ControlMessageReceived() when controlMessageReceived != null:
+return controlMessageReceived(_that);case UiEvent_DataReceived() when dataReceived != null:
+return dataReceived(_that);case UiEvent_FileTransferStarted() when fileTransferStarted != null:
+return fileTransferStarted(_that);case UiEvent_FileTransferProgress() when fileTransferProgress != null:
+return fileTransferProgress(_that);case UiEvent_FileTransferFinished() when fileTransferFinished != null:
+return fileTransferFinished(_that);case UiEvent_RemoteCommand() when remoteCommand != null:
+return remoteCommand(_that);case UiEvent_RemoteCommandReply() when remoteCommandReply != null:
+return remoteCommandReply(_that);case UiEvent_PeerMediaState() when peerMediaState != null:
+return peerMediaState(_that);case UiEvent_Error() when error != null:
+return error(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( UiEvent_Log value)  log,required TResult Function( UiEvent_ConnectionState value)  connectionState,required TResult Function( UiEvent_HandshakeInitiated value)  handshakeInitiated,required TResult Function( UiEvent_HandshakeComplete value)  handshakeComplete,required TResult Function( UiEvent_Progress value)  progress,required TResult Function( UiEvent_Telemetry value)  telemetry,required TResult Function( UiEvent_FrameDrop value)  frameDrop,required TResult Function( UiEvent_Fault value)  fault,required TResult Function( UiEvent_BitrateChanged value)  bitrateChanged,required TResult Function( UiEvent_VideoFrameReceived value)  videoFrameReceived,required TResult Function( UiEvent_AudioFrameReceived value)  audioFrameReceived,required TResult Function( UiEvent_AudioConcealment value)  audioConcealment,required TResult Function( UiEvent_PcmAudioReceived value)  pcmAudioReceived,required TResult Function( UiEvent_DecodedVideoFrame value)  decodedVideoFrame,required TResult Function( UiEvent_StreamFormat value)  streamFormat,required TResult Function( UiEvent_Congestion value)  congestion,required TResult Function( UiEvent_TemporalLayers value)  temporalLayers,required TResult Function( UiEvent_AudioGap value)  audioGap,required TResult Function( UiEvent_AudioActivity value)  audioActivity,required TResult Function( UiEvent_CodecNegotiated value)  codecNegotiated,required TResult Function( UiEvent_AudioCodecNegotiated value)  audioCodecNegotiated,required TResult Function( UiEvent_ControlMessageReceived value)  controlMessageReceived,required TResult Function( UiEvent_DataReceived value)  dataReceived,required TResult Function( UiEvent_FileTransferStarted value)  fileTransferStarted,required TResult Function( UiEvent_FileTransferProgress value)  fileTransferProgress,required TResult Function( UiEvent_FileTransferFinished value)  fileTransferFinished,required TResult Function( UiEvent_RemoteCommand value)  remoteCommand,required TResult Function( UiEvent_RemoteCommandReply value)  remoteCommandReply,required TResult Function( UiEvent_PeerMediaState value)  peerMediaState,required TResult Function( UiEvent_Error value)  error,}){
+final _that = this;
+switch (_that) {
+case UiEvent_Log():
+return log(_that);case UiEvent_ConnectionState():
+return connectionState(_that);case UiEvent_HandshakeInitiated():
+return handshakeInitiated(_that);case UiEvent_HandshakeComplete():
+return handshakeComplete(_that);case UiEvent_Progress():
+return progress(_that);case UiEvent_Telemetry():
+return telemetry(_that);case UiEvent_FrameDrop():
+return frameDrop(_that);case UiEvent_Fault():
+return fault(_that);case UiEvent_BitrateChanged():
+return bitrateChanged(_that);case UiEvent_VideoFrameReceived():
+return videoFrameReceived(_that);case UiEvent_AudioFrameReceived():
+return audioFrameReceived(_that);case UiEvent_AudioConcealment():
+return audioConcealment(_that);case UiEvent_PcmAudioReceived():
+return pcmAudioReceived(_that);case UiEvent_DecodedVideoFrame():
+return decodedVideoFrame(_that);case UiEvent_StreamFormat():
+return streamFormat(_that);case UiEvent_Congestion():
+return congestion(_that);case UiEvent_TemporalLayers():
+return temporalLayers(_that);case UiEvent_AudioGap():
+return audioGap(_that);case UiEvent_AudioActivity():
+return audioActivity(_that);case UiEvent_CodecNegotiated():
+return codecNegotiated(_that);case UiEvent_AudioCodecNegotiated():
+return audioCodecNegotiated(_that);case UiEvent_ControlMessageReceived():
+return controlMessageReceived(_that);case UiEvent_DataReceived():
+return dataReceived(_that);case UiEvent_FileTransferStarted():
+return fileTransferStarted(_that);case UiEvent_FileTransferProgress():
+return fileTransferProgress(_that);case UiEvent_FileTransferFinished():
+return fileTransferFinished(_that);case UiEvent_RemoteCommand():
+return remoteCommand(_that);case UiEvent_RemoteCommandReply():
+return remoteCommandReply(_that);case UiEvent_PeerMediaState():
+return peerMediaState(_that);case UiEvent_Error():
+return error(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( UiEvent_Log value)?  log,TResult? Function( UiEvent_ConnectionState value)?  connectionState,TResult? Function( UiEvent_HandshakeInitiated value)?  handshakeInitiated,TResult? Function( UiEvent_HandshakeComplete value)?  handshakeComplete,TResult? Function( UiEvent_Progress value)?  progress,TResult? Function( UiEvent_Telemetry value)?  telemetry,TResult? Function( UiEvent_FrameDrop value)?  frameDrop,TResult? Function( UiEvent_Fault value)?  fault,TResult? Function( UiEvent_BitrateChanged value)?  bitrateChanged,TResult? Function( UiEvent_VideoFrameReceived value)?  videoFrameReceived,TResult? Function( UiEvent_AudioFrameReceived value)?  audioFrameReceived,TResult? Function( UiEvent_AudioConcealment value)?  audioConcealment,TResult? Function( UiEvent_PcmAudioReceived value)?  pcmAudioReceived,TResult? Function( UiEvent_DecodedVideoFrame value)?  decodedVideoFrame,TResult? Function( UiEvent_StreamFormat value)?  streamFormat,TResult? Function( UiEvent_Congestion value)?  congestion,TResult? Function( UiEvent_TemporalLayers value)?  temporalLayers,TResult? Function( UiEvent_AudioGap value)?  audioGap,TResult? Function( UiEvent_AudioActivity value)?  audioActivity,TResult? Function( UiEvent_CodecNegotiated value)?  codecNegotiated,TResult? Function( UiEvent_AudioCodecNegotiated value)?  audioCodecNegotiated,TResult? Function( UiEvent_ControlMessageReceived value)?  controlMessageReceived,TResult? Function( UiEvent_DataReceived value)?  dataReceived,TResult? Function( UiEvent_FileTransferStarted value)?  fileTransferStarted,TResult? Function( UiEvent_FileTransferProgress value)?  fileTransferProgress,TResult? Function( UiEvent_FileTransferFinished value)?  fileTransferFinished,TResult? Function( UiEvent_RemoteCommand value)?  remoteCommand,TResult? Function( UiEvent_RemoteCommandReply value)?  remoteCommandReply,TResult? Function( UiEvent_PeerMediaState value)?  peerMediaState,TResult? Function( UiEvent_Error value)?  error,}){
+final _that = this;
+switch (_that) {
+case UiEvent_Log() when log != null:
+return log(_that);case UiEvent_ConnectionState() when connectionState != null:
+return connectionState(_that);case UiEvent_HandshakeInitiated() when handshakeInitiated != null:
+return handshakeInitiated(_that);case UiEvent_HandshakeComplete() when handshakeComplete != null:
+return handshakeComplete(_that);case UiEvent_Progress() when progress != null:
+return progress(_that);case UiEvent_Telemetry() when telemetry != null:
+return telemetry(_that);case UiEvent_FrameDrop() when frameDrop != null:
+return frameDrop(_that);case UiEvent_Fault() when fault != null:
+return fault(_that);case UiEvent_BitrateChanged() when bitrateChanged != null:
+return bitrateChanged(_that);case UiEvent_VideoFrameReceived() when videoFrameReceived != null:
+return videoFrameReceived(_that);case UiEvent_AudioFrameReceived() when audioFrameReceived != null:
+return audioFrameReceived(_that);case UiEvent_AudioConcealment() when audioConcealment != null:
+return audioConcealment(_that);case UiEvent_PcmAudioReceived() when pcmAudioReceived != null:
+return pcmAudioReceived(_that);case UiEvent_DecodedVideoFrame() when decodedVideoFrame != null:
+return decodedVideoFrame(_that);case UiEvent_StreamFormat() when streamFormat != null:
+return streamFormat(_that);case UiEvent_Congestion() when congestion != null:
+return congestion(_that);case UiEvent_TemporalLayers() when temporalLayers != null:
+return temporalLayers(_that);case UiEvent_AudioGap() when audioGap != null:
+return audioGap(_that);case UiEvent_AudioActivity() when audioActivity != null:
+return audioActivity(_that);case UiEvent_CodecNegotiated() when codecNegotiated != null:
+return codecNegotiated(_that);case UiEvent_AudioCodecNegotiated() when audioCodecNegotiated != null:
+return audioCodecNegotiated(_that);case UiEvent_ControlMessageReceived() when controlMessageReceived != null:
+return controlMessageReceived(_that);case UiEvent_DataReceived() when dataReceived != null:
+return dataReceived(_that);case UiEvent_FileTransferStarted() when fileTransferStarted != null:
+return fileTransferStarted(_that);case UiEvent_FileTransferProgress() when fileTransferProgress != null:
+return fileTransferProgress(_that);case UiEvent_FileTransferFinished() when fileTransferFinished != null:
+return fileTransferFinished(_that);case UiEvent_RemoteCommand() when remoteCommand != null:
+return remoteCommand(_that);case UiEvent_RemoteCommandReply() when remoteCommandReply != null:
+return remoteCommandReply(_that);case UiEvent_PeerMediaState() when peerMediaState != null:
+return peerMediaState(_that);case UiEvent_Error() when error != null:
+return error(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String msg)?  log,TResult Function( String state,  String detail)?  connectionState,TResult Function()?  handshakeInitiated,TResult Function( BigInt sessionId,  String bootstrapMode)?  handshakeComplete,TResult Function( int streamId,  BigInt frameIndex,  BigInt bytes,  BigInt frames)?  progress,TResult Function( String name,  BigInt value)?  telemetry,TResult Function( int streamId,  String reason)?  frameDrop,TResult Function( String code,  String message)?  fault,TResult Function( int bitrateBps)?  bitrateChanged,TResult Function( Uint8List data,  BigInt pts)?  videoFrameReceived,TResult Function( Uint8List data,  BigInt pts,  int framesPerPacket)?  audioFrameReceived,TResult Function( int streamId,  BigInt pts,  int framesPerPacket)?  audioConcealment,TResult Function( int streamId,  BigInt pts,  int sampleRate,  int channels,  int framesPerPacket,  Int16List samples,  bool concealed)?  pcmAudioReceived,TResult Function( int streamId,  BigInt pts,  int width,  int height,  DecodedVideoFormat format,  Uint8List data)?  decodedVideoFrame,TResult Function( int streamId,  String codec,  int width,  int height,  int profileIdc,  bool highTier,  int levelIdc,  int chromaFormatIdc,  int bitDepthLuma,  int bitDepthChroma,  int frameRateNum,  int frameRateDen)?  streamFormat,TResult Function( int queueDepth,  int queueCapacity,  int estimatedSendDelayMs,  String recommendedAction)?  congestion,TResult Function( int streamId,  int activeLayers,  int totalLayers)?  temporalLayers,TResult Function( int streamId,  BigInt expectedPts,  BigInt missingUs,  int missingPackets,  int concealmentPackets)?  audioGap,TResult Function( int streamId,  bool local,  bool speaking)?  audioActivity,TResult Function( int codec,  String codecName,  bool peerNegotiated)?  codecNegotiated,TResult Function( int codec,  String codecName,  int sampleRate,  int channels,  Uint8List codecConfig,  bool peerNegotiated)?  audioCodecNegotiated,TResult Function( ControlMessage message)?  controlMessageReceived,TResult Function( String channel,  Uint8List data,  bool reliable)?  dataReceived,TResult Function( int transferId,  bool incoming,  String name,  BigInt size)?  fileTransferStarted,TResult Function( int transferId,  bool incoming,  BigInt bytes,  BigInt size)?  fileTransferProgress,TResult Function( int transferId,  bool incoming,  String name,  FileTransferOutcome outcome,  String path,  String detail)?  fileTransferFinished,TResult Function( int commandId,  RemoteCommand command)?  remoteCommand,TResult Function( int commandId,  RemoteCommandStatus status,  String detail)?  remoteCommandReply,TResult Function( bool microphoneMuted,  bool cameraOff,  bool audioOnly,  bool screenShare)?  peerMediaState,TResult Function( String msg)?  error,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case UiEvent_Log() when log != null:
+return log(_that.msg);case UiEvent_ConnectionState() when connectionState != null:
+return connectionState(_that.state,_that.detail);case UiEvent_HandshakeInitiated() when handshakeInitiated != null:
+return handshakeInitiated();case UiEvent_HandshakeComplete() when handshakeComplete != null:
+return handshakeComplete(_that.sessionId,_that.bootstrapMode);case UiEvent_Progress() when progress != null:
+return progress(_that.streamId,_that.frameIndex,_that.bytes,_that.frames);case UiEvent_Telemetry() when telemetry != null:
+return telemetry(_that.name,_that.value);case UiEvent_FrameDrop() when frameDrop != null:
+return frameDrop(_that.streamId,_that.reason);case UiEvent_Fault() when fault != null:
+return fault(_that.code,_that.message);case UiEvent_BitrateChanged() when bitrateChanged != null:
+return bitrateChanged(_that.bitrateBps);case UiEvent_VideoFrameReceived() when videoFrameReceived != null:
+return videoFrameReceived(_that.data,_that.pts);case UiEvent_AudioFrameReceived() when audioFrameReceived != null:
+return audioFrameReceived(_that.data,_that.pts,_that.framesPerPacket);case UiEvent_AudioConcealment() when audioConcealment != null:
+return audioConcealment(_that.streamId,_that.pts,_that.framesPerPacket);case UiEvent_PcmAudioReceived() when pcmAudioReceived != null:
+return pcmAudioReceived(_that.streamId,_that.pts,_that.sampleRate,_that.channels,_that.framesPerPacket,_that.samples,_that.concealed);case UiEvent_DecodedVideoFrame() when decodedVideoFrame != null:
+return decodedVideoFrame(_that.streamId,_that.pts,_that.width,_that.height,_that.format,_that.data);case UiEvent_StreamFormat() when streamFormat != null:
+return streamFormat(_that.streamId,_that.codec,_that.width,_that.height,_that.profileIdc,_that.highTier,_that.levelIdc,_that.chromaFormatIdc,_that.bitDepthLuma,_that.bitDepthChroma,_that.frameRateNum,_that.frameRateDen);case UiEvent_Congestion() when congestion != null:
+return congestion(_that.queueDepth,_that.queueCapacity,_that.estimatedSendDelayMs,_that.recommendedAction);case UiEvent_TemporalLayers() when temporalLayers != null:
+return temporalLayers(_that.streamId,_that.activeLayers,_that.totalLayers);case UiEvent_AudioGap() when audioGap != null:
+return audioGap(_that.streamId,_that.expectedPts,_that.missingUs,_that.missingPackets,_that.concealmentPackets);case UiEvent_AudioActivity() when audioActivity != null:
+return audioActivity(_that.streamId,_that.local,_that.speaking);case UiEvent_CodecNegotiated() when codecNegotiated != null:
+return codecNegotiated(_that.codec,_that.codecName,_that.peerNegotiated);case UiEvent_AudioCodecNegotiated() when audioCodecNegotiated != null:
+return audioCodecNegotiated(_that.codec,_that.codecName,_that.sampleRate,_that.channels,_that.codecConfig,_that.peerNegotiated);case UiEvent_ControlMessageReceived() when controlMessageReceived != null:
+return controlMessageReceived(_that.message);case UiEvent_DataReceived() when dataReceived != null:
+return dataReceived(_that.channel,_that.data,_that.reliable);case UiEvent_FileTransferStarted() when fileTransferStarted != null:
+return fileTransferStarted(_that.transferId,_that.incoming,_that.name,_that.size);case UiEvent_FileTransferProgress() when fileTransferProgress != null:
+return fileTransferProgress(_that.transferId,_that.incoming,_that.bytes,_that.size);case UiEvent_FileTransferFinished() when fileTransferFinished != null:
+return fileTransferFinished(_that.transferId,_that.incoming,_that.name,_that.outcome,_that.path,_that.detail);case UiEvent_RemoteCommand() when remoteCommand != null:
+return remoteCommand(_that.commandId,_that.command);case UiEvent_RemoteCommandReply() when remoteCommandReply != null:
+return remoteCommandReply(_that.commandId,_that.status,_that.detail);case UiEvent_PeerMediaState() when peerMediaState != null:
+return peerMediaState(_that.microphoneMuted,_that.cameraOff,_that.audioOnly,_that.screenShare);case UiEvent_Error() when error != null:
+return error(_that.msg);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String msg)  log,required TResult Function( String state,  String detail)  connectionState,required TResult Function()  handshakeInitiated,required TResult Function( BigInt sessionId,  String bootstrapMode)  handshakeComplete,required TResult Function( int streamId,  BigInt frameIndex,  BigInt bytes,  BigInt frames)  progress,required TResult Function( String name,  BigInt value)  telemetry,required TResult Function( int streamId,  String reason)  frameDrop,required TResult Function( String code,  String message)  fault,required TResult Function( int bitrateBps)  bitrateChanged,required TResult Function( Uint8List data,  BigInt pts)  videoFrameReceived,required TResult Function( Uint8List data,  BigInt pts,  int framesPerPacket)  audioFrameReceived,required TResult Function( int streamId,  BigInt pts,  int framesPerPacket)  audioConcealment,required TResult Function( int streamId,  BigInt pts,  int sampleRate,  int channels,  int framesPerPacket,  Int16List samples,  bool concealed)  pcmAudioReceived,required TResult Function( int streamId,  BigInt pts,  int width,  int height,  DecodedVideoFormat format,  Uint8List data)  decodedVideoFrame,required TResult Function( int streamId,  String codec,  int width,  int height,  int profileIdc,  bool highTier,  int levelIdc,  int chromaFormatIdc,  int bitDepthLuma,  int bitDepthChroma,  int frameRateNum,  int frameRateDen)  streamFormat,required TResult Function( int queueDepth,  int queueCapacity,  int estimatedSendDelayMs,  String recommendedAction)  congestion,required TResult Function( int streamId,  int activeLayers,  int totalLayers)  temporalLayers,required TResult Function( int streamId,  BigInt expectedPts,  BigInt missingUs,  int missingPackets,  int concealmentPackets)  audioGap,required TResult Function( int streamId,  bool local,  bool speaking)  audioActivity,required TResult Function( int codec,  String codecName,  bool peerNegotiated)  codecNegotiated,required TResult Function( int codec,  String codecName,  int sampleRate,  int channels,  Uint8List codecConfig,  bool peerNegotiated)  audioCodecNegotiated,required TResult Function( ControlMessage message)  controlMessageReceived,required TResult Function( String channel,  Uint8List data,  bool reliable)  dataReceived,required TResult Function( int transferId,  bool incoming,  String name,  BigInt size)  fileTransferStarted,required TResult Function( int transferId,  bool incoming,  BigInt bytes,  BigInt size)  fileTransferProgress,required TResult Function( int transferId,  bool incoming,  String name,  FileTransferOutcome outcome,  String path,  String detail)  fileTransferFinished,required TResult Function( int commandId,  RemoteCommand command)  remoteCommand,required TResult Function( int commandId,  RemoteCommandStatus status,  String detail)  remoteCommandReply,required TResult Function( bool microphoneMuted,  bool cameraOff,  bool audioOnly,  bool screenShare)  peerMediaState,required TResult Function( String msg)  error,}) {final _that = this;
+switch (_that) {
+case UiEvent_Log():
+return log(_that.msg);case UiEvent_ConnectionState():
+return connectionState(_that.state,_that.detail);case UiEvent_HandshakeInitiated():
+return handshakeInitiated();case UiEvent_HandshakeComplete():
+return handshakeComplete(_that.sessionId,_that.bootstrapMode);case UiEvent_Progress():
+return progress(_that.streamId,_that.frameIndex,_that.bytes,_that.frames);case UiEvent_Telemetry():
+return telemetry(_that.name,_that.value);case UiEvent_FrameDrop():
+return frameDrop(_that.streamId,_that.reason);case UiEvent_Fault():
+return fault(_that.code,_that.message);case UiEvent_BitrateChanged():
+return bitrateChanged(_that.bitrateBps);case UiEvent_VideoFrameReceived():
+return videoFrameReceived(_that.data,_that.pts);case UiEvent_AudioFrameReceived():
+return audioFrameReceived(_that.data,_that.pts,_that.framesPerPacket);case UiEvent_AudioConcealment():
+return audioConcealment(_that.streamId,_that.pts,_that.framesPerPacket);case UiEvent_PcmAudioReceived():
+return pcmAudioReceived(_that.streamId,_that.pts,_that.sampleRate,_that.channels,_that.framesPerPacket,_that.samples,_that.concealed);case UiEvent_DecodedVideoFrame():
+return decodedVideoFrame(_that.streamId,_that.pts,_that.width,_that.height,_that.format,_that.data);case UiEvent_StreamFormat():
+return streamFormat(_that.streamId,_that.codec,_that.width,_that.height,_that.profileIdc,_that.highTier,_that.levelIdc,_that.chromaFormatIdc,_that.bitDepthLuma,_that.bitDepthChroma,_that.frameRateNum,_that.frameRateDen);case UiEvent_Congestion():
+return congestion(_that.queueDepth,_that.queueCapacity,_that.estimatedSendDelayMs,_that.recommendedAction);case UiEvent_TemporalLayers():
+return temporalLayers(_that.streamId,_that.activeLayers,_that.totalLayers);case UiEvent_AudioGap():
+return audioGap(_that.streamId,_that.expectedPts,_that.missingUs,_that.missingPackets,_that.concealmentPackets);case UiEvent_AudioActivity():
+return audioActivity(_that.streamId,_that.local,_that.speaking);case UiEvent_CodecNegotiated():
+return codecNegotiated(_that.codec,_that.codecName,_that.peerNegotiated);case UiEvent_AudioCodecNegotiated():
+return audioCodecNegotiated(_that.codec,_that.codecName,_that.sampleRate,_that.channels,_that.codecConfig,_that.peerNegotiated);case UiEvent_ControlMessageReceived():
+return controlMessageReceived(_that.message);case UiEvent_DataReceived():
+return dataReceived(_that.channel,_that.data,_that.reliable);case UiEvent_FileTransferStarted():
+return fileTransferStarted(_that.transferId,_that.incoming,_that.name,_that.size);case UiEvent_FileTransferProgress():
+return fileTransferProgress(_that.transferId,_that.incoming,_that.bytes,_that.size);case UiEvent_FileTransferFinished():
+return fileTransferFinished(_that.transferId,_that.incoming,_that.name,_that.outcome,_that.path,_that.detail);case UiEvent_RemoteCommand():
+return remoteCommand(_that.commandId,_that.command);case UiEvent_RemoteCommandReply():
+return remoteCommandReply(_that.commandId,_that.status,_that.detail);case UiEvent_PeerMediaState():
+return peerMediaState(_that.microphoneMuted,_that.cameraOff,_that.audioOnly,_that.screenShare);case UiEvent_Error():
+return error(_that.msg);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String msg)?  log,TResult? Function( String state,  String detail)?  connectionState,TResult? Function()?  handshakeInitiated,TResult? Function( BigInt sessionId,  String bootstrapMode)?  handshakeComplete,TResult? Function( int streamId,  BigInt frameIndex,  BigInt bytes,  BigInt frames)?  progress,TResult? Function( String name,  BigInt value)?  telemetry,TResult? Function( int streamId,  String reason)?  frameDrop,TResult? Function( String code,  String message)?  fault,TResult? Function( int bitrateBps)?  bitrateChanged,TResult? Function( Uint8List data,  BigInt pts)?  videoFrameReceived,TResult? Function( Uint8List data,  BigInt pts,  int framesPerPacket)?  audioFrameReceived,TResult? Function( int streamId,  BigInt pts,  int framesPerPacket)?  audioConcealment,TResult? Function( int streamId,  BigInt pts,  int sampleRate,  int channels,  int framesPerPacket,  Int16List samples,  bool concealed)?  pcmAudioReceived,TResult? Function( int streamId,  BigInt pts,  int width,  int height,  DecodedVideoFormat format,  Uint8List data)?  decodedVideoFrame,TResult? Function( int streamId,  String codec,  int width,  int height,  int profileIdc,  bool highTier,  int levelIdc,  int chromaFormatIdc,  int bitDepthLuma,  int bitDepthChroma,  int frameRateNum,  int frameRateDen)?  streamFormat,TResult? Function( int queueDepth,  int queueCapacity,  int estimatedSendDelayMs,  String recommendedAction)?  congestion,TResult? Function( int streamId,  int activeLayers,  int totalLayers)?  temporalLayers,TResult? Function( int streamId,  BigInt expectedPts,  BigInt missingUs,  int missingPackets,  int concealmentPackets)?  audioGap,TResult? Function( int streamId,  bool local,  bool speaking)?  audioActivity,TResult? Function( int codec,  String codecName,  bool peerNegotiated)?  codecNegotiated,TResult? Function( int codec,  String codecName,  int sampleRate,  int channels,  Uint8List codecConfig,  bool peerNegotiated)?  audioCodecNegotiated,TResult? Function( ControlMessage message)?  controlMessageReceived,TResult? Function( String channel,  Uint8List data,  bool reliable)?  dataReceived,TResult? Function( int transferId,  bool incoming,  String name,  BigInt size)?  fileTransferStarted,TResult? Function( int transferId,  bool incoming,  BigInt bytes,  BigInt size)?  fileTransferProgress,TResult? Function( int transferId,  bool incoming,  String name,  FileTransferOutcome outcome,  String path,  String detail)?  fileTransferFinished,TResult? Function( int commandId,  RemoteCommand command)?  remoteCommand,TResult? Function( int commandId,  RemoteCommandStatus status,  String detail)?  remoteCommandReply,TResult? Function( bool microphoneMuted,  bool cameraOff,  bool audioOnly,  bool screenShare)?  peerMediaState,TResult? Function( String msg)?  error,}) {final _that = this;
+switch (_that) {
+case UiEvent_Log() when log != null:
+return log(_that.msg);case UiEvent_ConnectionState() when connectionState != null:
+return connectionState(_that.state,_that.detail);case UiEvent_HandshakeInitiated() when handshakeInitiated != null:
+return handshakeInitiated();case UiEvent_HandshakeComplete() when handshakeComplete != null:
+return handshakeComplete(_that.sessionId,_that.bootstrapMode);case UiEvent_Progress() when progress != null:
+return progress(_that.streamId,_that.frameIndex,_that.bytes,_that.frames);case UiEvent_Telemetry() when telemetry != null:
+return telemetry(_that.name,_that.value);case UiEvent_FrameDrop() when frameDrop != null:
+return frameDrop(_that.streamId,_that.reason);case UiEvent_Fault() when fault != null:
+return fault(_that.code,_that.message);case UiEvent_BitrateChanged() when bitrateChanged != null:
+return bitrateChanged(_that.bitrateBps);case UiEvent_VideoFrameReceived() when videoFrameReceived != null:
+return videoFrameReceived(_that.data,_that.pts);case UiEvent_AudioFrameReceived() when audioFrameReceived != null:
+return audioFrameReceived(_that.data,_that.pts,_that.framesPerPacket);case UiEvent_AudioConcealment() when audioConcealment != null:
+return audioConcealment(_that.streamId,_that.pts,_that.framesPerPacket);case UiEvent_PcmAudioReceived() when pcmAudioReceived != null:
+return pcmAudioReceived(_that.streamId,_that.pts,_that.sampleRate,_that.channels,_that.framesPerPacket,_that.samples,_that.concealed);case UiEvent_DecodedVideoFrame() when decodedVideoFrame != null:
+return decodedVideoFrame(_that.streamId,_that.pts,_that.width,_that.height,_that.format,_that.data);case UiEvent_StreamFormat() when streamFormat != null:
+return streamFormat(_that.streamId,_that.codec,_that.width,_that.height,_that.profileIdc,_that.highTier,_that.levelIdc,_that.chromaFormatIdc,_that.bitDepthLuma,_that.bitDepthChroma,_that.frameRateNum,_that.frameRateDen);case UiEvent_Congestion() when congestion != null:
+return congestion(_that.queueDepth,_that.queueCapacity,_that.estimatedSendDelayMs,_that.recommendedAction);case UiEvent_TemporalLayers() when temporalLayers != null:
+return temporalLayers(_that.streamId,_that.activeLayers,_that.totalLayers);case UiEvent_AudioGap() when audioGap != null:
+return audioGap(_that.streamId,_that.expectedPts,_that.missingUs,_that.missingPackets,_that.concealmentPackets);case UiEvent_AudioActivity() when audioActivity != null:
+return audioActivity(_that.streamId,_that.local,_that.speaking);case UiEvent_CodecNegotiated() when codecNegotiated != null:
+return codecNegotiated(_that.codec,_that.codecName,_that.peerNegotiated);case UiEvent_AudioCodecNegotiated() when audioCodecNegotiated != null:
+return audioCodecNegotiated(_that.codec,_that.codecName,_that.sampleRate,_that.channels,_that.codecConfig,_that.peerNegotiated);case UiEvent_ControlMessageReceived() when controlMessageReceived != null:
+return controlMessageReceived(_that.message);case UiEvent_DataReceived() when dataReceived != null:
+return dataReceived(_that.channel,_that.data,_that.reliable);case UiEvent_FileTransferStarted() when fileTransferStarted != null:
+return fileTransferStarted(_that.transferId,_that.incoming,_that.name,_that.size);case UiEvent_FileTransferProgress() when fileTransferProgress != null:
+return fileTransferProgress(_that.transferId,_that.incoming,_that.bytes,_that.size);case UiEvent_FileTransferFinished() when fileTransferFinished != null:
+return fileTransferFinished(_that.transferId,_that.incoming,_that.name,_that.outcome,_that.path,_that.detail);case UiEvent_RemoteCommand() when remoteCommand != null:
+return remoteCommand(_that.commandId,_that.command);case UiEvent_RemoteCommandReply() when remoteCommandReply != null:
+return remoteCommandReply(_that.commandId,_that.status,_that.detail);case UiEvent_PeerMediaState() when peerMediaState != null:
+return peerMediaState(_that.microphoneMuted,_that.cameraOff,_that.audioOnly,_that.screenShare);case UiEvent_Error() when error != null:
+return error(_that.msg);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class UiEvent_Log extends UiEvent {
+  const UiEvent_Log({required this.msg}): super._();
+  
+
+ final  String msg;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UiEvent_LogCopyWith<UiEvent_Log> get copyWith => _$UiEvent_LogCopyWithImpl<UiEvent_Log>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_Log&&(identical(other.msg, msg) || other.msg == msg));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,msg);
+
+@override
+String toString() {
+  return 'UiEvent.log(msg: $msg)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UiEvent_LogCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_LogCopyWith(UiEvent_Log value, $Res Function(UiEvent_Log) _then) = _$UiEvent_LogCopyWithImpl;
+@useResult
+$Res call({
+ String msg
+});
+
+
+
+
+}
+/// @nodoc
+class _$UiEvent_LogCopyWithImpl<$Res>
+    implements $UiEvent_LogCopyWith<$Res> {
+  _$UiEvent_LogCopyWithImpl(this._self, this._then);
+
+  final UiEvent_Log _self;
+  final $Res Function(UiEvent_Log) _then;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? msg = null,}) {
+  return _then(UiEvent_Log(
+msg: null == msg ? _self.msg : msg // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UiEvent_ConnectionState extends UiEvent {
+  const UiEvent_ConnectionState({required this.state, required this.detail}): super._();
+  
+
+ final  String state;
+ final  String detail;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UiEvent_ConnectionStateCopyWith<UiEvent_ConnectionState> get copyWith => _$UiEvent_ConnectionStateCopyWithImpl<UiEvent_ConnectionState>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_ConnectionState&&(identical(other.state, state) || other.state == state)&&(identical(other.detail, detail) || other.detail == detail));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,state,detail);
+
+@override
+String toString() {
+  return 'UiEvent.connectionState(state: $state, detail: $detail)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UiEvent_ConnectionStateCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_ConnectionStateCopyWith(UiEvent_ConnectionState value, $Res Function(UiEvent_ConnectionState) _then) = _$UiEvent_ConnectionStateCopyWithImpl;
+@useResult
+$Res call({
+ String state, String detail
+});
+
+
+
+
+}
+/// @nodoc
+class _$UiEvent_ConnectionStateCopyWithImpl<$Res>
+    implements $UiEvent_ConnectionStateCopyWith<$Res> {
+  _$UiEvent_ConnectionStateCopyWithImpl(this._self, this._then);
+
+  final UiEvent_ConnectionState _self;
+  final $Res Function(UiEvent_ConnectionState) _then;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? state = null,Object? detail = null,}) {
+  return _then(UiEvent_ConnectionState(
+state: null == state ? _self.state : state // ignore: cast_nullable_to_non_nullable
+as String,detail: null == detail ? _self.detail : detail // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UiEvent_HandshakeInitiated extends UiEvent {
+  const UiEvent_HandshakeInitiated(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_HandshakeInitiated);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'UiEvent.handshakeInitiated()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class UiEvent_HandshakeComplete extends UiEvent {
+  const UiEvent_HandshakeComplete({required this.sessionId, required this.bootstrapMode}): super._();
+  
+
+ final  BigInt sessionId;
+ final  String bootstrapMode;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UiEvent_HandshakeCompleteCopyWith<UiEvent_HandshakeComplete> get copyWith => _$UiEvent_HandshakeCompleteCopyWithImpl<UiEvent_HandshakeComplete>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_HandshakeComplete&&(identical(other.sessionId, sessionId) || other.sessionId == sessionId)&&(identical(other.bootstrapMode, bootstrapMode) || other.bootstrapMode == bootstrapMode));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,sessionId,bootstrapMode);
+
+@override
+String toString() {
+  return 'UiEvent.handshakeComplete(sessionId: $sessionId, bootstrapMode: $bootstrapMode)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UiEvent_HandshakeCompleteCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_HandshakeCompleteCopyWith(UiEvent_HandshakeComplete value, $Res Function(UiEvent_HandshakeComplete) _then) = _$UiEvent_HandshakeCompleteCopyWithImpl;
+@useResult
+$Res call({
+ BigInt sessionId, String bootstrapMode
+});
+
+
+
+
+}
+/// @nodoc
+class _$UiEvent_HandshakeCompleteCopyWithImpl<$Res>
+    implements $UiEvent_HandshakeCompleteCopyWith<$Res> {
+  _$UiEvent_HandshakeCompleteCopyWithImpl(this._self, this._then);
+
+  final UiEvent_HandshakeComplete _self;
+  final $Res Function(UiEvent_HandshakeComplete) _then;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? sessionId = null,Object? bootstrapMode = null,}) {
+  return _then(UiEvent_HandshakeComplete(
+sessionId: null == sessionId ? _self.sessionId : sessionId // ignore: cast_nullable_to_non_nullable
+as BigInt,bootstrapMode: null == bootstrapMode ? _self.bootstrapMode : bootstrapMode // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UiEvent_Progress extends UiEvent {
+  const UiEvent_Progress({required this.streamId, required this.frameIndex, required this.bytes, required this.frames}): super._();
+  
+
+ final  int streamId;
+ final  BigInt frameIndex;
+ final  BigInt bytes;
+ final  BigInt frames;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UiEvent_ProgressCopyWith<UiEvent_Progress> get copyWith => _$UiEvent_ProgressCopyWithImpl<UiEvent_Progress>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_Progress&&(identical(other.streamId, streamId) || other.streamId == streamId)&&(identical(other.frameIndex, frameIndex) || other.frameIndex == frameIndex)&&(identical(other.bytes, bytes) || other.bytes == bytes)&&(identical(other.frames, frames) || other.frames == frames));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,streamId,frameIndex,bytes,frames);
+
+@override
+String toString() {
+  return 'UiEvent.progress(streamId: $streamId, frameIndex: $frameIndex, bytes: $bytes, frames: $frames)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UiEvent_ProgressCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_ProgressCopyWith(UiEvent_Progress value, $Res Function(UiEvent_Progress) _then) = _$UiEvent_ProgressCopyWithImpl;
+@useResult
+$Res call({
+ int streamId, BigInt frameIndex, BigInt bytes, BigInt frames
+});
+
+
+
+
+}
+/// @nodoc
+class _$UiEvent_ProgressCopyWithImpl<$Res>
+    implements $UiEvent_ProgressCopyWith<$Res> {
+  _$UiEvent_ProgressCopyWithImpl(this._self, this._then);
+
+  final UiEvent_Progress _self;
+  final $Res Function(UiEvent_Progress) _then;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? streamId = null,Object? frameIndex = null,Object? bytes = null,Object? frames = null,}) {
+  return _then(UiEvent_Progress(
+streamId: null == streamId ? _self.streamId : streamId // ignore: cast_nullable_to_non_nullable
+as int,frameIndex: null == frameIndex ? _self.frameIndex : frameIndex // ignore: cast_nullable_to_non_nullable
+as BigInt,bytes: null == bytes ? _self.bytes : bytes // ignore: cast_nullable_to_non_nullable
+as BigInt,frames: null == frames ? _self.frames : frames // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UiEvent_Telemetry extends UiEvent {
+  const UiEvent_Telemetry({required this.name, required this.value}): super._();
+  
+
+ final  String name;
+ final  BigInt value;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UiEvent_TelemetryCopyWith<UiEvent_Telemetry> get copyWith => _$UiEvent_TelemetryCopyWithImpl<UiEvent_Telemetry>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_Telemetry&&(identical(other.name, name) || other.name == name)&&(identical(other.value, value) || other.value == value));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,name,value);
+
+@override
+String toString() {
+  return 'UiEvent.telemetry(name: $name, value: $value)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UiEvent_TelemetryCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_TelemetryCopyWith(UiEvent_Telemetry value, $Res Function(UiEvent_Telemetry) _then) = _$UiEvent_TelemetryCopyWithImpl;
+@useResult
+$Res call({
+ String name, BigInt value
+});
+
+
+
+
+}
+/// @nodoc
+class _$UiEvent_TelemetryCopyWithImpl<$Res>
+    implements $UiEvent_TelemetryCopyWith<$Res> {
+  _$UiEvent_TelemetryCopyWithImpl(this._self, this._then);
+
+  final UiEvent_Telemetry _self;
+  final $Res Function(UiEvent_Telemetry) _then;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? name = null,Object? value = null,}) {
+  return _then(UiEvent_Telemetry(
+name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,value: null == value ? _self.value : value // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UiEvent_FrameDrop extends UiEvent {
+  const UiEvent_FrameDrop({required this.streamId, required this.reason}): super._();
+  
+
+ final  int streamId;
+ final  String reason;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UiEvent_FrameDropCopyWith<UiEvent_FrameDrop> get copyWith => _$UiEvent_FrameDropCopyWithImpl<UiEvent_FrameDrop>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_FrameDrop&&(identical(other.streamId, streamId) || other.streamId == streamId)&&(identical(other.reason, reason) || other.reason == reason));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,streamId,reason);
+
+@override
+String toString() {
+  return 'UiEvent.frameDrop(streamId: $streamId, reason: $reason)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UiEvent_FrameDropCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_FrameDropCopyWith(UiEvent_FrameDrop value, $Res Function(UiEvent_FrameDrop) _then) = _$UiEvent_FrameDropCopyWithImpl;
+@useResult
+$Res call({
+ int streamId, String reason
+});
+
+
+
+
+}
+/// @nodoc
+class _$UiEvent_FrameDropCopyWithImpl<$Res>
+    implements $UiEvent_FrameDropCopyWith<$Res> {
+  _$UiEvent_FrameDropCopyWithImpl(this._self, this._then);
+
+  final UiEvent_FrameDrop _self;
+  final $Res Function(UiEvent_FrameDrop) _then;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? streamId = null,Object? reason = null,}) {
+  return _then(UiEvent_FrameDrop(
+streamId: null == streamId ? _self.streamId : streamId // ignore: cast_nullable_to_non_nullable
+as int,reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UiEvent_Fault extends UiEvent {
+  const UiEvent_Fault({required this.code, required this.message}): super._();
+  
+
+ final  String code;
+ final  String message;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UiEvent_FaultCopyWith<UiEvent_Fault> get copyWith => _$UiEvent_FaultCopyWithImpl<UiEvent_Fault>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_Fault&&(identical(other.code, code) || other.code == code)&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,code,message);
+
+@override
+String toString() {
+  return 'UiEvent.fault(code: $code, message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UiEvent_FaultCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_FaultCopyWith(UiEvent_Fault value, $Res Function(UiEvent_Fault) _then) = _$UiEvent_FaultCopyWithImpl;
+@useResult
+$Res call({
+ String code, String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$UiEvent_FaultCopyWithImpl<$Res>
+    implements $UiEvent_FaultCopyWith<$Res> {
+  _$UiEvent_FaultCopyWithImpl(this._self, this._then);
+
+  final UiEvent_Fault _self;
+  final $Res Function(UiEvent_Fault) _then;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? code = null,Object? message = null,}) {
+  return _then(UiEvent_Fault(
+code: null == code ? _self.code : code // ignore: cast_nullable_to_non_nullable
+as String,message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UiEvent_BitrateChanged extends UiEvent {
+  const UiEvent_BitrateChanged({required this.bitrateBps}): super._();
+  
+
+ final  int bitrateBps;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UiEvent_BitrateChangedCopyWith<UiEvent_BitrateChanged> get copyWith => _$UiEvent_BitrateChangedCopyWithImpl<UiEvent_BitrateChanged>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_BitrateChanged&&(identical(other.bitrateBps, bitrateBps) || other.bitrateBps == bitrateBps));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,bitrateBps);
+
+@override
+String toString() {
+  return 'UiEvent.bitrateChanged(bitrateBps: $bitrateBps)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UiEvent_BitrateChangedCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_BitrateChangedCopyWith(UiEvent_BitrateChanged value, $Res Function(UiEvent_BitrateChanged) _then) = _$UiEvent_BitrateChangedCopyWithImpl;
+@useResult
+$Res call({
+ int bitrateBps
+});
+
+
+
+
+}
+/// @nodoc
+class _$UiEvent_BitrateChangedCopyWithImpl<$Res>
+    implements $UiEvent_BitrateChangedCopyWith<$Res> {
+  _$UiEvent_BitrateChangedCopyWithImpl(this._self, this._then);
+
+  final UiEvent_BitrateChanged _self;
+  final $Res Function(UiEvent_BitrateChanged) _then;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? bitrateBps = null,}) {
+  return _then(UiEvent_BitrateChanged(
+bitrateBps: null == bitrateBps ? _self.bitrateBps : bitrateBps // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UiEvent_VideoFrameReceived extends UiEvent {
+  const UiEvent_VideoFrameReceived({required this.data, required this.pts}): super._();
+  
+
+ final  Uint8List data;
+ final  BigInt pts;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UiEvent_VideoFrameReceivedCopyWith<UiEvent_VideoFrameReceived> get copyWith => _$UiEvent_VideoFrameReceivedCopyWithImpl<UiEvent_VideoFrameReceived>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_VideoFrameReceived&&const DeepCollectionEquality().equals(other.data, data)&&(identical(other.pts, pts) || other.pts == pts));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(data),pts);
+
+@override
+String toString() {
+  return 'UiEvent.videoFrameReceived(data: $data, pts: $pts)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UiEvent_VideoFrameReceivedCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_VideoFrameReceivedCopyWith(UiEvent_VideoFrameReceived value, $Res Function(UiEvent_VideoFrameReceived) _then) = _$UiEvent_VideoFrameReceivedCopyWithImpl;
+@useResult
+$Res call({
+ Uint8List data, BigInt pts
+});
+
+
+
+
+}
+/// @nodoc
+class _$UiEvent_VideoFrameReceivedCopyWithImpl<$Res>
+    implements $UiEvent_VideoFrameReceivedCopyWith<$Res> {
+  _$UiEvent_VideoFrameReceivedCopyWithImpl(this._self, this._then);
+
+  final UiEvent_VideoFrameReceived _self;
+  final $Res Function(UiEvent_VideoFrameReceived) _then;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? data = null,Object? pts = null,}) {
+  return _then(UiEvent_VideoFrameReceived(
+data: null == data ? _self.data : data // ignore: cast_nullable_to_non_nullable
+as Uint8List,pts: null == pts ? _self.pts : pts // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UiEvent_AudioFrameReceived extends UiEvent {
+  const UiEvent_AudioFrameReceived({required this.data, required this.pts, required this.framesPerPacket}): super._();
+  
+
+ final  Uint8List data;
+ final  BigInt pts;
+ final  int framesPerPacket;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UiEvent_AudioFrameReceivedCopyWith<UiEvent_AudioFrameReceived> get copyWith => _$UiEvent_AudioFrameReceivedCopyWithImpl<UiEvent_AudioFrameReceived>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_AudioFrameReceived&&const DeepCollectionEquality().equals(other.data, data)&&(identical(other.pts, pts) || other.pts == pts)&&(identical(other.framesPerPacket, framesPerPacket) || other.framesPerPacket == framesPerPacket));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(data),pts,framesPerPacket);
+
+@override
+String toString() {
+  return 'UiEvent.audioFrameReceived(data: $data, pts: $pts, framesPerPacket: $framesPerPacket)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UiEvent_AudioFrameReceivedCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_AudioFrameReceivedCopyWith(UiEvent_AudioFrameReceived value, $Res Function(UiEvent_AudioFrameReceived) _then) = _$UiEvent_AudioFrameReceivedCopyWithImpl;
+@useResult
+$Res call({
+ Uint8List data, BigInt pts, int framesPerPacket
+});
+
+
+
+
+}
+/// @nodoc
+class _$UiEvent_AudioFrameReceivedCopyWithImpl<$Res>
+    implements $UiEvent_AudioFrameReceivedCopyWith<$Res> {
+  _$UiEvent_AudioFrameReceivedCopyWithImpl(this._self, this._then);
+
+  final UiEvent_AudioFrameReceived _self;
+  final $Res Function(UiEvent_AudioFrameReceived) _then;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? data = null,Object? pts = null,Object? framesPerPacket = null,}) {
+  return _then(UiEvent_AudioFrameReceived(
+data: null == data ? _self.data : data // ignore: cast_nullable_to_non_nullable
+as Uint8List,pts: null == pts ? _self.pts : pts // ignore: cast_nullable_to_non_nullable
+as BigInt,framesPerPacket: null == framesPerPacket ? _self.framesPerPacket : framesPerPacket // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UiEvent_AudioConcealment extends UiEvent {
+  const UiEvent_AudioConcealment({required this.streamId, required this.pts, required this.framesPerPacket}): super._();
+  
+
+ final  int streamId;
+ final  BigInt pts;
+ final  int framesPerPacket;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UiEvent_AudioConcealmentCopyWith<UiEvent_AudioConcealment> get copyWith => _$UiEvent_AudioConcealmentCopyWithImpl<UiEvent_AudioConcealment>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_AudioConcealment&&(identical(other.streamId, streamId) || other.streamId == streamId)&&(identical(other.pts, pts) || other.pts == pts)&&(identical(other.framesPerPacket, framesPerPacket) || other.framesPerPacket == framesPerPacket));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,streamId,pts,framesPerPacket);
+
+@override
+String toString() {
+  return 'UiEvent.audioConcealment(streamId: $streamId, pts: $pts, framesPerPacket: $framesPerPacket)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UiEvent_AudioConcealmentCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_AudioConcealmentCopyWith(UiEvent_AudioConcealment value, $Res Function(UiEvent_AudioConcealment) _then) = _$UiEvent_AudioConcealmentCopyWithImpl;
+@useResult
+$Res call({
+ int streamId, BigInt pts, int framesPerPacket
+});
+
+
+
+
+}
+/// @nodoc
+class _$UiEvent_AudioConcealmentCopyWithImpl<$Res>
+    implements $UiEvent_AudioConcealmentCopyWith<$Res> {
+  _$UiEvent_AudioConcealmentCopyWithImpl(this._self, this._then);
+
+  final UiEvent_AudioConcealment _self;
+  final $Res Function(UiEvent_AudioConcealment) _then;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? streamId = null,Object? pts = null,Object? framesPerPacket = null,}) {
+  return _then(UiEvent_AudioConcealment(
+streamId: null == streamId ? _self.streamId : streamId // ignore: cast_nullable_to_non_nullable
+as int,pts: null == pts ? _self.pts : pts // ignore: cast_nullable_to_non_nullable
+as BigInt,framesPerPacket: null == framesPerPacket ? _self.framesPerPacket : framesPerPacket // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UiEvent_PcmAudioReceived extends UiEvent {
+  const UiEvent_PcmAudioReceived({required this.streamId, required this.pts, required this.sampleRate, required this.channels, required this.framesPerPacket, required this.samples, required this.concealed}): super._();
+  
+
+ final  int streamId;
+ final  BigInt pts;
+ final  int sampleRate;
+ final  int channels;
+ final  int framesPerPacket;
+ final  Int16List samples;
+ final  bool concealed;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UiEvent_PcmAudioReceivedCopyWith<UiEvent_PcmAudioReceived> get copyWith => _$UiEvent_PcmAudioReceivedCopyWithImpl<UiEvent_PcmAudioReceived>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_PcmAudioReceived&&(identical(other.streamId, streamId) || other.streamId == streamId)&&(identical(other.pts, pts) || other.pts == pts)&&(identical(other.sampleRate, sampleRate) || other.sampleRate == sampleRate)&&(identical(other.channels, channels) || other.channels == channels)&&(identical(other.framesPerPacket, framesPerPacket) || other.framesPerPacket == framesPerPacket)&&const DeepCollectionEquality().equals(other.samples, samples)&&(identical(other.concealed, concealed) || other.concealed == concealed));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,streamId,pts,sampleRate,channels,framesPerPacket,const DeepCollectionEquality().hash(samples),concealed);
+
+@override
+String toString() {
+  return 'UiEvent.pcmAudioReceived(streamId: $streamId, pts: $pts, sampleRate: $sampleRate, channels: $channels, framesPerPacket: $framesPerPacket, samples: $samples, concealed: $concealed)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UiEvent_PcmAudioReceivedCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_PcmAudioReceivedCopyWith(UiEvent_PcmAudioReceived value, $Res Function(UiEvent_PcmAudioReceived) _then) = _$UiEvent_PcmAudioReceivedCopyWithImpl;
+@useResult
+$Res call({
+ int streamId, BigInt pts, int sampleRate, int channels, int framesPerPacket, Int16List samples, bool concealed
+});
+
+
+
+
+}
+/// @nodoc
+class _$UiEvent_PcmAudioReceivedCopyWithImpl<$Res>
+    implements $UiEvent_PcmAudioReceivedCopyWith<$Res> {
+  _$UiEvent_PcmAudioReceivedCopyWithImpl(this._self, this._then);
+
+  final UiEvent_PcmAudioReceived _self;
+  final $Res Function(UiEvent_PcmAudioReceived) _then;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? streamId = null,Object? pts = null,Object? sampleRate = null,Object? channels = null,Object? framesPerPacket = null,Object? samples = null,Object? concealed = null,}) {
+  return _then(UiEvent_PcmAudioReceived(
+streamId: null == streamId ? _self.streamId : streamId // ignore: cast_nullable_to_non_nullable
+as int,pts: null == pts ? _self.pts : pts // ignore: cast_nullable_to_non_nullable
+as BigInt,sampleRate: null == sampleRate ? _self.sampleRate : sampleRate // ignore: cast_nullable_to_non_nullable
+as int,channels: null == channels ? _self.channels : channels // ignore: cast_nullable_to_non_nullable
+as int,framesPerPacket: null == framesPerPacket ? _self.framesPerPacket : framesPerPacket // ignore: cast_nullable_to_non_nullable
+as int,samples: null == samples ? _self.samples : samples // ignore: cast_nullable_to_non_nullable
+as Int16List,concealed: null == concealed ? _self.concealed : concealed // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UiEvent_DecodedVideoFrame extends UiEvent {
+  const UiEvent_DecodedVideoFrame({required this.streamId, required this.pts, required this.width, required this.height, required this.format, required this.data}): super._();
+  
+
+ final  int streamId;
+ final  BigInt pts;
+ final  int width;
+ final  int height;
+ final  DecodedVideoFormat format;
+ final  Uint8List data;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UiEvent_DecodedVideoFrameCopyWith<UiEvent_DecodedVideoFrame> get copyWith => _$UiEvent_DecodedVideoFrameCopyWithImpl<UiEvent_DecodedVideoFrame>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_DecodedVideoFrame&&(identical(other.streamId, streamId) || other.streamId == streamId)&&(identical(other.pts, pts) || other.pts == pts)&&(identical(other.width, width) || other.width == width)&&(identical(other.height, height) || other.height == height)&&(identical(other.format, format) || other.format == format)&&const DeepCollectionEquality().equals(other.data, data));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,streamId,pts,width,height,format,const DeepCollectionEquality().hash(data));
+
+@override
+String toString() {
+  return 'UiEvent.decodedVideoFrame(streamId: $streamId, pts: $pts, width: $width, height: $height, format: $format, data: $data)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UiEvent_DecodedVideoFrameCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_DecodedVideoFrameCopyWith(UiEvent_DecodedVideoFrame value, $Res Function(UiEvent_DecodedVideoFrame) _then) = _$UiEvent_DecodedVideoFrameCopyWithImpl;
+@useResult
+$Res call({
+ int streamId, BigInt pts, int width, int height, DecodedVideoFormat format, Uint8List data
+});
+
+
+
+
+}
+/// @nodoc
+class _$UiEvent_DecodedVideoFrameCopyWithImpl<$Res>
+    implements $UiEvent_DecodedVideoFrameCopyWith<$Res> {
+  _$UiEvent_DecodedVideoFrameCopyWithImpl(this._self, this._then);
+
+  final UiEvent_DecodedVideoFrame _self;
+  final $Res Function(UiEvent_DecodedVideoFrame) _then;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? streamId = null,Object? pts = null,Object? width = null,Object? height = null,Object? format = null,Object? data = null,}) {
+  return _then(UiEvent_DecodedVideoFrame(
+streamId: null == streamId ? _self.streamId : streamId // ignore: cast_nullable_to_non_nullable
+as int,pts: null == pts ? _self.pts : pts // ignore: cast_nullable_to_non_nullable
+as BigInt,width: null == width ? _self.width : width // ignore: cast_nullable_to_non_nullable
+as int,height: null == height ? _self.height : height // ignore: cast_nullable_to_non_nullable
+as int,format: null == format ? _self.format : format // ignore: cast_nullable_to_non_nullable
+as DecodedVideoFormat,data: null == data ? _self.data : data // ignore: cast_nullable_to_non_nullable
+as Uint8List,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UiEvent_StreamFormat extends UiEvent {
+  const UiEvent_StreamFormat({required this.streamId, required this.codec, required this.width, required this.height, required this.profileIdc, required this.highTier, required this.levelIdc, required this.chromaFormatIdc, required this.bitDepthLuma, required this.bitDepthChroma, required this.frameRateNum, required this.frameRateDen}): super._();
+  
+
+ final  int streamId;
+ final  String codec;
+ final  int width;
+ final  int height;
+ final  int profileIdc;
+ final  bool highTier;
+ final  int levelIdc;
+ final  int chromaFormatIdc;
+ final  int bitDepthLuma;
+ final  int bitDepthChroma;
+ final  int frameRateNum;
+ final  int frameRateDen;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UiEvent_StreamFormatCopyWith<UiEvent_StreamFormat> get copyWith => _$UiEvent_StreamFormatCopyWithImpl<UiEvent_StreamFormat>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_StreamFormat&&(identical(other.streamId, streamId) || other.streamId == streamId)&&(identical(other.codec, codec) || other.codec == codec)&&(identical(other.width, width) || other.width == width)&&(identical(other.height, height) || other.height == height)&&(identical(other.profileIdc, profileIdc) || other.profileIdc == profileIdc)&&(identical(other.highTier, highTier) || other.highTier == highTier)&&(identical(other.levelIdc, levelIdc) || other.levelIdc == levelIdc)&&(identical(other.chromaFormatIdc, chromaFormatIdc) || other.chromaFormatIdc == chromaFormatIdc)&&(identical(other.bitDepthLuma, bitDepthLuma) || other.bitDepthLuma == bitDepthLuma)&&(identical(other.bitDepthChroma, bitDepthChroma) || other.bitDepthChroma == bitDepthChroma)&&(identical(other.frameRateNum, frameRateNum) || other.frameRateNum == frameRateNum)&&(identical(other.frameRateDen, frameRateDen) || other.frameRateDen == frameRateDen));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,streamId,codec,width,height,profileIdc,highTier,levelIdc,chromaFormatIdc,bitDepthLuma,bitDepthChroma,frameRateNum,frameRateDen);
+
+@override
+String toString() {
+  return 'UiEvent.streamFormat(streamId: $streamId, codec: $codec, width: $width, height: $height, profileIdc: $profileIdc, highTier: $highTier, levelIdc: $levelIdc, chromaFormatIdc: $chromaFormatIdc, bitDepthLuma: $bitDepthLuma, bitDepthChroma: $bitDepthChroma, frameRateNum: $frameRateNum, frameRateDen: $frameRateDen)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UiEvent_StreamFormatCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_StreamFormatCopyWith(UiEvent_StreamFormat value, $Res Function(UiEvent_StreamFormat) _then) = _$UiEvent_StreamFormatCopyWithImpl;
+@useResult
+$Res call({
+ int streamId, String codec, int width, int height, int profileIdc, bool highTier, int levelIdc, int chromaFormatIdc, int bitDepthLuma, int bitDepthChroma, int frameRateNum, int frameRateDen
+});
+
+
+
+
+}
+/// @nodoc
+class _$UiEvent_StreamFormatCopyWithImpl<$Res>
+    implements $UiEvent_StreamFormatCopyWith<$Res> {
+  _$UiEvent_StreamFormatCopyWithImpl(this._self, this._then);
+
+  final UiEvent_StreamFormat _self;
+  final $Res Function(UiEvent_StreamFormat) _then;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? streamId = null,Object? codec = null,Object? width = null,Object? height = null,Object? profileIdc = null,Object? highTier = null,Object? levelIdc = null,Object? chromaFormatIdc = null,Object? bitDepthLuma = null,Object? bitDepthChroma = null,Object? frameRateNum = null,Object? frameRateDen = null,}) {
+  return _then(UiEvent_StreamFormat(
+streamId: null == streamId ? _self.streamId : streamId // ignore: cast_nullable_to_non_nullable
+as int,codec: null == codec ? _self.codec : codec // ignore: cast_nullable_to_non_nullable
+as String,width: null == width ? _self.width : width // ignore: cast_nullable_to_non_nullable
+as int,height: null == height ? _self.height : height // ignore: cast_nullable_to_non_nullable
+as int,profileIdc: null == profileIdc ? _self.profileIdc : profileIdc // ignore: cast_nullable_to_non_nullable
+as int,highTier: null == highTier ? _self.highTier : highTier // ignore: cast_nullable_to_non_nullable
+as bool,levelIdc: null == levelIdc ? _self.levelIdc : levelIdc // ignore: cast_nullable_to_non_nullable
+as int,chromaFormatIdc: null == chromaFormatIdc ? _self.chromaFormatIdc : chromaFormatIdc // ignore: cast_nullable_to_non_nullable
+as int,bitDepthLuma: null == bitDepthLuma ? _self.bitDepthLuma : bitDepthLuma // ignore: cast_nullable_to_non_nullable
+as int,bitDepthChroma: null == bitDepthChroma ? _self.bitDepthChroma : bitDepthChroma // ignore: cast_nullable_to_non_nullable
+as int,frameRateNum: null == frameRateNum ? _self.frameRateNum : frameRateNum // ignore: cast_nullable_to_non_nullable
+as int,frameRateDen: null == frameRateDen ? _self.frameRateDen : frameRateDen // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UiEvent_Congestion extends UiEvent {
+  const UiEvent_Congestion({required this.queueDepth, required this.queueCapacity, required this.estimatedSendDelayMs, required this.recommendedAction}): super._();
+  
+
+ final  int queueDepth;
+ final  int queueCapacity;
+ final  int estimatedSendDelayMs;
+ final  String recommendedAction;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UiEvent_CongestionCopyWith<UiEvent_Congestion> get copyWith => _$UiEvent_CongestionCopyWithImpl<UiEvent_Congestion>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_Congestion&&(identical(other.queueDepth, queueDepth) || other.queueDepth == queueDepth)&&(identical(other.queueCapacity, queueCapacity) || other.queueCapacity == queueCapacity)&&(identical(other.estimatedSendDelayMs, estimatedSendDelayMs) || other.estimatedSendDelayMs == estimatedSendDelayMs)&&(identical(other.recommendedAction, recommendedAction) || other.recommendedAction == recommendedAction));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,queueDepth,queueCapacity,estimatedSendDelayMs,recommendedAction);
+
+@override
+String toString() {
+  return 'UiEvent.congestion(queueDepth: $queueDepth, queueCapacity: $queueCapacity, estimatedSendDelayMs: $estimatedSendDelayMs, recommendedAction: $recommendedAction)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UiEvent_CongestionCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_CongestionCopyWith(UiEvent_Congestion value, $Res Function(UiEvent_Congestion) _then) = _$UiEvent_CongestionCopyWithImpl;
+@useResult
+$Res call({
+ int queueDepth, int queueCapacity, int estimatedSendDelayMs, String recommendedAction
+});
+
+
+
+
+}
+/// @nodoc
+class _$UiEvent_CongestionCopyWithImpl<$Res>
+    implements $UiEvent_CongestionCopyWith<$Res> {
+  _$UiEvent_CongestionCopyWithImpl(this._self, this._then);
+
+  final UiEvent_Congestion _self;
+  final $Res Function(UiEvent_Congestion) _then;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? queueDepth = null,Object? queueCapacity = null,Object? estimatedSendDelayMs = null,Object? recommendedAction = null,}) {
+  return _then(UiEvent_Congestion(
+queueDepth: null == queueDepth ? _self.queueDepth : queueDepth // ignore: cast_nullable_to_non_nullable
+as int,queueCapacity: null == queueCapacity ? _self.queueCapacity : queueCapacity // ignore: cast_nullable_to_non_nullable
+as int,estimatedSendDelayMs: null == estimatedSendDelayMs ? _self.estimatedSendDelayMs : estimatedSendDelayMs // ignore: cast_nullable_to_non_nullable
+as int,recommendedAction: null == recommendedAction ? _self.recommendedAction : recommendedAction // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UiEvent_TemporalLayers extends UiEvent {
+  const UiEvent_TemporalLayers({required this.streamId, required this.activeLayers, required this.totalLayers}): super._();
+  
+
+ final  int streamId;
+ final  int activeLayers;
+ final  int totalLayers;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UiEvent_TemporalLayersCopyWith<UiEvent_TemporalLayers> get copyWith => _$UiEvent_TemporalLayersCopyWithImpl<UiEvent_TemporalLayers>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_TemporalLayers&&(identical(other.streamId, streamId) || other.streamId == streamId)&&(identical(other.activeLayers, activeLayers) || other.activeLayers == activeLayers)&&(identical(other.totalLayers, totalLayers) || other.totalLayers == totalLayers));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,streamId,activeLayers,totalLayers);
+
+@override
+String toString() {
+  return 'UiEvent.temporalLayers(streamId: $streamId, activeLayers: $activeLayers, totalLayers: $totalLayers)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UiEvent_TemporalLayersCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_TemporalLayersCopyWith(UiEvent_TemporalLayers value, $Res Function(UiEvent_TemporalLayers) _then) = _$UiEvent_TemporalLayersCopyWithImpl;
+@useResult
+$Res call({
+ int streamId, int activeLayers, int totalLayers
+});
+
+
+
+
+}
+/// @nodoc
+class _$UiEvent_TemporalLayersCopyWithImpl<$Res>
+    implements $UiEvent_TemporalLayersCopyWith<$Res> {
+  _$UiEvent_TemporalLayersCopyWithImpl(this._self, this._then);
+
+  final UiEvent_TemporalLayers _self;
+  final $Res Function(UiEvent_TemporalLayers) _then;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? streamId = null,Object? activeLayers = null,Object? totalLayers = null,}) {
+  return _then(UiEvent_TemporalLayers(
+streamId: null == streamId ? _self.streamId : streamId // ignore: cast_nullable_to_non_nullable
+as int,activeLayers: null == activeLayers ? _self.activeLayers : activeLayers // ignore: cast_nullable_to_non_nullable
+as int,totalLayers: null == totalLayers ? _self.totalLayers : totalLayers // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UiEvent_AudioGap extends UiEvent {
+  const UiEvent_AudioGap({required this.streamId, required this.expectedPts, required this.missingUs, required this.missingPackets, required this.concealmentPackets}): super._();
+  
+
+ final  int streamId;
+ final  BigInt expectedPts;
+ final  BigInt missingUs;
+ final  int missingPackets;
+ final  int concealmentPackets;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UiEvent_AudioGapCopyWith<UiEvent_AudioGap> get copyWith => _$UiEvent_AudioGapCopyWithImpl<UiEvent_AudioGap>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_AudioGap&&(identical(other.streamId, streamId) || other.streamId == streamId)&&(identical(other.expectedPts, expectedPts) || other.expectedPts == expectedPts)&&(identical(other.missingUs, missingUs) || other.missingUs == missingUs)&&(identical(other.missingPackets, missingPackets) || other.missingPackets == missingPackets)&&(identical(other.concealmentPackets, concealmentPackets) || other.concealmentPackets == concealmentPackets));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,streamId,expectedPts,missingUs,missingPackets,concealmentPackets);
+
+@override
+String toString() {
+  return 'UiEvent.audioGap(streamId: $streamId, expectedPts: $expectedPts, missingUs: $missingUs, missingPackets: $missingPackets, concealmentPackets: $concealmentPackets)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UiEvent_AudioGapCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_AudioGapCopyWith(UiEvent_AudioGap value, $Res Function(UiEvent_AudioGap) _then) = _$UiEvent_AudioGapCopyWithImpl;
+@useResult
+$Res call({
+ int streamId, BigInt expectedPts, BigInt missingUs, int missingPackets, int concealmentPackets
+});
+
+
+
+
+}
+/// @nodoc
+class _$UiEvent_AudioGapCopyWithImpl<$Res>
+    implements $UiEvent_AudioGapCopyWith<$Res> {
+  _$UiEvent_AudioGapCopyWithImpl(this._self, this._then);
+
+  final UiEvent_AudioGap _self;
+  final $Res Function(UiEvent_AudioGap) _then;
+
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? streamId = null,Object? expectedPts = null,Object? missingUs = null,Object? missingPackets = null,Object? concealmentPackets = null,}) {
+  return _then(UiEvent_AudioGap(
+streamId: null == streamId ? _self.streamId : streamId // ignore: cast_nullable_to_non_nullable
+as int,expectedPts: null == expectedPts ? _self.expectedPts : expectedPts // ignore: cast_nullable_to_non_nullable
+as BigInt,missingUs: null == missingUs ? _self.missingUs : missingUs // ignore: cast_nullable_to_non_nullable
+as BigInt,missingPackets: null == missingPackets ? _self.missingPackets : missingPackets // ignore: cast_nullable_to_non_nullable
+as int,concealmentPackets: null == concealmentPackets ? _self.concealmentPackets : concealmentPackets // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UiEvent_AudioActivity extends UiEvent {
+  const UiEvent_AudioActivity({required this.streamId, required this.local, required this.speaking}): super._();
   
 
- final  String msg;
+ final  int streamId;
+ final  bool local;
+ final  bool speaking;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$UiEvent_LogCopyWith<UiEvent_Log> get copyWith => _$UiEvent_LogCopyWithImpl<UiEvent_Log>(this, _$identity);
+$UiEvent_AudioActivityCopyWith<UiEvent_AudioActivity> get copyWith => _$UiEvent_AudioActivityCopyWithImpl<UiEvent_AudioActivity>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_Log&&(identical(other.msg, msg) || other.msg == msg));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_AudioActivity&&(identical(other.streamId, streamId) || other.streamId == streamId)&&(identical(other.local, local) || other.local == local)&&(identical(other.speaking, speaking) || other.speaking == speaking));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,msg);
+int get hashCode => Object.hash(runtimeType,streamId,local,speaking);
 
 @override
 String toString() {
-  return 'UiEvent.log(msg: $msg)';
+  return 'UiEvent.audioActivity(streamId: $streamId, local: $local, speaking: $speaking)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $UiEvent_LogCopyWith<$Res> implements $UiEventCopyWith<$Res> {
-  factory $UiEvent_LogCopyWith(UiEvent_Log value, $Res Function(UiEvent_Log) _then) = _$UiEvent_LogCopyWithImpl;
+abstract mixin class $UiEvent_AudioActivityCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_AudioActivityCopyWith(UiEvent_AudioActivity value, $Res Function(UiEvent_AudioActivity) _then) = _$UiEvent_AudioActivityCopyWithImpl;
 @useResult
 $Res call({
- String msg
+ int streamId, bool local, bool speaking
 });
 
 
@@ -278,19 +2674,21 @@ $Res call({
 
 }
 /// @nodoc
-class _$UiEvent_LogCopyWithImpl<$Res>
-    implements $UiEvent_LogCopyWith<$Res> {
-  _$UiEvent_LogCopyWithImpl(this._self, this._then);
+class _$UiEvent_AudioActivityCopyWithImpl<$Res>
+    implements $UiEvent_AudioActivityCopyWith<$Res> {
+  _$UiEvent_AudioActivityCopyWithImpl(this._self, this._then);
 
-  final UiEvent_Log _self;
-  final $Res Function(UiEvent_Log) _then;
+  final UiEvent_AudioActivity _self;
+  final $Res Function(UiEvent_AudioActivity) _then;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? msg = null,}) {
-  return _then(UiEvent_Log(
-msg: null == msg ? _self.msg : msg // ignore: cast_nullable_to_non_nullable
-as String,
+@pragma('vm:prefer-inline') $Res call({Object? streamId = null,Object? local = null,Object? speaking = null,}) {
+  return _then(UiEvent_AudioActivity(
+streamId: null == streamId ? _self.streamId : streamId // ignore: cast_nullable_to_non_nullable
+as int,local: null == local ? _self.local : local // ignore: cast_nullable_to_non_nullable
+as bool,speaking: null == speaking ? _self.speaking : speaking // ignore: cast_nullable_to_non_nullable
+as bool,
   ));
 }
 
@@ -300,44 +2698,45 @@ as String,
 /// @nodoc
 
 
-class UiEvent_ConnectionState extends UiEvent {
-  const UiEvent_ConnectionState({required this.state, required this.detail}): super._();
+class UiEvent_CodecNegotiated extends UiEvent {
+  const UiEvent_CodecNegotiated({required this.codec, required this.codecName, required this.peerNegotiated}): super._();
   
 
- final  String state;
- final  String detail;
+ final  int codec;
+ final  String codecName;
+ final  bool peerNegotiated;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$UiEvent_ConnectionStateCopyWith<UiEvent_ConnectionState> get copyWith => _$UiEvent_ConnectionStateCopyWithImpl<UiEvent_ConnectionState>(this, _$identity);
+$UiEvent_CodecNegotiatedCopyWith<UiEvent_CodecNegotiated> get copyWith => _$UiEvent_CodecNegotiatedCopyWithImpl<UiEvent_CodecNegotiated>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_ConnectionState&&(identical(other.state, state) || other.state == state)&&(identical(other.detail, detail) || other.detail == detail));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_CodecNegotiated&&(identical(other.codec, codec) || other.codec == codec)&&(identical(other.codecName, codecName) || other.codecName == codecName)&&(identical(other.peerNegotiated, peerNegotiated) || other.peerNegotiated == peerNegotiated));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,state,detail);
+int get hashCode => Object.hash(runtimeType,codec,codecName,peerNegotiated);
 
 @override
 String toString() {
-  return 'UiEvent.connectionState(state: $state, detail: $detail)';
+  return 'UiEvent.codecNegotiated(codec: $codec, codecName: $codecName, peerNegotiated: $peerNegotiated)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $UiEvent_ConnectionStateCopyWith<$Res> implements $UiEventCopyWith<$Res> {
-  factory $UiEvent_ConnectionStateCopyWith(UiEvent_ConnectionState value, $Res Function(UiEvent_ConnectionState) _then) = _$UiEvent_ConnectionStateCopyWithImpl;
+abstract mixin class $UiEvent_CodecNegotiatedCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_CodecNegotiatedCopyWith(UiEvent_CodecNegotiated value, $Res Function(UiEvent_CodecNegotiated) _then) = _$UiEvent_CodecNegotiatedCopyWithImpl;
 @useResult
 $Res call({
- String state, String detail
+ int codec, String codecName, bool peerNegotiated
 });
 
 
@@ -345,20 +2744,21 @@ $Res call({
 
 }
 /// @nodoc
-class _$UiEvent_ConnectionStateCopyWithImpl<$Res>
-    implements $UiEvent_ConnectionStateCopyWith<$Res> {
-  _$UiEvent_ConnectionStateCopyWithImpl(this._self, this._then);
+class _$UiEvent_CodecNegotiatedCopyWithImpl<$Res>
+    implements $UiEvent_CodecNegotiatedCopyWith<$Res> {
+  _$UiEvent_CodecNegotiatedCopyWithImpl(this._self, this._then);
 
-  final UiEvent_ConnectionState _self;
-  final $Res Function(UiEvent_ConnectionState) _then;
+  final UiEvent_CodecNegotiated _self;
+  final $Res Function(UiEvent_CodecNegotiated) _then;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? state = null,Object? detail = null,}) {
-  return _then(UiEvent_ConnectionState(
-state: null == state ? _self.state : state // ignore: cast_nullable_to_non_nullable
-as String,detail: null == detail ? _self.detail : detail // ignore: cast_nullable_to_non_nullable
-as String,
+@pragma('vm:prefer-inline') $Res call({Object? codec = null,Object? codecName = null,Object? peerNegotiated = null,}) {
+  return _then(UiEvent_CodecNegotiated(
+codec: null == codec ? _self.codec : codec // ignore: cast_nullable_to_non_nullable
+as int,codecName: null == codecName ? _self.codecName : codecName // ignore: cast_nullable_to_non_nullable
+as String,peerNegotiated: null == peerNegotiated ? _self.peerNegotiated : peerNegotiated // ignore: cast_nullable_to_non_nullable
+as bool,
   ));
 }
 
@@ -368,146 +2768,197 @@ as String,
 /// @nodoc
 
 
-class UiEvent_HandshakeInitiated extends UiEvent {
-  const UiEvent_HandshakeInitiated(): super._();
+class UiEvent_AudioCodecNegotiated extends UiEvent {
+  const UiEvent_AudioCodecNegotiated({required this.codec, required this.codecName, required this.sampleRate, required this.channels, required this.codecConfig, required this.peerNegotiated}): super._();
   
 
+ final  int codec;
+ final  String codecName;
+ final  int sampleRate;
+ final  int channels;
+ final  Uint8List codecConfig;
+ final  bool peerNegotiated;
 
-
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UiEvent_AudioCodecNegotiatedCopyWith<UiEvent_AudioCodecNegotiated> get copyWith => _$UiEvent_AudioCodecNegotiatedCopyWithImpl<UiEvent_AudioCodecNegotiated>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_HandshakeInitiated);
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_AudioCodecNegotiated&&(identical(other.codec, codec) || other.codec == codec)&&(identical(other.codecName, codecName) || other.codecName == codecName)&&(identical(other.sampleRate, sampleRate) || other.sampleRate == sampleRate)&&(identical(other.channels, channels) || other.channels == channels)&&const DeepCollectionEquality().equals(other.codecConfig, codecConfig)&&(identical(other.peerNegotiated, peerNegotiated) || other.peerNegotiated == peerNegotiated));
 }
 
 
 @override
-int get hashCode => runtimeType.hashCode;
+int get hashCode => Object.hash(runtimeType,codec,codecName,sampleRate,channels,const DeepCollectionEquality().hash(codecConfig),peerNegotiated);
 
 @override
 String toString() {
-  return 'UiEvent.handshakeInitiated()';
+  return 'UiEvent.audioCodecNegotiated(codec: $codec, codecName: $codecName, sampleRate: $sampleRate, channels: $channels, codecConfig: $codecConfig, peerNegotiated: $peerNegotiated)';
 }
 
 
 }
 
+/// @nodoc
+abstract mixin class $UiEvent_AudioCodecNegotiatedCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_AudioCodecNegotiatedCopyWith(UiEvent_AudioCodecNegotiated value, $Res Function(UiEvent_AudioCodecNegotiated) _then) = _$UiEvent_AudioCodecNegotiatedCopyWithImpl;
+@useResult
+$Res call({
+ int codec, String codecName, int sampleRate, int channels, Uint8List codecConfig, bool peerNegotiated
+});
+
 
 
 
+}
 /// @nodoc
+class _$UiEvent_AudioCodecNegotiatedCopyWithImpl<$Res>
+    implements $UiEvent_AudioCodecNegotiatedCopyWith<$Res> {
+  _$UiEvent_AudioCodecNegotiatedCopyWithImpl(this._self, this._then);
 
+  final UiEvent_AudioCodecNegotiated _self;
+  final $Res Function(UiEvent_AudioCodecNegotiated) _then;
 
-class UiEvent_HandshakeComplete extends UiEvent {
-  const UiEvent_HandshakeComplete({required this.sessionId, required this.bootstrapMode}): super._();
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? codec = null,Object? codecName = null,Object? sampleRate = null,Object? channels = null,Object? codecConfig = null,Object? peerNegotiated = null,}) {
+  return _then(UiEvent_AudioCodecNegotiated(
+codec: null == codec ? _self.codec : codec // ignore: cast_nullable_to_non_nullable
+as int,codecName: null == codecName ? _self.codecName : codecName // ignore: cast_nullable_to_non_nullable
+as String,sampleRate: null == sampleRate ? _self.sampleRate : sampleRate // ignore: cast_nullable_to_non_nullable
+as int,channels: null == channels ? _self.channels : channels // ignore: cast_nullable_to_non_nullable
+as int,codecConfig: null == codecConfig ? _self.codecConfig : codecConfig // ignore: cast_nullable_to_non_nullable
+as Uint8List,peerNegotiated: null == peerNegotiated ? _self.peerNegotiated : peerNegotiated // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UiEvent_ControlMessageReceived extends UiEvent {
+  const UiEvent_ControlMessageReceived({required this.message}): super._();
   
 
- final  BigInt sessionId;
- final  String bootstrapMode;
+ final  ControlMessage message;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$UiEvent_HandshakeCompleteCopyWith<UiEvent_HandshakeComplete> get copyWith => _$UiEvent_HandshakeCompleteCopyWithImpl<UiEvent_HandshakeComplete>(this, _$identity);
+$UiEvent_ControlMessageReceivedCopyWith<UiEvent_ControlMessageReceived> get copyWith => _$UiEvent_ControlMessageReceivedCopyWithImpl<UiEvent_ControlMessageReceived>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_HandshakeComplete&&(identical(other.sessionId, sessionId) || other.sessionId == sessionId)&&(identical(other.bootstrapMode, bootstrapMode) || other.bootstrapMode == bootstrapMode));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_ControlMessageReceived&&(identical(other.message, message) || other.message == message));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,sessionId,bootstrapMode);
+int get hashCode => Object.hash(runtimeType,message);
 
 @override
 String toString() {
-  return 'UiEvent.handshakeComplete(sessionId: $sessionId, bootstrapMode: $bootstrapMode)';
+  return 'UiEvent.controlMessageReceived(message: $message)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $UiEvent_HandshakeCompleteCopyWith<$Res> implements $UiEventCopyWith<$Res> {
-  factory $UiEvent_HandshakeCompleteCopyWith(UiEvent_HandshakeComplete value, $Res Function(UiEvent_HandshakeComplete) _then) = _$UiEvent_HandshakeCompleteCopyWithImpl;
+abstract mixin class $UiEvent_ControlMessageReceivedCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_ControlMessageReceivedCopyWith(UiEvent_ControlMessageReceived value, $Res Function(UiEvent_ControlMessageReceived) _then) = _$UiEvent_ControlMessageReceivedCopyWithImpl;
 @useResult
 $Res call({
- BigInt sessionId, String bootstrapMode
+ ControlMessage message
 });
 
 
 
+@override $ControlMessageCopyWith<$Res> get message;
 
 }
 /// @nodoc
-class _$UiEvent_HandshakeCompleteCopyWithImpl<$Res>
-    implements $UiEvent_HandshakeCompleteCopyWith<$Res> {
-  _$UiEvent_HandshakeCompleteCopyWithImpl(this._self, this._then);
+class _$UiEvent_ControlMessageReceivedCopyWithImpl<$Res>
+    implements $UiEvent_ControlMessageReceivedCopyWith<$Res> {
+  _$UiEvent_ControlMessageReceivedCopyWithImpl(this._self, this._then);
 
-  final UiEvent_HandshakeComplete _self;
-  final $Res Function(UiEvent_HandshakeComplete) _then;
+  final UiEvent_ControlMessageReceived _self;
+  final $Res Function(UiEvent_ControlMessageReceived) _then;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? sessionId = null,Object? bootstrapMode = null,}) {
-  return _then(UiEvent_HandshakeComplete(
-sessionId: null == sessionId ? _self.sessionId : sessionId // ignore: cast_nullable_to_non_nullable
-as BigInt,bootstrapMode: null == bootstrapMode ? _self.bootstrapMode : bootstrapMode // ignore: cast_nullable_to_non_nullable
-as String,
+@pragma('vm:prefer-inline') $Res call({Object? message = null,}) {
+  return _then(UiEvent_ControlMessageReceived(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as ControlMessage,
   ));
 }
 
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$ControlMessageCopyWith<$Res> get message {
 
+  return $ControlMessageCopyWith<$Res>(_self.message, (value) {
+    return _then(_self.copyWith(message: value));
+  });
+}
 }
 
 /// @nodoc
 
 
-class UiEvent_Progress extends UiEvent {
-  const UiEvent_Progress({required this.streamId, required this.frameIndex, required this.bytes, required this.frames}): super._();
+class UiEvent_DataReceived extends UiEvent {
+  const UiEvent_DataReceived({required this.channel, required this.data, required this.reliable}): super._();
   
 
- final  int streamId;
- final  BigInt frameIndex;
- final  BigInt bytes;
- final  BigInt frames;
+ final  String channel;
+ final  Uint8List data;
+ final  bool reliable;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$UiEvent_ProgressCopyWith<UiEvent_Progress> get copyWith => _$UiEvent_ProgressCopyWithImpl<UiEvent_Progress>(this, _$identity);
+$UiEvent_DataReceivedCopyWith<UiEvent_DataReceived> get copyWith => _$UiEvent_DataReceivedCopyWithImpl<UiEvent_DataReceived>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_Progress&&(identical(other.streamId, streamId) || other.streamId == streamId)&&(identical(other.frameIndex, frameIndex) || other.frameIndex == frameIndex)&&(identical(other.bytes, bytes) || other.bytes == bytes)&&(identical(other.frames, frames) || other.frames == frames));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_DataReceived&&(identical(other.channel, channel) || other.channel == channel)&&const DeepCollectionEquality().equals(other.data, data)&&(identical(other.reliable, reliable) || other.reliable == reliable));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,streamId,frameIndex,bytes,frames);
+int get hashCode => Object.hash(runtimeType,channel,const DeepCollectionEquality().hash(data),reliable);
 
 @override
 String toString() {
-  return 'UiEvent.progress(streamId: $streamId, frameIndex: $frameIndex, bytes: $bytes, frames: $frames)';
+  return 'UiEvent.dataReceived(channel: $channel, data: $data, reliable: $reliable)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $UiEvent_ProgressCopyWith<$Res> implements $UiEventCopyWith<$Res> {
-  factory $UiEvent_ProgressCopyWith(UiEvent_Progress value, $Res Function(UiEvent_Progress) _then) = _$UiEvent_ProgressCopyWithImpl;
+abstract mixin class $UiEvent_DataReceivedCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_DataReceivedCopyWith(UiEvent_DataReceived value, $Res Function(UiEvent_DataReceived) _then) = _$UiEvent_DataReceivedCopyWithImpl;
 @useResult
 $Res call({
- int streamId, BigInt frameIndex, BigInt bytes, BigInt frames
+ String channel, Uint8List data, bool reliable
 });
 
 
@@ -515,22 +2966,21 @@ $Res call({
 
 }
 /// @nodoc
-class _$UiEvent_ProgressCopyWithImpl<$Res>
-    implements $UiEvent_ProgressCopyWith<$Res> {
-  _$UiEvent_ProgressCopyWithImpl(this._self, this._then);
+class _$UiEvent_DataReceivedCopyWithImpl<$Res>
+    implements $UiEvent_DataReceivedCopyWith<$Res> {
+  _$UiEvent_DataReceivedCopyWithImpl(this._self, this._then);
 
-  final UiEvent_Progress _self;
-  final $Res Function(UiEvent_Progress) _then;
+  final UiEvent_DataReceived _self;
+  final $Res Function(UiEvent_DataReceived) _then;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? streamId = null,Object? frameIndex = null,Object? bytes = null,Object? frames = null,}) {
-  return _then(UiEvent_Progress(
-streamId: null == streamId ? _self.streamId : streamId // ignore: cast_nullable_to_non_nullable
-as int,frameIndex: null == frameIndex ? _self.frameIndex : frameIndex // ignore: cast_nullable_to_non_nullable
-as BigInt,bytes: null == bytes ? _self.bytes : bytes // ignore: cast_nullable_to_non_nullable
-as BigInt,frames: null == frames ? _self.frames : frames // ignore: cast_nullable_to_non_nullable
-as BigInt,
+@pragma('vm:prefer-inline') $Res call({Object? channel = null,Object? data = null,Object? reliable = null,}) {
+  return _then(UiEvent_DataReceived(
+channel: null == channel ? _self.channel : channel // ignore: cast_nullable_to_non_nullable
+as String,data: null == data ? _self.data : data // ignore: cast_nullable_to_non_nullable
+as Uint8List,reliable: null == reliable ? _self.reliable : reliable // ignore: cast_nullable_to_non_nullable
+as bool,
   ));
 }
 
@@ -540,44 +2990,46 @@ as BigInt,
 /// @nodoc
 
 
-class UiEvent_Telemetry extends UiEvent {
-  const UiEvent_Telemetry({required this.name, required this.value}): super._();
+class UiEvent_FileTransferStarted extends UiEvent {
+  const UiEvent_FileTransferStarted({required this.transferId, required this.incoming, required this.name, required this.size}): super._();
   
 
+ final  int transferId;
+ final  bool incoming;
  final  String name;
- final  BigInt value;
+ final  BigInt size;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$UiEvent_TelemetryCopyWith<UiEvent_Telemetry> get copyWith => _$UiEvent_TelemetryCopyWithImpl<UiEvent_Telemetry>(this, _$identity);
+$UiEvent_FileTransferStartedCopyWith<UiEvent_FileTransferStarted> get copyWith => _$UiEvent_FileTransferStartedCopyWithImpl<UiEvent_FileTransferStarted>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_Telemetry&&(identical(other.name, name) || other.name == name)&&(identical(other.value, value) || other.value == value));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_FileTransferStarted&&(identical(other.transferId, transferId) || other.transferId == transferId)&&(identical(other.incoming, incoming) || other.incoming == incoming)&&(identical(other.name, name) || other.name == name)&&(identical(other.size, size) || other.size == size));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,name,value);
+int get hashCode => Object.hash(runtimeType,transferId,incoming,name,size);
 
 @override
 String toString() {
-  return 'UiEvent.telemetry(name: $name, value: $value)';
+  return 'UiEvent.fileTransferStarted(transferId: $transferId, incoming: $incoming, name: $name, size: $size)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $UiEvent_TelemetryCopyWith<$Res> implements $UiEventCopyWith<$Res> {
-  factory $UiEvent_TelemetryCopyWith(UiEvent_Telemetry value, $Res Function(UiEvent_Telemetry) _then) = _$UiEvent_TelemetryCopyWithImpl;
+abstract mixin class $UiEvent_FileTransferStartedCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_FileTransferStartedCopyWith(UiEvent_FileTransferStarted value, $Res Function(UiEvent_FileTransferStarted) _then) = _$UiEvent_FileTransferStartedCopyWithImpl;
 @useResult
 $Res call({
- String name, BigInt value
+ int transferId, bool incoming, String name, BigInt size
 });
 
 
@@ -585,19 +3037,21 @@ $Res call({
 
 }
 /// @nodoc
-class _$UiEvent_TelemetryCopyWithImpl<$Res>
-    implements $UiEvent_TelemetryCopyWith<$Res> {
-  _$UiEvent_TelemetryCopyWithImpl(this._self, this._then);
+class _$UiEvent_FileTransferStartedCopyWithImpl<$Res>
+    implements $UiEvent_FileTransferStartedCopyWith<$Res> {
+  _$UiEvent_FileTransferStartedCopyWithImpl(this._self, this._then);
 
-  final UiEvent_Telemetry _self;
-  final $Res Function(UiEvent_Telemetry) _then;
+  final UiEvent_FileTransferStarted _self;
+  final $Res Function(UiEvent_FileTransferStarted) _then;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? name = null,Object? value = null,}) {
-  return _then(UiEvent_Telemetry(
-name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
-as String,value: null == value ? _self.value : value // ignore: cast_nullable_to_non_nullable
+@pragma('vm:prefer-inline') $Res call({Object? transferId = null,Object? incoming = null,Object? name = null,Object? size = null,}) {
+  return _then(UiEvent_FileTransferStarted(
+transferId: null == transferId ? _self.transferId : transferId // ignore: cast_nullable_to_non_nullable
+as int,incoming: null == incoming ? _self.incoming : incoming // ignore: cast_nullable_to_non_nullable
+as bool,name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,size: null == size ? _self.size : size // ignore: cast_nullable_to_non_nullable
 as BigInt,
   ));
 }
@@ -608,44 +3062,46 @@ as BigInt,
 /// @nodoc
 
 
-class UiEvent_FrameDrop extends UiEvent {
-  const UiEvent_FrameDrop({required this.streamId, required this.reason}): super._();
+class UiEvent_FileTransferProgress extends UiEvent {
+  const UiEvent_FileTransferProgress({required this.transferId, required this.incoming, required this.bytes, required this.size}): super._();
   
 
- final  int streamId;
- final  String reason;
+ final  int transferId;
+ final  bool incoming;
+ final  BigInt bytes;
+ final  BigInt size;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$UiEvent_FrameDropCopyWith<UiEvent_FrameDrop> get copyWith => _$UiEvent_FrameDropCopyWithImpl<UiEvent_FrameDrop>(this, _$identity);
+$UiEvent_FileTransferProgressCopyWith<UiEvent_FileTransferProgress> get copyWith => _$UiEvent_FileTransferProgressCopyWithImpl<UiEvent_FileTransferProgress>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_FrameDrop&&(identical(other.streamId, streamId) || other.streamId == streamId)&&(identical(other.reason, reason) || other.reason == reason));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_FileTransferProgress&&(identical(other.transferId, transferId) || other.transferId == transferId)&&(identical(other.incoming, incoming) || other.incoming == incoming)&&(identical(other.bytes, bytes) || other.bytes == bytes)&&(identical(other.size, size) || other.size == size));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,streamId,reason);
+int get hashCode => Object.hash(runtimeType,transferId,incoming,bytes,size);
 
 @override
 String toString() {
-  return 'UiEvent.frameDrop(streamId: $streamId, reason: $reason)';
+  return 'UiEvent.fileTransferProgress(transferId: $transferId, incoming: $incoming, bytes: $bytes, size: $size)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $UiEvent_FrameDropCopyWith<$Res> implements $UiEventCopyWith<$Res> {
-  factory $UiEvent_FrameDropCopyWith(UiEvent_FrameDrop value, $Res Function(UiEvent_FrameDrop) _then) = _$UiEvent_FrameDropCopyWithImpl;
+abstract mixin class $UiEvent_FileTransferProgressCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_FileTransferProgressCopyWith(UiEvent_FileTransferProgress value, $Res Function(UiEvent_FileTransferProgress) _then) = _$UiEvent_FileTransferProgressCopyWithImpl;
 @useResult
 $Res call({
- int streamId, String reason
+ int transferId, bool incoming, BigInt bytes, BigInt size
 });
 
 
@@ -653,20 +3109,22 @@ $Res call({
 
 }
 /// @nodoc
-class _$UiEvent_FrameDropCopyWithImpl<$Res>
-    implements $UiEvent_FrameDropCopyWith<$Res> {
-  _$UiEvent_FrameDropCopyWithImpl(this._self, this._then);
+class _$UiEvent_FileTransferProgressCopyWithImpl<$Res>
+    implements $UiEvent_FileTransferProgressCopyWith<$Res> {
+  _$UiEvent_FileTransferProgressCopyWithImpl(this._self, this._then);
 
-  final UiEvent_FrameDrop _self;
-  final $Res Function(UiEvent_FrameDrop) _then;
+  final UiEvent_FileTransferProgress _self;
+  final $Res Function(UiEvent_FileTransferProgress) _then;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? streamId = null,Object? reason = null,}) {
-  return _then(UiEvent_FrameDrop(
-streamId: null == streamId ? _self.streamId : streamId // ignore: cast_nullable_to_non_nullable
-as int,reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
-as String,
+@pragma('vm:prefer-inline') $Res call({Object? transferId = null,Object? incoming = null,Object? bytes = null,Object? size = null,}) {
+  return _then(UiEvent_FileTransferProgress(
+transferId: null == transferId ? _self.transferId : transferId // ignore: cast_nullable_to_non_nullable
+as int,incoming: null == incoming ? _self.incoming : incoming // ignore: cast_nullable_to_non_nullable
+as bool,bytes: null == bytes ? _self.bytes : bytes // ignore: cast_nullable_to_non_nullable
+as BigInt,size: null == size ? _self.size : size // ignore: cast_nullable_to_non_nullable
+as BigInt,
   ));
 }
 
@@ -676,44 +3134,48 @@ as String,
 /// @nodoc
 
 
-class UiEvent_Fault extends UiEvent {
-  const UiEvent_Fault({required this.code, required this.message}): super._();
+class UiEvent_FileTransferFinished extends UiEvent {
+  const UiEvent_FileTransferFinished({required this.transferId, required this.incoming, required this.name, required this.outcome, required this.path, required this.detail}): super._();
   
 
- final  String code;
- final  String message;
+ final  int transferId;
+ final  bool incoming;
+ final  String name;
+ final  FileTransferOutcome outcome;
+ final  String path;
+ final  String detail;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$UiEvent_FaultCopyWith<UiEvent_Fault> get copyWith => _$UiEvent_FaultCopyWithImpl<UiEvent_Fault>(this, _$identity);
+$UiEvent_FileTransferFinishedCopyWith<UiEvent_FileTransferFinished> get copyWith => _$UiEvent_FileTransferFinishedCopyWithImpl<UiEvent_FileTransferFinished>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_Fault&&(identical(other.code, code) || other.code == code)&&(identical(other.message, message) || other.message == message));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_FileTransferFinished&&(identical(other.transferId, transferId) || other.transferId == transferId)&&(identical(other.incoming, incoming) || other.incoming == incoming)&&(identical(other.name, name) || other.name == name)&&(identical(other.outcome, outcome) || other.outcome == outcome)&&(identical(other.path, path) || other.path == path)&&(identical(other.detail, detail) || other.detail == detail));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,code,message);
+int get hashCode => Object.hash(runtimeType,transferId,incoming,name,outcome,path,detail);
 
 @override
 String toString() {
-  return 'UiEvent.fault(code: $code, message: $message)';
+  return 'UiEvent.fileTransferFinished(transferId: $transferId, incoming: $incoming, name: $name, outcome: $outcome, path: $path, detail: $detail)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $UiEvent_FaultCopyWith<$Res> implements $UiEventCopyWith<$Res> {
-  factory $UiEvent_FaultCopyWith(UiEvent_Fault value, $Res Function(UiEvent_Fault) _then) = _$UiEvent_FaultCopyWithImpl;
+abstract mixin class $UiEvent_FileTransferFinishedCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_FileTransferFinishedCopyWith(UiEvent_FileTransferFinished value, $Res Function(UiEvent_FileTransferFinished) _then) = _$UiEvent_FileTransferFinishedCopyWithImpl;
 @useResult
 $Res call({
- String code, String message
+ int transferId, bool incoming, String name, FileTransferOutcome outcome, String path, String detail
 });
 
 
@@ -721,19 +3183,23 @@ $Res call({
 
 }
 /// @nodoc
-class _$UiEvent_FaultCopyWithImpl<$Res>
-    implements $UiEvent_FaultCopyWith<$Res> {
-  _$UiEvent_FaultCopyWithImpl(this._self, this._then);
+class _$UiEvent_FileTransferFinishedCopyWithImpl<$Res>
+    implements $UiEvent_FileTransferFinishedCopyWith<$Res> {
+  _$UiEvent_FileTransferFinishedCopyWithImpl(this._self, this._then);
 
-  final UiEvent_Fault _self;
-  final $Res Function(UiEvent_Fault) _then;
+  final UiEvent_FileTransferFinished _self;
+  final $Res Function(UiEvent_FileTransferFinished) _then;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? code = null,Object? message = null,}) {
-  return _then(UiEvent_Fault(
-code: null == code ? _self.code : code // ignore: cast_nullable_to_non_nullable
-as String,message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+@pragma('vm:prefer-inline') $Res call({Object? transferId = null,Object? incoming = null,Object? name = null,Object? outcome = null,Object? path = null,Object? detail = null,}) {
+  return _then(UiEvent_FileTransferFinished(
+transferId: null == transferId ? _self.transferId : transferId // ignore: cast_nullable_to_non_nullable
+as int,incoming: null == incoming ? _self.incoming : incoming // ignore: cast_nullable_to_non_nullable
+as bool,name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,outcome: null == outcome ? _self.outcome : outcome // ignore: cast_nullable_to_non_nullable
+as FileTransferOutcome,path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as String,detail: null == detail ? _self.detail : detail // ignore: cast_nullable_to_non_nullable
 as String,
   ));
 }
@@ -744,110 +3210,123 @@ as String,
 /// @nodoc
 
 
-class UiEvent_BitrateChanged extends UiEvent {
-  const UiEvent_BitrateChanged({required this.bitrateBps}): super._();
+class UiEvent_RemoteCommand extends UiEvent {
+  const UiEvent_RemoteCommand({required this.commandId, required this.command}): super._();
   
 
- final  int bitrateBps;
+ final  int commandId;
+ final  RemoteCommand command;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$UiEvent_BitrateChangedCopyWith<UiEvent_BitrateChanged> get copyWith => _$UiEvent_BitrateChangedCopyWithImpl<UiEvent_BitrateChanged>(this, _$identity);
+$UiEvent_RemoteCommandCopyWith<UiEvent_RemoteCommand> get copyWith => _$UiEvent_RemoteCommandCopyWithImpl<UiEvent_RemoteCommand>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_BitrateChanged&&(identical(other.bitrateBps, bitrateBps) || other.bitrateBps == bitrateBps));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_RemoteCommand&&(identical(other.commandId, commandId) || other.commandId == commandId)&&(identical(other.command, command) || other.command == command));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,bitrateBps);
+int get hashCode => Object.hash(runtimeType,commandId,command);
 
 @override
 String toString() {
-  return 'UiEvent.bitrateChanged(bitrateBps: $bitrateBps)';
+  return 'UiEvent.remoteCommand(commandId: $commandId, command: $command)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $UiEvent_BitrateChangedCopyWith<$Res> implements $UiEventCopyWith<$Res> {
-  factory $UiEvent_BitrateChangedCopyWith(UiEvent_BitrateChanged value, $Res Function(UiEvent_BitrateChanged) _then) = _$UiEvent_BitrateChangedCopyWithImpl;
+abstract mixin class $UiEvent_RemoteCommandCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_RemoteCommandCopyWith(UiEvent_RemoteCommand value, $Res Function(UiEvent_RemoteCommand) _then) = _$UiEvent_RemoteCommandCopyWithImpl;
 @useResult
 $Res call({
- int bitrateBps
+ int commandId, RemoteCommand command
 });
 
 
 
+@override $RemoteCommandCopyWith<$Res> get command;
 
 }
 /// @nodoc
-class _$UiEvent_BitrateChangedCopyWithImpl<$Res>
-    implements $UiEvent_BitrateChangedCopyWith<$Res> {
-  _$UiEvent_BitrateChangedCopyWithImpl(this._self, this._then);
+class _$UiEvent_RemoteCommandCopyWithImpl<$Res>
+    implements $UiEvent_RemoteCommandCopyWith<$Res> {
+  _$UiEvent_RemoteCommandCopyWithImpl(this._self, this._then);
 
-  final UiEvent_BitrateChanged _self;
-  final $Res Function(UiEvent_BitrateChanged) _then;
+  final UiEvent_RemoteCommand _self;
+  final $Res Function(UiEvent_RemoteCommand) _then;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? bitrateBps = null,}) {
-  return _then(UiEvent_BitrateChanged(
-bitrateBps: null == bitrateBps ? _self.bitrateBps : bitrateBps // ignore: cast_nullable_to_non_nullable
-as int,
+@pragma('vm:prefer-inline') $Res call({Object? commandId = null,Object? command = null,}) {
+  return _then(UiEvent_RemoteCommand(
+commandId: null == commandId ? _self.commandId : commandId // ignore: cast_nullable_to_non_nullable
+as int,command: null == command ? _self.command : command // ignore: cast_nullable_to_non_nullable
+as RemoteCommand,
   ));
 }
 
+/// Create a copy of UiEvent
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$RemoteCommandCopyWith<$Res> get command {
 
+  return $RemoteCommandCopyWith<$Res>(_self.command, (value) {
+    return _then(_self.copyWith(command: value));
+  });
+}
 }
 
 /// @nodoc
 
 
-class UiEvent_VideoFrameReceived extends UiEvent {
-  const UiEvent_VideoFrameReceived({required this.data, required this.pts}): super._();
+class UiEvent_RemoteCommandReply extends UiEvent {
+  const UiEvent_RemoteCommandReply({required this.commandId, required this.status, required this.detail}): super._();
   
 
- final  Uint8List data;
- final  BigInt pts;
+ final  int commandId;
+ final  RemoteCommandStatus status;
+ final  String detail;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$UiEvent_VideoFrameReceivedCopyWith<UiEvent_VideoFrameReceived> get copyWith => _$UiEvent_VideoFrameReceivedCopyWithImpl<UiEvent_VideoFrameReceived>(this, _$identity);
+$UiEvent_RemoteCommandReplyCopyWith<UiEvent_RemoteCommandReply> get copyWith => _$UiEvent_RemoteCommandReplyCopyWithImpl<UiEvent_RemoteCommandReply>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_VideoFrameReceived&&const DeepCollectionEquality().equals(other.data, data)&&(identical(other.pts, pts) || other.pts == pts));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_RemoteCommandReply&&(identical(other.commandId, commandId) || other.commandId == commandId)&&(identical(other.status, status) || other.status == status)&&(identical(other.detail, detail) || other.detail == detail));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(data),pts);
+int get hashCode => Object.hash(runtimeType,commandId,status,detail);
 
 @override
 String toString() {
-  return 'UiEvent.videoFrameReceived(data: $data, pts: $pts)';
+  return 'UiEvent.remoteCommandReply(commandId: $commandId, status: $status, detail: $detail)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $UiEvent_VideoFrameReceivedCopyWith<$Res> implements $UiEventCopyWith<$Res> {
-  factory $UiEvent_VideoFrameReceivedCopyWith(UiEvent_VideoFrameReceived value, $Res Function(UiEvent_VideoFrameReceived) _then) = _$UiEvent_VideoFrameReceivedCopyWithImpl;
+abstract mixin class $UiEvent_RemoteCommandReplyCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_RemoteCommandReplyCopyWith(UiEvent_RemoteCommandReply value, $Res Function(UiEvent_RemoteCommandReply) _then) = _$UiEvent_RemoteCommandReplyCopyWithImpl;
 @useResult
 $Res call({
- Uint8List data, BigInt pts
+ int commandId, RemoteCommandStatus status, String detail
 });
 
 
@@ -855,20 +3334,21 @@ $Res call({
 
 }
 /// @nodoc
-class _$UiEvent_VideoFrameReceivedCopyWithImpl<$Res>
-    implements $UiEvent_VideoFrameReceivedCopyWith<$Res> {
-  _$UiEvent_VideoFrameReceivedCopyWithImpl(this._self, this._then);
+class _$UiEvent_RemoteCommandReplyCopyWithImpl<$Res>
+    implements $UiEvent_RemoteCommandReplyCopyWith<$Res> {
+  _$UiEvent_RemoteCommandReplyCopyWithImpl(this._self, this._then);
 
-  final UiEvent_VideoFrameReceived _self;
-  final $Res Function(UiEvent_VideoFrameReceived) _then;
+  final UiEvent_RemoteCommandReply _self;
+  final $Res Function(UiEvent_RemoteCommandReply) _then;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? data = null,Object? pts = null,}) {
-  return _then(UiEvent_VideoFrameReceived(
-data: null == data ? _self.data : data // ignore: cast_nullable_to_non_nullable
-as Uint8List,pts: null == pts ? _self.pts : pts // ignore: cast_nullable_to_non_nullable
-as BigInt,
+@pragma('vm:prefer-inline') $Res call({Object? commandId = null,Object? status = null,Object? detail = null,}) {
+  return _then(UiEvent_RemoteCommandReply(
+commandId: null == commandId ? _self.commandId : commandId // ignore: cast_nullable_to_non_nullable
+as int,status: null == status ? _self.status : status // ignore: cast_nullable_to_non_nullable
+as RemoteCommandStatus,detail: null == detail ? _self.detail : detail // ignore: cast_nullable_to_non_nullable
+as String,
   ));
 }
 
@@ -878,45 +3358,46 @@ as BigInt,
 /// @nodoc
 
 
-class UiEvent_AudioFrameReceived extends UiEvent {
-  const UiEvent_AudioFrameReceived({required this.data, required this.pts, required this.framesPerPacket}): super._();
+class UiEvent_PeerMediaState extends UiEvent {
+  const UiEvent_PeerMediaState({required this.microphoneMuted, required this.cameraOff, required this.audioOnly, required this.screenShare}): super._();
   
 
- final  Uint8List data;
- final  BigInt pts;
- final  int framesPerPacket;
+ final  bool microphoneMuted;
+ final  bool cameraOff;
+ final  bool audioOnly;
+ final  bool screenShare;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$UiEvent_AudioFrameReceivedCopyWith<UiEvent_AudioFrameReceived> get copyWith => _$UiEvent_AudioFrameReceivedCopyWithImpl<UiEvent_AudioFrameReceived>(this, _$identity);
+$UiEvent_PeerMediaStateCopyWith<UiEvent_PeerMediaState> get copyWith => _$UiEvent_PeerMediaStateCopyWithImpl<UiEvent_PeerMediaState>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_AudioFrameReceived&&const DeepCollectionEquality().equals(other.data, data)&&(identical(other.pts, pts) || other.pts == pts)&&(identical(other.framesPerPacket, framesPerPacket) || other.framesPerPacket == framesPerPacket));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UiEvent_PeerMediaState&&(identical(other.microphoneMuted, microphoneMuted) || other.microphoneMuted == microphoneMuted)&&(identical(other.cameraOff, cameraOff) || other.cameraOff == cameraOff)&&(identical(other.audioOnly, audioOnly) || other.audioOnly == audioOnly)&&(identical(other.screenShare, screenShare) || other.screenShare == screenShare));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(data),pts,framesPerPacket);
+int get hashCode => Object.hash(runtimeType,microphoneMuted,cameraOff,audioOnly,screenShare);
 
 @override
 String toString() {
-  return 'UiEvent.audioFrameReceived(data: $data, pts: $pts, framesPerPacket: $framesPerPacket)';
+  return 'UiEvent.peerMediaState(microphoneMuted: $microphoneMuted, cameraOff: $cameraOff, audioOnly: $audioOnly, screenShare: $screenShare)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $UiEvent_AudioFrameReceivedCopyWith<$Res> implements $UiEventCopyWith<$Res> {
-  factory $UiEvent_AudioFrameReceivedCopyWith(UiEvent_AudioFrameReceived value, $Res Function(UiEvent_AudioFrameReceived) _then) = _$UiEvent_AudioFrameReceivedCopyWithImpl;
+abstract mixin class $UiEvent_PeerMediaStateCopyWith<$Res> implements $UiEventCopyWith<$Res> {
+  factory $UiEvent_PeerMediaStateCopyWith(UiEvent_PeerMediaState value, $Res Function(UiEvent_PeerMediaState) _then) = _$UiEvent_PeerMediaStateCopyWithImpl;
 @useResult
 $Res call({
- Uint8List data, BigInt pts, int framesPerPacket
+ bool microphoneMuted, bool cameraOff, bool audioOnly, bool screenShare
 });
 
 
@@ -924,21 +3405,22 @@ $Res call({
 
 }
 /// @nodoc
-class _$UiEvent_AudioFrameReceivedCopyWithImpl<$Res>
-    implements $UiEvent_AudioFrameReceivedCopyWith<$Res> {
-  _$UiEvent_AudioFrameReceivedCopyWithImpl(this._self, this._then);
+class _$UiEvent_PeerMediaStateCopyWithImpl<$Res>
+    implements $UiEvent_PeerMediaStateCopyWith<$Res> {
+  _$UiEvent_PeerMediaStateCopyWithImpl(this._self, this._then);
 
-  final UiEvent_AudioFrameReceived _self;
-  final $Res Function(UiEvent_AudioFrameReceived) _then;
+  final UiEvent_PeerMediaState _self;
+  final $Res Function(UiEvent_PeerMediaState) _then;
 
 /// Create a copy of UiEvent
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? data = null,Object? pts = null,Object? framesPerPacket = null,}) {
-  return _then(UiEvent_AudioFrameReceived(
-data: null == data ? _self.data : data // ignore: cast_nullable_to_non_nullable
-as Uint8List,pts: null == pts ? _self.pts : pts // ignore: cast_nullable_to_non_nullable
-as BigInt,framesPerPacket: null == framesPerPacket ? _self.framesPerPacket : framesPerPacket // ignore: cast_nullable_to_non_nullable
-as int,
+@pragma('vm:prefer-inline') $Res call({Object? microphoneMuted = null,Object? cameraOff = null,Object? audioOnly = null,Object? screenShare = null,}) {
+  return _then(UiEvent_PeerMediaState(
+microphoneMuted: null == microphoneMuted ? _self.microphoneMuted : microphoneMuted // ignore: cast_nullable_to_non_nullable
+as bool,cameraOff: null == cameraOff ? _self.cameraOff : cameraOff // ignore: cast_nullable_to_non_nullable
+as bool,audioOnly: null == audioOnly ? _self.audioOnly : audioOnly // ignore: cast_nullable_to_non_nullable
+as bool,screenShare: null == screenShare ? _self.screenShare : screenShare // ignore: cast_nullable_to_non_nullable
+as bool,
   ));
 }
 
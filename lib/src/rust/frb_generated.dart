@@ -66,7 +66,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 805631695;
+  int get rustContentHash => 1979931018;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -77,8 +77,124 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<void> crateApiSimpleAnnounceMediaState({
+    required bool microphoneMuted,
+    required bool cameraOff,
+    required bool audioOnly,
+    required bool screenShare,
+  });
+
+  Future<void> crateApiSimpleCancelFileTransfer({
+    required int transferId,
+    required bool incoming,
+  });
+
+  Future<void> crateApiSimpleCloseDataChannel({required String label});
+
+  Future<void> crateApiSimpleConfigureFileTransfer({
+    String? receiveDir,
+    required BigInt maxBytes,
+  });
+
+  Future<void> crateApiSimpleConfigureReceiverAudioCodecs({
+    required List<int> codecs,
+  });
+
+  Future<void> crateApiSimpleConfigureReceiverAudioConcealment({
+    required bool enabled,
+  });
+
+  Future<void> crateApiSimpleConfigureReceiverJitterBuffer({
+    required bool enabled,
+    required int minDelayMs,
+    required int maxDelayMs,
+  });
+
+  Future<void> crateApiSimpleConfigureReceiverLipSync({
+    required bool enabled,
+    required int manualCorrectionMs,
+  });
+
+  Future<void> crateApiSimpleConfigureReceiverOpusDecode({
+    required bool enabled,
+    String? outputPath,
+  });
+
+  Future<void> crateApiSimpleConfigureReceiverVideoCodecs({
+    required List<int> codecs,
+  });
+
+  Future<void> crateApiSimpleConfigureReceiverVideoFormat({
+    required int nalLengthSize,
+  });
+
+  Future<void> crateApiSimpleConfigureRemoteCommandPolicy({
+    required RemoteCommandPolicy policy,
+  });
+
+  Future<void> crateApiSimpleConfigureSenderAacConfig({
+    required List<int> audioSpecificConfig,
+  });
+
+  Future<void> crateApiSimpleConfigureSenderAudioCodecs({
+    required List<int> codecs,
+  });
+
+  Future<void> crateApiSimpleConfigureSenderAudioRedundancy({
+    required int depth,
+  });
+
+  Future<void> crateApiSimpleConfigureSenderKeyframeDetection({
+    required KeyframeDetectionMode mode,
+  });
+
+  Future<void> crateApiSimpleConfigureSenderPcmFormat({
+    required int sampleRate,
+    required int channels,
+  });
+
+  Future<void> crateApiSimpleConfigureSenderStreamLatencyBudget({
+    required int streamId,
+    required bool enabled,
+    required int budgetMs,
+  });
+
+  Future<void> crateApiSimpleConfigureSenderVideoCodecs({
+    required List<int> codecs,
+  });
+
+  Future<void> crateApiSimpleConfigureSenderVideoLatencyBudget({
+    required bool enabled,
+    required int budgetMs,
+  });
+
+  Future<void> crateApiSimpleConfigureSenderVideoQueue({
+    required int capacity,
+    required VideoQueuePolicy policy,
+  });
+
+  Future<void> crateApiSimpleConfigureVideoBytesFrameRate({
+    required int frameRateNum,
+    required int frameRateDen,
+  });
+
+  Future<CongestionAction> crateApiSimpleCongestionActionDefault();
+
+  Future<CongestionState> crateApiSimpleCongestionStateDefault();
+
+  Future<CongestionState> crateApiSimpleFlushVideoBytes({required int codec});
+
+  Future<Uint8List?> crateApiSimpleGetCachedParameterSets({
+    required int streamId,
+  });
+
   Future<void> crateApiSimpleInitApp();
 
+  Future<void> crateApiSimpleOpenDataChannel({
+    required String label,
+    required DataChannelDelivery delivery,
+  });
+
   Future<void> crateApiSimplePushAudioFrame({
     required List<int> frameBytes,
     required BigInt pts,
@@ -86,11 +202,48 @@ abstract class RustLibApi extends BaseApi {
     required int framesPerPacket,
   });
 
-  Future<void> crateApiSimplePushVideoFrame({
+  Future<CongestionState> crateApiSimplePushVideoBytes({
+    required List<int> chunk,
+    BigInt? pts,
+    required int codec,
+  });
+
+  Future<CongestionState> crateApiSimplePushVideoFrame({
     required List<int> frameBytes,
     required bool isKeyframe,
     required BigInt pts,
     required int codec,
+    required int nalLengthSize,
+  });
+
+  Future<RemoteCommandPolicy> crateApiSimpleRemoteCommandPolicyDefault();
+
+  Future<void> crateApiSimpleReplyRemoteCommand({
+    required int commandId,
+    required RemoteCommandStatus status,
+    required String detail,
+  });
+
+  Future<void> crateApiSimpleSendControlMessage({
+    required ControlMessage message,
+  });
+
+  Future<void> crateApiSimpleSendData({
+    required String channel,
+    required List<int> data,
+  });
+
+  Future<void> crateApiSimpleSendDebugReport({
+    required String name,
+    required List<int> report,
+  });
+
+  Future<int> crateApiSimpleSendFile({required String path});
+
+  Future<int> crateApiSimpleSendRemoteCommand({required RemoteCommand command});
+
+  Future<void> crateApiSimpleSetReceiverLipSyncCorrection({
+    required int manualCorrectionMs,
   });
 
   Stream<UiEvent> crateApiSimpleStartSankakuReceiver({
@@ -103,9 +256,17 @@ abstract class RustLibApi extends BaseApi {
     required List<int> graphBytes,
   });
 
+  Stream<UiEvent> crateApiSimpleStartSoftwareVideoDecode({
+    required DecodedVideoFormat format,
+    required int maxWidth,
+    required int maxHeight,
+  });
+
   Future<void> crateApiSimpleStopSankakuReceiver();
 
   Future<void> crateApiSimpleStopSankakuSender();
+
+  Future<void> crateApiSimpleStopSoftwareVideoDecode();
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -117,11 +278,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Future<void> crateApiSimpleAnnounceMediaState({
+    required bool microphoneMuted,
+    required bool cameraOff,
+    required bool audioOnly,
+    required bool screenShare,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(microphoneMuted, serializer);
+          sse_encode_bool(cameraOff, serializer);
+          sse_encode_bool(audioOnly, serializer);
+          sse_encode_bool(screenShare, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -131,33 +301,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSimpleAnnounceMediaStateConstMeta,
+        argValues: [microphoneMuted, cameraOff, audioOnly, screenShare],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiSimpleAnnounceMediaStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "announce_media_state",
+        argNames: ["microphoneMuted", "cameraOff", "audioOnly", "screenShare"],
+      );
 
   @override
-  Future<void> crateApiSimplePushAudioFrame({
-    required List<int> frameBytes,
-    required BigInt pts,
-    required int codec,
-    required int framesPerPacket,
+  Future<void> crateApiSimpleCancelFileTransfer({
+    required int transferId,
+    required bool incoming,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(frameBytes, serializer);
-          sse_encode_u_64(pts, serializer);
-          sse_encode_u_8(codec, serializer);
-          sse_encode_u_32(framesPerPacket, serializer);
+          sse_encode_u_32(transferId, serializer);
+          sse_encode_bool(incoming, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -169,34 +338,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSimplePushAudioFrameConstMeta,
-        argValues: [frameBytes, pts, codec, framesPerPacket],
+        constMeta: kCrateApiSimpleCancelFileTransferConstMeta,
+        argValues: [transferId, incoming],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimplePushAudioFrameConstMeta =>
+  TaskConstMeta get kCrateApiSimpleCancelFileTransferConstMeta =>
       const TaskConstMeta(
-        debugName: "push_audio_frame",
-        argNames: ["frameBytes", "pts", "codec", "framesPerPacket"],
+        debugName: "cancel_file_transfer",
+        argNames: ["transferId", "incoming"],
       );
 
   @override
-  Future<void> crateApiSimplePushVideoFrame({
-    required List<int> frameBytes,
-    required bool isKeyframe,
-    required BigInt pts,
-    required int codec,
-  }) {
+  Future<void> crateApiSimpleCloseDataChannel({required String label}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(frameBytes, serializer);
-          sse_encode_bool(isKeyframe, serializer);
-          sse_encode_u_64(pts, serializer);
-          sse_encode_u_8(codec, serializer);
+          sse_encode_String(label, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -208,105 +369,93 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSimplePushVideoFrameConstMeta,
-        argValues: [frameBytes, isKeyframe, pts, codec],
+        constMeta: kCrateApiSimpleCloseDataChannelConstMeta,
+        argValues: [label],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimplePushVideoFrameConstMeta =>
-      const TaskConstMeta(
-        debugName: "push_video_frame",
-        argNames: ["frameBytes", "isKeyframe", "pts", "codec"],
-      );
+  TaskConstMeta get kCrateApiSimpleCloseDataChannelConstMeta =>
+      const TaskConstMeta(debugName: "close_data_channel", argNames: ["label"]);
 
   @override
-  Stream<UiEvent> crateApiSimpleStartSankakuReceiver({
-    required String bindAddr,
-    required List<int> graphBytes,
+  Future<void> crateApiSimpleConfigureFileTransfer({
+    String? receiveDir,
+    required BigInt maxBytes,
   }) {
-    final sink = RustStreamSink<UiEvent>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_StreamSink_ui_event_Sse(sink, serializer);
-            sse_encode_String(bindAddr, serializer);
-            sse_encode_list_prim_u_8_loose(graphBytes, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 4,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: sse_decode_AnyhowException,
-          ),
-          constMeta: kCrateApiSimpleStartSankakuReceiverConstMeta,
-          argValues: [sink, bindAddr, graphBytes],
-          apiImpl: this,
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(receiveDir, serializer);
+          sse_encode_u_64(maxBytes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 4,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
+        constMeta: kCrateApiSimpleConfigureFileTransferConstMeta,
+        argValues: [receiveDir, maxBytes],
+        apiImpl: this,
       ),
     );
-    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiSimpleStartSankakuReceiverConstMeta =>
+  TaskConstMeta get kCrateApiSimpleConfigureFileTransferConstMeta =>
       const TaskConstMeta(
-        debugName: "start_sankaku_receiver",
-        argNames: ["sink", "bindAddr", "graphBytes"],
+        debugName: "configure_file_transfer",
+        argNames: ["receiveDir", "maxBytes"],
       );
 
   @override
-  Stream<UiEvent> crateApiSimpleStartSankakuSender({
-    required String dest,
-    required List<int> graphBytes,
+  Future<void> crateApiSimpleConfigureReceiverAudioCodecs({
+    required List<int> codecs,
   }) {
-    final sink = RustStreamSink<UiEvent>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_StreamSink_ui_event_Sse(sink, serializer);
-            sse_encode_String(dest, serializer);
-            sse_encode_list_prim_u_8_loose(graphBytes, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 5,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: sse_decode_AnyhowException,
-          ),
-          constMeta: kCrateApiSimpleStartSankakuSenderConstMeta,
-          argValues: [sink, dest, graphBytes],
-          apiImpl: this,
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(codecs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 5,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
+        constMeta: kCrateApiSimpleConfigureReceiverAudioCodecsConstMeta,
+        argValues: [codecs],
+        apiImpl: this,
       ),
     );
-    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiSimpleStartSankakuSenderConstMeta =>
+  TaskConstMeta get kCrateApiSimpleConfigureReceiverAudioCodecsConstMeta =>
       const TaskConstMeta(
-        debugName: "start_sankaku_sender",
-        argNames: ["sink", "dest", "graphBytes"],
+        debugName: "configure_receiver_audio_codecs",
+        argNames: ["codecs"],
       );
 
   @override
-  Future<void> crateApiSimpleStopSankakuReceiver() {
+  Future<void> crateApiSimpleConfigureReceiverAudioConcealment({
+    required bool enabled,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -318,22 +467,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSimpleStopSankakuReceiverConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSimpleConfigureReceiverAudioConcealmentConstMeta,
+        argValues: [enabled],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleStopSankakuReceiverConstMeta =>
-      const TaskConstMeta(debugName: "stop_sankaku_receiver", argNames: []);
+  TaskConstMeta get kCrateApiSimpleConfigureReceiverAudioConcealmentConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_receiver_audio_concealment",
+        argNames: ["enabled"],
+      );
 
   @override
-  Future<void> crateApiSimpleStopSankakuSender() {
+  Future<void> crateApiSimpleConfigureReceiverJitterBuffer({
+    required bool enabled,
+    required int minDelayMs,
+    required int maxDelayMs,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          sse_encode_u_32(minDelayMs, serializer);
+          sse_encode_u_32(maxDelayMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -345,45 +504,1452 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSimpleStopSankakuSenderConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSimpleConfigureReceiverJitterBufferConstMeta,
+        argValues: [enabled, minDelayMs, maxDelayMs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleStopSankakuSenderConstMeta =>
-      const TaskConstMeta(debugName: "stop_sankaku_sender", argNames: []);
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  RustStreamSink<UiEvent> dco_decode_StreamSink_ui_event_Sse(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
+  TaskConstMeta get kCrateApiSimpleConfigureReceiverJitterBufferConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_receiver_jitter_buffer",
+        argNames: ["enabled", "minDelayMs", "maxDelayMs"],
+      );
 
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  @override
+  Future<void> crateApiSimpleConfigureReceiverLipSync({
+    required bool enabled,
+    required int manualCorrectionMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          sse_encode_i_32(manualCorrectionMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 8,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleConfigureReceiverLipSyncConstMeta,
+        argValues: [enabled, manualCorrectionMs],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
+  TaskConstMeta get kCrateApiSimpleConfigureReceiverLipSyncConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_receiver_lip_sync",
+        argNames: ["enabled", "manualCorrectionMs"],
+      );
 
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
-  }
+  @override
+  Future<void> crateApiSimpleConfigureReceiverOpusDecode({
+    required bool enabled,
+    String? outputPath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          sse_encode_opt_String(outputPath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 9,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleConfigureReceiverOpusDecodeConstMeta,
+        argValues: [enabled, outputPath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleConfigureReceiverOpusDecodeConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_receiver_opus_decode",
+        argNames: ["enabled", "outputPath"],
+      );
+
+  @override
+  Future<void> crateApiSimpleConfigureReceiverVideoCodecs({
+    required List<int> codecs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(codecs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 10,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleConfigureReceiverVideoCodecsConstMeta,
+        argValues: [codecs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleConfigureReceiverVideoCodecsConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_receiver_video_codecs",
+        argNames: ["codecs"],
+      );
+
+  @override
+  Future<void> crateApiSimpleConfigureReceiverVideoFormat({
+    required int nalLengthSize,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_8(nalLengthSize, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 11,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleConfigureReceiverVideoFormatConstMeta,
+        argValues: [nalLengthSize],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleConfigureReceiverVideoFormatConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_receiver_video_format",
+        argNames: ["nalLengthSize"],
+      );
+
+  @override
+  Future<void> crateApiSimpleConfigureRemoteCommandPolicy({
+    required RemoteCommandPolicy policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_remote_command_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 12,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleConfigureRemoteCommandPolicyConstMeta,
+        argValues: [policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleConfigureRemoteCommandPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_remote_command_policy",
+        argNames: ["policy"],
+      );
+
+  @override
+  Future<void> crateApiSimpleConfigureSenderAacConfig({
+    required List<int> audioSpecificConfig,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(audioSpecificConfig, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 13,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleConfigureSenderAacConfigConstMeta,
+        argValues: [audioSpecificConfig],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleConfigureSenderAacConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_sender_aac_config",
+        argNames: ["audioSpecificConfig"],
+      );
+
+  @override
+  Future<void> crateApiSimpleConfigureSenderAudioCodecs({
+    required List<int> codecs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(codecs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleConfigureSenderAudioCodecsConstMeta,
+        argValues: [codecs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleConfigureSenderAudioCodecsConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_sender_audio_codecs",
+        argNames: ["codecs"],
+      );
+
+  @override
+  Future<void> crateApiSimpleConfigureSenderAudioRedundancy({
+    required int depth,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(depth, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleConfigureSenderAudioRedundancyConstMeta,
+        argValues: [depth],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleConfigureSenderAudioRedundancyConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_sender_audio_redundancy",
+        argNames: ["depth"],
+      );
+
+  @override
+  Future<void> crateApiSimpleConfigureSenderKeyframeDetection({
+    required KeyframeDetectionMode mode,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_keyframe_detection_mode(mode, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleConfigureSenderKeyframeDetectionConstMeta,
+        argValues: [mode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleConfigureSenderKeyframeDetectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_sender_keyframe_detection",
+        argNames: ["mode"],
+      );
+
+  @override
+  Future<void> crateApiSimpleConfigureSenderPcmFormat({
+    required int sampleRate,
+    required int channels,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(sampleRate, serializer);
+          sse_encode_u_32(channels, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleConfigureSenderPcmFormatConstMeta,
+        argValues: [sampleRate, channels],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleConfigureSenderPcmFormatConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_sender_pcm_format",
+        argNames: ["sampleRate", "channels"],
+      );
+
+  @override
+  Future<void> crateApiSimpleConfigureSenderStreamLatencyBudget({
+    required int streamId,
+    required bool enabled,
+    required int budgetMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(streamId, serializer);
+          sse_encode_bool(enabled, serializer);
+          sse_encode_u_32(budgetMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleConfigureSenderStreamLatencyBudgetConstMeta,
+        argValues: [streamId, enabled, budgetMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleConfigureSenderStreamLatencyBudgetConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_sender_stream_latency_budget",
+        argNames: ["streamId", "enabled", "budgetMs"],
+      );
+
+  @override
+  Future<void> crateApiSimpleConfigureSenderVideoCodecs({
+    required List<int> codecs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(codecs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleConfigureSenderVideoCodecsConstMeta,
+        argValues: [codecs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleConfigureSenderVideoCodecsConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_sender_video_codecs",
+        argNames: ["codecs"],
+      );
+
+  @override
+  Future<void> crateApiSimpleConfigureSenderVideoLatencyBudget({
+    required bool enabled,
+    required int budgetMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          sse_encode_u_32(budgetMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleConfigureSenderVideoLatencyBudgetConstMeta,
+        argValues: [enabled, budgetMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleConfigureSenderVideoLatencyBudgetConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_sender_video_latency_budget",
+        argNames: ["enabled", "budgetMs"],
+      );
+
+  @override
+  Future<void> crateApiSimpleConfigureSenderVideoQueue({
+    required int capacity,
+    required VideoQueuePolicy policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(capacity, serializer);
+          sse_encode_video_queue_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleConfigureSenderVideoQueueConstMeta,
+        argValues: [capacity, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleConfigureSenderVideoQueueConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_sender_video_queue",
+        argNames: ["capacity", "policy"],
+      );
+
+  @override
+  Future<void> crateApiSimpleConfigureVideoBytesFrameRate({
+    required int frameRateNum,
+    required int frameRateDen,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(frameRateNum, serializer);
+          sse_encode_u_32(frameRateDen, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleConfigureVideoBytesFrameRateConstMeta,
+        argValues: [frameRateNum, frameRateDen],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleConfigureVideoBytesFrameRateConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_video_bytes_frame_rate",
+        argNames: ["frameRateNum", "frameRateDen"],
+      );
+
+  @override
+  Future<CongestionAction> crateApiSimpleCongestionActionDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_congestion_action,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleCongestionActionDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleCongestionActionDefaultConstMeta =>
+      const TaskConstMeta(debugName: "congestion_action_default", argNames: []);
+
+  @override
+  Future<CongestionState> crateApiSimpleCongestionStateDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_congestion_state,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleCongestionStateDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleCongestionStateDefaultConstMeta =>
+      const TaskConstMeta(debugName: "congestion_state_default", argNames: []);
+
+  @override
+  Future<CongestionState> crateApiSimpleFlushVideoBytes({required int codec}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_8(codec, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_congestion_state,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleFlushVideoBytesConstMeta,
+        argValues: [codec],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleFlushVideoBytesConstMeta =>
+      const TaskConstMeta(debugName: "flush_video_bytes", argNames: ["codec"]);
+
+  @override
+  Future<Uint8List?> crateApiSimpleGetCachedParameterSets({
+    required int streamId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(streamId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleGetCachedParameterSetsConstMeta,
+        argValues: [streamId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetCachedParameterSetsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_cached_parameter_sets",
+        argNames: ["streamId"],
+      );
+
+  @override
+  Future<void> crateApiSimpleInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<void> crateApiSimpleOpenDataChannel({
+    required String label,
+    required DataChannelDelivery delivery,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(label, serializer);
+          sse_encode_data_channel_delivery(delivery, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleOpenDataChannelConstMeta,
+        argValues: [label, delivery],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleOpenDataChannelConstMeta =>
+      const TaskConstMeta(
+        debugName: "open_data_channel",
+        argNames: ["label", "delivery"],
+      );
+
+  @override
+  Future<void> crateApiSimplePushAudioFrame({
+    required List<int> frameBytes,
+    required BigInt pts,
+    required int codec,
+    required int framesPerPacket,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(frameBytes, serializer);
+          sse_encode_u_64(pts, serializer);
+          sse_encode_u_8(codec, serializer);
+          sse_encode_u_32(framesPerPacket, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimplePushAudioFrameConstMeta,
+        argValues: [frameBytes, pts, codec, framesPerPacket],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimplePushAudioFrameConstMeta =>
+      const TaskConstMeta(
+        debugName: "push_audio_frame",
+        argNames: ["frameBytes", "pts", "codec", "framesPerPacket"],
+      );
+
+  @override
+  Future<CongestionState> crateApiSimplePushVideoBytes({
+    required List<int> chunk,
+    BigInt? pts,
+    required int codec,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(chunk, serializer);
+          sse_encode_opt_box_autoadd_u_64(pts, serializer);
+          sse_encode_u_8(codec, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_congestion_state,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimplePushVideoBytesConstMeta,
+        argValues: [chunk, pts, codec],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimplePushVideoBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "push_video_bytes",
+        argNames: ["chunk", "pts", "codec"],
+      );
+
+  @override
+  Future<CongestionState> crateApiSimplePushVideoFrame({
+    required List<int> frameBytes,
+    required bool isKeyframe,
+    required BigInt pts,
+    required int codec,
+    required int nalLengthSize,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(frameBytes, serializer);
+          sse_encode_bool(isKeyframe, serializer);
+          sse_encode_u_64(pts, serializer);
+          sse_encode_u_8(codec, serializer);
+          sse_encode_u_8(nalLengthSize, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_congestion_state,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimplePushVideoFrameConstMeta,
+        argValues: [frameBytes, isKeyframe, pts, codec, nalLengthSize],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimplePushVideoFrameConstMeta =>
+      const TaskConstMeta(
+        debugName: "push_video_frame",
+        argNames: ["frameBytes", "isKeyframe", "pts", "codec", "nalLengthSize"],
+      );
+
+  @override
+  Future<RemoteCommandPolicy> crateApiSimpleRemoteCommandPolicyDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_remote_command_policy,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleRemoteCommandPolicyDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleRemoteCommandPolicyDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "remote_command_policy_default",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiSimpleReplyRemoteCommand({
+    required int commandId,
+    required RemoteCommandStatus status,
+    required String detail,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(commandId, serializer);
+          sse_encode_remote_command_status(status, serializer);
+          sse_encode_String(detail, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleReplyRemoteCommandConstMeta,
+        argValues: [commandId, status, detail],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleReplyRemoteCommandConstMeta =>
+      const TaskConstMeta(
+        debugName: "reply_remote_command",
+        argNames: ["commandId", "status", "detail"],
+      );
+
+  @override
+  Future<void> crateApiSimpleSendControlMessage({
+    required ControlMessage message,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_control_message(message, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleSendControlMessageConstMeta,
+        argValues: [message],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSendControlMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_control_message",
+        argNames: ["message"],
+      );
+
+  @override
+  Future<void> crateApiSimpleSendData({
+    required String channel,
+    required List<int> data,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(channel, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleSendDataConstMeta,
+        argValues: [channel, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSendDataConstMeta => const TaskConstMeta(
+    debugName: "send_data",
+    argNames: ["channel", "data"],
+  );
+
+  @override
+  Future<void> crateApiSimpleSendDebugReport({
+    required String name,
+    required List<int> report,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_list_prim_u_8_loose(report, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleSendDebugReportConstMeta,
+        argValues: [name, report],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSendDebugReportConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_debug_report",
+        argNames: ["name", "report"],
+      );
+
+  @override
+  Future<int> crateApiSimpleSendFile({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleSendFileConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSendFileConstMeta =>
+      const TaskConstMeta(debugName: "send_file", argNames: ["path"]);
+
+  @override
+  Future<int> crateApiSimpleSendRemoteCommand({
+    required RemoteCommand command,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_remote_command(command, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleSendRemoteCommandConstMeta,
+        argValues: [command],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSendRemoteCommandConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_remote_command",
+        argNames: ["command"],
+      );
+
+  @override
+  Future<void> crateApiSimpleSetReceiverLipSyncCorrection({
+    required int manualCorrectionMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_32(manualCorrectionMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleSetReceiverLipSyncCorrectionConstMeta,
+        argValues: [manualCorrectionMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetReceiverLipSyncCorrectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_receiver_lip_sync_correction",
+        argNames: ["manualCorrectionMs"],
+      );
+
+  @override
+  Stream<UiEvent> crateApiSimpleStartSankakuReceiver({
+    required String bindAddr,
+    required List<int> graphBytes,
+  }) {
+    final sink = RustStreamSink<UiEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_ui_event_Sse(sink, serializer);
+            sse_encode_String(bindAddr, serializer);
+            sse_encode_list_prim_u_8_loose(graphBytes, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 40,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiSimpleStartSankakuReceiverConstMeta,
+          argValues: [sink, bindAddr, graphBytes],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiSimpleStartSankakuReceiverConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_sankaku_receiver",
+        argNames: ["sink", "bindAddr", "graphBytes"],
+      );
+
+  @override
+  Stream<UiEvent> crateApiSimpleStartSankakuSender({
+    required String dest,
+    required List<int> graphBytes,
+  }) {
+    final sink = RustStreamSink<UiEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_ui_event_Sse(sink, serializer);
+            sse_encode_String(dest, serializer);
+            sse_encode_list_prim_u_8_loose(graphBytes, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 41,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiSimpleStartSankakuSenderConstMeta,
+          argValues: [sink, dest, graphBytes],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiSimpleStartSankakuSenderConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_sankaku_sender",
+        argNames: ["sink", "dest", "graphBytes"],
+      );
+
+  @override
+  Stream<UiEvent> crateApiSimpleStartSoftwareVideoDecode({
+    required DecodedVideoFormat format,
+    required int maxWidth,
+    required int maxHeight,
+  }) {
+    final sink = RustStreamSink<UiEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_ui_event_Sse(sink, serializer);
+            sse_encode_decoded_video_format(format, serializer);
+            sse_encode_u_32(maxWidth, serializer);
+            sse_encode_u_32(maxHeight, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 42,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiSimpleStartSoftwareVideoDecodeConstMeta,
+          argValues: [sink, format, maxWidth, maxHeight],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiSimpleStartSoftwareVideoDecodeConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_software_video_decode",
+        argNames: ["sink", "format", "maxWidth", "maxHeight"],
+      );
+
+  @override
+  Future<void> crateApiSimpleStopSankakuReceiver() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleStopSankakuReceiverConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleStopSankakuReceiverConstMeta =>
+      const TaskConstMeta(debugName: "stop_sankaku_receiver", argNames: []);
+
+  @override
+  Future<void> crateApiSimpleStopSankakuSender() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleStopSankakuSenderConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleStopSankakuSenderConstMeta =>
+      const TaskConstMeta(debugName: "stop_sankaku_sender", argNames: []);
+
+  @override
+  Future<void> crateApiSimpleStopSoftwareVideoDecode() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleStopSoftwareVideoDecodeConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleStopSoftwareVideoDecodeConstMeta =>
+      const TaskConstMeta(
+        debugName: "stop_software_video_decode",
+        argNames: [],
+      );
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  RustStreamSink<UiEvent> dco_decode_StreamSink_ui_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  ControlMessage dco_decode_box_autoadd_control_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_control_message(raw);
+  }
+
+  @protected
+  RemoteCommand dco_decode_box_autoadd_remote_command(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_remote_command(raw);
+  }
+
+  @protected
+  RemoteCommandPolicy dco_decode_box_autoadd_remote_command_policy(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_remote_command_policy(raw);
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  CongestionAction dco_decode_congestion_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CongestionAction.values[raw as int];
+  }
+
+  @protected
+  CongestionState dco_decode_congestion_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CongestionState(
+      queueDepth: dco_decode_u_32(arr[0]),
+      queueCapacity: dco_decode_u_32(arr[1]),
+      estimatedSendDelayMs: dco_decode_u_32(arr[2]),
+      recommendedAction: dco_decode_congestion_action(arr[3]),
+      frameQueued: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  ControlMessage dco_decode_control_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ControlMessage_KeyframeRequest(
+          streamId: dco_decode_u_32(raw[1]),
+        );
+      case 1:
+        return ControlMessage_MuteState(
+          audioMuted: dco_decode_bool(raw[1]),
+          videoMuted: dco_decode_bool(raw[2]),
+        );
+      case 2:
+        return ControlMessage_Hangup(reason: dco_decode_String(raw[1]));
+      case 3:
+        return ControlMessage_Capabilities(
+          videoCodecs: dco_decode_list_prim_u_8_strict(raw[1]),
+          audioCodecs: dco_decode_list_prim_u_8_strict(raw[2]),
+          maxWidth: dco_decode_u_32(raw[3]),
+          maxHeight: dco_decode_u_32(raw[4]),
+        );
+      case 4:
+        return ControlMessage_ReceiverReport(
+          streamId: dco_decode_u_32(raw[1]),
+          highestFrameIndex: dco_decode_u_64(raw[2]),
+          lostFrames: dco_decode_u_64(raw[3]),
+          lateFrames: dco_decode_u_64(raw[4]),
+          awaitingKeyframe: dco_decode_bool(raw[5]),
+          jitterUs: dco_decode_u_32(raw[6]),
+        );
+      case 5:
+        return ControlMessage_Custom(
+          kind: dco_decode_String(raw[1]),
+          payload: dco_decode_list_prim_u_8_strict(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  DataChannelDelivery dco_decode_data_channel_delivery(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DataChannelDelivery.values[raw as int];
+  }
+
+  @protected
+  DecodedVideoFormat dco_decode_decoded_video_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DecodedVideoFormat.values[raw as int];
+  }
+
+  @protected
+  FileTransferOutcome dco_decode_file_transfer_outcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FileTransferOutcome.values[raw as int];
+  }
+
+  @protected
+  int dco_decode_i_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  KeyframeDetectionMode dco_decode_keyframe_detection_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return KeyframeDetectionMode.values[raw as int];
+  }
+
+  @protected
+  Int16List dco_decode_list_prim_i_16_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Int16List;
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
 
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
@@ -391,6 +1957,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as Uint8List;
   }
 
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
+  }
+
+  @protected
+  RemoteCommand dco_decode_remote_command(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return RemoteCommand_SwitchCamera();
+      case 1:
+        return RemoteCommand_SetTorch(enabled: dco_decode_bool(raw[1]));
+      case 2:
+        return RemoteCommand_SetBitrate(bitrateBps: dco_decode_u_32(raw[1]));
+      case 3:
+        return RemoteCommand_StartCapture();
+      case 4:
+        return RemoteCommand_StopCapture();
+      case 5:
+        return RemoteCommand_RequestDebugReport();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  RemoteCommandPolicy dco_decode_remote_command_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RemoteCommandPolicy(
+      allowSwitchCamera: dco_decode_bool(arr[0]),
+      allowTorch: dco_decode_bool(arr[1]),
+      allowBitrate: dco_decode_bool(arr[2]),
+      allowCapture: dco_decode_bool(arr[3]),
+      allowDebugReport: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  RemoteCommandStatus dco_decode_remote_command_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RemoteCommandStatus.values[raw as int];
+  }
+
   @protected
   int dco_decode_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -463,6 +2089,139 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           framesPerPacket: dco_decode_u_32(raw[3]),
         );
       case 11:
+        return UiEvent_AudioConcealment(
+          streamId: dco_decode_u_32(raw[1]),
+          pts: dco_decode_u_64(raw[2]),
+          framesPerPacket: dco_decode_u_32(raw[3]),
+        );
+      case 12:
+        return UiEvent_PcmAudioReceived(
+          streamId: dco_decode_u_32(raw[1]),
+          pts: dco_decode_u_64(raw[2]),
+          sampleRate: dco_decode_u_32(raw[3]),
+          channels: dco_decode_u_32(raw[4]),
+          framesPerPacket: dco_decode_u_32(raw[5]),
+          samples: dco_decode_list_prim_i_16_strict(raw[6]),
+          concealed: dco_decode_bool(raw[7]),
+        );
+      case 13:
+        return UiEvent_DecodedVideoFrame(
+          streamId: dco_decode_u_32(raw[1]),
+          pts: dco_decode_u_64(raw[2]),
+          width: dco_decode_u_32(raw[3]),
+          height: dco_decode_u_32(raw[4]),
+          format: dco_decode_decoded_video_format(raw[5]),
+          data: dco_decode_list_prim_u_8_strict(raw[6]),
+        );
+      case 14:
+        return UiEvent_StreamFormat(
+          streamId: dco_decode_u_32(raw[1]),
+          codec: dco_decode_String(raw[2]),
+          width: dco_decode_u_32(raw[3]),
+          height: dco_decode_u_32(raw[4]),
+          profileIdc: dco_decode_u_32(raw[5]),
+          highTier: dco_decode_bool(raw[6]),
+          levelIdc: dco_decode_u_32(raw[7]),
+          chromaFormatIdc: dco_decode_u_32(raw[8]),
+          bitDepthLuma: dco_decode_u_32(raw[9]),
+          bitDepthChroma: dco_decode_u_32(raw[10]),
+          frameRateNum: dco_decode_u_32(raw[11]),
+          frameRateDen: dco_decode_u_32(raw[12]),
+        );
+      case 15:
+        return UiEvent_Congestion(
+          queueDepth: dco_decode_u_32(raw[1]),
+          queueCapacity: dco_decode_u_32(raw[2]),
+          estimatedSendDelayMs: dco_decode_u_32(raw[3]),
+          recommendedAction: dco_decode_String(raw[4]),
+        );
+      case 16:
+        return UiEvent_TemporalLayers(
+          streamId: dco_decode_u_32(raw[1]),
+          activeLayers: dco_decode_u_32(raw[2]),
+          totalLayers: dco_decode_u_32(raw[3]),
+        );
+      case 17:
+        return UiEvent_AudioGap(
+          streamId: dco_decode_u_32(raw[1]),
+          expectedPts: dco_decode_u_64(raw[2]),
+          missingUs: dco_decode_u_64(raw[3]),
+          missingPackets: dco_decode_u_32(raw[4]),
+          concealmentPackets: dco_decode_u_32(raw[5]),
+        );
+      case 18:
+        return UiEvent_AudioActivity(
+          streamId: dco_decode_u_32(raw[1]),
+          local: dco_decode_bool(raw[2]),
+          speaking: dco_decode_bool(raw[3]),
+        );
+      case 19:
+        return UiEvent_CodecNegotiated(
+          codec: dco_decode_u_8(raw[1]),
+          codecName: dco_decode_String(raw[2]),
+          peerNegotiated: dco_decode_bool(raw[3]),
+        );
+      case 20:
+        return UiEvent_AudioCodecNegotiated(
+          codec: dco_decode_u_8(raw[1]),
+          codecName: dco_decode_String(raw[2]),
+          sampleRate: dco_decode_u_32(raw[3]),
+          channels: dco_decode_u_32(raw[4]),
+          codecConfig: dco_decode_list_prim_u_8_strict(raw[5]),
+          peerNegotiated: dco_decode_bool(raw[6]),
+        );
+      case 21:
+        return UiEvent_ControlMessageReceived(
+          message: dco_decode_box_autoadd_control_message(raw[1]),
+        );
+      case 22:
+        return UiEvent_DataReceived(
+          channel: dco_decode_String(raw[1]),
+          data: dco_decode_list_prim_u_8_strict(raw[2]),
+          reliable: dco_decode_bool(raw[3]),
+        );
+      case 23:
+        return UiEvent_FileTransferStarted(
+          transferId: dco_decode_u_32(raw[1]),
+          incoming: dco_decode_bool(raw[2]),
+          name: dco_decode_String(raw[3]),
+          size: dco_decode_u_64(raw[4]),
+        );
+      case 24:
+        return UiEvent_FileTransferProgress(
+          transferId: dco_decode_u_32(raw[1]),
+          incoming: dco_decode_bool(raw[2]),
+          bytes: dco_decode_u_64(raw[3]),
+          size: dco_decode_u_64(raw[4]),
+        );
+      case 25:
+        return UiEvent_FileTransferFinished(
+          transferId: dco_decode_u_32(raw[1]),
+          incoming: dco_decode_bool(raw[2]),
+          name: dco_decode_String(raw[3]),
+          outcome: dco_decode_file_transfer_outcome(raw[4]),
+          path: dco_decode_String(raw[5]),
+          detail: dco_decode_String(raw[6]),
+        );
+      case 26:
+        return UiEvent_RemoteCommand(
+          commandId: dco_decode_u_32(raw[1]),
+          command: dco_decode_box_autoadd_remote_command(raw[2]),
+        );
+      case 27:
+        return UiEvent_RemoteCommandReply(
+          commandId: dco_decode_u_32(raw[1]),
+          status: dco_decode_remote_command_status(raw[2]),
+          detail: dco_decode_String(raw[3]),
+        );
+      case 28:
+        return UiEvent_PeerMediaState(
+          microphoneMuted: dco_decode_bool(raw[1]),
+          cameraOff: dco_decode_bool(raw[2]),
+          audioOnly: dco_decode_bool(raw[3]),
+          screenShare: dco_decode_bool(raw[4]),
+        );
+      case 29:
         return UiEvent_Error(msg: dco_decode_String(raw[1]));
       default:
         throw Exception("unreachable");
@@ -475,6 +2234,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return;
   }
 
+  @protected
+  VideoQueuePolicy dco_decode_video_queue_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return VideoQueuePolicy.values[raw as int];
+  }
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -503,6 +2268,169 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getUint8() != 0;
   }
 
+  @protected
+  ControlMessage sse_decode_box_autoadd_control_message(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_control_message(deserializer));
+  }
+
+  @protected
+  RemoteCommand sse_decode_box_autoadd_remote_command(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_remote_command(deserializer));
+  }
+
+  @protected
+  RemoteCommandPolicy sse_decode_box_autoadd_remote_command_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_remote_command_policy(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  CongestionAction sse_decode_congestion_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CongestionAction.values[inner];
+  }
+
+  @protected
+  CongestionState sse_decode_congestion_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_queueDepth = sse_decode_u_32(deserializer);
+    var var_queueCapacity = sse_decode_u_32(deserializer);
+    var var_estimatedSendDelayMs = sse_decode_u_32(deserializer);
+    var var_recommendedAction = sse_decode_congestion_action(deserializer);
+    var var_frameQueued = sse_decode_bool(deserializer);
+    return CongestionState(
+      queueDepth: var_queueDepth,
+      queueCapacity: var_queueCapacity,
+      estimatedSendDelayMs: var_estimatedSendDelayMs,
+      recommendedAction: var_recommendedAction,
+      frameQueued: var_frameQueued,
+    );
+  }
+
+  @protected
+  ControlMessage sse_decode_control_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_streamId = sse_decode_u_32(deserializer);
+        return ControlMessage_KeyframeRequest(streamId: var_streamId);
+      case 1:
+        var var_audioMuted = sse_decode_bool(deserializer);
+        var var_videoMuted = sse_decode_bool(deserializer);
+        return ControlMessage_MuteState(
+          audioMuted: var_audioMuted,
+          videoMuted: var_videoMuted,
+        );
+      case 2:
+        var var_reason = sse_decode_String(deserializer);
+        return ControlMessage_Hangup(reason: var_reason);
+      case 3:
+        var var_videoCodecs = sse_decode_list_prim_u_8_strict(deserializer);
+        var var_audioCodecs = sse_decode_list_prim_u_8_strict(deserializer);
+        var var_maxWidth = sse_decode_u_32(deserializer);
+        var var_maxHeight = sse_decode_u_32(deserializer);
+        return ControlMessage_Capabilities(
+          videoCodecs: var_videoCodecs,
+          audioCodecs: var_audioCodecs,
+          maxWidth: var_maxWidth,
+          maxHeight: var_maxHeight,
+        );
+      case 4:
+        var var_streamId = sse_decode_u_32(deserializer);
+        var var_highestFrameIndex = sse_decode_u_64(deserializer);
+        var var_lostFrames = sse_decode_u_64(deserializer);
+        var var_lateFrames = sse_decode_u_64(deserializer);
+        var var_awaitingKeyframe = sse_decode_bool(deserializer);
+        var var_jitterUs = sse_decode_u_32(deserializer);
+        return ControlMessage_ReceiverReport(
+          streamId: var_streamId,
+          highestFrameIndex: var_highestFrameIndex,
+          lostFrames: var_lostFrames,
+          lateFrames: var_lateFrames,
+          awaitingKeyframe: var_awaitingKeyframe,
+          jitterUs: var_jitterUs,
+        );
+      case 5:
+        var var_kind = sse_decode_String(deserializer);
+        var var_payload = sse_decode_list_prim_u_8_strict(deserializer);
+        return ControlMessage_Custom(kind: var_kind, payload: var_payload);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  DataChannelDelivery sse_decode_data_channel_delivery(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DataChannelDelivery.values[inner];
+  }
+
+  @protected
+  DecodedVideoFormat sse_decode_decoded_video_format(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DecodedVideoFormat.values[inner];
+  }
+
+  @protected
+  FileTransferOutcome sse_decode_file_transfer_outcome(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FileTransferOutcome.values[inner];
+  }
+
+  @protected
+  int sse_decode_i_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt16();
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  KeyframeDetectionMode sse_decode_keyframe_detection_mode(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return KeyframeDetectionMode.values[inner];
+  }
+
+  @protected
+  Int16List sse_decode_list_prim_i_16_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt16List(len_);
+  }
+
   @protected
   List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -511,10 +2439,96 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_8_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RemoteCommand sse_decode_remote_command(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return RemoteCommand_SwitchCamera();
+      case 1:
+        var var_enabled = sse_decode_bool(deserializer);
+        return RemoteCommand_SetTorch(enabled: var_enabled);
+      case 2:
+        var var_bitrateBps = sse_decode_u_32(deserializer);
+        return RemoteCommand_SetBitrate(bitrateBps: var_bitrateBps);
+      case 3:
+        return RemoteCommand_StartCapture();
+      case 4:
+        return RemoteCommand_StopCapture();
+      case 5:
+        return RemoteCommand_RequestDebugReport();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  RemoteCommandPolicy sse_decode_remote_command_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_allowSwitchCamera = sse_decode_bool(deserializer);
+    var var_allowTorch = sse_decode_bool(deserializer);
+    var var_allowBitrate = sse_decode_bool(deserializer);
+    var var_allowCapture = sse_decode_bool(deserializer);
+    var var_allowDebugReport = sse_decode_bool(deserializer);
+    return RemoteCommandPolicy(
+      allowSwitchCamera: var_allowSwitchCamera,
+      allowTorch: var_allowTorch,
+      allowBitrate: var_allowBitrate,
+      allowCapture: var_allowCapture,
+      allowDebugReport: var_allowDebugReport,
+    );
+  }
+
+  @protected
+  RemoteCommandStatus sse_decode_remote_command_status(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    var inner = sse_decode_i_32(deserializer);
+    return RemoteCommandStatus.values[inner];
   }
 
   @protected
@@ -597,6 +2611,216 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           framesPerPacket: var_framesPerPacket,
         );
       case 11:
+        var var_streamId = sse_decode_u_32(deserializer);
+        var var_pts = sse_decode_u_64(deserializer);
+        var var_framesPerPacket = sse_decode_u_32(deserializer);
+        return UiEvent_AudioConcealment(
+          streamId: var_streamId,
+          pts: var_pts,
+          framesPerPacket: var_framesPerPacket,
+        );
+      case 12:
+        var var_streamId = sse_decode_u_32(deserializer);
+        var var_pts = sse_decode_u_64(deserializer);
+        var var_sampleRate = sse_decode_u_32(deserializer);
+        var var_channels = sse_decode_u_32(deserializer);
+        var var_framesPerPacket = sse_decode_u_32(deserializer);
+        var var_samples = sse_decode_list_prim_i_16_strict(deserializer);
+        var var_concealed = sse_decode_bool(deserializer);
+        return UiEvent_PcmAudioReceived(
+          streamId: var_streamId,
+          pts: var_pts,
+          sampleRate: var_sampleRate,
+          channels: var_channels,
+          framesPerPacket: var_framesPerPacket,
+          samples: var_samples,
+          concealed: var_concealed,
+        );
+      case 13:
+        var var_streamId = sse_decode_u_32(deserializer);
+        var var_pts = sse_decode_u_64(deserializer);
+        var var_width = sse_decode_u_32(deserializer);
+        var var_height = sse_decode_u_32(deserializer);
+        var var_format = sse_decode_decoded_video_format(deserializer);
+        var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+        return UiEvent_DecodedVideoFrame(
+          streamId: var_streamId,
+          pts: var_pts,
+          width: var_width,
+          height: var_height,
+          format: var_format,
+          data: var_data,
+        );
+      case 14:
+        var var_streamId = sse_decode_u_32(deserializer);
+        var var_codec = sse_decode_String(deserializer);
+        var var_width = sse_decode_u_32(deserializer);
+        var var_height = sse_decode_u_32(deserializer);
+        var var_profileIdc = sse_decode_u_32(deserializer);
+        var var_highTier = sse_decode_bool(deserializer);
+        var var_levelIdc = sse_decode_u_32(deserializer);
+        var var_chromaFormatIdc = sse_decode_u_32(deserializer);
+        var var_bitDepthLuma = sse_decode_u_32(deserializer);
+        var var_bitDepthChroma = sse_decode_u_32(deserializer);
+        var var_frameRateNum = sse_decode_u_32(deserializer);
+        var var_frameRateDen = sse_decode_u_32(deserializer);
+        return UiEvent_StreamFormat(
+          streamId: var_streamId,
+          codec: var_codec,
+          width: var_width,
+          height: var_height,
+          profileIdc: var_profileIdc,
+          highTier: var_highTier,
+          levelIdc: var_levelIdc,
+          chromaFormatIdc: var_chromaFormatIdc,
+          bitDepthLuma: var_bitDepthLuma,
+          bitDepthChroma: var_bitDepthChroma,
+          frameRateNum: var_frameRateNum,
+          frameRateDen: var_frameRateDen,
+        );
+      case 15:
+        var var_queueDepth = sse_decode_u_32(deserializer);
+        var var_queueCapacity = sse_decode_u_32(deserializer);
+        var var_estimatedSendDelayMs = sse_decode_u_32(deserializer);
+        var var_recommendedAction = sse_decode_String(deserializer);
+        return UiEvent_Congestion(
+          queueDepth: var_queueDepth,
+          queueCapacity: var_queueCapacity,
+          estimatedSendDelayMs: var_estimatedSendDelayMs,
+          recommendedAction: var_recommendedAction,
+        );
+      case 16:
+        var var_streamId = sse_decode_u_32(deserializer);
+        var var_activeLayers = sse_decode_u_32(deserializer);
+        var var_totalLayers = sse_decode_u_32(deserializer);
+        return UiEvent_TemporalLayers(
+          streamId: var_streamId,
+          activeLayers: var_activeLayers,
+          totalLayers: var_totalLayers,
+        );
+      case 17:
+        var var_streamId = sse_decode_u_32(deserializer);
+        var var_expectedPts = sse_decode_u_64(deserializer);
+        var var_missingUs = sse_decode_u_64(deserializer);
+        var var_missingPackets = sse_decode_u_32(deserializer);
+        var var_concealmentPackets = sse_decode_u_32(deserializer);
+        return UiEvent_AudioGap(
+          streamId: var_streamId,
+          expectedPts: var_expectedPts,
+          missingUs: var_missingUs,
+          missingPackets: var_missingPackets,
+          concealmentPackets: var_concealmentPackets,
+        );
+      case 18:
+        var var_streamId = sse_decode_u_32(deserializer);
+        var var_local = sse_decode_bool(deserializer);
+        var var_speaking = sse_decode_bool(deserializer);
+        return UiEvent_AudioActivity(
+          streamId: var_streamId,
+          local: var_local,
+          speaking: var_speaking,
+        );
+      case 19:
+        var var_codec = sse_decode_u_8(deserializer);
+        var var_codecName = sse_decode_String(deserializer);
+        var var_peerNegotiated = sse_decode_bool(deserializer);
+        return UiEvent_CodecNegotiated(
+          codec: var_codec,
+          codecName: var_codecName,
+          peerNegotiated: var_peerNegotiated,
+        );
+      case 20:
+        var var_codec = sse_decode_u_8(deserializer);
+        var var_codecName = sse_decode_String(deserializer);
+        var var_sampleRate = sse_decode_u_32(deserializer);
+        var var_channels = sse_decode_u_32(deserializer);
+        var var_codecConfig = sse_decode_list_prim_u_8_strict(deserializer);
+        var var_peerNegotiated = sse_decode_bool(deserializer);
+        return UiEvent_AudioCodecNegotiated(
+          codec: var_codec,
+          codecName: var_codecName,
+          sampleRate: var_sampleRate,
+          channels: var_channels,
+          codecConfig: var_codecConfig,
+          peerNegotiated: var_peerNegotiated,
+        );
+      case 21:
+        var var_message = sse_decode_box_autoadd_control_message(deserializer);
+        return UiEvent_ControlMessageReceived(message: var_message);
+      case 22:
+        var var_channel = sse_decode_String(deserializer);
+        var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+        var var_reliable = sse_decode_bool(deserializer);
+        return UiEvent_DataReceived(
+          channel: var_channel,
+          data: var_data,
+          reliable: var_reliable,
+        );
+      case 23:
+        var var_transferId = sse_decode_u_32(deserializer);
+        var var_incoming = sse_decode_bool(deserializer);
+        var var_name = sse_decode_String(deserializer);
+        var var_size = sse_decode_u_64(deserializer);
+        return UiEvent_FileTransferStarted(
+          transferId: var_transferId,
+          incoming: var_incoming,
+          name: var_name,
+          size: var_size,
+        );
+      case 24:
+        var var_transferId = sse_decode_u_32(deserializer);
+        var var_incoming = sse_decode_bool(deserializer);
+        var var_bytes = sse_decode_u_64(deserializer);
+        var var_size = sse_decode_u_64(deserializer);
+        return UiEvent_FileTransferProgress(
+          transferId: var_transferId,
+          incoming: var_incoming,
+          bytes: var_bytes,
+          size: var_size,
+        );
+      case 25:
+        var var_transferId = sse_decode_u_32(deserializer);
+        var var_incoming = sse_decode_bool(deserializer);
+        var var_name = sse_decode_String(deserializer);
+        var var_outcome = sse_decode_file_transfer_outcome(deserializer);
+        var var_path = sse_decode_String(deserializer);
+        var var_detail = sse_decode_String(deserializer);
+        return UiEvent_FileTransferFinished(
+          transferId: var_transferId,
+          incoming: var_incoming,
+          name: var_name,
+          outcome: var_outcome,
+          path: var_path,
+          detail: var_detail,
+        );
+      case 26:
+        var var_commandId = sse_decode_u_32(deserializer);
+        var var_command = sse_decode_box_autoadd_remote_command(deserializer);
+        return UiEvent_RemoteCommand(
+          commandId: var_commandId,
+          command: var_command,
+        );
+      case 27:
+        var var_commandId = sse_decode_u_32(deserializer);
+        var var_status = sse_decode_remote_command_status(deserializer);
+        var var_detail = sse_decode_String(deserializer);
+        return UiEvent_RemoteCommandReply(
+          commandId: var_commandId,
+          status: var_status,
+          detail: var_detail,
+        );
+      case 28:
+        var var_microphoneMuted = sse_decode_bool(deserializer);
+        var var_cameraOff = sse_decode_bool(deserializer);
+        var var_audioOnly = sse_decode_bool(deserializer);
+        var var_screenShare = sse_decode_bool(deserializer);
+        return UiEvent_PeerMediaState(
+          microphoneMuted: var_microphoneMuted,
+          cameraOff: var_cameraOff,
+          audioOnly: var_audioOnly,
+          screenShare: var_screenShare,
+        );
+      case 29:
         var var_msg = sse_decode_String(deserializer);
         return UiEvent_Error(msg: var_msg);
       default:
@@ -610,9 +2834,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  VideoQueuePolicy sse_decode_video_queue_policy(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    var inner = sse_decode_i_32(deserializer);
+    return VideoQueuePolicy.values[inner];
   }
 
   @protected
@@ -653,6 +2878,172 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8(self ? 1 : 0);
   }
 
+  @protected
+  void sse_encode_box_autoadd_control_message(
+    ControlMessage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_control_message(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_remote_command(
+    RemoteCommand self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_remote_command(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_remote_command_policy(
+    RemoteCommandPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_remote_command_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_congestion_action(
+    CongestionAction self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_congestion_state(
+    CongestionState self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.queueDepth, serializer);
+    sse_encode_u_32(self.queueCapacity, serializer);
+    sse_encode_u_32(self.estimatedSendDelayMs, serializer);
+    sse_encode_congestion_action(self.recommendedAction, serializer);
+    sse_encode_bool(self.frameQueued, serializer);
+  }
+
+  @protected
+  void sse_encode_control_message(
+    ControlMessage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case ControlMessage_KeyframeRequest(streamId: final streamId):
+        sse_encode_i_32(0, serializer);
+        sse_encode_u_32(streamId, serializer);
+      case ControlMessage_MuteState(
+        audioMuted: final audioMuted,
+        videoMuted: final videoMuted,
+      ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_bool(audioMuted, serializer);
+        sse_encode_bool(videoMuted, serializer);
+      case ControlMessage_Hangup(reason: final reason):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(reason, serializer);
+      case ControlMessage_Capabilities(
+        videoCodecs: final videoCodecs,
+        audioCodecs: final audioCodecs,
+        maxWidth: final maxWidth,
+        maxHeight: final maxHeight,
+      ):
+        sse_encode_i_32(3, serializer);
+        sse_encode_list_prim_u_8_strict(videoCodecs, serializer);
+        sse_encode_list_prim_u_8_strict(audioCodecs, serializer);
+        sse_encode_u_32(maxWidth, serializer);
+        sse_encode_u_32(maxHeight, serializer);
+      case ControlMessage_ReceiverReport(
+        streamId: final streamId,
+        highestFrameIndex: final highestFrameIndex,
+        lostFrames: final lostFrames,
+        lateFrames: final lateFrames,
+        awaitingKeyframe: final awaitingKeyframe,
+        jitterUs: final jitterUs,
+      ):
+        sse_encode_i_32(4, serializer);
+        sse_encode_u_32(streamId, serializer);
+        sse_encode_u_64(highestFrameIndex, serializer);
+        sse_encode_u_64(lostFrames, serializer);
+        sse_encode_u_64(lateFrames, serializer);
+        sse_encode_bool(awaitingKeyframe, serializer);
+        sse_encode_u_32(jitterUs, serializer);
+      case ControlMessage_Custom(kind: final kind, payload: final payload):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(kind, serializer);
+        sse_encode_list_prim_u_8_strict(payload, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_data_channel_delivery(
+    DataChannelDelivery self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_decoded_video_format(
+    DecodedVideoFormat self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_file_transfer_outcome(
+    FileTransferOutcome self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_i_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt16(self);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_keyframe_detection_mode(
+    KeyframeDetectionMode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_list_prim_i_16_strict(
+    Int16List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt16List(self);
+  }
+
   @protected
   void sse_encode_list_prim_u_8_loose(
     List<int> self,
@@ -675,6 +3066,82 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8List(self);
   }
 
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_8_strict(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_remote_command(RemoteCommand self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case RemoteCommand_SwitchCamera():
+        sse_encode_i_32(0, serializer);
+      case RemoteCommand_SetTorch(enabled: final enabled):
+        sse_encode_i_32(1, serializer);
+        sse_encode_bool(enabled, serializer);
+      case RemoteCommand_SetBitrate(bitrateBps: final bitrateBps):
+        sse_encode_i_32(2, serializer);
+        sse_encode_u_32(bitrateBps, serializer);
+      case RemoteCommand_StartCapture():
+        sse_encode_i_32(3, serializer);
+      case RemoteCommand_StopCapture():
+        sse_encode_i_32(4, serializer);
+      case RemoteCommand_RequestDebugReport():
+        sse_encode_i_32(5, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_remote_command_policy(
+    RemoteCommandPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.allowSwitchCamera, serializer);
+    sse_encode_bool(self.allowTorch, serializer);
+    sse_encode_bool(self.allowBitrate, serializer);
+    sse_encode_bool(self.allowCapture, serializer);
+    sse_encode_bool(self.allowDebugReport, serializer);
+  }
+
+  @protected
+  void sse_encode_remote_command_status(
+    RemoteCommandStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -752,8 +3219,218 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_strict(data, serializer);
         sse_encode_u_64(pts, serializer);
         sse_encode_u_32(framesPerPacket, serializer);
-      case UiEvent_Error(msg: final msg):
+      case UiEvent_AudioConcealment(
+        streamId: final streamId,
+        pts: final pts,
+        framesPerPacket: final framesPerPacket,
+      ):
         sse_encode_i_32(11, serializer);
+        sse_encode_u_32(streamId, serializer);
+        sse_encode_u_64(pts, serializer);
+        sse_encode_u_32(framesPerPacket, serializer);
+      case UiEvent_PcmAudioReceived(
+        streamId: final streamId,
+        pts: final pts,
+        sampleRate: final sampleRate,
+        channels: final channels,
+        framesPerPacket: final framesPerPacket,
+        samples: final samples,
+        concealed: final concealed,
+      ):
+        sse_encode_i_32(12, serializer);
+        sse_encode_u_32(streamId, serializer);
+        sse_encode_u_64(pts, serializer);
+        sse_encode_u_32(sampleRate, serializer);
+        sse_encode_u_32(channels, serializer);
+        sse_encode_u_32(framesPerPacket, serializer);
+        sse_encode_list_prim_i_16_strict(samples, serializer);
+        sse_encode_bool(concealed, serializer);
+      case UiEvent_DecodedVideoFrame(
+        streamId: final streamId,
+        pts: final pts,
+        width: final width,
+        height: final height,
+        format: final format,
+        data: final data,
+      ):
+        sse_encode_i_32(13, serializer);
+        sse_encode_u_32(streamId, serializer);
+        sse_encode_u_64(pts, serializer);
+        sse_encode_u_32(width, serializer);
+        sse_encode_u_32(height, serializer);
+        sse_encode_decoded_video_format(format, serializer);
+        sse_encode_list_prim_u_8_strict(data, serializer);
+      case UiEvent_StreamFormat(
+        streamId: final streamId,
+        codec: final codec,
+        width: final width,
+        height: final height,
+        profileIdc: final profileIdc,
+        highTier: final highTier,
+        levelIdc: final levelIdc,
+        chromaFormatIdc: final chromaFormatIdc,
+        bitDepthLuma: final bitDepthLuma,
+        bitDepthChroma: final bitDepthChroma,
+        frameRateNum: final frameRateNum,
+        frameRateDen: final frameRateDen,
+      ):
+        sse_encode_i_32(14, serializer);
+        sse_encode_u_32(streamId, serializer);
+        sse_encode_String(codec, serializer);
+        sse_encode_u_32(width, serializer);
+        sse_encode_u_32(height, serializer);
+        sse_encode_u_32(profileIdc, serializer);
+        sse_encode_bool(highTier, serializer);
+        sse_encode_u_32(levelIdc, serializer);
+        sse_encode_u_32(chromaFormatIdc, serializer);
+        sse_encode_u_32(bitDepthLuma, serializer);
+        sse_encode_u_32(bitDepthChroma, serializer);
+        sse_encode_u_32(frameRateNum, serializer);
+        sse_encode_u_32(frameRateDen, serializer);
+      case UiEvent_Congestion(
+        queueDepth: final queueDepth,
+        queueCapacity: final queueCapacity,
+        estimatedSendDelayMs: final estimatedSendDelayMs,
+        recommendedAction: final recommendedAction,
+      ):
+        sse_encode_i_32(15, serializer);
+        sse_encode_u_32(queueDepth, serializer);
+        sse_encode_u_32(queueCapacity, serializer);
+        sse_encode_u_32(estimatedSendDelayMs, serializer);
+        sse_encode_String(recommendedAction, serializer);
+      case UiEvent_TemporalLayers(
+        streamId: final streamId,
+        activeLayers: final activeLayers,
+        totalLayers: final totalLayers,
+      ):
+        sse_encode_i_32(16, serializer);
+        sse_encode_u_32(streamId, serializer);
+        sse_encode_u_32(activeLayers, serializer);
+        sse_encode_u_32(totalLayers, serializer);
+      case UiEvent_AudioGap(
+        streamId: final streamId,
+        expectedPts: final expectedPts,
+        missingUs: final missingUs,
+        missingPackets: final missingPackets,
+        concealmentPackets: final concealmentPackets,
+      ):
+        sse_encode_i_32(17, serializer);
+        sse_encode_u_32(streamId, serializer);
+        sse_encode_u_64(expectedPts, serializer);
+        sse_encode_u_64(missingUs, serializer);
+        sse_encode_u_32(missingPackets, serializer);
+        sse_encode_u_32(concealmentPackets, serializer);
+      case UiEvent_AudioActivity(
+        streamId: final streamId,
+        local: final local,
+        speaking: final speaking,
+      ):
+        sse_encode_i_32(18, serializer);
+        sse_encode_u_32(streamId, serializer);
+        sse_encode_bool(local, serializer);
+        sse_encode_bool(speaking, serializer);
+      case UiEvent_CodecNegotiated(
+        codec: final codec,
+        codecName: final codecName,
+        peerNegotiated: final peerNegotiated,
+      ):
+        sse_encode_i_32(19, serializer);
+        sse_encode_u_8(codec, serializer);
+        sse_encode_String(codecName, serializer);
+        sse_encode_bool(peerNegotiated, serializer);
+      case UiEvent_AudioCodecNegotiated(
+        codec: final codec,
+        codecName: final codecName,
+        sampleRate: final sampleRate,
+        channels: final channels,
+        codecConfig: final codecConfig,
+        peerNegotiated: final peerNegotiated,
+      ):
+        sse_encode_i_32(20, serializer);
+        sse_encode_u_8(codec, serializer);
+        sse_encode_String(codecName, serializer);
+        sse_encode_u_32(sampleRate, serializer);
+        sse_encode_u_32(channels, serializer);
+        sse_encode_list_prim_u_8_strict(codecConfig, serializer);
+        sse_encode_bool(peerNegotiated, serializer);
+      case UiEvent_ControlMessageReceived(message: final message):
+        sse_encode_i_32(21, serializer);
+        sse_encode_box_autoadd_control_message(message, serializer);
+      case UiEvent_DataReceived(
+        channel: final channel,
+        data: final data,
+        reliable: final reliable,
+      ):
+        sse_encode_i_32(22, serializer);
+        sse_encode_String(channel, serializer);
+        sse_encode_list_prim_u_8_strict(data, serializer);
+        sse_encode_bool(reliable, serializer);
+      case UiEvent_FileTransferStarted(
+        transferId: final transferId,
+        incoming: final incoming,
+        name: final name,
+        size: final size,
+      ):
+        sse_encode_i_32(23, serializer);
+        sse_encode_u_32(transferId, serializer);
+        sse_encode_bool(incoming, serializer);
+        sse_encode_String(name, serializer);
+        sse_encode_u_64(size, serializer);
+      case UiEvent_FileTransferProgress(
+        transferId: final transferId,
+        incoming: final incoming,
+        bytes: final bytes,
+        size: final size,
+      ):
+        sse_encode_i_32(24, serializer);
+        sse_encode_u_32(transferId, serializer);
+        sse_encode_bool(incoming, serializer);
+        sse_encode_u_64(bytes, serializer);
+        sse_encode_u_64(size, serializer);
+      case UiEvent_FileTransferFinished(
+        transferId: final transferId,
+        incoming: final incoming,
+        name: final name,
+        outcome: final outcome,
+        path: final path,
+        detail: final detail,
+      ):
+        sse_encode_i_32(25, serializer);
+        sse_encode_u_32(transferId, serializer);
+        sse_encode_bool(incoming, serializer);
+        sse_encode_String(name, serializer);
+        sse_encode_file_transfer_outcome(outcome, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_String(detail, serializer);
+      case UiEvent_RemoteCommand(
+        commandId: final commandId,
+        command: final command,
+      ):
+        sse_encode_i_32(26, serializer);
+        sse_encode_u_32(commandId, serializer);
+        sse_encode_box_autoadd_remote_command(command, serializer);
+      case UiEvent_RemoteCommandReply(
+        commandId: final commandId,
+        status: final status,
+        detail: final detail,
+      ):
+        sse_encode_i_32(27, serializer);
+        sse_encode_u_32(commandId, serializer);
+        sse_encode_remote_command_status(status, serializer);
+        sse_encode_String(detail, serializer);
+      case UiEvent_PeerMediaState(
+        microphoneMuted: final microphoneMuted,
+        cameraOff: final cameraOff,
+        audioOnly: final audioOnly,
+        screenShare: final screenShare,
+      ):
+        sse_encode_i_32(28, serializer);
+        sse_encode_bool(microphoneMuted, serializer);
+        sse_encode_bool(cameraOff, serializer);
+        sse_encode_bool(audioOnly, serializer);
+        sse_encode_bool(screenShare, serializer);
+      case UiEvent_Error(msg: final msg):
+        sse_encode_i_32(29, serializer);
         sse_encode_String(msg, serializer);
     }
   }
@@ -764,8 +3441,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_video_queue_policy(
+    VideoQueuePolicy self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_i_32(self.index, serializer);
   }
 }
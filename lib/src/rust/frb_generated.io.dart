@@ -30,12 +30,72 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  ControlMessage dco_decode_box_autoadd_control_message(dynamic raw);
+
+  @protected
+  RemoteCommand dco_decode_box_autoadd_remote_command(dynamic raw);
+
+  @protected
+  RemoteCommandPolicy dco_decode_box_autoadd_remote_command_policy(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  CongestionAction dco_decode_congestion_action(dynamic raw);
+
+  @protected
+  CongestionState dco_decode_congestion_state(dynamic raw);
+
+  @protected
+  ControlMessage dco_decode_control_message(dynamic raw);
+
+  @protected
+  DataChannelDelivery dco_decode_data_channel_delivery(dynamic raw);
+
+  @protected
+  DecodedVideoFormat dco_decode_decoded_video_format(dynamic raw);
+
+  @protected
+  FileTransferOutcome dco_decode_file_transfer_outcome(dynamic raw);
+
+  @protected
+  int dco_decode_i_16(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  KeyframeDetectionMode dco_decode_keyframe_detection_mode(dynamic raw);
+
+  @protected
+  Int16List dco_decode_list_prim_i_16_strict(dynamic raw);
+
   @protected
   List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  RemoteCommand dco_decode_remote_command(dynamic raw);
+
+  @protected
+  RemoteCommandPolicy dco_decode_remote_command_policy(dynamic raw);
+
+  @protected
+  RemoteCommandStatus dco_decode_remote_command_status(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
@@ -51,6 +111,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void dco_decode_unit(dynamic raw);
 
+  @protected
+  VideoQueuePolicy dco_decode_video_queue_policy(dynamic raw);
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
@@ -65,12 +128,90 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  ControlMessage sse_decode_box_autoadd_control_message(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RemoteCommand sse_decode_box_autoadd_remote_command(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RemoteCommandPolicy sse_decode_box_autoadd_remote_command_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  CongestionAction sse_decode_congestion_action(SseDeserializer deserializer);
+
+  @protected
+  CongestionState sse_decode_congestion_state(SseDeserializer deserializer);
+
+  @protected
+  ControlMessage sse_decode_control_message(SseDeserializer deserializer);
+
+  @protected
+  DataChannelDelivery sse_decode_data_channel_delivery(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DecodedVideoFormat sse_decode_decoded_video_format(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FileTransferOutcome sse_decode_file_transfer_outcome(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_i_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  KeyframeDetectionMode sse_decode_keyframe_detection_mode(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Int16List sse_decode_list_prim_i_16_strict(SseDeserializer deserializer);
+
   @protected
   List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
 
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  RemoteCommand sse_decode_remote_command(SseDeserializer deserializer);
+
+  @protected
+  RemoteCommandPolicy sse_decode_remote_command_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RemoteCommandStatus sse_decode_remote_command_status(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer);
 
@@ -87,7 +228,7 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_decode_unit(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  VideoQueuePolicy sse_decode_video_queue_policy(SseDeserializer deserializer);
 
   @protected
   void sse_encode_AnyhowException(
@@ -107,6 +248,81 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_control_message(
+    ControlMessage self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_remote_command(
+    RemoteCommand self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_remote_command_policy(
+    RemoteCommandPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_congestion_action(
+    CongestionAction self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_congestion_state(
+    CongestionState self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_control_message(
+    ControlMessage self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_data_channel_delivery(
+    DataChannelDelivery self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_decoded_video_format(
+    DecodedVideoFormat self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_file_transfer_outcome(
+    FileTransferOutcome self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_i_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_keyframe_detection_mode(
+    KeyframeDetectionMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_i_16_strict(
+    Int16List self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
 
@@ -116,6 +332,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_remote_command(RemoteCommand self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_remote_command_policy(
+    RemoteCommandPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_remote_command_status(
+    RemoteCommandStatus self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
@@ -132,7 +375,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_unit(void self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_video_queue_policy(
+    VideoQueuePolicy self,
+    SseSerializer serializer,
+  );
 }
 
 // Section: wire_class
@@ -1,30 +1,121 @@
+use crate::audio_activity::AudioActivityMonitor;
+use crate::audio_codec::{AudioCodec, AudioFormat};
+use crate::audio_gaps::{AudioArrival, AudioGap, AudioGapTracker};
+use crate::audio_redundancy::{
+    AudioRedundancyDecoder, AudioRedundancyEncoder, UnwrappedAudioPacket, MAX_REDUNDANCY_DEPTH,
+};
+use crate::bitstream::{convert_framing, NalFraming};
+use crate::codec::{
+    VideoCodec, VideoFormatTracker, VideoParameterSetCache, VideoStreamFormat, AUDIO_CODEC_OPUS_RED,
+};
+use crate::control::{self, ControlChannel, ControlSender, MediaState, ReceiverReport};
+use crate::data_channel::{self, DataMessage, DatagramChannel};
+use crate::debug_report::{self, LegacyReportAssembler, LegacyReportEvent};
+use crate::file_transfer::{
+    self, FileMessage, FileStreamItem, FileStreamReader, FileStreams, IncomingFile, OutgoingFile,
+    DEFAULT_MAX_FILE_BYTES, SHA256_BYTES,
+};
+use crate::frame_sequence::{FrameArrival, FrameLossCounters, FrameSequenceTracker};
 use crate::frb_generated::StreamSink;
+use crate::hevc::{self, AccessUnit, AccessUnitSplitter};
+use crate::ingress_queue::{
+    Backpressure, CongestionSnapshot, OverflowPolicy, PushOutcome, QueuedFrame, VideoIngressQueue,
+};
+use crate::jitter_buffer::{JitterBuffer, JitterBufferConfig};
+use crate::lip_sync::LipSync;
+use crate::negotiation::{
+    self, answer_codecs, offer_codecs, AudioCodecOffer, AudioNegotiationOutcome, NegotiationOutcome,
+};
+use crate::opus::{self, OpusPacketInfo};
+#[cfg(feature = "opus-decode")]
+use crate::opus_decode::{
+    OpusPcmDecoder, PcmBlock, PcmFileWriter, PCM_CHANNELS, PCM_SAMPLE_RATE_HZ,
+};
+use crate::send_delay::SendDelayStats;
+use crate::stale_frames::{StaleFrameFilters, StaleVerdict};
+use crate::temporal_layers::TemporalLayerShaper;
+#[cfg(feature = "hevc-decode")]
+use crate::video_decode::{DecodeTarget, DecodedPicture, OutputPixels, VideoDecodeWorker};
 use anyhow::{anyhow, bail, Context};
 use flutter_rust_bridge::frb;
 use sankaku_core::{
     KyuEvent as SankakuEvent, SankakuReceiver, SankakuSender, StreamType, VideoFrame,
     AUDIO_CODEC_DEBUG_TEXT, AUDIO_CODEC_OPUS, VIDEO_CODEC_HEVC,
 };
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 use std::net::SocketAddr;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::path::{Path, PathBuf};
+#[cfg(feature = "hevc-decode")]
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU32, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc::{self, UnboundedSender};
 use tokio::task::spawn_blocking;
 
-type HevcFrameTx = UnboundedSender<(Vec<u8>, bool, u64, u8)>;
-type AudioFrameTx = UnboundedSender<(Vec<u8>, u64, u8, u32)>;
+type AudioFrameTx = UnboundedSender<QueuedAudioFrame>;
+type FileCommandTx = UnboundedSender<FileCommand>;
+
+struct QueuedAudioFrame {
+    payload: Vec<u8>,
+    pts: u64,
+    codec: u8,
+    frames_per_packet: u32,
+    queued_at: Instant,
+}
 
 /// Sankaku protocol defaults. Dart currently passes bind/dial addresses explicitly,
 /// but keeping the canonical port here prevents drift across layers.
 pub const DEFAULT_SANKAKU_UDP_PORT: u16 = 9292;
 pub const DEFAULT_SANKAKU_RECEIVER_BIND_HOST: &str = "[::]";
+/// quinn send priority of the sender's audio stream. Video stays at the default
+/// of 0, so audio written while a large video frame is still queued in quinn is
+/// sent first.
+const AUDIO_STREAM_PRIORITY: i32 = 1;
 
-static HEVC_FRAME_TX: OnceLock<Mutex<Option<HevcFrameTx>>> = OnceLock::new();
+static VIDEO_INGRESS: OnceLock<Mutex<Option<VideoIngress>>> = OnceLock::new();
+static SENDER_VIDEO_QUEUE_CONFIG: OnceLock<Mutex<(u32, VideoQueuePolicy)>> = OnceLock::new();
+static SENDER_LATENCY_BUDGETS: OnceLock<Mutex<LatencyBudgets>> = OnceLock::new();
 static AUDIO_FRAME_TX: OnceLock<Mutex<Option<AudioFrameTx>>> = OnceLock::new();
+static VIDEO_BYTES_INGRESS: OnceLock<Mutex<VideoBytesIngress>> = OnceLock::new();
 static SENDER_SHOULD_RUN: AtomicBool = AtomicBool::new(false);
+/// The running sender's peer predates the control stream, so debug reports go on
+/// the audio stream; see `debug_report`.
+static SENDER_LEGACY_DEBUG_REPORTS: AtomicBool = AtomicBool::new(false);
 static RECEIVER_SHOULD_RUN: AtomicBool = AtomicBool::new(false);
+static RECEIVER_VIDEO_FRAMING: OnceLock<Mutex<NalFraming>> = OnceLock::new();
+static RECEIVER_PARAMETER_SETS: OnceLock<Mutex<BTreeMap<u32, VideoParameterSetCache>>> =
+    OnceLock::new();
+static SENDER_VIDEO_CODECS: OnceLock<Mutex<Vec<u8>>> = OnceLock::new();
+static RECEIVER_VIDEO_CODECS: OnceLock<Mutex<Vec<u8>>> = OnceLock::new();
+static SENDER_AUDIO_CODECS: OnceLock<Mutex<SenderAudioCodecs>> = OnceLock::new();
+static RECEIVER_AUDIO_CODECS: OnceLock<Mutex<Vec<u8>>> = OnceLock::new();
+static SENDER_KEYFRAME_DETECTION: OnceLock<Mutex<KeyframeDetectionMode>> = OnceLock::new();
+static RECEIVER_JITTER_BUFFER_CONFIG: OnceLock<Mutex<Option<JitterBufferConfig>>> = OnceLock::new();
+static RECEIVER_LIP_SYNC_ENABLED: AtomicBool = AtomicBool::new(false);
+static SENDER_AUDIO_REDUNDANCY_DEPTH: AtomicU32 = AtomicU32::new(0);
+static RECEIVER_AUDIO_CONCEALMENT_ENABLED: AtomicBool = AtomicBool::new(false);
+#[cfg(feature = "opus-decode")]
+static RECEIVER_OPUS_DECODE: OnceLock<Mutex<Option<PcmOutput>>> = OnceLock::new();
+#[cfg(feature = "hevc-decode")]
+static SOFTWARE_VIDEO_DECODE: OnceLock<Mutex<Option<SoftwareVideoOutput>>> = OnceLock::new();
+#[cfg(feature = "hevc-decode")]
+static SOFTWARE_VIDEO_DECODE_GENERATION: AtomicU64 = AtomicU64::new(0);
+static RECEIVER_LIP_SYNC_CORRECTION_MS: AtomicI32 = AtomicI32::new(0);
+static SENDER_CONTROL: OnceLock<Mutex<Option<ControlSender>>> = OnceLock::new();
+static RECEIVER_CONTROL: OnceLock<Mutex<Option<ControlSender>>> = OnceLock::new();
+static SENDER_DATAGRAMS: OnceLock<Mutex<Option<quinn::Connection>>> = OnceLock::new();
+static RECEIVER_DATAGRAMS: OnceLock<Mutex<Option<quinn::Connection>>> = OnceLock::new();
+static DATA_CHANNELS: OnceLock<Mutex<BTreeMap<String, DataChannelDelivery>>> = OnceLock::new();
+static FILE_TRANSFER_CONFIG: OnceLock<Mutex<FileTransferConfig>> = OnceLock::new();
+static SENDER_FILE_COMMANDS: OnceLock<Mutex<Option<FileCommandTx>>> = OnceLock::new();
+static RECEIVER_FILE_COMMANDS: OnceLock<Mutex<Option<FileCommandTx>>> = OnceLock::new();
+static NEXT_FILE_TRANSFER_ID: AtomicU32 = AtomicU32::new(1);
+static REMOTE_COMMAND_POLICY: OnceLock<Mutex<RemoteCommandPolicy>> = OnceLock::new();
+static SENDER_PENDING_COMMANDS: OnceLock<Mutex<Option<BTreeMap<u32, Instant>>>> = OnceLock::new();
+static RECEIVER_ISSUED_COMMANDS: OnceLock<Mutex<Option<BTreeMap<u32, Instant>>>> = OnceLock::new();
+static NEXT_REMOTE_COMMAND_ID: AtomicU32 = AtomicU32::new(1);
+static LOCAL_MEDIA_STATE: OnceLock<Mutex<MediaState>> = OnceLock::new();
 
 #[derive(Debug)]
 struct SkipServerVerification;
@@ -105,7 +196,10 @@ fn make_server_endpoint(bind_addr: &str) -> anyhow::Result<quinn::Endpoint> {
         .with_no_client_auth()
         .with_single_cert(vec![cert_der], key_der.into())
         .context("failed to build QUIC rustls server config")?;
-    server_crypto.alpn_protocols = vec![b"sankaku-rt".to_vec()];
+    server_crypto.alpn_protocols = negotiation::alpn_protocols();
+    server_crypto
+        .alpn_protocols
+        .push(data_channel::DATA_ALPN.to_vec());
     let server_config = quinn::ServerConfig::with_crypto(Arc::new(
         quinn::crypto::rustls::QuicServerConfig::try_from(server_crypto)
             .context("failed to build QUIC server crypto config")?,
@@ -120,20 +214,22 @@ fn make_client_endpoint() -> anyhow::Result<quinn::Endpoint> {
     let bind_addr: SocketAddr = "[::]:0"
         .parse()
         .context("failed to parse QUIC client bind address")?;
+    let mut endpoint =
+        quinn::Endpoint::client(bind_addr).context("failed to bind QUIC client endpoint")?;
+    endpoint.set_default_client_config(make_client_config(negotiation::alpn_protocols())?);
+    Ok(endpoint)
+}
+
+fn make_client_config(alpn_protocols: Vec<Vec<u8>>) -> anyhow::Result<quinn::ClientConfig> {
     let mut client_crypto = rustls::ClientConfig::builder()
         .dangerous()
         .with_custom_certificate_verifier(SkipServerVerification::new())
         .with_no_client_auth();
-    client_crypto.alpn_protocols = vec![b"sankaku-rt".to_vec()];
-    let client_config = quinn::ClientConfig::new(Arc::new(
+    client_crypto.alpn_protocols = alpn_protocols;
+    Ok(quinn::ClientConfig::new(Arc::new(
         quinn::crypto::rustls::QuicClientConfig::try_from(client_crypto)
             .context("failed to build QUIC client crypto config")?,
-    ));
-
-    let mut endpoint =
-        quinn::Endpoint::client(bind_addr).context("failed to bind QUIC client endpoint")?;
-    endpoint.set_default_client_config(client_config);
-    Ok(endpoint)
+    )))
 }
 
 #[frb(init)]
@@ -187,6 +283,167 @@ pub enum UiEvent {
         pts: u64,
         frames_per_packet: u32,
     },
+    /// Stands in for a lost audio packet when concealment is enabled, in pts order
+    /// with `AudioFrameReceived`; the player should conceal `frames_per_packet`
+    /// frames at `pts`.
+    AudioConcealment {
+        stream_id: u32,
+        pts: u64,
+        frames_per_packet: u32,
+    },
+    /// Received Opus decoded in Rust (`opus-decode` feature), delivered instead of
+    /// `AudioFrameReceived`. `samples` are interleaved 16-bit; `concealed` blocks
+    /// were synthesised by the decoder for lost packets.
+    PcmAudioReceived {
+        stream_id: u32,
+        pts: u64,
+        sample_rate: u32,
+        channels: u32,
+        frames_per_packet: u32,
+        samples: Vec<i16>,
+        concealed: bool,
+    },
+    /// A received picture decoded in Rust (`hevc-decode` feature), sent only on the
+    /// stream passed to `start_software_video_decode`. `data` holds tightly packed
+    /// planes in `format`, ready to upload as a texture.
+    DecodedVideoFrame {
+        stream_id: u32,
+        pts: u64,
+        width: u32,
+        height: u32,
+        format: DecodedVideoFormat,
+        data: Vec<u8>,
+    },
+    /// Emitted when a stream's parameter sets first appear or change. Frame rate is
+    /// `frame_rate_num / frame_rate_den`, both zero when the stream carries no timing info.
+    StreamFormat {
+        stream_id: u32,
+        codec: String,
+        width: u32,
+        height: u32,
+        profile_idc: u32,
+        high_tier: bool,
+        level_idc: u32,
+        chroma_format_idc: u32,
+        bit_depth_luma: u32,
+        bit_depth_chroma: u32,
+        frame_rate_num: u32,
+        frame_rate_den: u32,
+    },
+    /// Sender video queue state, emitted every second and whenever the recommended
+    /// action changes.
+    Congestion {
+        queue_depth: u32,
+        queue_capacity: u32,
+        estimated_send_delay_ms: u32,
+        recommended_action: String,
+    },
+    /// HEVC temporal layers the sender currently transmits out of those seen in the
+    /// stream. Emitted when layers are dropped or restored to follow the bitrate
+    /// estimate, and when a new layer first appears.
+    TemporalLayers {
+        stream_id: u32,
+        active_layers: u32,
+        total_layers: u32,
+    },
+    /// Received audio skipped ahead of the pts expected from the previous packet's
+    /// `frames_per_packet`. `concealment_packets` `AudioConcealment` placeholders,
+    /// or concealed `PcmAudioReceived` blocks, were emitted when concealment is
+    /// enabled.
+    AudioGap {
+        stream_id: u32,
+        expected_pts: u64,
+        missing_us: u64,
+        missing_packets: u32,
+        concealment_packets: u32,
+    },
+    /// Speaking/silent transitions of an Opus stream, derived from DTX and packet
+    /// bitrate. `local` is true for the sender's own microphone stream.
+    AudioActivity {
+        stream_id: u32,
+        local: bool,
+        speaking: bool,
+    },
+    /// Outcome of codec negotiation at session start. `peer_negotiated` is false when
+    /// the peer predates negotiation and HEVC was assumed.
+    CodecNegotiated {
+        codec: u8,
+        codec_name: String,
+        peer_negotiated: bool,
+    },
+    /// Audio codec agreed at session start, with what Dart needs to set up its
+    /// decoder: the AudioSpecificConfig for AAC-LC or the 5-byte PCM config (sample
+    /// rate u32 BE, channels). `channels` is zero for Opus, which signals it per
+    /// packet. `peer_negotiated` is false when the peer predates audio negotiation
+    /// and Opus was assumed.
+    AudioCodecNegotiated {
+        codec: u8,
+        codec_name: String,
+        sample_rate: u32,
+        channels: u32,
+        codec_config: Vec<u8>,
+        peer_negotiated: bool,
+    },
+    /// A message the peer sent on the control stream.
+    ControlMessageReceived {
+        message: ControlMessage,
+    },
+    /// A message on one of the peer's data channels. Channels need not be opened
+    /// locally to receive on them.
+    DataReceived {
+        channel: String,
+        data: Vec<u8>,
+        reliable: bool,
+    },
+    /// A file transfer began streaming: the peer accepted a file passed to
+    /// `send_file`, or an incoming offer was accepted under the
+    /// `configure_file_transfer` policy. `incoming` tells the two id spaces apart.
+    FileTransferStarted {
+        transfer_id: u32,
+        incoming: bool,
+        name: String,
+        size: u64,
+    },
+    /// Bytes sent or received so far, at most every 250 ms and once at the end.
+    FileTransferProgress {
+        transfer_id: u32,
+        incoming: bool,
+        bytes: u64,
+        size: u64,
+    },
+    /// Final event of every transfer. `path` is where an incoming file was saved
+    /// and is empty otherwise; `detail` explains any outcome but `Completed`.
+    FileTransferFinished {
+        transfer_id: u32,
+        incoming: bool,
+        name: String,
+        outcome: FileTransferOutcome,
+        path: String,
+        detail: String,
+    },
+    /// Sender: the receiver asked for `command` and the policy set with
+    /// `configure_remote_command_policy` allows it. Answer it with
+    /// `reply_remote_command` and the same `command_id`.
+    RemoteCommand {
+        command_id: u32,
+        command: RemoteCommand,
+    },
+    /// Receiver: the sender's answer to a `send_remote_command` call, or
+    /// `TimedOut` when none arrived in time.
+    RemoteCommandReply {
+        command_id: u32,
+        status: RemoteCommandStatus,
+        detail: String,
+    },
+    /// Receiver: what the sender announced with `announce_media_state`, once when
+    /// the session starts and again on every change. Audio and video that stop
+    /// while muted or off are not reported as gaps or loss.
+    PeerMediaState {
+        microphone_muted: bool,
+        camera_off: bool,
+        audio_only: bool,
+        screen_share: bool,
+    },
     Error {
         msg: String,
     },
@@ -245,475 +502,1006 @@ fn emit_quic_network_telemetry(
     );
 }
 
-fn hevc_frame_tx_slot() -> &'static Mutex<Option<HevcFrameTx>> {
-    HEVC_FRAME_TX.get_or_init(|| Mutex::new(None))
+fn emit_frame_arrival(
+    sink: &StreamSink<UiEvent>,
+    stream_id: u32,
+    frame_index: u64,
+    keyframe: bool,
+    arrival: FrameArrival,
+) {
+    let reason = match arrival {
+        FrameArrival::InOrder => return,
+        FrameArrival::Gap {
+            first_missing,
+            missing,
+            missing_keyframes,
+        } => format!(
+            "gap: missing {missing} frame(s) {first_missing}..={} (keyframes={missing_keyframes}, delta={})",
+            frame_index.saturating_sub(1),
+            missing - missing_keyframes
+        ),
+        FrameArrival::Late { behind } => format!(
+            "late: frame_index={frame_index} keyframe={keyframe} arrived {behind} frame(s) behind"
+        ),
+        // Nothing was lost, so these are logged rather than counted as drops.
+        FrameArrival::Duplicate => {
+            let msg = format!("stream {stream_id} duplicate: frame_index={frame_index}");
+            return sink_event(sink, UiEvent::Log { msg });
+        }
+        FrameArrival::Restarted { previous_highest } => {
+            let msg = format!(
+                "stream {stream_id} restart: frame_index went back from {previous_highest} to {frame_index}"
+            );
+            return sink_event(sink, UiEvent::Log { msg });
+        }
+    };
+    sink_event(sink, UiEvent::FrameDrop { stream_id, reason });
+}
+
+fn receiver_video_framing_slot() -> &'static Mutex<NalFraming> {
+    RECEIVER_VIDEO_FRAMING.get_or_init(|| Mutex::new(NalFraming::AnnexB))
 }
 
-fn audio_frame_tx_slot() -> &'static Mutex<Option<AudioFrameTx>> {
-    AUDIO_FRAME_TX.get_or_init(|| Mutex::new(None))
+fn receiver_parameter_sets_slot() -> &'static Mutex<BTreeMap<u32, VideoParameterSetCache>> {
+    RECEIVER_PARAMETER_SETS.get_or_init(|| Mutex::new(BTreeMap::new()))
 }
 
-fn install_hevc_frame_tx(tx: HevcFrameTx) -> anyhow::Result<()> {
-    let mut guard = hevc_frame_tx_slot()
-        .lock()
-        .map_err(|_| anyhow!("failed to lock HEVC frame sender slot"))?;
-    if guard.is_some() {
-        bail!("sender loop already running");
+fn clear_receiver_parameter_sets() {
+    if let Ok(mut guard) = receiver_parameter_sets_slot().lock() {
+        guard.clear();
     }
-    *guard = Some(tx);
-    Ok(())
 }
 
-fn clear_hevc_frame_tx() {
-    if let Ok(mut guard) = hevc_frame_tx_slot().lock() {
-        *guard = None;
+/// Records the access unit's parameter sets and, for keyframes that arrived
+/// without them, returns the payload with the cached sets prepended.
+fn complete_with_cached_parameter_sets(
+    stream_id: u32,
+    codec: VideoCodec,
+    payload: Vec<u8>,
+) -> (Vec<u8>, bool) {
+    let Ok(mut guard) = receiver_parameter_sets_slot().lock() else {
+        return (payload, false);
+    };
+    let cache = guard
+        .entry(stream_id)
+        .or_insert_with(|| VideoParameterSetCache::new(codec));
+    cache.observe_access_unit(&payload);
+    match cache.complete_keyframe(&payload) {
+        Some(completed) => (completed, true),
+        None => (payload, false),
     }
 }
 
-fn install_audio_frame_tx(tx: AudioFrameTx) -> anyhow::Result<()> {
-    let mut guard = audio_frame_tx_slot()
-        .lock()
-        .map_err(|_| anyhow!("failed to lock audio frame sender slot"))?;
-    if guard.is_some() {
-        bail!("sender loop already running");
-    }
-    *guard = Some(tx);
-    Ok(())
+fn emit_video_stream_format(
+    sink: &StreamSink<UiEvent>,
+    stream_id: u32,
+    codec: VideoCodec,
+    format: VideoStreamFormat,
+) {
+    sink_event(
+        sink,
+        UiEvent::Log {
+            msg: format!(
+                "stream {stream_id} format: {} {}x{} profile={} level={} chroma={} depth={}/{} fps={}/{}",
+                codec.name(),
+                format.width,
+                format.height,
+                format.profile_idc,
+                format.level_idc,
+                format.chroma_format_idc,
+                format.bit_depth_luma,
+                format.bit_depth_chroma,
+                format.frame_rate_num,
+                format.frame_rate_den
+            ),
+        },
+    );
+    sink_event(
+        sink,
+        UiEvent::StreamFormat {
+            stream_id,
+            codec: codec.name().to_string(),
+            width: format.width,
+            height: format.height,
+            profile_idc: u32::from(format.profile_idc),
+            high_tier: format.high_tier,
+            level_idc: u32::from(format.level_idc),
+            chroma_format_idc: u32::from(format.chroma_format_idc),
+            bit_depth_luma: u32::from(format.bit_depth_luma),
+            bit_depth_chroma: u32::from(format.bit_depth_chroma),
+            frame_rate_num: format.frame_rate_num,
+            frame_rate_den: format.frame_rate_den,
+        },
+    );
 }
 
-fn clear_audio_frame_tx() {
-    if let Ok(mut guard) = audio_frame_tx_slot().lock() {
-        *guard = None;
+fn emit_frame_loss_telemetry(
+    sink: &StreamSink<UiEvent>,
+    trackers: &BTreeMap<u32, FrameSequenceTracker>,
+) {
+    if trackers.is_empty() {
+        return;
+    }
+    let mut totals = FrameLossCounters::default();
+    let mut awaiting_keyframe = false;
+    for tracker in trackers.values() {
+        totals.accumulate(&tracker.counters());
+        awaiting_keyframe |= tracker.awaiting_keyframe();
+    }
+    for (name, value) in [
+        ("rx_video.lost_keyframes", totals.lost_keyframes),
+        ("rx_video.lost_delta_frames", totals.lost_delta_frames),
+        ("rx_video.late_keyframes", totals.late_keyframes),
+        ("rx_video.late_delta_frames", totals.late_delta_frames),
+        ("rx_video.duplicate_frames", totals.duplicates),
+        ("rx_video.awaiting_keyframe", u64::from(awaiting_keyframe)),
+    ] {
+        sink_event(
+            sink,
+            UiEvent::Telemetry {
+                name: name.to_string(),
+                value,
+            },
+        );
     }
 }
 
-struct FrameIngressGuard;
-
-impl Drop for FrameIngressGuard {
-    fn drop(&mut self) {
-        clear_hevc_frame_tx();
-        clear_audio_frame_tx();
-    }
+fn receiver_jitter_buffer_config_slot() -> &'static Mutex<Option<JitterBufferConfig>> {
+    RECEIVER_JITTER_BUFFER_CONFIG.get_or_init(|| Mutex::new(None))
 }
 
-struct SenderRunGuard;
+fn receiver_jitter_buffer_config() -> Option<JitterBufferConfig> {
+    receiver_jitter_buffer_config_slot()
+        .lock()
+        .ok()
+        .and_then(|guard| *guard)
+}
 
-impl Drop for SenderRunGuard {
-    fn drop(&mut self) {
-        SENDER_SHOULD_RUN.store(false, Ordering::Relaxed);
+async fn sleep_until_playout(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => {
+            tokio::time::sleep_until(tokio::time::Instant::from_std(deadline)).await;
+        }
+        None => std::future::pending::<()>().await,
     }
 }
 
-struct ReceiverRunGuard;
-
-impl Drop for ReceiverRunGuard {
-    fn drop(&mut self) {
-        RECEIVER_SHOULD_RUN.store(false, Ordering::Relaxed);
+fn next_playout_deadline(
+    video: &Option<JitterBuffer<UiEvent>>,
+    audio: &Option<JitterBuffer<UiEvent>>,
+) -> Option<Instant> {
+    let video_at = video.as_ref().and_then(JitterBuffer::next_release_at);
+    let audio_at = audio.as_ref().and_then(JitterBuffer::next_release_at);
+    match (video_at, audio_at) {
+        (Some(video_at), Some(audio_at)) => Some(video_at.min(audio_at)),
+        (video_at, audio_at) => video_at.or(audio_at),
     }
 }
 
-const DEBUG_REPORT_MAGIC: &[u8; 4] = b"NRPT";
-const DEBUG_REPORT_PROTOCOL_VERSION: u8 = 1;
-const DEBUG_REPORT_PACKET_BEGIN: u8 = 0x01;
-const DEBUG_REPORT_PACKET_CHUNK: u8 = 0x02;
-const DEBUG_REPORT_PACKET_END: u8 = 0x03;
+/// Fills a gap with `AudioConcealment` placeholders. Returns the number of
+/// placeholders emitted.
+fn emit_concealment_placeholders(
+    sink: &StreamSink<UiEvent>,
+    buffer: &mut Option<JitterBuffer<UiEvent>>,
+    stream_id: u32,
+    gap: &AudioGap,
+    sample_rate: u32,
+) -> u32 {
+    let frames_per_packet = (gap.packet_duration_us * u64::from(sample_rate) / 1_000_000) as u32;
+    let mut concealment_packets = 0;
+    for pts in gap.concealment_pts() {
+        buffer_or_emit_frame(
+            sink,
+            buffer,
+            stream_id,
+            pts,
+            UiEvent::AudioConcealment {
+                stream_id,
+                pts,
+                frames_per_packet,
+            },
+        );
+        concealment_packets += 1;
+    }
+    concealment_packets
+}
 
-struct RemoteDebugReportAssembly {
-    report_id: u32,
-    filename: String,
-    total_chunks: u32,
-    expected_bytes: u32,
-    chunks: BTreeMap<u32, Vec<u8>>,
+fn emit_audio_gap(
+    sink: &StreamSink<UiEvent>,
+    stream_id: u32,
+    gap: &AudioGap,
+    concealment_packets: u32,
+) {
+    sink_event(
+        sink,
+        UiEvent::AudioGap {
+            stream_id,
+            expected_pts: gap.expected_pts,
+            missing_us: gap.missing_us,
+            missing_packets: gap.missing_packets,
+            concealment_packets,
+        },
+    );
 }
 
-fn parse_u16_le(bytes: &[u8]) -> Option<u16> {
-    let array: [u8; 2] = bytes.get(..2)?.try_into().ok()?;
-    Some(u16::from_le_bytes(array))
+/// Where the receiver sends audio decoded by the `opus-decode` feature.
+#[cfg(feature = "opus-decode")]
+#[derive(Debug, Clone)]
+enum PcmOutput {
+    Dart,
+    /// Raw s16le written to a file or FIFO, e.g. one read by `pacat --raw`.
+    File(std::path::PathBuf),
 }
 
-fn parse_u32_le(bytes: &[u8]) -> Option<u32> {
-    let array: [u8; 4] = bytes.get(..4)?.try_into().ok()?;
-    Some(u32::from_le_bytes(array))
+/// Decodes received Opus to PCM and delivers it in place of the encoded frames.
+#[cfg(feature = "opus-decode")]
+struct PcmPlayout {
+    decoder: OpusPcmDecoder,
+    file: Option<PcmFileWriter>,
+    /// Packets that arrived behind the decoder and were never decoded.
+    late_dropped: u64,
 }
 
-fn emit_remote_report_text_lines(sink: &StreamSink<UiEvent>, payload: &[u8]) {
-    let text = String::from_utf8_lossy(payload);
-    for line in text.lines() {
-        if line.is_empty() {
-            continue;
+/// Stands in for the decoder when the `opus-decode` feature is off; never
+/// constructed.
+#[cfg(not(feature = "opus-decode"))]
+enum PcmPlayout {}
+
+#[cfg(feature = "opus-decode")]
+impl PcmPlayout {
+    /// Returns the decoder configured with `configure_receiver_opus_decode`, if any.
+    /// Sessions that negotiated another audio codec keep receiving encoded frames.
+    fn start(sink: &StreamSink<UiEvent>, format: &AudioFormat) -> anyhow::Result<Option<Self>> {
+        let Some(output) = receiver_opus_decode_slot()
+            .lock()
+            .map_err(|_| anyhow!("failed to lock receiver opus decode config"))?
+            .clone()
+        else {
+            return Ok(None);
+        };
+        if format.codec != AudioCodec::Opus {
+            sink_event(
+                sink,
+                UiEvent::Log {
+                    msg: format!(
+                        "opus decoding is enabled but the session uses {}; delivering encoded audio",
+                        format.codec.name()
+                    ),
+                },
+            );
+            return Ok(None);
         }
-        sink_event(
-            sink,
-            UiEvent::Log {
-                msg: format!("[RemoteReport] {line}"),
-            },
-        );
+        let file = match output {
+            PcmOutput::Dart => None,
+            PcmOutput::File(path) => Some(PcmFileWriter::open(&path)?),
+        };
+        Ok(Some(Self {
+            decoder: OpusPcmDecoder::new()?,
+            file,
+            late_dropped: 0,
+        }))
     }
-    if text.ends_with('\n') {
-        sink_event(
-            sink,
-            UiEvent::Log {
-                msg: "[RemoteReport] <blank line>".to_string(),
-            },
-        );
-    }
-}
 
-fn sanitize_debug_report_filename(input: &str) -> String {
-    let mut out = String::with_capacity(input.len());
-    for ch in input.chars() {
-        if ch.is_ascii_alphanumeric() || matches!(ch, '.' | '_' | '-') {
-            out.push(ch);
-        } else {
-            out.push('_');
+    /// Rebuilds the packets missing before `next_packet`. The last one comes from
+    /// the FEC data in `next_packet` when the gap is short enough to be concealed
+    /// in full; the rest use the decoder's loss concealment.
+    fn conceal_gap(
+        &mut self,
+        sink: &StreamSink<UiEvent>,
+        buffer: &mut Option<JitterBuffer<UiEvent>>,
+        stream_id: u32,
+        gap: &AudioGap,
+        next_packet: &[u8],
+    ) -> u32 {
+        let frames = (gap.packet_duration_us * u64::from(PCM_SAMPLE_RATE_HZ) / 1_000_000) as u32;
+        let packets = gap.concealment_packets();
+        let fec_index = (packets == gap.missing_packets).then(|| packets.saturating_sub(1));
+        let mut concealed = 0;
+        for (index, pts) in (0..).zip(gap.concealment_pts()) {
+            let fec_packet = (Some(index) == fec_index).then_some(next_packet);
+            match self.decoder.conceal(pts, frames, fec_packet) {
+                Ok(block) => {
+                    self.output(sink, buffer, stream_id, block);
+                    concealed += 1;
+                }
+                Err(error) => emit_pcm_decode_error(sink, stream_id, &error),
+            }
         }
+        concealed
     }
-    let trimmed = out.trim_matches('_');
-    if trimmed.is_empty() {
-        "nomikai_sender_report.log".to_string()
-    } else {
-        trimmed.to_string()
+
+    /// Drops a packet behind ones already decoded. Its pts was concealed or
+    /// played around, and decoding it now would corrupt the decoder's state and
+    /// write its audio out of order.
+    fn drop_late(&mut self) {
+        self.late_dropped = self.late_dropped.saturating_add(1);
     }
-}
 
-fn save_remote_debug_report_file(assembly: &RemoteDebugReportAssembly) -> anyhow::Result<String> {
-    let mut data = Vec::new();
-    if assembly.total_chunks > 0 {
-        for seq in 0..assembly.total_chunks {
-            let chunk = assembly
-                .chunks
-                .get(&seq)
-                .with_context(|| format!("missing debug report chunk {seq}"))?;
-            data.extend_from_slice(chunk);
+    fn deliver(
+        &mut self,
+        sink: &StreamSink<UiEvent>,
+        buffer: &mut Option<JitterBuffer<UiEvent>>,
+        stream_id: u32,
+        pts: u64,
+        packet: &[u8],
+    ) {
+        match self.decoder.decode(pts, packet) {
+            Ok(block) => self.output(sink, buffer, stream_id, block),
+            Err(error) => emit_pcm_decode_error(sink, stream_id, &error),
         }
     }
 
-    if assembly.expected_bytes != 0 && data.len() != assembly.expected_bytes as usize {
-        bail!(
-            "debug report byte length mismatch (expected={}, actual={})",
-            assembly.expected_bytes,
-            data.len()
+    /// Queues audio for the file output as soon as it is decoded, so the jitter
+    /// buffer and lip-sync only apply to PCM delivered to Dart. A failed output
+    /// falls back to Dart delivery for the rest of the session.
+    fn output(
+        &mut self,
+        sink: &StreamSink<UiEvent>,
+        buffer: &mut Option<JitterBuffer<UiEvent>>,
+        stream_id: u32,
+        block: PcmBlock,
+    ) {
+        if let Some(file) = self.file.as_mut() {
+            match file.write(&block.samples) {
+                Ok(()) => return,
+                Err(error) => {
+                    self.file = None;
+                    sink_event(
+                        sink,
+                        UiEvent::Error {
+                            msg: format!("{error:#}; delivering PCM to Dart instead"),
+                        },
+                    );
+                }
+            }
+        }
+        let pts = block.pts;
+        buffer_or_emit_frame(
+            sink,
+            buffer,
+            stream_id,
+            pts,
+            UiEvent::PcmAudioReceived {
+                stream_id,
+                pts,
+                sample_rate: PCM_SAMPLE_RATE_HZ,
+                channels: u32::from(PCM_CHANNELS),
+                frames_per_packet: block.frames(),
+                samples: block.samples,
+                concealed: block.concealed,
+            },
         );
     }
 
-    let cwd = std::env::current_dir().unwrap_or_else(|_| std::env::temp_dir());
-    let mut reports_dir = cwd.join("remote_reports");
-    if std::fs::create_dir_all(&reports_dir).is_err() {
-        reports_dir = std::env::temp_dir().join("nomikai_remote_reports");
-        std::fs::create_dir_all(&reports_dir)
-            .context("failed to create remote debug report output dir")?;
-    }
-
-    let ts_ms = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_millis();
-    let mut filename = sanitize_debug_report_filename(&assembly.filename);
-    if let Some((stem, ext)) = filename.rsplit_once('.') {
-        filename = format!("{stem}_{ts_ms}.{ext}");
-    } else {
-        filename = format!("{filename}_{ts_ms}.log");
+    fn emit_telemetry(&self, sink: &StreamSink<UiEvent>) {
+        let counters = self.decoder.counters();
+        for (name, value) in [
+            ("audio.pcm.decoded", counters.decoded),
+            ("audio.pcm.fec_recovered", counters.fec_recovered),
+            ("audio.pcm.plc_packets", counters.plc_packets),
+            ("audio.pcm.errors", counters.errors),
+            ("audio.pcm.late_dropped", self.late_dropped),
+            (
+                "audio.pcm.output_dropped",
+                self.file.as_ref().map_or(0, PcmFileWriter::dropped),
+            ),
+        ] {
+            sink_event(
+                sink,
+                UiEvent::Telemetry {
+                    name: name.to_string(),
+                    value,
+                },
+            );
+        }
     }
+}
 
-    let path = reports_dir.join(filename);
-    std::fs::write(&path, data).context("failed to write remote debug report file")?;
-    Ok(path.display().to_string())
+#[cfg(feature = "opus-decode")]
+fn emit_pcm_decode_error(sink: &StreamSink<UiEvent>, stream_id: u32, error: &anyhow::Error) {
+    sink_event(
+        sink,
+        UiEvent::FrameDrop {
+            stream_id,
+            reason: format!("{error:#}"),
+        },
+    );
 }
 
-fn handle_remote_debug_report_payload(
-    sink: &StreamSink<UiEvent>,
-    payload: &[u8],
-    assembly: &mut Option<RemoteDebugReportAssembly>,
-) {
-    if payload.len() < 6 || payload.get(..4) != Some(DEBUG_REPORT_MAGIC) {
-        emit_remote_report_text_lines(sink, payload);
-        sink_event(
-            sink,
-            UiEvent::Telemetry {
-                name: "debug_report_bytes".to_string(),
-                value: payload.len() as u64,
-            },
-        );
-        return;
+#[cfg(not(feature = "opus-decode"))]
+impl PcmPlayout {
+    fn start(_sink: &StreamSink<UiEvent>, _format: &AudioFormat) -> anyhow::Result<Option<Self>> {
+        Ok(None)
     }
 
-    if payload.len() < 10 {
-        sink_event(
-            sink,
-            UiEvent::Log {
-                msg: "[RemoteReport] malformed packet: header too short".to_string(),
-            },
-        );
-        return;
+    fn conceal_gap(
+        &mut self,
+        _sink: &StreamSink<UiEvent>,
+        _buffer: &mut Option<JitterBuffer<UiEvent>>,
+        _stream_id: u32,
+        _gap: &AudioGap,
+        _next_packet: &[u8],
+    ) -> u32 {
+        match *self {}
     }
 
-    let version = payload[4];
-    let packet_type = payload[5];
-    let Some(report_id) = parse_u32_le(&payload[6..10]) else {
-        sink_event(
-            sink,
-            UiEvent::Log {
-                msg: "[RemoteReport] malformed packet: invalid report id".to_string(),
-            },
-        );
-        return;
-    };
+    fn drop_late(&mut self) {
+        match *self {}
+    }
 
-    if version != DEBUG_REPORT_PROTOCOL_VERSION {
-        sink_event(
-            sink,
-            UiEvent::Log {
-                msg: format!(
-                    "[RemoteReport] unsupported protocol version {} (expected {})",
-                    version, DEBUG_REPORT_PROTOCOL_VERSION
-                ),
-            },
-        );
-        return;
+    fn deliver(
+        &mut self,
+        _sink: &StreamSink<UiEvent>,
+        _buffer: &mut Option<JitterBuffer<UiEvent>>,
+        _stream_id: u32,
+        _pts: u64,
+        _packet: &[u8],
+    ) {
+        match *self {}
+    }
+
+    fn emit_telemetry(&self, _sink: &StreamSink<UiEvent>) {
+        match *self {}
     }
+}
 
-    match packet_type {
-        DEBUG_REPORT_PACKET_BEGIN => {
-            if payload.len() < 20 {
+/// Stream registered with `start_software_video_decode` and the output it asked for.
+#[cfg(feature = "hevc-decode")]
+#[derive(Clone)]
+struct SoftwareVideoOutput {
+    sink: StreamSink<UiEvent>,
+    target: DecodeTarget,
+    /// Distinguishes each `start_software_video_decode` call, so a running session
+    /// notices when the output was replaced.
+    generation: u64,
+}
+
+/// Decodes received HEVC in software, one worker thread per video stream. Follows
+/// `start_software_video_decode` and `stop_software_video_decode` while the
+/// session runs.
+#[cfg(feature = "hevc-decode")]
+struct SoftwareVideoDecode {
+    output: Option<SoftwareVideoOutput>,
+    /// `None` once a stream's decoder failed to start, so it is not retried for
+    /// every frame.
+    workers: BTreeMap<u32, Option<VideoDecodeWorker>>,
+}
+
+/// Stands in for the decoder when the `hevc-decode` feature is off; never
+/// constructed.
+#[cfg(not(feature = "hevc-decode"))]
+enum SoftwareVideoDecode {}
+
+#[cfg(feature = "hevc-decode")]
+impl SoftwareVideoDecode {
+    /// Returns `None` for sessions that cannot be decoded in software.
+    fn start(sink: &StreamSink<UiEvent>, codec: VideoCodec) -> anyhow::Result<Option<Self>> {
+        let output = software_video_decode_slot()
+            .lock()
+            .map_err(|_| anyhow!("failed to lock software video decode output"))?
+            .clone();
+        if codec != VideoCodec::Hevc {
+            if output.is_some() {
                 sink_event(
                     sink,
                     UiEvent::Log {
-                        msg: "[RemoteReport] malformed BEGIN packet".to_string(),
+                        msg: format!(
+                            "software video decoding only supports hevc; the session uses {}",
+                            codec.name()
+                        ),
                     },
                 );
-                return;
             }
-            let Some(total_chunks) = parse_u32_le(&payload[10..14]) else {
-                return;
-            };
-            let Some(expected_bytes) = parse_u32_le(&payload[14..18]) else {
-                return;
-            };
-            let Some(filename_len) = parse_u16_le(&payload[18..20]) else {
-                return;
-            };
-            let filename_end = 20usize.saturating_add(filename_len as usize);
-            let Some(filename_bytes) = payload.get(20..filename_end) else {
-                sink_event(
-                    sink,
-                    UiEvent::Log {
-                        msg: "[RemoteReport] malformed BEGIN packet filename".to_string(),
-                    },
-                );
-                return;
-            };
-            let filename = String::from_utf8_lossy(filename_bytes).to_string();
-
-            if let Some(existing) = assembly.take() {
-                sink_event(
-                    sink,
-                    UiEvent::Log {
-                        msg: format!(
-                            "[RemoteReport] dropping incomplete report id={} while starting new report id={}",
-                            existing.report_id, report_id
-                        ),
-                    },
-                );
-            }
-
-            *assembly = Some(RemoteDebugReportAssembly {
-                report_id,
-                filename: if filename.is_empty() {
-                    "nomikai_sender_report.log".to_string()
-                } else {
-                    filename
-                },
-                total_chunks,
-                expected_bytes,
-                chunks: BTreeMap::new(),
-            });
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            output,
+            workers: BTreeMap::new(),
+        }))
+    }
 
-            sink_event(
-                sink,
-                UiEvent::Log {
-                    msg: format!(
-                        "[RemoteReport] begin id={} file={} chunks={} bytes={}",
-                        report_id,
-                        assembly
-                            .as_ref()
-                            .map(|a| a.filename.as_str())
-                            .unwrap_or("unknown"),
-                        total_chunks,
-                        expected_bytes
-                    ),
-                },
-            );
+    /// Picks up a started, replaced or stopped output. Workers of the previous
+    /// output are dropped, which detaches their threads.
+    fn refresh(&mut self) {
+        let Ok(guard) = software_video_decode_slot().lock() else {
+            return;
+        };
+        let generation =
+            |output: &Option<SoftwareVideoOutput>| output.as_ref().map(|output| output.generation);
+        if generation(&guard) != generation(&self.output) {
+            self.output = guard.clone();
+            self.workers.clear();
         }
-        DEBUG_REPORT_PACKET_CHUNK => {
-            if payload.len() < 16 {
-                sink_event(
-                    sink,
-                    UiEvent::Log {
-                        msg: "[RemoteReport] malformed CHUNK packet".to_string(),
-                    },
-                );
-                return;
-            }
-            let Some(seq) = parse_u32_le(&payload[10..14]) else {
-                return;
-            };
-            let Some(chunk_len) = parse_u16_le(&payload[14..16]) else {
-                return;
-            };
-            let chunk_end = 16usize.saturating_add(chunk_len as usize);
-            let Some(chunk_bytes) = payload.get(16..chunk_end) else {
-                sink_event(
-                    sink,
-                    UiEvent::Log {
-                        msg: format!("[RemoteReport] malformed CHUNK packet seq={seq}"),
-                    },
-                );
-                return;
-            };
+    }
 
-            let Some(active) = assembly.as_mut() else {
-                sink_event(
-                    sink,
-                    UiEvent::Log {
-                        msg: format!(
-                            "[RemoteReport] dropped chunk seq={} for report id={} (no active report)",
-                            seq, report_id
-                        ),
+    /// Queues an Annex B access unit for decoding, if an output is registered.
+    fn submit(&mut self, sink: &StreamSink<UiEvent>, stream_id: u32, pts: u64, payload: &[u8]) {
+        self.refresh();
+        let Some(output) = self.output.as_ref() else {
+            return;
+        };
+        let worker = self.workers.entry(stream_id).or_insert_with(|| {
+            let target = output.target;
+            let pixels = target.pixels;
+            let output = output.sink.clone();
+            let errors = sink.clone();
+            let deliver = move |picture: DecodedPicture| {
+                let _ = output.add(UiEvent::DecodedVideoFrame {
+                    stream_id,
+                    pts: picture.pts,
+                    width: picture.width,
+                    height: picture.height,
+                    format: match pixels {
+                        OutputPixels::Rgba => DecodedVideoFormat::Rgba,
+                        OutputPixels::I420 => DecodedVideoFormat::I420,
                     },
-                );
-                return;
+                    data: picture.data,
+                });
             };
-
-            if active.report_id != report_id {
+            let report_error = move |error: anyhow::Error| {
                 sink_event(
-                    sink,
-                    UiEvent::Log {
-                        msg: format!(
-                            "[RemoteReport] dropped chunk seq={} for report id={} (active id={})",
-                            seq, report_id, active.report_id
-                        ),
+                    &errors,
+                    UiEvent::FrameDrop {
+                        stream_id,
+                        reason: format!("software decode: {error:#}"),
                     },
                 );
-                return;
-            }
+            };
+            VideoDecodeWorker::spawn(target, deliver, report_error)
+                .inspect_err(|error| {
+                    sink_event(
+                        sink,
+                        UiEvent::Error {
+                            msg: format!("failed to start software video decoder: {error:#}"),
+                        },
+                    );
+                })
+                .ok()
+        });
+        if let Some(worker) = worker {
+            worker.submit(pts, payload.to_vec());
+        }
+    }
 
-            active.chunks.entry(seq).or_insert_with(|| chunk_bytes.to_vec());
+    fn emit_telemetry(&self, sink: &StreamSink<UiEvent>) {
+        let mut decoded = 0;
+        let mut dropped_late = 0;
+        let mut dropped_backlog = 0;
+        let mut skipped_until_irap = 0;
+        let mut errors = 0;
+        let mut samples = 0;
+        let mut total = Duration::ZERO;
+        let mut max = Duration::ZERO;
+        for worker in self.workers.values().flatten() {
+            let stats = worker.take_stats();
+            decoded += stats.decoded;
+            dropped_late += stats.dropped_late;
+            dropped_backlog += stats.dropped_backlog;
+            skipped_until_irap += stats.skipped_until_irap;
+            errors += stats.errors;
+            samples += stats.decode_samples;
+            total += stats.decode_total;
+            max = max.max(stats.decode_max);
+        }
+        let average_us = match samples {
+            0 => 0,
+            samples => total.as_micros() as u64 / samples,
+        };
+        for (name, value) in [
+            ("video.decode.frames", decoded),
+            ("video.decode.dropped_late", dropped_late),
+            ("video.decode.dropped_backlog", dropped_backlog),
+            ("video.decode.skipped_until_irap", skipped_until_irap),
+            ("video.decode.errors", errors),
+            ("video.decode.avg_us", average_us),
+            ("video.decode.max_us", max.as_micros() as u64),
+        ] {
             sink_event(
                 sink,
                 UiEvent::Telemetry {
-                    name: "debug_report_bytes".to_string(),
-                    value: chunk_bytes.len() as u64,
+                    name: name.to_string(),
+                    value,
                 },
             );
         }
-        DEBUG_REPORT_PACKET_END => {
-            if payload.len() < 18 {
-                sink_event(
-                    sink,
-                    UiEvent::Log {
-                        msg: "[RemoteReport] malformed END packet".to_string(),
-                    },
-                );
-                return;
+    }
+}
+
+#[cfg(not(feature = "hevc-decode"))]
+impl SoftwareVideoDecode {
+    fn start(_sink: &StreamSink<UiEvent>, _codec: VideoCodec) -> anyhow::Result<Option<Self>> {
+        Ok(None)
+    }
+
+    fn submit(&mut self, _sink: &StreamSink<UiEvent>, _stream_id: u32, _pts: u64, _payload: &[u8]) {
+        match *self {}
+    }
+
+    fn emit_telemetry(&self, _sink: &StreamSink<UiEvent>) {
+        match *self {}
+    }
+}
+
+fn buffer_or_emit_frame(
+    sink: &StreamSink<UiEvent>,
+    buffer: &mut Option<JitterBuffer<UiEvent>>,
+    stream_id: u32,
+    pts: u64,
+    event: UiEvent,
+) {
+    let Some(buffer) = buffer.as_mut() else {
+        sink_event(sink, event);
+        return;
+    };
+    if !buffer.push(pts, Instant::now(), event) {
+        sink_event(
+            sink,
+            UiEvent::FrameDrop {
+                stream_id,
+                reason: format!("jitter buffer: late frame pts_us={pts}"),
+            },
+        );
+    }
+}
+
+fn release_jitter_buffer(buffer: &mut Option<JitterBuffer<UiEvent>>, sink: &StreamSink<UiEvent>) {
+    let Some(buffer) = buffer.as_mut() else {
+        return;
+    };
+    let now = Instant::now();
+    while let Some((_, event)) = buffer.pop_ready(now) {
+        sink_event(sink, event);
+    }
+}
+
+fn apply_lip_sync(
+    lip_sync: &mut Option<LipSync>,
+    video: &mut Option<JitterBuffer<UiEvent>>,
+    audio: &mut Option<JitterBuffer<UiEvent>>,
+) {
+    let (Some(lip_sync), Some(video), Some(audio)) =
+        (lip_sync.as_mut(), video.as_mut(), audio.as_mut())
+    else {
+        return;
+    };
+    let correction_us =
+        i64::from(RECEIVER_LIP_SYNC_CORRECTION_MS.load(Ordering::Relaxed)).saturating_mul(1_000);
+    if let Some(hold) = lip_sync.update(
+        video.playout_offset_us(),
+        audio.playout_offset_us(),
+        correction_us,
+    ) {
+        video.set_hold(hold.video);
+        audio.set_hold(hold.audio);
+    }
+}
+
+fn emit_lip_sync_telemetry(sink: &StreamSink<UiEvent>, lip_sync: &Option<LipSync>) {
+    let Some(lip_sync) = lip_sync.as_ref() else {
+        return;
+    };
+    let hold = lip_sync.hold();
+    for (name, value) in [
+        ("av_sync.video_hold_us", hold.video.as_micros() as u64),
+        ("av_sync.audio_hold_us", hold.audio.as_micros() as u64),
+    ] {
+        sink_event(
+            sink,
+            UiEvent::Telemetry {
+                name: name.to_string(),
+                value,
+            },
+        );
+    }
+}
+
+fn emit_jitter_buffer_telemetry(
+    sink: &StreamSink<UiEvent>,
+    prefix: &str,
+    buffer: &Option<JitterBuffer<UiEvent>>,
+) {
+    let Some(buffer) = buffer.as_ref() else {
+        return;
+    };
+    for (name, value) in [
+        ("depth", buffer.depth() as u64),
+        ("target_delay_ms", buffer.target_delay().as_millis() as u64),
+        ("jitter_us", buffer.jitter().as_micros() as u64),
+        ("late_drops", buffer.late_drops()),
+        ("overflow_drops", buffer.overflow_drops()),
+    ] {
+        sink_event(
+            sink,
+            UiEvent::Telemetry {
+                name: format!("{prefix}.{name}"),
+                value,
+            },
+        );
+    }
+}
+
+/// The running sender's video queue and the keyframe classification frames pass
+/// before they are queued, so the overflow policy sees the resolved flag.
+#[derive(Clone)]
+struct VideoIngress {
+    queue: Arc<VideoIngressQueue>,
+    keyframe_check: Arc<Mutex<KeyframeCheck>>,
+}
+
+fn video_ingress_slot() -> &'static Mutex<Option<VideoIngress>> {
+    VIDEO_INGRESS.get_or_init(|| Mutex::new(None))
+}
+
+fn audio_frame_tx_slot() -> &'static Mutex<Option<AudioFrameTx>> {
+    AUDIO_FRAME_TX.get_or_init(|| Mutex::new(None))
+}
+
+fn install_video_ingress(ingress: VideoIngress) -> anyhow::Result<()> {
+    let mut guard = video_ingress_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock video ingress slot"))?;
+    if guard.is_some() {
+        bail!("sender loop already running");
+    }
+    *guard = Some(ingress);
+    Ok(())
+}
+
+fn clear_video_ingress() {
+    if let Ok(mut guard) = video_ingress_slot().lock() {
+        if let Some(ingress) = guard.take() {
+            ingress.queue.close();
+        }
+    }
+}
+
+fn install_audio_frame_tx(tx: AudioFrameTx) -> anyhow::Result<()> {
+    let mut guard = audio_frame_tx_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock audio frame sender slot"))?;
+    if guard.is_some() {
+        bail!("sender loop already running");
+    }
+    *guard = Some(tx);
+    Ok(())
+}
+
+fn clear_audio_frame_tx() {
+    if let Ok(mut guard) = audio_frame_tx_slot().lock() {
+        *guard = None;
+    }
+}
+
+/// Default frame rate used to synthesise pts for `push_video_bytes`.
+const DEFAULT_VIDEO_BYTES_FRAME_RATE: (u32, u32) = (30, 1);
+
+/// Access-unit framing state for raw elementary streams fed through `push_video_bytes`.
+struct VideoBytesIngress {
+    splitter: AccessUnitSplitter,
+    frame_rate: (u32, u32),
+    next_pts: u64,
+    /// Stream byte offset of the next pushed chunk.
+    stream_offset: u64,
+    /// Byte ranges of pushed chunks that carried a pts not yet attached to an access unit.
+    supplied_pts: VecDeque<(std::ops::Range<u64>, u64)>,
+}
+
+impl VideoBytesIngress {
+    fn new() -> Self {
+        Self {
+            splitter: AccessUnitSplitter::default(),
+            frame_rate: DEFAULT_VIDEO_BYTES_FRAME_RATE,
+            next_pts: 0,
+            stream_offset: 0,
+            supplied_pts: VecDeque::new(),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.splitter = AccessUnitSplitter::default();
+        self.next_pts = 0;
+        self.stream_offset = 0;
+        self.supplied_pts.clear();
+    }
+
+    fn push(&mut self, chunk: &[u8], pts: Option<u64>) -> Vec<(AccessUnit, u64)> {
+        let start = self.stream_offset;
+        self.stream_offset += chunk.len() as u64;
+        if let Some(pts) = pts.filter(|_| !chunk.is_empty()) {
+            self.supplied_pts
+                .push_back((start..self.stream_offset, pts));
+        }
+        let units = self.splitter.push(chunk);
+        self.stamp(units)
+    }
+
+    fn flush(&mut self) -> Vec<(AccessUnit, u64)> {
+        let units = self.splitter.flush();
+        let stamped = self.stamp(units);
+        self.supplied_pts.clear();
+        stamped
+    }
+
+    fn frame_duration_us(&self) -> u64 {
+        let (num, den) = self.frame_rate;
+        u64::from(den).saturating_mul(1_000_000) / u64::from(num.max(1))
+    }
+
+    /// Assigns pts to completed access units: a pts supplied with the chunk holding an
+    /// access unit's first byte anchors that unit, others advance by one frame duration.
+    fn stamp(&mut self, units: Vec<AccessUnit>) -> Vec<(AccessUnit, u64)> {
+        let duration = self.frame_duration_us();
+        units
+            .into_iter()
+            .map(|unit| {
+                let pts = self.take_supplied_pts(unit.offset).unwrap_or(self.next_pts);
+                self.next_pts = pts.saturating_add(duration);
+                (unit, pts)
+            })
+            .collect()
+    }
+
+    fn take_supplied_pts(&mut self, offset: u64) -> Option<u64> {
+        while let Some((range, pts)) = self.supplied_pts.front() {
+            if range.end <= offset {
+                self.supplied_pts.pop_front();
+                continue;
             }
-            let Some(total_chunks_sent) = parse_u32_le(&payload[10..14]) else {
-                return;
-            };
-            let Some(total_bytes_sent) = parse_u32_le(&payload[14..18]) else {
-                return;
-            };
+            if range.contains(&offset) {
+                let pts = *pts;
+                self.supplied_pts.pop_front();
+                return Some(pts);
+            }
+            return None;
+        }
+        None
+    }
+}
+
+fn video_bytes_ingress_slot() -> &'static Mutex<VideoBytesIngress> {
+    VIDEO_BYTES_INGRESS.get_or_init(|| Mutex::new(VideoBytesIngress::new()))
+}
+
+fn reset_video_bytes_ingress() {
+    if let Ok(mut guard) = video_bytes_ingress_slot().lock() {
+        guard.reset();
+    }
+}
+
+struct FrameIngressGuard;
 
-            let Some(active) = assembly.take() else {
+impl Drop for FrameIngressGuard {
+    fn drop(&mut self) {
+        clear_video_ingress();
+        clear_audio_frame_tx();
+        reset_video_bytes_ingress();
+    }
+}
+
+struct SenderRunGuard;
+
+impl Drop for SenderRunGuard {
+    fn drop(&mut self) {
+        SENDER_SHOULD_RUN.store(false, Ordering::Relaxed);
+        SENDER_LEGACY_DEBUG_REPORTS.store(false, Ordering::Relaxed);
+        let _ = set_session_slot(sender_control_slot(), None);
+        let _ = set_session_slot(sender_datagrams_slot(), None);
+        let _ = set_session_slot(sender_file_commands_slot(), None);
+        let _ = set_session_slot(sender_pending_commands_slot(), None);
+    }
+}
+
+struct ReceiverRunGuard;
+
+impl Drop for ReceiverRunGuard {
+    fn drop(&mut self) {
+        RECEIVER_SHOULD_RUN.store(false, Ordering::Relaxed);
+        let _ = set_session_slot(receiver_control_slot(), None);
+        let _ = set_session_slot(receiver_datagrams_slot(), None);
+        let _ = set_session_slot(receiver_file_commands_slot(), None);
+        let _ = set_session_slot(receiver_issued_commands_slot(), None);
+    }
+}
+
+/// Saves a `DebugReport` control message off the session loop, under
+/// `remote_reports` in the working directory (or the temp directory when that is
+/// not writable) and named like a received file, and reports where it went.
+fn receive_debug_report(sink: &StreamSink<UiEvent>, name: String, report: Vec<u8>) {
+    let sink = sink.clone();
+    spawn_blocking(move || {
+        let name = if name.is_empty() {
+            "nomikai_sender_report.log"
+        } else {
+            name.as_str()
+        };
+        let cwd = std::env::current_dir().unwrap_or_else(|_| std::env::temp_dir());
+        let saved =
+            file_transfer::save_file(&cwd.join("remote_reports"), name, &report).or_else(|_| {
+                let fallback = std::env::temp_dir().join("nomikai_remote_reports");
+                file_transfer::save_file(&fallback, name, &report)
+            });
+        match saved {
+            Ok(path) => {
                 sink_event(
-                    sink,
+                    &sink,
                     UiEvent::Log {
                         msg: format!(
-                            "[RemoteReport] END received for report id={} with no active report",
-                            report_id
+                            "[RemoteReport] saved file path={} bytes={}",
+                            path.display(),
+                            report.len()
                         ),
                     },
                 );
-                return;
-            };
-
-            if active.report_id != report_id {
                 sink_event(
-                    sink,
-                    UiEvent::Log {
-                        msg: format!(
-                            "[RemoteReport] END report id mismatch active={} received={}",
-                            active.report_id, report_id
-                        ),
+                    &sink,
+                    UiEvent::Telemetry {
+                        name: "debug_report_saved_bytes".to_string(),
+                        value: report.len() as u64,
                     },
                 );
-                return;
             }
-
-            let received_chunk_count = active.chunks.len() as u32;
-            if active.total_chunks != total_chunks_sent || active.expected_bytes != total_bytes_sent {
+            Err(error) => {
                 sink_event(
-                    sink,
-                    UiEvent::Log {
-                        msg: format!(
-                            "[RemoteReport] END metadata mismatch id={} begin(chunks={},bytes={}) end(chunks={},bytes={})",
-                            report_id, active.total_chunks, active.expected_bytes, total_chunks_sent, total_bytes_sent
-                        ),
+                    &sink,
+                    UiEvent::Error {
+                        msg: format!("remote debug report save failed: {error:#}"),
                     },
                 );
             }
+        }
+    });
+}
 
-            if active.total_chunks != received_chunk_count {
+/// Follows a debug report sent on the audio stream by a sender that predates the
+/// control stream, logging plain text payloads and saving completed reports like
+/// `receive_debug_report`.
+fn handle_legacy_debug_report(
+    sink: &StreamSink<UiEvent>,
+    assembler: &mut LegacyReportAssembler,
+    payload: &[u8],
+) {
+    match assembler.handle(payload) {
+        Ok(LegacyReportEvent::Text(text)) => {
+            for line in text.lines().filter(|line| !line.is_empty()) {
                 sink_event(
                     sink,
                     UiEvent::Log {
-                        msg: format!(
-                            "[RemoteReport] incomplete report id={} chunks_received={}/{}",
-                            report_id, received_chunk_count, active.total_chunks
-                        ),
+                        msg: format!("[RemoteReport] {line}"),
                     },
                 );
-                return;
             }
-
-            match save_remote_debug_report_file(&active) {
-                Ok(path) => {
-                    sink_event(
-                        sink,
-                        UiEvent::Log {
-                            msg: format!(
-                                "[RemoteReport] saved file path={} bytes={} chunks={}",
-                                path, active.expected_bytes, active.total_chunks
-                            ),
-                        },
-                    );
-                    sink_event(
-                        sink,
-                        UiEvent::Telemetry {
-                            name: "debug_report_saved_bytes".to_string(),
-                            value: active.expected_bytes as u64,
-                        },
-                    );
-                }
-                Err(error) => {
-                    sink_event(
-                        sink,
-                        UiEvent::Error {
-                            msg: format!("remote debug report save failed: {error}"),
-                        },
-                    );
-                }
-            }
-        }
-        _ => {
-            sink_event(
-                sink,
-                UiEvent::Log {
-                    msg: format!("[RemoteReport] unsupported packet type 0x{packet_type:02X}"),
-                },
-            );
-        }
-    }
-}
+        }
+        Ok(LegacyReportEvent::Started {
+            report_id,
+            name,
+            chunks,
+            bytes,
+        }) => sink_event(
+            sink,
+            UiEvent::Log {
+                msg: format!(
+                    "[RemoteReport] begin id={report_id} file={name} chunks={chunks} bytes={bytes}"
+                ),
+            },
+        ),
+        Ok(LegacyReportEvent::Chunk { bytes }) => sink_event(
+            sink,
+            UiEvent::Telemetry {
+                name: "debug_report_bytes".to_string(),
+                value: bytes as u64,
+            },
+        ),
+        Ok(LegacyReportEvent::Complete { name, report }) => {
+            receive_debug_report(sink, name, report);
+        }
+        Err(error) => sink_event(
+            sink,
+            UiEvent::Log {
+                msg: format!("[RemoteReport] {error:#}"),
+            },
+        ),
+    }
+}
 
 fn announce_sender_handshake_if_needed(
     sink: &StreamSink<UiEvent>,
@@ -742,6 +1530,430 @@ fn announce_sender_handshake_if_needed(
     );
 }
 
+/// Recommended capture-side reaction to sender congestion, mildest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CongestionAction {
+    #[default]
+    None,
+    ReduceBitrate,
+    ReduceFrameRate,
+    SkipFrame,
+}
+
+impl CongestionAction {
+    fn as_str(self) -> &'static str {
+        match self {
+            CongestionAction::None => "none",
+            CongestionAction::ReduceBitrate => "reduce_bitrate",
+            CongestionAction::ReduceFrameRate => "reduce_fps",
+            CongestionAction::SkipFrame => "skip_frame",
+        }
+    }
+}
+
+impl From<Backpressure> for CongestionAction {
+    fn from(backpressure: Backpressure) -> Self {
+        match backpressure {
+            Backpressure::None => CongestionAction::None,
+            Backpressure::ReduceBitrate => CongestionAction::ReduceBitrate,
+            Backpressure::ReduceFrameRate => CongestionAction::ReduceFrameRate,
+            Backpressure::SkipFrame => CongestionAction::SkipFrame,
+        }
+    }
+}
+
+/// Sender video queue state returned by the video ingress calls, so the capture
+/// pipeline can adapt before the transport's bitrate estimate catches up. All
+/// zero while no sender is running.
+#[derive(Debug, Clone, Default)]
+pub struct CongestionState {
+    pub queue_depth: u32,
+    pub queue_capacity: u32,
+    /// Time to drain the queue at the current target bitrate.
+    pub estimated_send_delay_ms: u32,
+    pub recommended_action: CongestionAction,
+    /// False when the queue's overflow policy dropped a frame pushed by this call.
+    pub frame_queued: bool,
+}
+
+impl CongestionState {
+    fn new(snapshot: CongestionSnapshot, frame_queued: bool) -> Self {
+        Self {
+            queue_depth: snapshot.depth as u32,
+            queue_capacity: snapshot.capacity as u32,
+            estimated_send_delay_ms: snapshot.send_delay.as_millis().min(u128::from(u32::MAX))
+                as u32,
+            recommended_action: snapshot.backpressure.into(),
+            frame_queued,
+        }
+    }
+}
+
+fn emit_congestion(sink: &StreamSink<UiEvent>, state: &CongestionState) {
+    sink_event(
+        sink,
+        UiEvent::Congestion {
+            queue_depth: state.queue_depth,
+            queue_capacity: state.queue_capacity,
+            estimated_send_delay_ms: state.estimated_send_delay_ms,
+            recommended_action: state.recommended_action.as_str().to_string(),
+        },
+    );
+}
+
+/// What `push_video_frame` does when the sender's video queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VideoQueuePolicy {
+    /// Evict the oldest queued delta frame to make room, with the delta frames
+    /// that reference it; if those run past the queue, incoming delta frames are
+    /// dropped until the caller's next keyframe.
+    DropOldestDeltaFrames,
+    /// Drop incoming delta frames until the caller's next keyframe, so the receiver
+    /// never sees a broken decoding chain.
+    DropUntilKeyframe,
+    /// Block the calling thread until the sender loop frees a slot.
+    Block,
+}
+
+impl From<VideoQueuePolicy> for OverflowPolicy {
+    fn from(policy: VideoQueuePolicy) -> Self {
+        match policy {
+            VideoQueuePolicy::DropOldestDeltaFrames => OverflowPolicy::DropOldestDelta,
+            VideoQueuePolicy::DropUntilKeyframe => OverflowPolicy::DropUntilKeyframe,
+            VideoQueuePolicy::Block => OverflowPolicy::Block,
+        }
+    }
+}
+
+/// About two seconds of 30 fps video.
+const DEFAULT_SENDER_VIDEO_QUEUE_CONFIG: (u32, VideoQueuePolicy) =
+    (60, VideoQueuePolicy::DropUntilKeyframe);
+
+fn sender_video_queue_config_slot() -> &'static Mutex<(u32, VideoQueuePolicy)> {
+    SENDER_VIDEO_QUEUE_CONFIG.get_or_init(|| Mutex::new(DEFAULT_SENDER_VIDEO_QUEUE_CONFIG))
+}
+
+fn emit_video_queue_drops(sink: &StreamSink<UiEvent>, stream_id: u32, queue: &VideoIngressQueue) {
+    for dropped in queue.take_drops() {
+        sink_event(
+            sink,
+            UiEvent::FrameDrop {
+                stream_id,
+                reason: format!(
+                    "{}: pts={} keyframe={}",
+                    dropped.reason.as_str(),
+                    dropped.pts,
+                    dropped.keyframe
+                ),
+            },
+        );
+    }
+}
+
+fn emit_video_queue_telemetry(sink: &StreamSink<UiEvent>, queue: &VideoIngressQueue) {
+    let counters = queue.drop_counters();
+    for (name, value) in [
+        ("ingress.video.depth", queue.depth() as u64),
+        ("ingress.video.capacity", queue.capacity() as u64),
+        ("ingress.video.dropped_evicted", counters.evicted),
+        (
+            "ingress.video.dropped_awaiting_keyframe",
+            counters.awaiting_keyframe,
+        ),
+        ("ingress.video.drops_unreported", counters.unreported),
+    ] {
+        sink_event(
+            sink,
+            UiEvent::Telemetry {
+                name: name.to_string(),
+                value,
+            },
+        );
+    }
+}
+
+/// Sender latency budgets: `streams` overrides `default` for individual stream ids
+/// of the running session.
+#[derive(Debug)]
+struct LatencyBudgets {
+    default: Option<Duration>,
+    streams: BTreeMap<u32, Option<Duration>>,
+}
+
+impl LatencyBudgets {
+    fn new() -> Self {
+        Self {
+            default: None,
+            streams: BTreeMap::new(),
+        }
+    }
+
+    fn budget(&self, stream_id: u32) -> Option<Duration> {
+        self.streams
+            .get(&stream_id)
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+fn sender_latency_budgets_slot() -> &'static Mutex<LatencyBudgets> {
+    SENDER_LATENCY_BUDGETS.get_or_init(|| Mutex::new(LatencyBudgets::new()))
+}
+
+fn sender_latency_budget(stream_id: u32) -> Option<Duration> {
+    sender_latency_budgets_slot()
+        .lock()
+        .ok()
+        .and_then(|guard| guard.budget(stream_id))
+}
+
+fn emit_temporal_layers(sink: &StreamSink<UiEvent>, stream_id: u32, shaper: &TemporalLayerShaper) {
+    sink_event(
+        sink,
+        UiEvent::TemporalLayers {
+            stream_id,
+            active_layers: u32::from(shaper.active_layers()),
+            total_layers: u32::from(shaper.total_layers()),
+        },
+    );
+}
+
+fn emit_temporal_layer_telemetry(sink: &StreamSink<UiEvent>, shaper: &TemporalLayerShaper) {
+    if shaper.total_layers() == 0 {
+        return;
+    }
+    let counters = shaper.counters();
+    for (name, value) in [
+        (
+            "temporal.video.active_layers",
+            u64::from(shaper.active_layers()),
+        ),
+        (
+            "temporal.video.total_layers",
+            u64::from(shaper.total_layers()),
+        ),
+        ("temporal.video.dropped", counters.dropped),
+        ("temporal.video.layer_changes", counters.layer_changes),
+        ("temporal.video.stream_bps", shaper.stream_bitrate_bps()),
+    ] {
+        sink_event(
+            sink,
+            UiEvent::Telemetry {
+                name: name.to_string(),
+                value,
+            },
+        );
+    }
+}
+
+fn emit_stale_frame_telemetry(sink: &StreamSink<UiEvent>, filters: &StaleFrameFilters) {
+    for (stream_id, counters) in filters.counters() {
+        for (name, value) in [
+            ("discarded_stale", counters.discarded_stale),
+            ("discarded_dependent", counters.discarded_dependent),
+            ("stale_keyframes_sent", counters.stale_keyframes_sent),
+        ] {
+            sink_event(
+                sink,
+                UiEvent::Telemetry {
+                    name: format!("stale.stream_{stream_id}.{name}"),
+                    value,
+                },
+            );
+        }
+    }
+}
+
+/// Pixel layout of `DecodedVideoFrame` events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodedVideoFormat {
+    /// Packed 8-bit RGBA, `width * 4` bytes per row.
+    Rgba,
+    /// The Y plane followed by the quarter-size U and V planes.
+    I420,
+}
+
+/// How the sender treats the `is_keyframe` flag passed to `push_video_frame`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyframeDetectionMode {
+    /// Use the caller's flag as-is without inspecting the bitstream.
+    TrustCaller,
+    /// Use the caller's flag, but classify NAL unit types and count disagreements.
+    CrossCheck,
+    /// Replace the caller's flag with the NAL-derived classification when available.
+    Override,
+}
+
+fn sender_keyframe_detection_slot() -> &'static Mutex<KeyframeDetectionMode> {
+    SENDER_KEYFRAME_DETECTION.get_or_init(|| Mutex::new(KeyframeDetectionMode::CrossCheck))
+}
+
+/// Per-session keyframe classification, applied by `push_video_frame` before a
+/// frame is queued.
+struct KeyframeCheck {
+    mode: KeyframeDetectionMode,
+    missed: u64,
+    spurious: u64,
+    unclassified: u64,
+}
+
+impl KeyframeCheck {
+    fn new(mode: KeyframeDetectionMode) -> Self {
+        Self {
+            mode,
+            missed: 0,
+            spurious: 0,
+            unclassified: 0,
+        }
+    }
+
+    /// Returns the keyframe flag to send with this access unit.
+    fn resolve(&mut self, payload: &[u8], codec: u8, caller_keyframe: bool) -> bool {
+        if self.mode == KeyframeDetectionMode::TrustCaller {
+            return caller_keyframe;
+        }
+        let Some(detected) =
+            VideoCodec::from_id(codec).and_then(|codec| codec.classify_keyframe(payload))
+        else {
+            self.unclassified = self.unclassified.saturating_add(1);
+            return caller_keyframe;
+        };
+        if detected && !caller_keyframe {
+            self.missed = self.missed.saturating_add(1);
+        } else if !detected && caller_keyframe {
+            self.spurious = self.spurious.saturating_add(1);
+        }
+        match self.mode {
+            KeyframeDetectionMode::Override => detected,
+            _ => caller_keyframe,
+        }
+    }
+
+    fn emit_telemetry(&self, sink: &StreamSink<UiEvent>) {
+        if self.mode == KeyframeDetectionMode::TrustCaller {
+            return;
+        }
+        for (name, value) in [
+            ("keyframe_check.caller_missed", self.missed),
+            ("keyframe_check.caller_spurious", self.spurious),
+            ("keyframe_check.unclassified", self.unclassified),
+        ] {
+            sink_event(
+                sink,
+                UiEvent::Telemetry {
+                    name: name.to_string(),
+                    value,
+                },
+            );
+        }
+    }
+}
+
+/// Opus TOC inspection, AAC-LC/PCM framing checks and voice activity for one
+/// audio direction.
+struct AudioInspection {
+    activity: AudioActivityMonitor,
+    malformed: u64,
+    frames_per_packet_mismatched: u64,
+}
+
+impl AudioInspection {
+    fn new() -> Self {
+        Self {
+            activity: AudioActivityMonitor::default(),
+            malformed: 0,
+            frames_per_packet_mismatched: 0,
+        }
+    }
+
+    /// Parses the packet and updates activity. A non-zero `declared_frames` that
+    /// disagrees with the TOC is counted as a mismatch.
+    fn inspect(
+        &mut self,
+        sink: &StreamSink<UiEvent>,
+        stream_id: u32,
+        local: bool,
+        pts: u64,
+        payload: &[u8],
+        declared_frames: u32,
+    ) -> anyhow::Result<OpusPacketInfo> {
+        let info = opus::parse_packet(payload).inspect_err(|_| {
+            self.malformed = self.malformed.saturating_add(1);
+        })?;
+        if declared_frames != 0 && declared_frames != info.samples_48k() {
+            self.frames_per_packet_mismatched = self.frames_per_packet_mismatched.saturating_add(1);
+        }
+        if let Some(speaking) = self.activity.observe(pts, &info, payload.len()) {
+            sink_event(
+                sink,
+                UiEvent::AudioActivity {
+                    stream_id,
+                    local,
+                    speaking,
+                },
+            );
+        }
+        Ok(info)
+    }
+
+    /// Checks the framing of a non-Opus frame and returns its samples per channel,
+    /// counting malformed frames and mismatched `declared_frames` like `inspect`.
+    fn check_frame(
+        &mut self,
+        format: &AudioFormat,
+        payload: &[u8],
+        declared_frames: u32,
+    ) -> anyhow::Result<u32> {
+        let frames = format.check_frame(payload).inspect_err(|_| {
+            self.malformed = self.malformed.saturating_add(1);
+        })?;
+        if declared_frames != 0 && declared_frames != frames {
+            self.frames_per_packet_mismatched = self.frames_per_packet_mismatched.saturating_add(1);
+        }
+        Ok(frames)
+    }
+
+    fn emit_telemetry(&mut self, sink: &StreamSink<UiEvent>, prefix: &str) {
+        let activity = self.activity.take_interval();
+        let mut values = vec![
+            ("malformed", self.malformed),
+            (
+                "frames_per_packet_mismatched",
+                self.frames_per_packet_mismatched,
+            ),
+        ];
+        if let Some(activity) = activity {
+            values.extend([
+                ("activity_pct", activity.activity_percent),
+                ("bitrate_bps", activity.bitrate_bps),
+                ("dtx_packets", activity.dtx_packets),
+                ("bandwidth_hz", activity.bandwidth_hz),
+                ("frame_duration_us", activity.frame_duration_us),
+            ]);
+        }
+        for (name, value) in values {
+            sink_event(
+                sink,
+                UiEvent::Telemetry {
+                    name: format!("{prefix}.{name}"),
+                    value,
+                },
+            );
+        }
+    }
+}
+
+/// Per-session processing of outgoing audio ahead of the transport.
+struct SenderAudioState {
+    format: AudioFormat,
+    inspection: AudioInspection,
+    redundancy: Option<AudioRedundancyEncoder>,
+    send_delay: SendDelayStats,
+    /// Passes `AUDIO_CODEC_DEBUG_TEXT` frames through for a peer that predates the
+    /// control stream.
+    legacy_debug_reports: bool,
+}
+
 async fn send_sender_frame(
     sink: &StreamSink<UiEvent>,
     sender: &mut SankakuSender,
@@ -809,59 +2021,2541 @@ async fn send_sender_frame(
     }
 }
 
-pub fn push_video_frame(
-    frame_bytes: Vec<u8>,
-    is_keyframe: bool,
-    pts: u64,
-    codec: u8,
+/// Sends one audio packet and records its ingress-to-send delay. Returns whether the
+/// packet went out; failures are reported to Dart without ending the session.
+async fn send_sender_audio(
+    sink: &StreamSink<UiEvent>,
+    sender: &mut SankakuSender,
+    stream_id: u32,
+    frame: QueuedAudioFrame,
+    dest: &str,
+    handshake_announced: &mut bool,
+    audio: &mut SenderAudioState,
+) -> bool {
+    let Some(frame) = check_sender_audio(sink, stream_id, frame, audio) else {
+        return false;
+    };
+    let QueuedAudioFrame {
+        payload,
+        pts,
+        codec,
+        frames_per_packet,
+        queued_at,
+    } = apply_audio_redundancy(&mut audio.redundancy, frame);
+    let audio_len = payload.len();
+    match sender
+        .send_audio_frame(stream_id, pts, codec, frames_per_packet, payload)
+        .await
+    {
+        Ok(_) => {
+            audio.send_delay.record(queued_at.elapsed());
+            println!(
+                "DEBUG: Sankaku sender sent AUDIO packet: stream_id={} bytes={} pts_us={} codec=0x{:02X} frames_per_packet={} dest={}",
+                stream_id, audio_len, pts, codec, frames_per_packet, dest
+            );
+            announce_sender_handshake_if_needed(sink, sender, dest, handshake_announced);
+            if let Some(bitrate_bps) = sender.take_bitrate_update_bps() {
+                sink_event(sink, UiEvent::BitrateChanged { bitrate_bps });
+            }
+            true
+        }
+        Err(error) => {
+            sink_event(
+                sink,
+                UiEvent::Error {
+                    msg: format!("audio send failed: {error}"),
+                },
+            );
+            false
+        }
+    }
+}
+
+/// Checks one queued audio packet before it is sent. Packets must use the
+/// negotiated codec; a `codec` of 0 stands for it. Opus packets are checked
+/// against their TOC and AAC-LC/PCM frames against the negotiated format, which
+/// also supplies `frames_per_packet` when the caller's value is missing or wrong.
+/// Returns `None` for packets that must not be sent, after reporting why.
+fn check_sender_audio(
+    sink: &StreamSink<UiEvent>,
+    stream_id: u32,
+    frame: QueuedAudioFrame,
+    audio: &mut SenderAudioState,
+) -> Option<QueuedAudioFrame> {
+    let QueuedAudioFrame {
+        mut payload,
+        pts,
+        mut codec,
+        mut frames_per_packet,
+        queued_at,
+    } = frame;
+    if payload.is_empty() {
+        return None;
+    }
+    if codec == 0 {
+        codec = audio.format.codec.id();
+    }
+    if codec == AUDIO_CODEC_DEBUG_TEXT && audio.legacy_debug_reports {
+        return Some(QueuedAudioFrame {
+            payload,
+            pts,
+            codec,
+            frames_per_packet,
+            queued_at,
+        });
+    }
+    if codec != audio.format.codec.id() {
+        sink_event(
+            sink,
+            UiEvent::FrameDrop {
+                stream_id,
+                reason: format!(
+                    "audio codec 0x{codec:02X} does not match the negotiated {}",
+                    audio.format.codec.name()
+                ),
+            },
+        );
+        return None;
+    }
+    if codec == AUDIO_CODEC_OPUS {
+        match audio
+            .inspection
+            .inspect(sink, stream_id, true, pts, &payload, frames_per_packet)
+        {
+            Ok(info) => frames_per_packet = info.samples_48k(),
+            Err(error) => {
+                sink_event(
+                    sink,
+                    UiEvent::FrameDrop {
+                        stream_id,
+                        reason: format!("malformed opus packet: {error}"),
+                    },
+                );
+                return None;
+            }
+        }
+    } else {
+        let checked = match audio.format.strip_adts(payload) {
+            Ok(stripped) => audio
+                .inspection
+                .check_frame(&audio.format, &stripped, frames_per_packet)
+                .map(|frames| (stripped, frames)),
+            Err(error) => {
+                audio.inspection.malformed = audio.inspection.malformed.saturating_add(1);
+                Err(error)
+            }
+        };
+        match checked {
+            Ok((stripped, frames)) => {
+                payload = stripped;
+                frames_per_packet = frames;
+            }
+            Err(error) => {
+                sink_event(
+                    sink,
+                    UiEvent::FrameDrop {
+                        stream_id,
+                        reason: format!("malformed {} frame: {error}", audio.format.codec.name()),
+                    },
+                );
+                return None;
+            }
+        }
+    }
+    Some(QueuedAudioFrame {
+        payload,
+        pts,
+        codec,
+        frames_per_packet,
+        queued_at,
+    })
+}
+
+/// Swaps an Opus packet for its redundant encoding when redundancy is enabled.
+fn apply_audio_redundancy(
+    encoder: &mut Option<AudioRedundancyEncoder>,
+    frame: QueuedAudioFrame,
+) -> QueuedAudioFrame {
+    match encoder {
+        Some(encoder) if frame.codec == AUDIO_CODEC_OPUS && !frame.payload.is_empty() => {
+            QueuedAudioFrame {
+                payload: encoder.wrap(frame.pts, frame.frames_per_packet, frame.payload),
+                codec: AUDIO_CODEC_OPUS_RED,
+                ..frame
+            }
+        }
+        _ => frame,
+    }
+}
+
+fn emit_send_delay_telemetry(sink: &StreamSink<UiEvent>, kind: &str, stats: &mut SendDelayStats) {
+    let Some(summary) = stats.take() else {
+        return;
+    };
+    for (suffix, value) in [
+        ("avg_us", summary.average.as_micros() as u64),
+        ("max_us", summary.max.as_micros() as u64),
+        ("samples", summary.samples),
+    ] {
+        sink_event(
+            sink,
+            UiEvent::Telemetry {
+                name: format!("send_delay.{kind}.{suffix}"),
+                value,
+            },
+        );
+    }
+}
+
+/// Queues one access unit for sending. `codec` is `VIDEO_CODEC_HEVC` (also used
+/// for 0), `VIDEO_CODEC_H264` or `VIDEO_CODEC_AV1`, and must match the codec
+/// negotiated for the session. `nal_length_size` declares how the caller framed
+/// its NAL units: 0 for Annex B start codes, or 1, 2 or 4 for hvcC/avcC-style
+/// length prefixes of that size. NAL-based frames are normalised to Annex B before
+/// sending; AV1 temporal units must use 0 and are sent as-is. Returns the sender's
+/// congestion state after queueing.
+pub fn push_video_frame(
+    frame_bytes: Vec<u8>,
+    is_keyframe: bool,
+    pts: u64,
+    codec: u8,
+    nal_length_size: u8,
+) -> anyhow::Result<CongestionState> {
+    let codec = if codec == 0 { VIDEO_CODEC_HEVC } else { codec };
+    let frame_len = frame_bytes.len();
+    println!(
+        "DEBUG: Rust received VIDEO frame from Dart: {} bytes (keyframe={}, pts_us={}, codec=0x{:02X}, nal_length_size={})",
+        frame_len, is_keyframe, pts, codec, nal_length_size
+    );
+    let video_codec = VideoCodec::from_id(codec)
+        .with_context(|| format!("unsupported video codec: 0x{codec:02X}"))?;
+    let framing = NalFraming::from_length_size(nal_length_size)
+        .with_context(|| format!("unsupported NAL length size: {nal_length_size}"))?;
+    let frame_bytes = if video_codec.uses_nal_units() {
+        convert_framing(frame_bytes, framing, NalFraming::AnnexB)
+            .with_context(|| format!("malformed {framing:?} video frame ({frame_len} bytes)"))?
+    } else if framing == NalFraming::AnnexB {
+        frame_bytes
+    } else {
+        bail!(
+            "{} frames do not use NAL length prefixes",
+            video_codec.name()
+        );
+    };
+    let ingress = active_video_ingress()?;
+    let keyframe = ingress
+        .keyframe_check
+        .lock()
+        .map_err(|_| anyhow!("failed to lock keyframe classification"))?
+        .resolve(&frame_bytes, codec, is_keyframe);
+    let queue = ingress.queue;
+    let frame_queued = match queue.push(QueuedFrame {
+        payload: frame_bytes,
+        keyframe,
+        pts,
+        codec,
+        queued_at: Instant::now(),
+    }) {
+        PushOutcome::Queued => true,
+        PushOutcome::Dropped => false,
+        PushOutcome::Closed => bail!("sender frame ingress queue is closed"),
+    };
+    Ok(CongestionState::new(queue.congestion(), frame_queued))
+}
+
+fn active_video_ingress() -> anyhow::Result<VideoIngress> {
+    let guard = video_ingress_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock video ingress slot"))?;
+    guard
+        .clone()
+        .context("sender is not active; call start_sankaku_sender first")
+}
+
+/// Congestion state for calls that did not complete a frame.
+fn current_video_congestion() -> CongestionState {
+    video_ingress_slot()
+        .lock()
+        .ok()
+        .and_then(|guard| guard.clone())
+        .map(|ingress| CongestionState::new(ingress.queue.congestion(), true))
+        .unwrap_or_default()
+}
+
+/// Feeds an arbitrary chunk of an Annex B HEVC elementary stream. Access units are
+/// reassembled across chunks and queued like `push_video_frame` once complete.
+/// Only HEVC streams can be split this way.
+/// When `pts` is `None`, pts are synthesised at the rate set by
+/// `configure_video_bytes_frame_rate`; a supplied pts applies to the access unit
+/// whose first byte is in this chunk, even if it completes on a later push.
+/// Fails without consuming the chunk while the sender is not active.
+pub fn push_video_bytes(
+    chunk: Vec<u8>,
+    pts: Option<u64>,
+    codec: u8,
+) -> anyhow::Result<CongestionState> {
+    let codec = if codec == 0 { VIDEO_CODEC_HEVC } else { codec };
+    if codec != VIDEO_CODEC_HEVC {
+        bail!("push_video_bytes only splits HEVC streams (codec=0x{codec:02X})");
+    }
+    // Fail before the splitter consumes any bytes, so the caller can retry the chunk.
+    active_video_ingress()?;
+    let stamped = {
+        let mut guard = video_bytes_ingress_slot()
+            .lock()
+            .map_err(|_| anyhow!("failed to lock video byte ingress"))?;
+        guard.push(&chunk, pts)
+    };
+    push_stamped_access_units(stamped, codec)
+}
+
+/// Queues split access units and merges their congestion states: the last
+/// snapshot wins, and `frame_queued` is false if any unit was dropped.
+fn push_stamped_access_units(
+    stamped: Vec<(AccessUnit, u64)>,
+    codec: u8,
+) -> anyhow::Result<CongestionState> {
+    let mut merged: Option<CongestionState> = None;
+    for (unit, pts) in stamped {
+        let state = push_video_frame(unit.data, unit.keyframe, pts, codec, 0)?;
+        let all_queued = merged.as_ref().is_none_or(|merged| merged.frame_queued);
+        merged = Some(CongestionState {
+            frame_queued: all_queued && state.frame_queued,
+            ..state
+        });
+    }
+    Ok(merged.unwrap_or_else(current_video_congestion))
+}
+
+/// Flushes the access unit still buffered by `push_video_bytes`, e.g. at end of file.
+pub fn flush_video_bytes(codec: u8) -> anyhow::Result<CongestionState> {
+    let codec = if codec == 0 { VIDEO_CODEC_HEVC } else { codec };
+    if codec != VIDEO_CODEC_HEVC {
+        bail!("flush_video_bytes only splits HEVC streams (codec=0x{codec:02X})");
+    }
+    active_video_ingress()?;
+    let stamped = {
+        let mut guard = video_bytes_ingress_slot()
+            .lock()
+            .map_err(|_| anyhow!("failed to lock video byte ingress"))?;
+        guard.flush()
+    };
+    push_stamped_access_units(stamped, codec)
+}
+
+/// Sets the frame rate used to synthesise pts for `push_video_bytes`.
+pub fn configure_video_bytes_frame_rate(
+    frame_rate_num: u32,
+    frame_rate_den: u32,
+) -> anyhow::Result<()> {
+    if frame_rate_num == 0 || frame_rate_den == 0 {
+        bail!("invalid frame rate {frame_rate_num}/{frame_rate_den}");
+    }
+    let mut guard = video_bytes_ingress_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock video byte ingress"))?;
+    guard.frame_rate = (frame_rate_num, frame_rate_den);
+    Ok(())
+}
+
+/// Queues one audio frame for the sender. `codec` 0 stands for the audio codec
+/// negotiated for the session.
+pub fn push_audio_frame(
+    frame_bytes: Vec<u8>,
+    pts: u64,
+    codec: u8,
+    frames_per_packet: u32,
+) -> anyhow::Result<()> {
+    let frame_len = frame_bytes.len();
+    println!(
+        "DEBUG: Rust received AUDIO frame from Dart: {} bytes (pts_us={}, codec=0x{:02X}, frames_per_packet={})",
+        frame_len, pts, codec, frames_per_packet
+    );
+    let tx = {
+        let guard = audio_frame_tx_slot()
+            .lock()
+            .map_err(|_| anyhow!("failed to lock audio frame sender slot"))?;
+        guard
+            .clone()
+            .context("sender is not active; call start_sankaku_sender first")?
+    };
+    tx.send(QueuedAudioFrame {
+        payload: frame_bytes,
+        pts,
+        codec,
+        frames_per_packet,
+        queued_at: Instant::now(),
+    })
+    .map_err(|_| anyhow!("sender audio ingress channel is closed"))?;
+    Ok(())
+}
+
+/// Bounds the sender's video queue to `capacity` frames and selects what happens
+/// when it is full. Keyframe-aware policies use the keyframe flag resolved by
+/// `configure_sender_keyframe_detection`. Applies to the next sender session.
+pub fn configure_sender_video_queue(capacity: u32, policy: VideoQueuePolicy) -> anyhow::Result<()> {
+    if capacity == 0 {
+        bail!("video queue capacity must be at least 1");
+    }
+    let mut guard = sender_video_queue_config_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock sender video queue config"))?;
+    *guard = (capacity, policy);
+    Ok(())
+}
+
+/// Sets the sender's default video latency budget, used by every stream without
+/// a budget of its own. A dequeued delta frame whose pts trails the newest pushed
+/// pts by more than `budget_ms` is discarded instead of sent, along with the delta
+/// frames that depend on it up to the next keyframe. Keyframes are always sent.
+/// Disabled by default; `enabled = false` turns it off again. Takes effect
+/// immediately, including for a running session.
+pub fn configure_sender_video_latency_budget(enabled: bool, budget_ms: u32) -> anyhow::Result<()> {
+    let mut guard = sender_latency_budgets_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock sender latency budgets"))?;
+    guard.default = enabled.then(|| Duration::from_millis(u64::from(budget_ms)));
+    Ok(())
+}
+
+/// Gives one stream of the running sender session its own latency budget,
+/// overriding the default from `configure_sender_video_latency_budget`;
+/// `enabled = false` disables discarding for that stream only. Stream ids are
+/// reported by the `stream_id` telemetry at session start, and the overrides
+/// are cleared when the next session starts.
+pub fn configure_sender_stream_latency_budget(
+    stream_id: u32,
+    enabled: bool,
+    budget_ms: u32,
+) -> anyhow::Result<()> {
+    let mut guard = sender_latency_budgets_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock sender latency budgets"))?;
+    guard.streams.insert(
+        stream_id,
+        enabled.then(|| Duration::from_millis(u64::from(budget_ms))),
+    );
+    Ok(())
+}
+
+/// Repeats the previous `depth` Opus packets inside each new audio payload (RFC 2198
+/// style) for subsequent `start_sankaku_sender` sessions, so the receiver can
+/// rebuild packets lost in between. Costs roughly `depth` times the audio bitrate.
+/// `0` disables it. Only used when the receiver negotiated redundant audio; older
+/// receivers get plain Opus.
+pub fn configure_sender_audio_redundancy(depth: u32) -> anyhow::Result<()> {
+    if depth as usize > MAX_REDUNDANCY_DEPTH {
+        bail!("audio redundancy depth must be at most {MAX_REDUNDANCY_DEPTH}");
+    }
+    SENDER_AUDIO_REDUNDANCY_DEPTH.store(depth, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Selects how subsequent `start_sankaku_sender` sessions derive keyframe flags.
+/// Defaults to [`KeyframeDetectionMode::CrossCheck`].
+pub fn configure_sender_keyframe_detection(mode: KeyframeDetectionMode) -> anyhow::Result<()> {
+    let mut guard = sender_keyframe_detection_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock sender keyframe detection mode"))?;
+    *guard = mode;
+    Ok(())
+}
+
+/// Enables the receiver jitter buffer for subsequent `start_sankaku_receiver` calls.
+/// Frames are reordered by pts and released after an adaptive playout delay bounded
+/// by `min_delay_ms..=max_delay_ms`. Passing `enabled = false` delivers frames as
+/// soon as they arrive.
+pub fn configure_receiver_jitter_buffer(
+    enabled: bool,
+    min_delay_ms: u32,
+    max_delay_ms: u32,
+) -> anyhow::Result<()> {
+    if enabled && min_delay_ms > max_delay_ms {
+        bail!("invalid jitter buffer delay range: min={min_delay_ms}ms max={max_delay_ms}ms");
+    }
+    let mut guard = receiver_jitter_buffer_config_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock receiver jitter buffer config"))?;
+    *guard = enabled.then(|| JitterBufferConfig {
+        min_delay: Duration::from_millis(u64::from(min_delay_ms)),
+        max_delay: Duration::from_millis(u64::from(max_delay_ms)),
+    });
+    Ok(())
+}
+
+/// Enables audio/video alignment for subsequent `start_sankaku_receiver` calls. The
+/// stream whose frames arrive ahead of the other is held back so equal pts are
+/// released together. `manual_correction_ms` is applied on top and may be changed
+/// while the receiver runs via `set_receiver_lip_sync_correction`; positive values
+/// delay audio relative to video.
+pub fn configure_receiver_lip_sync(enabled: bool, manual_correction_ms: i32) -> anyhow::Result<()> {
+    RECEIVER_LIP_SYNC_ENABLED.store(enabled, Ordering::Relaxed);
+    set_receiver_lip_sync_correction(manual_correction_ms)
+}
+
+/// Makes subsequent `start_sankaku_receiver` sessions fill audio gaps with
+/// `AudioConcealment` placeholder events, one per missing packet and up to 200 ms
+/// (at least one packet), so the player can run packet loss concealment (e.g.
+/// decode with no input) and keep its timeline. `AudioGap` events are emitted
+/// either way.
+pub fn configure_receiver_audio_concealment(enabled: bool) -> anyhow::Result<()> {
+    RECEIVER_AUDIO_CONCEALMENT_ENABLED.store(enabled, Ordering::Relaxed);
+    Ok(())
+}
+
+#[cfg(feature = "hevc-decode")]
+fn software_video_decode_slot() -> &'static Mutex<Option<SoftwareVideoOutput>> {
+    SOFTWARE_VIDEO_DECODE.get_or_init(|| Mutex::new(None))
+}
+
+/// Makes `start_sankaku_receiver` sessions decode received HEVC in software and
+/// send the pictures as `DecodedVideoFrame` events on `sink`, for desktop targets
+/// without a hardware decoder. Takes effect in running sessions from the next IRAP.
+/// Pictures larger than `max_width` x `max_height` are downscaled (zero means
+/// unbounded). Access units that waited too long for the decoder, or pictures that
+/// finish decoding too long after they arrived, are dropped and counted in
+/// `video.decode.dropped_late`; after a drop, decoding resumes at the next IRAP.
+/// `VideoFrameReceived` events are still emitted. Requires the `hevc-decode`
+/// cargo feature.
+pub fn start_software_video_decode(
+    sink: StreamSink<UiEvent>,
+    format: DecodedVideoFormat,
+    max_width: u32,
+    max_height: u32,
+) -> anyhow::Result<()> {
+    #[cfg(feature = "hevc-decode")]
+    {
+        let mut guard = software_video_decode_slot()
+            .lock()
+            .map_err(|_| anyhow!("failed to lock software video decode output"))?;
+        *guard = Some(SoftwareVideoOutput {
+            sink,
+            target: DecodeTarget {
+                pixels: match format {
+                    DecodedVideoFormat::Rgba => OutputPixels::Rgba,
+                    DecodedVideoFormat::I420 => OutputPixels::I420,
+                },
+                max_width,
+                max_height,
+            },
+            generation: SOFTWARE_VIDEO_DECODE_GENERATION.fetch_add(1, Ordering::Relaxed),
+        });
+        Ok(())
+    }
+    #[cfg(not(feature = "hevc-decode"))]
+    {
+        let _ = (sink, format, max_width, max_height);
+        bail!("software video decoding requires building with the hevc-decode feature");
+    }
+}
+
+/// Stops software decoding, including in running receiver sessions.
+pub fn stop_software_video_decode() -> anyhow::Result<()> {
+    #[cfg(feature = "hevc-decode")]
+    {
+        let mut guard = software_video_decode_slot()
+            .lock()
+            .map_err(|_| anyhow!("failed to lock software video decode output"))?;
+        *guard = None;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "opus-decode")]
+fn receiver_opus_decode_slot() -> &'static Mutex<Option<PcmOutput>> {
+    RECEIVER_OPUS_DECODE.get_or_init(|| Mutex::new(None))
+}
+
+/// Makes subsequent `start_sankaku_receiver` sessions decode Opus in Rust, for
+/// targets without a platform audio player. Decoded 48 kHz stereo audio arrives as
+/// `PcmAudioReceived` events, or is written as raw s16le to `output_path` (a file
+/// or a FIFO whose reader is already attached) when one is given. Writes happen on
+/// a separate thread; audio decoded while about a second is still unwritten is
+/// dropped and counted as `audio.pcm.output_dropped`. Gaps are always filled by
+/// the decoder's loss concealment, using in-band FEC where the sender provides it.
+/// Requires the `opus-decode` cargo feature.
+pub fn configure_receiver_opus_decode(
+    enabled: bool,
+    output_path: Option<String>,
+) -> anyhow::Result<()> {
+    #[cfg(feature = "opus-decode")]
+    {
+        let mut guard = receiver_opus_decode_slot()
+            .lock()
+            .map_err(|_| anyhow!("failed to lock receiver opus decode config"))?;
+        *guard = enabled.then(|| match output_path {
+            Some(path) => PcmOutput::File(path.into()),
+            None => PcmOutput::Dart,
+        });
+        Ok(())
+    }
+    #[cfg(not(feature = "opus-decode"))]
+    {
+        let _ = output_path;
+        if enabled {
+            bail!("opus decoding requires building with the opus-decode feature");
+        }
+        Ok(())
+    }
+}
+
+pub fn set_receiver_lip_sync_correction(manual_correction_ms: i32) -> anyhow::Result<()> {
+    RECEIVER_LIP_SYNC_CORRECTION_MS.store(manual_correction_ms, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Selects the NAL framing of `VideoFrameReceived` payloads: 0 for Annex B start
+/// codes (the default), or 1, 2 or 4 for length prefixes of that size. Takes effect
+/// immediately, including on a running receiver.
+pub fn configure_receiver_video_format(nal_length_size: u8) -> anyhow::Result<()> {
+    let framing = NalFraming::from_length_size(nal_length_size)
+        .with_context(|| format!("unsupported NAL length size: {nal_length_size}"))?;
+    let mut guard = receiver_video_framing_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock receiver video format"))?;
+    *guard = framing;
+    Ok(())
+}
+
+/// Returns the most recent decoder configuration seen on a receiver stream, so a
+/// decoder can be re-primed without waiting for the next keyframe: VPS/SPS/PPS
+/// (HEVC) or SPS/PPS (H.264) as an Annex B blob, or the AV1 sequence header OBU.
+/// Returns `None` until a complete set has been received.
+pub fn get_cached_parameter_sets(stream_id: u32) -> anyhow::Result<Option<Vec<u8>>> {
+    let guard = receiver_parameter_sets_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock receiver parameter set cache"))?;
+    Ok(guard
+        .get(&stream_id)
+        .filter(|cache| cache.is_complete())
+        .map(VideoParameterSetCache::to_bytes))
+}
+
+/// Audio codecs the sender offers, with the configuration sent alongside AAC-LC
+/// and PCM.
+struct SenderAudioCodecs {
+    codecs: Vec<u8>,
+    aac: Option<AudioFormat>,
+    pcm: Option<AudioFormat>,
+}
+
+impl SenderAudioCodecs {
+    fn offers(&self) -> anyhow::Result<Vec<AudioCodecOffer>> {
+        self.codecs
+            .iter()
+            .map(|&codec| {
+                let format = match AudioCodec::from_id(codec) {
+                    Some(AudioCodec::AacLc) => self.aac.as_ref().context(
+                        "aac_lc is offered but configure_sender_aac_config was not called",
+                    )?,
+                    Some(AudioCodec::PcmS16) => self.pcm.as_ref().context(
+                        "pcm_s16le is offered but configure_sender_pcm_format was not called",
+                    )?,
+                    _ => {
+                        return Ok(AudioCodecOffer {
+                            codec,
+                            config: Vec::new(),
+                        })
+                    }
+                };
+                Ok(AudioCodecOffer {
+                    codec,
+                    config: format.config.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+fn sender_video_codecs_slot() -> &'static Mutex<Vec<u8>> {
+    SENDER_VIDEO_CODECS.get_or_init(|| Mutex::new(vec![VIDEO_CODEC_HEVC]))
+}
+
+fn receiver_video_codecs_slot() -> &'static Mutex<Vec<u8>> {
+    RECEIVER_VIDEO_CODECS.get_or_init(|| Mutex::new(vec![VIDEO_CODEC_HEVC]))
+}
+
+fn validate_video_codecs(codecs: &[u8]) -> anyhow::Result<()> {
+    if codecs.is_empty() {
+        bail!("at least one video codec is required");
+    }
+    for &codec in codecs {
+        if VideoCodec::from_id(codec).is_none() {
+            bail!("unsupported video codec: 0x{codec:02X}");
+        }
+    }
+    Ok(())
+}
+
+/// Sets the video codecs the sender offers at session start, most preferred
+/// first. Ids are `VIDEO_CODEC_HEVC`, `VIDEO_CODEC_H264` (0xC0) and
+/// `VIDEO_CODEC_AV1` (0xC1). Defaults to HEVC only; applies to the next session.
+pub fn configure_sender_video_codecs(codecs: Vec<u8>) -> anyhow::Result<()> {
+    validate_video_codecs(&codecs)?;
+    let mut guard = sender_video_codecs_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock sender video codecs"))?;
+    *guard = codecs;
+    Ok(())
+}
+
+/// Sets the video codecs the receiver can decode. An offer sharing none of them is
+/// declined. Defaults to HEVC only; applies to the next session.
+pub fn configure_receiver_video_codecs(codecs: Vec<u8>) -> anyhow::Result<()> {
+    validate_video_codecs(&codecs)?;
+    let mut guard = receiver_video_codecs_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock receiver video codecs"))?;
+    *guard = codecs;
+    Ok(())
+}
+
+fn sender_audio_codecs_slot() -> &'static Mutex<SenderAudioCodecs> {
+    SENDER_AUDIO_CODECS.get_or_init(|| {
+        Mutex::new(SenderAudioCodecs {
+            codecs: vec![AUDIO_CODEC_OPUS],
+            aac: None,
+            pcm: None,
+        })
+    })
+}
+
+fn receiver_audio_codecs_slot() -> &'static Mutex<Vec<u8>> {
+    RECEIVER_AUDIO_CODECS.get_or_init(|| Mutex::new(vec![AUDIO_CODEC_OPUS]))
+}
+
+fn validate_audio_codecs(codecs: &[u8]) -> anyhow::Result<()> {
+    if codecs.is_empty() {
+        bail!("at least one audio codec is required");
+    }
+    for &codec in codecs {
+        if AudioCodec::from_id(codec).is_none() {
+            bail!("unsupported audio codec: 0x{codec:02X}");
+        }
+    }
+    Ok(())
+}
+
+/// Sets the audio codecs the sender offers at session start, most preferred
+/// first. Ids are `AUDIO_CODEC_OPUS`, `AUDIO_CODEC_AAC_LC` (0xC0) and
+/// `AUDIO_CODEC_PCM_S16LE` (0xC1); AAC-LC and PCM also need
+/// `configure_sender_aac_config` or `configure_sender_pcm_format`. Defaults to
+/// Opus only; applies to the next session.
+pub fn configure_sender_audio_codecs(codecs: Vec<u8>) -> anyhow::Result<()> {
+    validate_audio_codecs(&codecs)?;
+    let mut guard = sender_audio_codecs_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock sender audio codecs"))?;
+    guard.codecs = codecs;
+    Ok(())
+}
+
+/// Sets the AAC AudioSpecificConfig sent with an AAC-LC offer, as produced by the
+/// platform encoder. Only the AAC-LC object type is accepted, at one of the
+/// standard sampling frequencies; explicit frequencies are rejected.
+pub fn configure_sender_aac_config(audio_specific_config: Vec<u8>) -> anyhow::Result<()> {
+    let format = AudioFormat::aac_lc(audio_specific_config)?;
+    let mut guard = sender_audio_codecs_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock sender audio codecs"))?;
+    guard.aac = Some(format);
+    Ok(())
+}
+
+/// Sets the sample rate and channel count of interleaved 16-bit little-endian PCM
+/// sent with a PCM offer.
+pub fn configure_sender_pcm_format(sample_rate: u32, channels: u32) -> anyhow::Result<()> {
+    let channels = u8::try_from(channels)
+        .with_context(|| format!("unsupported PCM channel count: {channels}"))?;
+    let format = AudioFormat::pcm_s16(sample_rate, channels)?;
+    let mut guard = sender_audio_codecs_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock sender audio codecs"))?;
+    guard.pcm = Some(format);
+    Ok(())
+}
+
+/// Sets the audio codecs the receiver can decode. An offer sharing none of them is
+/// declined. Defaults to Opus only; applies to the next session.
+pub fn configure_receiver_audio_codecs(codecs: Vec<u8>) -> anyhow::Result<()> {
+    validate_audio_codecs(&codecs)?;
+    let mut guard = receiver_audio_codecs_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock receiver audio codecs"))?;
+    *guard = codecs;
+    Ok(())
+}
+
+fn audio_codec_list(codecs: &[u8]) -> String {
+    codecs
+        .iter()
+        .map(|&codec| {
+            AudioCodec::from_id(codec)
+                .map(|codec| codec.name().to_string())
+                .unwrap_or_else(|| format!("0x{codec:02X}"))
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn emit_audio_codec_negotiated(
+    sink: &StreamSink<UiEvent>,
+    format: &AudioFormat,
+    peer_negotiated: bool,
+) {
+    sink_event(
+        sink,
+        UiEvent::AudioCodecNegotiated {
+            codec: format.codec.id(),
+            codec_name: format.codec.name().to_string(),
+            sample_rate: format.sample_rate,
+            channels: u32::from(format.channels),
+            codec_config: format.config.clone(),
+            peer_negotiated,
+        },
+    );
+}
+
+fn emit_codec_declined(sink: &StreamSink<UiEvent>, detail: &str) {
+    sink_event(
+        sink,
+        UiEvent::ConnectionState {
+            state: "codec_declined".to_string(),
+            detail: detail.to_string(),
+        },
+    );
+}
+
+fn codec_list(codecs: &[u8]) -> String {
+    codecs
+        .iter()
+        .map(|&codec| {
+            VideoCodec::from_id(codec)
+                .map(|codec| codec.name().to_string())
+                .unwrap_or_else(|| format!("0x{codec:02X}"))
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn emit_codec_negotiated(sink: &StreamSink<UiEvent>, codec: VideoCodec, peer_negotiated: bool) {
+    sink_event(
+        sink,
+        UiEvent::CodecNegotiated {
+            codec: codec.id(),
+            codec_name: codec.name().to_string(),
+            peer_negotiated,
+        },
+    );
+}
+
+/// Messages on the control stream, a reliable channel both peers can write to
+/// once negotiation shows they support it. See `send_control_message`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ControlMessage {
+    /// Asks the sender's encoder for a keyframe on `stream_id`. The sender's app
+    /// receives it as `ControlMessageReceived` and forwards it to its encoder.
+    KeyframeRequest { stream_id: u32 },
+    /// A receiver reports it as `UiEvent::PeerMediaState`, with `video_muted` as
+    /// the camera being off; prefer `announce_media_state`.
+    MuteState {
+        audio_muted: bool,
+        video_muted: bool,
+    },
+    /// Sent before stopping a session so the peer can show why it ended.
+    Hangup { reason: String },
+    /// Codec ids and the largest picture the sending side can handle; zero
+    /// dimensions mean no limit.
+    Capabilities {
+        video_codecs: Vec<u8>,
+        audio_codecs: Vec<u8>,
+        max_width: u32,
+        max_height: u32,
+    },
+    /// Sent by the receiver every second for each video stream. Counters are
+    /// cumulative for the session.
+    ReceiverReport {
+        stream_id: u32,
+        highest_frame_index: u64,
+        lost_frames: u64,
+        late_frames: u64,
+        awaiting_keyframe: bool,
+        jitter_us: u32,
+    },
+    /// Application-defined message; `kind` lets the app tell its messages apart.
+    Custom { kind: String, payload: Vec<u8> },
+}
+
+impl From<control::ControlMessage> for UiEvent {
+    fn from(message: control::ControlMessage) -> Self {
+        let message = match message {
+            control::ControlMessage::KeyframeRequest { stream_id } => {
+                ControlMessage::KeyframeRequest { stream_id }
+            }
+            control::ControlMessage::MuteState {
+                audio_muted,
+                video_muted,
+            } => ControlMessage::MuteState {
+                audio_muted,
+                video_muted,
+            },
+            control::ControlMessage::Hangup { reason } => ControlMessage::Hangup { reason },
+            control::ControlMessage::Capabilities {
+                video_codecs,
+                audio_codecs,
+                max_width,
+                max_height,
+            } => ControlMessage::Capabilities {
+                video_codecs,
+                audio_codecs,
+                max_width,
+                max_height,
+            },
+            control::ControlMessage::ReceiverReport(report) => ControlMessage::ReceiverReport {
+                stream_id: report.stream_id,
+                highest_frame_index: report.highest_frame_index,
+                lost_frames: report.lost_frames,
+                late_frames: report.late_frames,
+                awaiting_keyframe: report.awaiting_keyframe,
+                jitter_us: report.jitter_us,
+            },
+            control::ControlMessage::Custom { kind, payload } => {
+                ControlMessage::Custom { kind, payload }
+            }
+            control::ControlMessage::Data(message) => {
+                return UiEvent::DataReceived {
+                    channel: message.channel,
+                    data: message.payload,
+                    reliable: true,
+                };
+            }
+            // Routed to the session's `FileTransfers` before conversion.
+            control::ControlMessage::File(_) => {
+                return UiEvent::Log {
+                    msg: "control stream: unhandled file transfer message".to_string(),
+                };
+            }
+            // Saved by `receive_debug_report`.
+            control::ControlMessage::DebugReport { name, .. } => {
+                return UiEvent::Log {
+                    msg: format!("control stream: unhandled debug report {name}"),
+                };
+            }
+            // Normally answered or passed on by `handle_remote_command`, which
+            // also applies the policy.
+            control::ControlMessage::Command {
+                command_id,
+                command,
+            } => {
+                return match remote_command_from_control(command) {
+                    Some(command) => UiEvent::RemoteCommand {
+                        command_id,
+                        command,
+                    },
+                    None => UiEvent::Log {
+                        msg: format!("control stream: unknown remote command {command_id}"),
+                    },
+                };
+            }
+            control::ControlMessage::CommandReply {
+                command_id,
+                status,
+                detail,
+            } => {
+                return UiEvent::RemoteCommandReply {
+                    command_id,
+                    status: status.into(),
+                    detail,
+                };
+            }
+            control::ControlMessage::MediaState(state) => {
+                return UiEvent::PeerMediaState {
+                    microphone_muted: state.microphone_muted,
+                    camera_off: state.camera_off,
+                    audio_only: state.audio_only,
+                    screen_share: state.screen_share,
+                };
+            }
+        };
+        UiEvent::ControlMessageReceived { message }
+    }
+}
+
+impl From<ControlMessage> for control::ControlMessage {
+    fn from(message: ControlMessage) -> Self {
+        match message {
+            ControlMessage::KeyframeRequest { stream_id } => {
+                control::ControlMessage::KeyframeRequest { stream_id }
+            }
+            ControlMessage::MuteState {
+                audio_muted,
+                video_muted,
+            } => control::ControlMessage::MuteState {
+                audio_muted,
+                video_muted,
+            },
+            ControlMessage::Hangup { reason } => control::ControlMessage::Hangup { reason },
+            ControlMessage::Capabilities {
+                video_codecs,
+                audio_codecs,
+                max_width,
+                max_height,
+            } => control::ControlMessage::Capabilities {
+                video_codecs,
+                audio_codecs,
+                max_width,
+                max_height,
+            },
+            ControlMessage::ReceiverReport {
+                stream_id,
+                highest_frame_index,
+                lost_frames,
+                late_frames,
+                awaiting_keyframe,
+                jitter_us,
+            } => control::ControlMessage::ReceiverReport(ReceiverReport {
+                stream_id,
+                highest_frame_index,
+                lost_frames,
+                late_frames,
+                awaiting_keyframe,
+                jitter_us,
+            }),
+            ControlMessage::Custom { kind, payload } => {
+                control::ControlMessage::Custom { kind, payload }
+            }
+        }
+    }
+}
+
+fn sender_control_slot() -> &'static Mutex<Option<ControlSender>> {
+    SENDER_CONTROL.get_or_init(|| Mutex::new(None))
+}
+
+fn receiver_control_slot() -> &'static Mutex<Option<ControlSender>> {
+    RECEIVER_CONTROL.get_or_init(|| Mutex::new(None))
+}
+
+fn sender_datagrams_slot() -> &'static Mutex<Option<quinn::Connection>> {
+    SENDER_DATAGRAMS.get_or_init(|| Mutex::new(None))
+}
+
+fn receiver_datagrams_slot() -> &'static Mutex<Option<quinn::Connection>> {
+    RECEIVER_DATAGRAMS.get_or_init(|| Mutex::new(None))
+}
+
+fn set_session_slot<T>(slot: &Mutex<Option<T>>, value: Option<T>) -> anyhow::Result<()> {
+    let mut guard = slot
+        .lock()
+        .map_err(|_| anyhow!("failed to lock session channel slot"))?;
+    *guard = value;
+    Ok(())
+}
+
+/// Opens (sender) or accepts (receiver) the control stream when negotiation
+/// agreed on it. A peer that fails to set it up leaves the session running
+/// without one.
+async fn start_control_channel(
+    sink: &StreamSink<UiEvent>,
+    connection: &quinn::Connection,
+    agreed: bool,
+    opener: bool,
+) -> Option<ControlChannel> {
+    if !agreed {
+        sink_event(
+            sink,
+            UiEvent::ConnectionState {
+                state: "control_unavailable".to_string(),
+                detail: "peer does not support the control stream".to_string(),
+            },
+        );
+        return None;
+    }
+    let result = if opener {
+        ControlChannel::open(connection).await
+    } else {
+        ControlChannel::accept(connection).await
+    };
+    match result {
+        Ok(channel) => {
+            sink_event(
+                sink,
+                UiEvent::ConnectionState {
+                    state: "control_ready".to_string(),
+                    detail: "control stream open".to_string(),
+                },
+            );
+            Some(channel)
+        }
+        Err(error) => {
+            sink_event(
+                sink,
+                UiEvent::ConnectionState {
+                    state: "control_unavailable".to_string(),
+                    detail: format!("{error:#}"),
+                },
+            );
+            None
+        }
+    }
+}
+
+/// Resolves with the next inbound control message, or never when there is no
+/// control stream.
+async fn next_control_message(
+    channel: &mut Option<ControlChannel>,
+) -> Option<anyhow::Result<control::ControlMessage>> {
+    match channel {
+        Some(channel) => channel.incoming.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Turns a `MuteState` from the sender into the `MediaState` it implies, so the
+/// receiver's pause handling and the app follow one state.
+fn fold_mute_state(
+    inbound: Option<anyhow::Result<control::ControlMessage>>,
+    peer_media_state: MediaState,
+) -> Option<anyhow::Result<control::ControlMessage>> {
+    match inbound {
+        Some(Ok(control::ControlMessage::MuteState {
+            audio_muted,
+            video_muted,
+        })) => Some(Ok(control::ControlMessage::MediaState(
+            peer_media_state.with_mute(audio_muted, video_muted),
+        ))),
+        inbound => inbound,
+    }
+}
+
+/// Surfaces one inbound control message. Returns false once the stream has
+/// ended, after which it should no longer be polled. Only the sender passes
+/// `takes_commands`.
+fn handle_control_message(
+    sink: &StreamSink<UiEvent>,
+    inbound: Option<anyhow::Result<control::ControlMessage>>,
+    control: Option<&ControlChannel>,
+    takes_commands: bool,
+    file_transfers: &mut Option<FileTransfers>,
+) -> bool {
+    match inbound {
+        Some(Ok(control::ControlMessage::Command {
+            command_id,
+            command,
+        })) => {
+            if let Some(control) = control {
+                handle_remote_command(sink, &control.sender, takes_commands, command_id, command);
+            }
+            true
+        }
+        Some(Ok(control::ControlMessage::DebugReport { name, report })) => {
+            receive_debug_report(sink, name, report);
+            true
+        }
+        Some(Ok(message @ control::ControlMessage::CommandReply { command_id, .. })) => {
+            if settle_issued_command(command_id) {
+                sink_event(sink, message.into());
+            } else {
+                sink_event(
+                    sink,
+                    UiEvent::Log {
+                        msg: format!(
+                            "control stream: late or unknown reply to command {command_id}"
+                        ),
+                    },
+                );
+            }
+            true
+        }
+        Some(Ok(control::ControlMessage::File(message))) if file_transfers.is_some() => {
+            if let Some(transfers) = file_transfers.as_mut() {
+                transfers.handle_peer(message);
+            }
+            true
+        }
+        Some(Ok(message)) => {
+            sink_event(sink, message.into());
+            true
+        }
+        Some(Err(error)) => {
+            sink_event(
+                sink,
+                UiEvent::Log {
+                    msg: format!("control stream: {error:#}"),
+                },
+            );
+            true
+        }
+        None => {
+            sink_event(
+                sink,
+                UiEvent::ConnectionState {
+                    state: "control_closed".to_string(),
+                    detail: "peer closed the control stream".to_string(),
+                },
+            );
+            fail_issued_commands(sink, "control stream closed before the sender replied");
+            false
+        }
+    }
+}
+
+/// Sets up the data connection when negotiation agreed on datagrams. Without it
+/// the session runs on, and unreliable data channels are unavailable.
+async fn start_datagram_channel(
+    sink: &StreamSink<UiEvent>,
+    agreed: bool,
+    setup: impl std::future::Future<Output = anyhow::Result<DatagramChannel>>,
+) -> Option<DatagramChannel> {
+    if !agreed {
+        sink_event(
+            sink,
+            UiEvent::ConnectionState {
+                state: "datagrams_unavailable".to_string(),
+                detail: "peer does not support data datagrams".to_string(),
+            },
+        );
+        return None;
+    }
+    match setup.await {
+        Ok(channel) => {
+            sink_event(
+                sink,
+                UiEvent::ConnectionState {
+                    state: "datagrams_ready".to_string(),
+                    detail: "data connection open".to_string(),
+                },
+            );
+            Some(channel)
+        }
+        Err(error) => {
+            sink_event(
+                sink,
+                UiEvent::ConnectionState {
+                    state: "datagrams_unavailable".to_string(),
+                    detail: format!("{error:#}"),
+                },
+            );
+            None
+        }
+    }
+}
+
+/// Resolves with the next inbound data datagram, or never when datagrams were not
+/// negotiated.
+async fn next_data_datagram(
+    datagrams: &mut Option<DatagramChannel>,
+) -> Option<anyhow::Result<DataMessage>> {
+    match datagrams {
+        Some(datagrams) => datagrams.incoming.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Resolves with the next file stream the peer opened, or never without file
+/// transfers.
+async fn next_file_stream(
+    transfers: &mut Option<FileTransfers>,
+) -> Option<(u32, quinn::RecvStream)> {
+    match transfers {
+        Some(transfers) => transfers.streams.incoming.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
+fn handle_data_datagram(sink: &StreamSink<UiEvent>, inbound: anyhow::Result<DataMessage>) {
+    match inbound {
+        Ok(message) => sink_event(
+            sink,
+            UiEvent::DataReceived {
+                channel: message.channel,
+                data: message.payload,
+                reliable: false,
+            },
+        ),
+        Err(error) => sink_event(
+            sink,
+            UiEvent::Log {
+                msg: format!("data channel: {error:#}"),
+            },
+        ),
+    }
+}
+
+fn send_receiver_reports(
+    control: &ControlSender,
+    trackers: &BTreeMap<u32, FrameSequenceTracker>,
+    video_jitter: &Option<JitterBuffer<UiEvent>>,
+) {
+    let jitter_us = video_jitter
+        .as_ref()
+        .map(|buffer| buffer.jitter().as_micros().min(u128::from(u32::MAX)) as u32)
+        .unwrap_or(0);
+    for (&stream_id, tracker) in trackers {
+        let counters = tracker.counters();
+        let report = ReceiverReport {
+            stream_id,
+            highest_frame_index: tracker.highest_index().unwrap_or(0),
+            lost_frames: counters
+                .lost_keyframes
+                .saturating_add(counters.lost_delta_frames),
+            late_frames: counters
+                .late_keyframes
+                .saturating_add(counters.late_delta_frames),
+            awaiting_keyframe: tracker.awaiting_keyframe(),
+            jitter_us,
+        };
+        let _ = control.send(&control::ControlMessage::ReceiverReport(report));
+    }
+}
+
+/// Sends `message` to the peer on the control stream of the running sender or
+/// receiver session (both, if both are running). Fails when no running session
+/// has a control stream, e.g. because the peer predates it.
+pub fn send_control_message(message: ControlMessage) -> anyhow::Result<()> {
+    let message = control::ControlMessage::from(message);
+    let mut delivered = false;
+    for slot in [sender_control_slot(), receiver_control_slot()] {
+        let control = slot
+            .lock()
+            .map_err(|_| anyhow!("failed to lock control stream slot"))?
+            .clone();
+        if let Some(control) = control {
+            control.send(&message)?;
+            delivered = true;
+        }
+    }
+    if !delivered {
+        bail!("no running session has a control stream");
+    }
+    Ok(())
+}
+
+/// Sends a diagnostics report to the peer on the control stream of the running
+/// session (the sender's, if both are running). A sender whose receiver predates
+/// the control stream sends it on the audio stream in the older packet format
+/// instead. The peer saves it under `remote_reports` and logs
+/// `[RemoteReport] saved file path=...`. Reports larger than a control message keep
+/// their last 64 KiB or so, the most recent lines.
+pub fn send_debug_report(name: String, report: Vec<u8>) -> anyhow::Result<()> {
+    let limit = control::MAX_CONTROL_MESSAGE_BYTES.saturating_sub(2 + name.len());
+    let report = if report.len() > limit {
+        report[report.len() - limit..].to_vec()
+    } else {
+        report
+    };
+    for slot in [sender_control_slot(), receiver_control_slot()] {
+        let control = slot
+            .lock()
+            .map_err(|_| anyhow!("failed to lock control stream slot"))?
+            .clone();
+        if let Some(control) = control {
+            return control.send(&control::ControlMessage::DebugReport { name, report });
+        }
+    }
+    if SENDER_LEGACY_DEBUG_REPORTS.load(Ordering::Relaxed) {
+        return send_legacy_debug_report(&name, &report);
+    }
+    bail!("no running session has a control stream")
+}
+
+/// Queues a debug report as `AUDIO_CODEC_DEBUG_TEXT` frames on the sender's audio
+/// stream, stamped with consecutive pts from the current time.
+fn send_legacy_debug_report(name: &str, report: &[u8]) -> anyhow::Result<()> {
+    let tx = audio_frame_tx_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock audio frame sender slot"))?
+        .clone()
+        .context("sender is not active")?;
+    let now_us = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_micros() as u64;
+    let packets = debug_report::encode_legacy_report(now_us as u32, name, report)?;
+    for (pts, payload) in (now_us..).zip(packets) {
+        tx.send(QueuedAudioFrame {
+            payload,
+            pts,
+            codec: AUDIO_CODEC_DEBUG_TEXT,
+            frames_per_packet: 0,
+            queued_at: Instant::now(),
+        })
+        .map_err(|_| anyhow!("sender audio ingress channel is closed"))?;
+    }
+    Ok(())
+}
+
+/// How messages on a data channel are delivered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataChannelDelivery {
+    /// Delivered in order and retransmitted on loss, on the control stream. Up to
+    /// 64 KiB per message.
+    ReliableOrdered,
+    /// Sent once as a QUIC datagram and possibly lost. Must fit in one datagram,
+    /// typically a little over 1100 bytes including the label.
+    Unreliable,
+}
+
+fn data_channels_slot() -> &'static Mutex<BTreeMap<String, DataChannelDelivery>> {
+    DATA_CHANNELS.get_or_init(|| Mutex::new(BTreeMap::new()))
+}
+
+/// Opens a named data channel for `send_data`. Channels stay open across sessions
+/// until closed; reopening one with a different delivery mode is an error.
+pub fn open_data_channel(label: String, delivery: DataChannelDelivery) -> anyhow::Result<()> {
+    data_channel::validate_label(&label)?;
+    let mut guard = data_channels_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock data channels"))?;
+    match guard.get(&label) {
+        Some(&open) if open != delivery => {
+            bail!("data channel {label:?} is already open as {open:?}")
+        }
+        _ => {
+            guard.insert(label, delivery);
+        }
+    }
+    Ok(())
+}
+
+pub fn close_data_channel(label: String) -> anyhow::Result<()> {
+    let mut guard = data_channels_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock data channels"))?;
+    guard.remove(&label);
+    Ok(())
+}
+
+/// Sends `data` on an open channel to the peer of the running sender or receiver
+/// session (both, if both are running). The peer receives it as
+/// `UiEvent::DataReceived`.
+pub fn send_data(channel: String, data: Vec<u8>) -> anyhow::Result<()> {
+    let delivery = data_channels_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock data channels"))?
+        .get(&channel)
+        .copied()
+        .with_context(|| format!("data channel {channel:?} is not open"))?;
+    let message = DataMessage {
+        channel,
+        payload: data,
+    };
+    let mut delivered = false;
+    match delivery {
+        DataChannelDelivery::ReliableOrdered => {
+            for slot in [sender_control_slot(), receiver_control_slot()] {
+                let control = slot
+                    .lock()
+                    .map_err(|_| anyhow!("failed to lock control stream slot"))?
+                    .clone();
+                if let Some(control) = control {
+                    control.send(&control::ControlMessage::Data(message.clone()))?;
+                    delivered = true;
+                }
+            }
+        }
+        DataChannelDelivery::Unreliable => {
+            for slot in [sender_datagrams_slot(), receiver_datagrams_slot()] {
+                let connection = slot
+                    .lock()
+                    .map_err(|_| anyhow!("failed to lock datagram slot"))?
+                    .clone();
+                if let Some(connection) = connection {
+                    data_channel::send_datagram(&connection, &message)?;
+                    delivered = true;
+                }
+            }
+        }
+    }
+    if !delivered {
+        bail!("no running session can carry {delivery:?} data");
+    }
+    Ok(())
+}
+
+/// How a file transfer ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileTransferOutcome {
+    /// Incoming: saved after the checksum matched. Outgoing: the peer confirmed it.
+    Completed,
+    /// The receiving side declined the offer.
+    Rejected,
+    /// Cancelled locally or by the peer.
+    Cancelled,
+    Failed,
+}
+
+const FILE_PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Clone)]
+struct FileTransferConfig {
+    receive_dir: Option<PathBuf>,
+    max_bytes: u64,
+}
+
+fn file_transfer_config_slot() -> &'static Mutex<FileTransferConfig> {
+    FILE_TRANSFER_CONFIG.get_or_init(|| {
+        Mutex::new(FileTransferConfig {
+            receive_dir: None,
+            max_bytes: DEFAULT_MAX_FILE_BYTES,
+        })
+    })
+}
+
+fn sender_file_commands_slot() -> &'static Mutex<Option<FileCommandTx>> {
+    SENDER_FILE_COMMANDS.get_or_init(|| Mutex::new(None))
+}
+
+fn receiver_file_commands_slot() -> &'static Mutex<Option<FileCommandTx>> {
+    RECEIVER_FILE_COMMANDS.get_or_init(|| Mutex::new(None))
+}
+
+enum FileCommand {
+    Send {
+        transfer_id: u32,
+        file: Box<OutgoingFile>,
+    },
+    Cancel {
+        transfer_id: u32,
+        incoming: bool,
+    },
+}
+
+/// What the peer, or a local cancel, said about an outgoing transfer.
+enum FileReply {
+    Accepted,
+    Rejected(String),
+    Done,
+    Cancel,
+}
+
+/// Most incoming files received at once; further offers are rejected until one
+/// finishes.
+const MAX_INCOMING_FILE_TRANSFERS: usize = 4;
+/// Chunks queued for an incoming file's writer. Beyond this the stream reader
+/// waits, so a slow disk holds back the peer through QUIC flow control instead of
+/// buffering the file in memory.
+const INCOMING_FILE_QUEUE_CHUNKS: usize = 16;
+
+/// What the session loop hands to an incoming transfer's stream reader.
+enum IncomingFileSignal {
+    Stream(quinn::RecvStream),
+    Abort(String),
+    Cancel,
+}
+
+/// What an incoming transfer's writer applies, in order. `Failed` means the file
+/// stream broke off before the file ended.
+enum IncomingFileEvent {
+    Chunk { offset: u64, data: Vec<u8> },
+    End { sha256: [u8; SHA256_BYTES] },
+    Abort(String),
+    Cancel,
+    Failed(String),
+}
+
+struct IncomingTransfer {
+    signals: UnboundedSender<IncomingFileSignal>,
+    task: tokio::task::JoinHandle<()>,
+}
+
+struct OutgoingTransfer {
+    name: String,
+    replies: UnboundedSender<FileReply>,
+    task: tokio::task::JoinHandle<()>,
+}
+
+/// File transfers of one session, in both directions. Each incoming file is read
+/// from its stream by a task of its own and written by a blocking thread; each
+/// outgoing file streams from its own task.
+struct FileTransfers {
+    sink: StreamSink<UiEvent>,
+    control: ControlSender,
+    connection: quinn::Connection,
+    streams: FileStreams,
+    incoming: BTreeMap<u32, IncomingTransfer>,
+    outgoing: BTreeMap<u32, OutgoingTransfer>,
+}
+
+impl FileTransfers {
+    /// Starts accepting the peer's file streams, so the control stream must
+    /// already be set up.
+    fn new(
+        sink: &StreamSink<UiEvent>,
+        control: ControlSender,
+        connection: &quinn::Connection,
+    ) -> Self {
+        Self {
+            sink: sink.clone(),
+            control,
+            connection: connection.clone(),
+            streams: FileStreams::accept(connection),
+            incoming: BTreeMap::new(),
+            outgoing: BTreeMap::new(),
+        }
+    }
+
+    fn command(&mut self, command: FileCommand) {
+        self.outgoing
+            .retain(|_, transfer| !transfer.task.is_finished());
+        match command {
+            FileCommand::Send { transfer_id, file } => {
+                let (replies, reply_rx) = mpsc::unbounded_channel();
+                let name = file.name.clone();
+                let task = tokio::spawn(run_outgoing_file(
+                    self.sink.clone(),
+                    self.control.clone(),
+                    self.connection.clone(),
+                    transfer_id,
+                    *file,
+                    reply_rx,
+                ));
+                self.outgoing.insert(
+                    transfer_id,
+                    OutgoingTransfer {
+                        name,
+                        replies,
+                        task,
+                    },
+                );
+            }
+            FileCommand::Cancel {
+                transfer_id,
+                incoming: true,
+            } => {
+                self.signal(transfer_id, IncomingFileSignal::Cancel);
+            }
+            FileCommand::Cancel {
+                transfer_id,
+                incoming: false,
+            } => {
+                if let Some(transfer) = self.outgoing.get(&transfer_id) {
+                    let _ = transfer.replies.send(FileReply::Cancel);
+                }
+            }
+        }
+    }
+
+    fn handle_peer(&mut self, message: FileMessage) {
+        match message {
+            FileMessage::Offer {
+                transfer_id,
+                name,
+                size,
+            } => self.offered(transfer_id, name, size),
+            FileMessage::Abort {
+                transfer_id,
+                reason,
+            } => self.signal(transfer_id, IncomingFileSignal::Abort(reason)),
+            FileMessage::Accept { transfer_id } => self.reply(transfer_id, FileReply::Accepted),
+            FileMessage::Reject {
+                transfer_id,
+                reason,
+            } => self.reply(transfer_id, FileReply::Rejected(reason)),
+            FileMessage::Done { transfer_id } => self.reply(transfer_id, FileReply::Done),
+        }
+    }
+
+    /// Accepts an incoming file if receiving is enabled, it fits the size limit and
+    /// fewer than `MAX_INCOMING_FILE_TRANSFERS` are in progress.
+    fn offered(&mut self, transfer_id: u32, name: String, size: u64) {
+        self.incoming
+            .retain(|_, transfer| !transfer.task.is_finished());
+        let config = file_transfer_config_slot()
+            .lock()
+            .map(|guard| guard.clone())
+            .ok();
+        let dir = match config {
+            _ if self.incoming.len() >= MAX_INCOMING_FILE_TRANSFERS => Err(anyhow!(
+                "already receiving {MAX_INCOMING_FILE_TRANSFERS} files"
+            )),
+            Some(FileTransferConfig {
+                receive_dir: Some(dir),
+                max_bytes,
+            }) if size <= max_bytes => Ok(dir),
+            Some(FileTransferConfig {
+                receive_dir: Some(_),
+                max_bytes,
+            }) => Err(anyhow!("{size} bytes exceeds the {max_bytes}-byte limit")),
+            _ => Err(anyhow!("receiving files is disabled")),
+        };
+        match dir {
+            Ok(dir) => {
+                let (signals, signal_rx) = mpsc::unbounded_channel();
+                let (events, event_rx) = mpsc::channel(INCOMING_FILE_QUEUE_CHUNKS);
+                tokio::spawn(read_incoming_file(size, signal_rx, events));
+                let sink = self.sink.clone();
+                let control = self.control.clone();
+                let task = spawn_blocking(move || {
+                    run_incoming_file(sink, control, transfer_id, &dir, &name, size, event_rx)
+                });
+                self.incoming
+                    .insert(transfer_id, IncomingTransfer { signals, task });
+            }
+            Err(error) => {
+                reject_incoming_file(&self.control, transfer_id, &format!("{error:#}"));
+                emit_file_transfer_finished(
+                    &self.sink,
+                    transfer_id,
+                    true,
+                    &name,
+                    FileTransferOutcome::Rejected,
+                    "",
+                    &format!("{error:#}"),
+                );
+            }
+        }
+    }
+
+    /// Hands a file stream the peer opened to its transfer. A stream for an unknown
+    /// or finished transfer is dropped, which stops it.
+    fn stream_opened(&mut self, transfer_id: u32, stream: quinn::RecvStream) {
+        self.signal(transfer_id, IncomingFileSignal::Stream(stream));
+    }
+
+    fn signal(&mut self, transfer_id: u32, signal: IncomingFileSignal) {
+        if let Some(transfer) = self.incoming.get(&transfer_id) {
+            if transfer.signals.send(signal).is_err() {
+                self.incoming.remove(&transfer_id);
+            }
+        }
+    }
+
+    fn reply(&mut self, transfer_id: u32, reply: FileReply) {
+        if let Some(transfer) = self.outgoing.get(&transfer_id) {
+            if transfer.replies.send(reply).is_err() {
+                self.outgoing.remove(&transfer_id);
+            }
+        }
+    }
+}
+
+impl Drop for FileTransfers {
+    /// Transfers still running when the session ends are reported as failed.
+    /// Incoming ones do that themselves, and delete their partial file, once
+    /// their event channel closes.
+    fn drop(&mut self) {
+        for (transfer_id, transfer) in std::mem::take(&mut self.outgoing) {
+            if transfer.task.is_finished() {
+                continue;
+            }
+            transfer.task.abort();
+            emit_file_transfer_finished(
+                &self.sink,
+                transfer_id,
+                false,
+                &transfer.name,
+                FileTransferOutcome::Failed,
+                "",
+                "session ended",
+            );
+        }
+    }
+}
+
+fn reject_incoming_file(control: &ControlSender, transfer_id: u32, reason: &str) {
+    let _ = control.send(&control::ControlMessage::File(FileMessage::Reject {
+        transfer_id,
+        reason: reason.to_string(),
+    }));
+}
+
+/// Waits for an incoming transfer's file stream and forwards what it carries to
+/// the writer, until the file ends, the stream breaks off, or the session loop
+/// signals an abort or cancel.
+async fn read_incoming_file(
+    size: u64,
+    mut signals: mpsc::UnboundedReceiver<IncomingFileSignal>,
+    events: mpsc::Sender<IncomingFileEvent>,
+) {
+    let mut reader: Option<FileStreamReader> = None;
+    loop {
+        let event = tokio::select! {
+            biased;
+            signal = signals.recv() => match signal {
+                Some(IncomingFileSignal::Stream(stream)) => {
+                    // A second stream for the transfer is dropped, which stops it.
+                    if reader.is_none() {
+                        reader = Some(FileStreamReader::new(stream, size));
+                    }
+                    continue;
+                }
+                Some(IncomingFileSignal::Abort(reason)) => IncomingFileEvent::Abort(reason),
+                Some(IncomingFileSignal::Cancel) => IncomingFileEvent::Cancel,
+                None => return,
+            },
+            item = next_file_stream_item(&mut reader) => match item {
+                Ok(FileStreamItem::Chunk { offset, data }) => {
+                    IncomingFileEvent::Chunk { offset, data }
+                }
+                Ok(FileStreamItem::End { sha256 }) => IncomingFileEvent::End { sha256 },
+                // A sender that gives up ends its stream early; the reason follows
+                // as an abort on the control stream.
+                Err(error) => {
+                    match tokio::time::timeout(control::CONTROL_STREAM_TIMEOUT, signals.recv())
+                        .await
+                    {
+                        Ok(Some(IncomingFileSignal::Abort(reason))) => {
+                            IncomingFileEvent::Abort(reason)
+                        }
+                        Ok(Some(IncomingFileSignal::Cancel)) => IncomingFileEvent::Cancel,
+                        _ => IncomingFileEvent::Failed(format!("{error:#}")),
+                    }
+                }
+            },
+        };
+        let more = matches!(event, IncomingFileEvent::Chunk { .. });
+        if events.send(event).await.is_err() || !more {
+            return;
+        }
+    }
+}
+
+async fn next_file_stream_item(
+    reader: &mut Option<FileStreamReader>,
+) -> anyhow::Result<FileStreamItem> {
+    match reader {
+        Some(reader) => reader.next().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Receives one file on a blocking thread, applying its events in the order the
+/// stream reader forwarded them.
+fn run_incoming_file(
+    sink: StreamSink<UiEvent>,
+    control: ControlSender,
+    transfer_id: u32,
+    dir: &Path,
+    name: &str,
+    size: u64,
+    mut events: mpsc::Receiver<IncomingFileEvent>,
+) {
+    let mut file = match IncomingFile::create(dir, name, size) {
+        Ok(file) => file,
+        Err(error) => {
+            reject_incoming_file(&control, transfer_id, &format!("{error:#}"));
+            emit_file_transfer_finished(
+                &sink,
+                transfer_id,
+                true,
+                name,
+                FileTransferOutcome::Rejected,
+                "",
+                &format!("{error:#}"),
+            );
+            return;
+        }
+    };
+    let name = file.name.clone();
+    emit_file_transfer_started(&sink, transfer_id, true, &name, size);
+    let _ = control.send(&control::ControlMessage::File(FileMessage::Accept {
+        transfer_id,
+    }));
+
+    let mut last_progress = Instant::now();
+    let (outcome, path, detail) = loop {
+        let Some(event) = events.blocking_recv() else {
+            break (
+                FileTransferOutcome::Failed,
+                String::new(),
+                "session ended".to_string(),
+            );
+        };
+        match event {
+            IncomingFileEvent::Chunk { offset, data } => {
+                if let Err(error) = file.write(offset, &data) {
+                    reject_incoming_file(&control, transfer_id, &format!("{error:#}"));
+                    break (
+                        FileTransferOutcome::Failed,
+                        String::new(),
+                        format!("{error:#}"),
+                    );
+                }
+                if last_progress.elapsed() >= FILE_PROGRESS_INTERVAL {
+                    last_progress = Instant::now();
+                    emit_file_transfer_progress(&sink, transfer_id, true, file.received(), size);
+                }
+            }
+            IncomingFileEvent::End { sha256 } => match file.finish(&sha256) {
+                Ok(path) => {
+                    let _ = control.send(&control::ControlMessage::File(FileMessage::Done {
+                        transfer_id,
+                    }));
+                    emit_file_transfer_progress(&sink, transfer_id, true, size, size);
+                    break (
+                        FileTransferOutcome::Completed,
+                        path.display().to_string(),
+                        String::new(),
+                    );
+                }
+                Err(error) => {
+                    reject_incoming_file(&control, transfer_id, &format!("{error:#}"));
+                    break (
+                        FileTransferOutcome::Failed,
+                        String::new(),
+                        format!("{error:#}"),
+                    );
+                }
+            },
+            IncomingFileEvent::Abort(reason) => {
+                break (
+                    FileTransferOutcome::Cancelled,
+                    String::new(),
+                    format!("sender: {reason}"),
+                );
+            }
+            IncomingFileEvent::Cancel => {
+                reject_incoming_file(&control, transfer_id, "cancelled by the receiver");
+                break (
+                    FileTransferOutcome::Cancelled,
+                    String::new(),
+                    "cancelled locally".to_string(),
+                );
+            }
+            IncomingFileEvent::Failed(error) => {
+                reject_incoming_file(&control, transfer_id, &error);
+                break (FileTransferOutcome::Failed, String::new(), error);
+            }
+        }
+    };
+    emit_file_transfer_finished(&sink, transfer_id, true, &name, outcome, &path, &detail);
+}
+
+/// Offers `file` to the peer and streams it once accepted.
+async fn run_outgoing_file(
+    sink: StreamSink<UiEvent>,
+    control: ControlSender,
+    connection: quinn::Connection,
+    transfer_id: u32,
+    mut file: OutgoingFile,
+    mut replies: mpsc::UnboundedReceiver<FileReply>,
+) {
+    let name = file.name.clone();
+    let streamed = stream_file(
+        &sink,
+        &control,
+        &connection,
+        transfer_id,
+        &mut file,
+        &mut replies,
+    );
+    let (outcome, detail) = match streamed.await {
+        Ok(()) => (FileTransferOutcome::Completed, String::new()),
+        Err((outcome, detail)) => {
+            if outcome != FileTransferOutcome::Rejected {
+                let _ = control.send(&control::ControlMessage::File(FileMessage::Abort {
+                    transfer_id,
+                    reason: detail.clone(),
+                }));
+            }
+            (outcome, detail)
+        }
+    };
+    emit_file_transfer_finished(&sink, transfer_id, false, &name, outcome, "", &detail);
+}
+
+async fn stream_file(
+    sink: &StreamSink<UiEvent>,
+    control: &ControlSender,
+    connection: &quinn::Connection,
+    transfer_id: u32,
+    file: &mut OutgoingFile,
+    replies: &mut mpsc::UnboundedReceiver<FileReply>,
+) -> Result<(), (FileTransferOutcome, String)> {
+    let failed = |error: anyhow::Error| (FileTransferOutcome::Failed, format!("{error:#}"));
+    let offer = FileMessage::Offer {
+        transfer_id,
+        name: file.name.clone(),
+        size: file.size,
+    };
+    control
+        .send(&control::ControlMessage::File(offer))
+        .map_err(failed)?;
+    match replies.recv().await {
+        Some(FileReply::Accepted) => {}
+        Some(reply) => return Err(file_reply_error(reply)),
+        None => return Err((FileTransferOutcome::Failed, "session ended".to_string())),
+    }
+    emit_file_transfer_started(sink, transfer_id, false, &file.name, file.size);
+
+    let mut stream = file_transfer::open_file_stream(connection, transfer_id)
+        .await
+        .map_err(failed)?;
+    let mut last_progress = Instant::now();
+    loop {
+        if let Ok(reply) = replies.try_recv() {
+            return Err(file_reply_error(reply));
+        }
+        let Some(data) = file.next_chunk().map_err(failed)? else {
+            break;
+        };
+        if let Err(error) = stream.write_all(&data).await {
+            return Err(file_stream_stopped(replies, error.into()).await);
+        }
+        if last_progress.elapsed() >= FILE_PROGRESS_INTERVAL {
+            last_progress = Instant::now();
+            emit_file_transfer_progress(sink, transfer_id, false, file.sent(), file.size);
+        }
+    }
+    emit_file_transfer_progress(sink, transfer_id, false, file.sent(), file.size);
+    let sha256 = file.checksum();
+    let ended = async {
+        stream.write_all(&sha256).await?;
+        stream.finish()?;
+        anyhow::Ok(())
+    };
+    if let Err(error) = ended.await {
+        return Err(file_stream_stopped(replies, error).await);
+    }
+    match replies.recv().await {
+        Some(FileReply::Done) => Ok(()),
+        // A rejection after the checksum means the peer could not verify or save
+        // the file.
+        Some(FileReply::Rejected(reason)) => Err((FileTransferOutcome::Failed, reason)),
+        Some(reply) => Err(file_reply_error(reply)),
+        None => Err((
+            FileTransferOutcome::Failed,
+            "no confirmation from the peer".to_string(),
+        )),
+    }
+}
+
+/// Writes fail once the receiver stops the file stream; its reply on the control
+/// stream, which may arrive just after, says why.
+async fn file_stream_stopped(
+    replies: &mut mpsc::UnboundedReceiver<FileReply>,
+    error: anyhow::Error,
+) -> (FileTransferOutcome, String) {
+    match tokio::time::timeout(control::CONTROL_STREAM_TIMEOUT, replies.recv()).await {
+        Ok(Some(reply)) => file_reply_error(reply),
+        _ => (FileTransferOutcome::Failed, format!("{error:#}")),
+    }
+}
+
+fn file_reply_error(reply: FileReply) -> (FileTransferOutcome, String) {
+    match reply {
+        FileReply::Rejected(reason) => (FileTransferOutcome::Rejected, reason),
+        FileReply::Cancel => (
+            FileTransferOutcome::Cancelled,
+            "cancelled locally".to_string(),
+        ),
+        FileReply::Accepted | FileReply::Done => (
+            FileTransferOutcome::Failed,
+            "unexpected reply from the peer".to_string(),
+        ),
+    }
+}
+
+fn emit_file_transfer_started(
+    sink: &StreamSink<UiEvent>,
+    transfer_id: u32,
+    incoming: bool,
+    name: &str,
+    size: u64,
+) {
+    sink_event(
+        sink,
+        UiEvent::FileTransferStarted {
+            transfer_id,
+            incoming,
+            name: name.to_string(),
+            size,
+        },
+    );
+}
+
+fn emit_file_transfer_progress(
+    sink: &StreamSink<UiEvent>,
+    transfer_id: u32,
+    incoming: bool,
+    bytes: u64,
+    size: u64,
+) {
+    sink_event(
+        sink,
+        UiEvent::FileTransferProgress {
+            transfer_id,
+            incoming,
+            bytes,
+            size,
+        },
+    );
+}
+
+fn emit_file_transfer_finished(
+    sink: &StreamSink<UiEvent>,
+    transfer_id: u32,
+    incoming: bool,
+    name: &str,
+    outcome: FileTransferOutcome,
+    path: &str,
+    detail: &str,
+) {
+    sink_event(
+        sink,
+        UiEvent::FileTransferFinished {
+            transfer_id,
+            incoming,
+            name: name.to_string(),
+            outcome,
+            path: path.to_string(),
+            detail: detail.to_string(),
+        },
+    );
+}
+
+/// Enables receiving files into `receive_dir` (`None` refuses incoming offers)
+/// and sets the largest file accepted or sent. Applies to transfers offered after
+/// the call.
+pub fn configure_file_transfer(receive_dir: Option<String>, max_bytes: u64) -> anyhow::Result<()> {
+    if max_bytes == 0 {
+        bail!("file transfer size limit must be positive");
+    }
+    let mut guard = file_transfer_config_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock file transfer config"))?;
+    *guard = FileTransferConfig {
+        receive_dir: receive_dir.map(PathBuf::from),
+        max_bytes,
+    };
+    Ok(())
+}
+
+/// Offers the file at `path` to the peer of the running session (the sender's,
+/// if both are running) and returns the transfer id used in file transfer
+/// events. The file is streamed from disk once the peer accepts.
+pub fn send_file(path: String) -> anyhow::Result<u32> {
+    let max_bytes = file_transfer_config_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock file transfer config"))?
+        .max_bytes;
+    let file = OutgoingFile::open(Path::new(&path), max_bytes)?;
+    for slot in [sender_file_commands_slot(), receiver_file_commands_slot()] {
+        let commands = slot
+            .lock()
+            .map_err(|_| anyhow!("failed to lock file transfer slot"))?
+            .clone();
+        if let Some(commands) = commands {
+            let transfer_id = NEXT_FILE_TRANSFER_ID.fetch_add(1, Ordering::Relaxed);
+            commands
+                .send(FileCommand::Send {
+                    transfer_id,
+                    file: Box::new(file),
+                })
+                .map_err(|_| anyhow!("session is shutting down"))?;
+            return Ok(transfer_id);
+        }
+    }
+    bail!("no running session has a control stream")
+}
+
+/// Cancels a transfer; `incoming` selects between the peer's transfer and one
+/// started with `send_file`, whose ids may coincide.
+pub fn cancel_file_transfer(transfer_id: u32, incoming: bool) -> anyhow::Result<()> {
+    for slot in [sender_file_commands_slot(), receiver_file_commands_slot()] {
+        let commands = slot
+            .lock()
+            .map_err(|_| anyhow!("failed to lock file transfer slot"))?
+            .clone();
+        if let Some(commands) = commands {
+            let _ = commands.send(FileCommand::Cancel {
+                transfer_id,
+                incoming,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// A command the receiver can ask the sender to carry out with
+/// `send_remote_command`. The sender's app does the work; see
+/// `UiEvent::RemoteCommand`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteCommand {
+    SwitchCamera,
+    SetTorch { enabled: bool },
+    SetBitrate { bitrate_bps: u32 },
+    StartCapture,
+    StopCapture,
+    RequestDebugReport,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteCommandStatus {
+    Done,
+    Failed,
+    /// Not allowed by the sender's `RemoteCommandPolicy`.
+    Denied,
+    /// The peer does not know the command, or is not a sender.
+    Unsupported,
+    /// The sender's app did not answer within `REMOTE_COMMAND_TIMEOUT`, or no
+    /// reply reached the receiver in time.
+    TimedOut,
+}
+
+/// Which remote commands the sender passes on to the app. Commands not allowed
+/// here are answered with `Denied` without reaching Dart.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RemoteCommandPolicy {
+    pub allow_switch_camera: bool,
+    pub allow_torch: bool,
+    pub allow_bitrate: bool,
+    /// Covers both `StartCapture` and `StopCapture`.
+    pub allow_capture: bool,
+    pub allow_debug_report: bool,
+}
+
+impl RemoteCommandPolicy {
+    fn allows(&self, command: RemoteCommand) -> bool {
+        match command {
+            RemoteCommand::SwitchCamera => self.allow_switch_camera,
+            RemoteCommand::SetTorch { .. } => self.allow_torch,
+            RemoteCommand::SetBitrate { .. } => self.allow_bitrate,
+            RemoteCommand::StartCapture | RemoteCommand::StopCapture => self.allow_capture,
+            RemoteCommand::RequestDebugReport => self.allow_debug_report,
+        }
+    }
+}
+
+impl From<RemoteCommand> for control::RemoteCommand {
+    fn from(command: RemoteCommand) -> Self {
+        match command {
+            RemoteCommand::SwitchCamera => control::RemoteCommand::SwitchCamera,
+            RemoteCommand::SetTorch { enabled } => control::RemoteCommand::SetTorch { enabled },
+            RemoteCommand::SetBitrate { bitrate_bps } => {
+                control::RemoteCommand::SetBitrate { bitrate_bps }
+            }
+            RemoteCommand::StartCapture => control::RemoteCommand::StartCapture,
+            RemoteCommand::StopCapture => control::RemoteCommand::StopCapture,
+            RemoteCommand::RequestDebugReport => control::RemoteCommand::RequestDebugReport,
+        }
+    }
+}
+
+/// `None` for commands from a newer peer.
+fn remote_command_from_control(command: control::RemoteCommand) -> Option<RemoteCommand> {
+    let command = match command {
+        control::RemoteCommand::SwitchCamera => RemoteCommand::SwitchCamera,
+        control::RemoteCommand::SetTorch { enabled } => RemoteCommand::SetTorch { enabled },
+        control::RemoteCommand::SetBitrate { bitrate_bps } => {
+            RemoteCommand::SetBitrate { bitrate_bps }
+        }
+        control::RemoteCommand::StartCapture => RemoteCommand::StartCapture,
+        control::RemoteCommand::StopCapture => RemoteCommand::StopCapture,
+        control::RemoteCommand::RequestDebugReport => RemoteCommand::RequestDebugReport,
+        control::RemoteCommand::Unknown { .. } => return None,
+    };
+    Some(command)
+}
+
+impl From<control::CommandStatus> for RemoteCommandStatus {
+    fn from(status: control::CommandStatus) -> Self {
+        match status {
+            control::CommandStatus::Done => RemoteCommandStatus::Done,
+            control::CommandStatus::Failed => RemoteCommandStatus::Failed,
+            control::CommandStatus::Denied => RemoteCommandStatus::Denied,
+            control::CommandStatus::Unsupported => RemoteCommandStatus::Unsupported,
+            control::CommandStatus::TimedOut => RemoteCommandStatus::TimedOut,
+        }
+    }
+}
+
+impl From<RemoteCommandStatus> for control::CommandStatus {
+    fn from(status: RemoteCommandStatus) -> Self {
+        match status {
+            RemoteCommandStatus::Done => control::CommandStatus::Done,
+            RemoteCommandStatus::Failed => control::CommandStatus::Failed,
+            RemoteCommandStatus::Denied => control::CommandStatus::Denied,
+            RemoteCommandStatus::Unsupported => control::CommandStatus::Unsupported,
+            RemoteCommandStatus::TimedOut => control::CommandStatus::TimedOut,
+        }
+    }
+}
+
+fn remote_command_policy_slot() -> &'static Mutex<RemoteCommandPolicy> {
+    REMOTE_COMMAND_POLICY.get_or_init(|| Mutex::new(RemoteCommandPolicy::default()))
+}
+
+/// How long the sender's app has to answer a command before the receiver is
+/// told it timed out.
+const REMOTE_COMMAND_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Commands passed to the app and not yet answered, with when they arrived;
+/// `None` while no sender session has a control stream.
+fn sender_pending_commands_slot() -> &'static Mutex<Option<BTreeMap<u32, Instant>>> {
+    SENDER_PENDING_COMMANDS.get_or_init(|| Mutex::new(None))
+}
+
+/// How long the receiver waits for a reply to `send_remote_command`. Longer than
+/// `REMOTE_COMMAND_TIMEOUT`, so the sender's own `TimedOut` normally comes first.
+const REMOTE_COMMAND_REPLY_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Commands sent with `send_remote_command` and not yet answered, with when they
+/// were sent; `None` while no receiver session has a control stream.
+fn receiver_issued_commands_slot() -> &'static Mutex<Option<BTreeMap<u32, Instant>>> {
+    RECEIVER_ISSUED_COMMANDS.get_or_init(|| Mutex::new(None))
+}
+
+/// Marks an issued command as answered. False when it was not waiting for a
+/// reply, e.g. because it already timed out.
+fn settle_issued_command(command_id: u32) -> bool {
+    receiver_issued_commands_slot()
+        .lock()
+        .ok()
+        .and_then(|mut pending| pending.as_mut()?.remove(&command_id))
+        .is_some()
+}
+
+/// Reports issued commands that got no reply within `REMOTE_COMMAND_REPLY_TIMEOUT`
+/// as `TimedOut`.
+fn expire_issued_commands(sink: &StreamSink<UiEvent>) {
+    let mut expired = Vec::new();
+    if let Ok(mut pending) = receiver_issued_commands_slot().lock() {
+        if let Some(pending) = pending.as_mut() {
+            pending.retain(|&command_id, sent| {
+                let waiting = sent.elapsed() < REMOTE_COMMAND_REPLY_TIMEOUT;
+                if !waiting {
+                    expired.push(command_id);
+                }
+                waiting
+            });
+        }
+    }
+    let detail = format!(
+        "no reply from the sender within {}s",
+        REMOTE_COMMAND_REPLY_TIMEOUT.as_secs()
+    );
+    for command_id in expired {
+        emit_issued_command_timeout(sink, command_id, &detail);
+    }
+}
+
+/// Reports every issued command still waiting as `TimedOut`, once no reply can
+/// arrive any more.
+fn fail_issued_commands(sink: &StreamSink<UiEvent>, detail: &str) {
+    let pending = receiver_issued_commands_slot()
+        .lock()
+        .ok()
+        .and_then(|mut pending| pending.as_mut().map(std::mem::take))
+        .unwrap_or_default();
+    for command_id in pending.into_keys() {
+        emit_issued_command_timeout(sink, command_id, detail);
+    }
+}
+
+fn emit_issued_command_timeout(sink: &StreamSink<UiEvent>, command_id: u32, detail: &str) {
+    sink_event(
+        sink,
+        UiEvent::RemoteCommandReply {
+            command_id,
+            status: RemoteCommandStatus::TimedOut,
+            detail: detail.to_string(),
+        },
+    );
+}
+
+/// Answers commands the app left unanswered for `REMOTE_COMMAND_TIMEOUT` with
+/// `TimedOut`. A later `reply_remote_command` for them fails.
+fn expire_remote_commands(sink: &StreamSink<UiEvent>, control: &ControlSender) {
+    let mut expired = Vec::new();
+    if let Ok(mut pending) = sender_pending_commands_slot().lock() {
+        if let Some(pending) = pending.as_mut() {
+            pending.retain(|&command_id, arrived| {
+                let waiting = arrived.elapsed() < REMOTE_COMMAND_TIMEOUT;
+                if !waiting {
+                    expired.push(command_id);
+                }
+                waiting
+            });
+        }
+    }
+    for command_id in expired {
+        sink_event(
+            sink,
+            UiEvent::Log {
+                msg: format!("remote command {command_id} timed out without a reply"),
+            },
+        );
+        let _ = control.send(&control::ControlMessage::CommandReply {
+            command_id,
+            status: control::CommandStatus::TimedOut,
+            detail: format!(
+                "no answer from the sender's app within {}s",
+                REMOTE_COMMAND_TIMEOUT.as_secs()
+            ),
+        });
+    }
+}
+
+/// Answers a command straight away unless it is for the app: the receiver takes
+/// no commands, and the sender only passes on those its policy allows.
+fn handle_remote_command(
+    sink: &StreamSink<UiEvent>,
+    control: &ControlSender,
+    takes_commands: bool,
+    command_id: u32,
+    command: control::RemoteCommand,
+) {
+    let reply = |status: control::CommandStatus, detail: &str| {
+        let _ = control.send(&control::ControlMessage::CommandReply {
+            command_id,
+            status,
+            detail: detail.to_string(),
+        });
+    };
+    if !takes_commands {
+        reply(
+            control::CommandStatus::Unsupported,
+            "only the sender takes remote commands",
+        );
+        return;
+    }
+    let Some(command) = remote_command_from_control(command) else {
+        reply(control::CommandStatus::Unsupported, "unknown command");
+        return;
+    };
+    let allowed = remote_command_policy_slot()
+        .lock()
+        .map(|policy| policy.allows(command))
+        .unwrap_or(false);
+    if !allowed {
+        sink_event(
+            sink,
+            UiEvent::Log {
+                msg: format!("remote command {command:?} denied by policy"),
+            },
+        );
+        reply(
+            control::CommandStatus::Denied,
+            "not allowed by the sender's policy",
+        );
+        return;
+    }
+    if let Ok(mut pending) = sender_pending_commands_slot().lock() {
+        if let Some(pending) = pending.as_mut() {
+            pending.insert(command_id, Instant::now());
+        }
+    }
+    sink_event(
+        sink,
+        UiEvent::RemoteCommand {
+            command_id,
+            command,
+        },
+    );
+}
+
+/// Sets which commands from the receiver reach the sender's app. Until this is
+/// called every command is denied.
+pub fn configure_remote_command_policy(policy: RemoteCommandPolicy) -> anyhow::Result<()> {
+    let mut guard = remote_command_policy_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock remote command policy"))?;
+    *guard = policy;
+    Ok(())
+}
+
+/// Asks the sender at the other end of the running receiver session to carry out
+/// `command`. Returns the id that its `UiEvent::RemoteCommandReply` will carry.
+/// Every command gets exactly one reply: `TimedOut` when the sender does not
+/// answer within 20 seconds or the control stream closes first.
+pub fn send_remote_command(command: RemoteCommand) -> anyhow::Result<u32> {
+    let control = receiver_control_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock control stream slot"))?
+        .clone()
+        .context("no running receiver session has a control stream")?;
+    let command_id = NEXT_REMOTE_COMMAND_ID.fetch_add(1, Ordering::Relaxed);
+    // Tracked before sending, so a fast reply always finds it.
+    receiver_issued_commands_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock remote command slot"))?
+        .as_mut()
+        .context("receiver session has no control stream")?
+        .insert(command_id, Instant::now());
+    let sent = control.send(&control::ControlMessage::Command {
+        command_id,
+        command: command.into(),
+    });
+    if sent.is_err() {
+        settle_issued_command(command_id);
+    }
+    sent.map(|()| command_id)
+}
+
+/// Answers the `UiEvent::RemoteCommand` with `command_id`; each command takes
+/// exactly one reply.
+pub fn reply_remote_command(
+    command_id: u32,
+    status: RemoteCommandStatus,
+    detail: String,
 ) -> anyhow::Result<()> {
-    let codec = if codec == 0 { VIDEO_CODEC_HEVC } else { codec };
-    let frame_len = frame_bytes.len();
-    println!(
-        "DEBUG: Rust received VIDEO frame from Dart: {} bytes (keyframe={}, pts_us={}, codec=0x{:02X})",
-        frame_len, is_keyframe, pts, codec
-    );
-    let tx = {
-        let guard = hevc_frame_tx_slot()
-            .lock()
-            .map_err(|_| anyhow!("failed to lock HEVC frame sender slot"))?;
-        guard
-            .clone()
-            .context("sender is not active; call start_sankaku_sender first")?
-    };
-    tx.send((frame_bytes, is_keyframe, pts, codec))
-        .map_err(|_| anyhow!("sender frame ingress channel is closed"))?;
-    Ok(())
+    let answered = sender_pending_commands_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock remote command slot"))?
+        .as_mut()
+        .is_some_and(|pending| pending.remove(&command_id).is_some());
+    if !answered {
+        bail!("no remote command {command_id} is awaiting a reply");
+    }
+    let control = sender_control_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock control stream slot"))?
+        .clone()
+        .context("sender session has no control stream")?;
+    control.send(&control::ControlMessage::CommandReply {
+        command_id,
+        status: status.into(),
+        detail,
+    })
 }
 
-pub fn push_audio_frame(
-    frame_bytes: Vec<u8>,
-    pts: u64,
-    codec: u8,
-    frames_per_packet: u32,
+fn local_media_state_slot() -> &'static Mutex<MediaState> {
+    LOCAL_MEDIA_STATE.get_or_init(|| Mutex::new(MediaState::default()))
+}
+
+/// Sends the current media state. The lock is held while queueing so that an
+/// update racing with session start cannot be overtaken by the older state.
+fn send_local_media_state(control: &ControlSender) -> anyhow::Result<()> {
+    let state = local_media_state_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock media state"))?;
+    control.send(&control::ControlMessage::MediaState(*state))
+}
+
+/// Records what the local side is deliberately not sending and tells the
+/// receiver of the running sender session, if any; later sessions announce it
+/// when they start. `audio_only` implies no video, and `screen_share` means video
+/// continues with the camera off.
+pub fn announce_media_state(
+    microphone_muted: bool,
+    camera_off: bool,
+    audio_only: bool,
+    screen_share: bool,
 ) -> anyhow::Result<()> {
-    let codec = if codec == 0 { AUDIO_CODEC_OPUS } else { codec };
-    let frame_len = frame_bytes.len();
-    println!(
-        "DEBUG: Rust received AUDIO frame from Dart: {} bytes (pts_us={}, codec=0x{:02X}, frames_per_packet={})",
-        frame_len, pts, codec, frames_per_packet
-    );
-    let tx = {
-        let guard = audio_frame_tx_slot()
-            .lock()
-            .map_err(|_| anyhow!("failed to lock audio frame sender slot"))?;
-        guard
-            .clone()
-            .context("sender is not active; call start_sankaku_sender first")?
+    let mut state = local_media_state_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock media state"))?;
+    *state = MediaState {
+        microphone_muted,
+        camera_off,
+        audio_only,
+        screen_share,
     };
-    tx.send((frame_bytes, pts, codec, frames_per_packet))
-        .map_err(|_| anyhow!("sender audio ingress channel is closed"))?;
+    let control = sender_control_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock control stream slot"))?
+        .clone();
+    if let Some(control) = control {
+        control.send(&control::ControlMessage::MediaState(*state))?;
+    }
     Ok(())
 }
 
 pub fn stop_sankaku_sender() -> anyhow::Result<()> {
     SENDER_SHOULD_RUN.store(false, Ordering::Relaxed);
-    clear_hevc_frame_tx();
+    clear_video_ingress();
     clear_audio_frame_tx();
     Ok(())
 }
@@ -876,6 +4570,10 @@ async fn run_sender_loop(
     dest: String,
     graph_bytes: Vec<u8>,
 ) -> anyhow::Result<()> {
+    let audio_offers = sender_audio_codecs_slot()
+        .lock()
+        .map_err(|_| anyhow!("failed to lock sender audio codecs"))?
+        .offers()?;
     sink_event(
         &sink,
         UiEvent::ConnectionState {
@@ -909,7 +4607,77 @@ async fn run_sender_loop(
         },
     );
 
-    let mut sender = SankakuSender::new(connection).await?;
+    let offered_codecs = sender_video_codecs_slot()
+        .lock()
+        .map(|guard| guard.clone())
+        .unwrap_or_else(|_| vec![VIDEO_CODEC_HEVC]);
+    let negotiated = offer_codecs(&connection, &offered_codecs, &audio_offers).await?;
+    let video_codec = match negotiated.video {
+        NegotiationOutcome::Agreed(codec) => {
+            let codec = VideoCodec::from_id(codec)
+                .with_context(|| format!("negotiated unknown video codec 0x{codec:02X}"))?;
+            emit_codec_negotiated(&sink, codec, true);
+            codec
+        }
+        NegotiationOutcome::NoPeerSupport if offered_codecs.contains(&VIDEO_CODEC_HEVC) => {
+            emit_codec_negotiated(&sink, VideoCodec::Hevc, false);
+            VideoCodec::Hevc
+        }
+        outcome => {
+            let detail = match outcome {
+                NegotiationOutcome::NoPeerSupport => format!(
+                    "receiver does not negotiate codecs and only accepts hevc; offered [{}]",
+                    codec_list(&offered_codecs)
+                ),
+                _ => format!(
+                    "receiver declined video codecs [{}]",
+                    codec_list(&offered_codecs)
+                ),
+            };
+            emit_codec_declined(&sink, &detail);
+            bail!(detail);
+        }
+    };
+    let audio_format = match negotiated.audio {
+        AudioNegotiationOutcome::Agreed(offer) => {
+            let format = AudioFormat::from_config(offer.codec, &offer.config)?;
+            emit_audio_codec_negotiated(&sink, &format, true);
+            format
+        }
+        AudioNegotiationOutcome::NoPeerSupport
+            if audio_offers
+                .iter()
+                .any(|offer| offer.codec == AUDIO_CODEC_OPUS) =>
+        {
+            let format = AudioFormat::opus();
+            emit_audio_codec_negotiated(&sink, &format, false);
+            format
+        }
+        outcome => {
+            let offered: Vec<u8> = audio_offers.iter().map(|offer| offer.codec).collect();
+            let detail = match outcome {
+                AudioNegotiationOutcome::NoPeerSupport => format!(
+                    "receiver does not negotiate audio codecs and only accepts opus; offered [{}]",
+                    audio_codec_list(&offered)
+                ),
+                _ => format!(
+                    "receiver declined audio codecs [{}]",
+                    audio_codec_list(&offered)
+                ),
+            };
+            emit_codec_declined(&sink, &detail);
+            bail!(detail);
+        }
+    };
+
+    let mut control =
+        start_control_channel(&sink, &connection, negotiated.control_stream, true).await;
+    let mut datagrams = start_datagram_channel(&sink, negotiated.datagrams, async {
+        let config = make_client_config(vec![data_channel::DATA_ALPN.to_vec()])?;
+        DatagramChannel::connect(&endpoint, config, remote_addr).await
+    })
+    .await;
+    let mut sender = SankakuSender::new(connection.clone()).await?;
     sender.update_compression_graph(&graph_bytes)?;
 
     sink_event(
@@ -921,8 +4689,19 @@ async fn run_sender_loop(
     );
     sink_event(&sink, UiEvent::HandshakeInitiated);
 
+    if let Ok(mut budgets) = sender_latency_budgets_slot().lock() {
+        budgets.streams.clear();
+    }
     let video_stream_id = sender.open_stream_with_type(StreamType::Video)?;
     let audio_stream_id = sender.open_stream_with_type(StreamType::Audio)?;
+    if let Err(error) = sender.set_stream_priority(audio_stream_id, AUDIO_STREAM_PRIORITY) {
+        sink_event(
+            &sink,
+            UiEvent::Log {
+                msg: format!("failed to raise audio stream priority: {error}"),
+            },
+        );
+    }
     sink_event(
         &sink,
         UiEvent::Telemetry {
@@ -951,30 +4730,246 @@ async fn run_sender_loop(
         },
     );
 
-    let (frame_tx, mut frame_rx) = mpsc::unbounded_channel::<(Vec<u8>, bool, u64, u8)>();
-    let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<(Vec<u8>, u64, u8, u32)>();
-    install_hevc_frame_tx(frame_tx)?;
+    let (queue_capacity, queue_policy) = sender_video_queue_config_slot()
+        .lock()
+        .map(|guard| *guard)
+        .unwrap_or(DEFAULT_SENDER_VIDEO_QUEUE_CONFIG);
+    let video_queue = Arc::new(VideoIngressQueue::new(
+        queue_capacity as usize,
+        queue_policy.into(),
+    ));
+    let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<QueuedAudioFrame>();
+    video_queue.set_target_bitrate(sender.target_bitrate_bps());
+    let keyframe_check = Arc::new(Mutex::new(KeyframeCheck::new(
+        sender_keyframe_detection_slot()
+            .lock()
+            .map(|guard| *guard)
+            .unwrap_or(KeyframeDetectionMode::CrossCheck),
+    )));
+    install_video_ingress(VideoIngress {
+        queue: Arc::clone(&video_queue),
+        keyframe_check: Arc::clone(&keyframe_check),
+    })?;
     install_audio_frame_tx(audio_tx)?;
     let _frame_ingress_guard = FrameIngressGuard;
+    set_session_slot(
+        sender_control_slot(),
+        control.as_ref().map(|channel| channel.sender.clone()),
+    )?;
+    set_session_slot(
+        sender_datagrams_slot(),
+        datagrams.as_ref().map(|channel| channel.connection.clone()),
+    )?;
+    let mut control_active = control.is_some();
+    let (file_command_tx, mut file_commands) = mpsc::unbounded_channel();
+    let mut file_transfers = control
+        .as_ref()
+        .map(|channel| FileTransfers::new(&sink, channel.sender.clone(), &connection));
+    set_session_slot(
+        sender_file_commands_slot(),
+        file_transfers.is_some().then_some(file_command_tx),
+    )?;
+    set_session_slot(
+        sender_pending_commands_slot(),
+        control.is_some().then(BTreeMap::new),
+    )?;
+    if let Some(channel) = control.as_ref() {
+        send_local_media_state(&channel.sender)?;
+    }
 
     let mut handshake_announced = false;
     let mut sent_packets: u64 = 0;
+    let mut last_congestion_action = CongestionAction::None;
+    let mut stale_filters = StaleFrameFilters::default();
+    let mut temporal_shaper = TemporalLayerShaper::default();
+    let mut sender_audio = SenderAudioState {
+        format: audio_format,
+        inspection: AudioInspection::new(),
+        redundancy: match SENDER_AUDIO_REDUNDANCY_DEPTH.load(Ordering::Relaxed) {
+            0 => None,
+            _ if !negotiated.audio_redundancy => {
+                sink_event(
+                    &sink,
+                    UiEvent::Log {
+                        msg: "audio redundancy disabled: receiver did not negotiate it".to_string(),
+                    },
+                );
+                None
+            }
+            depth => Some(AudioRedundancyEncoder::new(depth as usize)),
+        },
+        send_delay: SendDelayStats::default(),
+        legacy_debug_reports: !negotiated.control_stream,
+    };
+    let mut video_send_delay = SendDelayStats::default();
     let mut telemetry_tick = tokio::time::interval(Duration::from_secs(1));
     telemetry_tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
     SENDER_SHOULD_RUN.store(true, Ordering::Relaxed);
+    SENDER_LEGACY_DEBUG_REPORTS.store(!negotiated.control_stream, Ordering::Relaxed);
     let _sender_run_guard = SenderRunGuard;
 
+    // Audio is polled first and drained ahead of every video frame, and its stream
+    // sits above video in quinn's scheduler, so audio overtakes the unsent part of
+    // a large video frame. Audio that arrives while sankaku is still accepting a
+    // video frame waits for that call to return. The control stream runs below
+    // both media streams.
     loop {
         tokio::select! {
+            biased;
+
+            Some(frame) = audio_rx.recv() => {
+                if send_sender_audio(
+                    &sink,
+                    &mut sender,
+                    audio_stream_id,
+                    frame,
+                    &dest,
+                    &mut handshake_announced,
+                    &mut sender_audio,
+                )
+                .await
+                {
+                    sent_packets = sent_packets.saturating_add(1);
+                }
+            }
             _ = telemetry_tick.tick() => {
                 if let Some(stats) = sender.network_stats() {
                     emit_quic_network_telemetry(&sink, stats);
                 }
+                if let Ok(keyframe_check) = keyframe_check.lock() {
+                    keyframe_check.emit_telemetry(&sink);
+                }
+                emit_video_queue_drops(&sink, video_stream_id, &video_queue);
+                emit_video_queue_telemetry(&sink, &video_queue);
+                emit_stale_frame_telemetry(&sink, &stale_filters);
+                emit_temporal_layer_telemetry(&sink, &temporal_shaper);
+                emit_send_delay_telemetry(&sink, "audio", &mut sender_audio.send_delay);
+                sender_audio.inspection.emit_telemetry(&sink, "audio.tx");
+                emit_send_delay_telemetry(&sink, "video", &mut video_send_delay);
+                video_queue.set_target_bitrate(sender.target_bitrate_bps());
+                let congestion = CongestionState::new(video_queue.congestion(), true);
+                last_congestion_action = congestion.recommended_action;
+                emit_congestion(&sink, &congestion);
+                if let Some(channel) = control.as_ref() {
+                    expire_remote_commands(&sink, &channel.sender);
+                }
             }
-            Some((frame_bytes, is_keyframe, pts, codec)) = frame_rx.recv() => {
+            inbound = next_control_message(&mut control), if control_active => {
+                control_active = handle_control_message(
+                    &sink,
+                    inbound,
+                    control.as_ref(),
+                    true,
+                    &mut file_transfers,
+                );
+            }
+            Some(command) = file_commands.recv(), if file_transfers.is_some() => {
+                if let Some(transfers) = file_transfers.as_mut() {
+                    transfers.command(command);
+                }
+            }
+            Some((transfer_id, stream)) = next_file_stream(&mut file_transfers) => {
+                if let Some(transfers) = file_transfers.as_mut() {
+                    transfers.stream_opened(transfer_id, stream);
+                }
+            }
+            Some(inbound) = next_data_datagram(&mut datagrams), if datagrams.is_some() => {
+                handle_data_datagram(&sink, inbound);
+            }
+            Some(frame) = video_queue.pop() => {
+                emit_video_queue_drops(&sink, video_stream_id, &video_queue);
+                let QueuedFrame {
+                    payload: frame_bytes,
+                    keyframe: is_keyframe,
+                    pts,
+                    codec,
+                    queued_at,
+                } = frame;
                 if frame_bytes.is_empty() {
                     continue;
                 }
+                if codec != video_codec.id() {
+                    sink_event(
+                        &sink,
+                        UiEvent::FrameDrop {
+                            stream_id: video_stream_id,
+                            reason: format!(
+                                "codec 0x{codec:02X} does not match negotiated {}",
+                                video_codec.name()
+                            ),
+                        },
+                    );
+                    continue;
+                }
+                if let Some(header) = (video_codec == VideoCodec::Hevc)
+                    .then(|| hevc::first_vcl_header(&frame_bytes))
+                    .flatten()
+                {
+                    let layers_before = temporal_shaper.layers();
+                    let admitted = temporal_shaper.admit(
+                        pts,
+                        header,
+                        frame_bytes.len(),
+                        sender.target_bitrate_bps(),
+                    );
+                    if temporal_shaper.layers() != layers_before {
+                        emit_temporal_layers(&sink, video_stream_id, &temporal_shaper);
+                    }
+                    if !admitted {
+                        let (active, total) = temporal_shaper.layers();
+                        sink_event(
+                            &sink,
+                            UiEvent::FrameDrop {
+                                stream_id: video_stream_id,
+                                reason: format!(
+                                    "temporal_layer: pts_us={pts} temporal_id={} above active {active}/{total}",
+                                    header.temporal_id
+                                ),
+                            },
+                        );
+                        continue;
+                    }
+                }
+                let newest_pts = video_queue.newest_pts().unwrap_or(pts);
+                let stale_reason = match stale_filters.check(
+                    video_stream_id,
+                    sender_latency_budget(video_stream_id),
+                    pts,
+                    is_keyframe,
+                    newest_pts,
+                ) {
+                    StaleVerdict::Send | StaleVerdict::SendStaleKeyframe => None,
+                    StaleVerdict::DiscardStale => Some("stale"),
+                    StaleVerdict::DiscardDependent => Some("stale_dependent"),
+                };
+                if let Some(reason) = stale_reason {
+                    sink_event(
+                        &sink,
+                        UiEvent::FrameDrop {
+                            stream_id: video_stream_id,
+                            reason: format!(
+                                "{reason}: pts_us={pts} is {}ms behind newest",
+                                newest_pts.saturating_sub(pts) / 1000
+                            ),
+                        },
+                    );
+                    continue;
+                }
+                while let Ok(audio_frame) = audio_rx.try_recv() {
+                    if send_sender_audio(
+                        &sink,
+                        &mut sender,
+                        audio_stream_id,
+                        audio_frame,
+                        &dest,
+                        &mut handshake_announced,
+                        &mut sender_audio,
+                    )
+                    .await
+                    {
+                        sent_packets = sent_packets.saturating_add(1);
+                    }
+                }
                 send_sender_frame(
                     &sink,
                     &mut sender,
@@ -986,42 +4981,13 @@ async fn run_sender_loop(
                     &dest,
                     &mut handshake_announced,
                 ).await?;
+                video_send_delay.record(queued_at.elapsed());
                 sent_packets = sent_packets.saturating_add(1);
-            }
-            Some((audio_bytes, pts, codec, frames_per_packet)) = audio_rx.recv() => {
-                if audio_bytes.is_empty() {
-                    continue;
-                }
-
-                let audio_len = audio_bytes.len();
-                match sender
-                    .send_audio_frame(audio_stream_id, pts, codec, frames_per_packet, audio_bytes)
-                    .await
-                {
-                    Ok(_) => {
-                        println!(
-                            "DEBUG: Sankaku sender sent AUDIO packet: stream_id={} bytes={} pts_us={} codec=0x{:02X} frames_per_packet={} dest={}",
-                            audio_stream_id, audio_len, pts, codec, frames_per_packet, dest
-                        );
-                        announce_sender_handshake_if_needed(
-                            &sink,
-                            &sender,
-                            &dest,
-                            &mut handshake_announced,
-                        );
-                        if let Some(bitrate_bps) = sender.take_bitrate_update_bps() {
-                            sink_event(&sink, UiEvent::BitrateChanged { bitrate_bps });
-                        }
-                        sent_packets = sent_packets.saturating_add(1);
-                    }
-                    Err(error) => {
-                        sink_event(
-                            &sink,
-                            UiEvent::Error {
-                                msg: format!("audio send failed: {error}"),
-                            },
-                        );
-                    }
+                video_queue.set_target_bitrate(sender.target_bitrate_bps());
+                let congestion = CongestionState::new(video_queue.congestion(), true);
+                if congestion.recommended_action != last_congestion_action {
+                    last_congestion_action = congestion.recommended_action;
+                    emit_congestion(&sink, &congestion);
                 }
             }
             else => {
@@ -1046,6 +5012,14 @@ async fn run_sender_loop(
         }
     }
 
+    set_session_slot(sender_control_slot(), None)?;
+    set_session_slot(sender_datagrams_slot(), None)?;
+    set_session_slot(sender_file_commands_slot(), None)?;
+    set_session_slot(sender_pending_commands_slot(), None)?;
+    drop(file_transfers);
+    if let Some(control) = control {
+        control.close().await;
+    }
     Ok(())
 }
 
@@ -1110,7 +5084,81 @@ async fn run_receiver_loop(
         },
     );
 
-    let mut receiver = SankakuReceiver::new(connection).await?;
+    let supported_codecs = receiver_video_codecs_slot()
+        .lock()
+        .map(|guard| guard.clone())
+        .unwrap_or_else(|_| vec![VIDEO_CODEC_HEVC]);
+    let supported_audio_codecs = receiver_audio_codecs_slot()
+        .lock()
+        .map(|guard| guard.clone())
+        .unwrap_or_else(|_| vec![AUDIO_CODEC_OPUS]);
+    let negotiated = answer_codecs(&connection, &supported_codecs, &supported_audio_codecs).await?;
+    let video_codec = match negotiated.video {
+        NegotiationOutcome::Agreed(codec) => {
+            let codec = VideoCodec::from_id(codec)
+                .with_context(|| format!("negotiated unknown video codec 0x{codec:02X}"))?;
+            emit_codec_negotiated(&sink, codec, true);
+            codec
+        }
+        NegotiationOutcome::NoPeerSupport if supported_codecs.contains(&VIDEO_CODEC_HEVC) => {
+            emit_codec_negotiated(&sink, VideoCodec::Hevc, false);
+            VideoCodec::Hevc
+        }
+        outcome => {
+            let detail = match outcome {
+                NegotiationOutcome::Declined(offered) => format!(
+                    "sender offered [{}], receiver supports [{}]",
+                    codec_list(&offered),
+                    codec_list(&supported_codecs)
+                ),
+                _ => format!(
+                    "sender does not negotiate codecs and sends hevc; receiver supports [{}]",
+                    codec_list(&supported_codecs)
+                ),
+            };
+            emit_codec_declined(&sink, &detail);
+            bail!(detail);
+        }
+    };
+    let audio_format = match negotiated.audio {
+        AudioNegotiationOutcome::Agreed(offer) => {
+            let format = AudioFormat::from_config(offer.codec, &offer.config)?;
+            emit_audio_codec_negotiated(&sink, &format, true);
+            format
+        }
+        AudioNegotiationOutcome::NoPeerSupport
+            if supported_audio_codecs.contains(&AUDIO_CODEC_OPUS) =>
+        {
+            let format = AudioFormat::opus();
+            emit_audio_codec_negotiated(&sink, &format, false);
+            format
+        }
+        outcome => {
+            let detail = match outcome {
+                AudioNegotiationOutcome::Declined(offered) => format!(
+                    "sender offered audio [{}], receiver supports [{}]",
+                    audio_codec_list(&offered),
+                    audio_codec_list(&supported_audio_codecs)
+                ),
+                _ => format!(
+                    "sender does not negotiate audio codecs and sends opus; receiver supports [{}]",
+                    audio_codec_list(&supported_audio_codecs)
+                ),
+            };
+            emit_codec_declined(&sink, &detail);
+            bail!(detail);
+        }
+    };
+
+    let mut control =
+        start_control_channel(&sink, &connection, negotiated.control_stream, false).await;
+    let mut datagrams = start_datagram_channel(
+        &sink,
+        negotiated.datagrams,
+        DatagramChannel::accept(&endpoint, remote_addr.ip()),
+    )
+    .await;
+    let mut receiver = SankakuReceiver::new(connection.clone()).await?;
     receiver.update_compression_graph(&graph_bytes)?;
     let stats_reader = receiver.network_stats_reader();
     sink_event(
@@ -1123,7 +5171,57 @@ async fn run_receiver_loop(
 
     let (mut inbound_video, mut inbound_audio) = receiver.spawn_media_channels();
     let mut handshake_announced = false;
-    let mut remote_debug_report_assembly: Option<RemoteDebugReportAssembly> = None;
+    let mut video_sequences: BTreeMap<u32, FrameSequenceTracker> = BTreeMap::new();
+    // The sender announces its media state once at session start, possibly before
+    // the first frame of a stream creates its tracker.
+    let mut peer_media_state = MediaState::default();
+    let mut video_formats: BTreeMap<u32, VideoFormatTracker> = BTreeMap::new();
+    let mut parameter_sets_prepended: u64 = 0;
+    let mut audio_redundancy = AudioRedundancyDecoder::default();
+    let mut audio_inspection = AudioInspection::new();
+    let mut audio_gaps = AudioGapTracker::default();
+    let mut legacy_debug_reports =
+        (!negotiated.control_stream).then(LegacyReportAssembler::default);
+    let mut audio_concealed: u64 = 0;
+    let conceal_audio_gaps = RECEIVER_AUDIO_CONCEALMENT_ENABLED.load(Ordering::Relaxed);
+    let mut pcm_playout = PcmPlayout::start(&sink, &audio_format)?;
+    let mut software_video = SoftwareVideoDecode::start(&sink, video_codec)?;
+    clear_receiver_parameter_sets();
+    let mut lip_sync = RECEIVER_LIP_SYNC_ENABLED
+        .load(Ordering::Relaxed)
+        .then(LipSync::default);
+    // Lip-sync needs the playout clock even when jitter smoothing is off, so it
+    // falls back to a zero-delay buffer.
+    let playout_config = receiver_jitter_buffer_config().or_else(|| {
+        lip_sync.as_ref().map(|_| JitterBufferConfig {
+            min_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+        })
+    });
+    let playout_epoch = Instant::now();
+    let mut video_jitter = playout_config.map(|config| JitterBuffer::new(config, playout_epoch));
+    let mut audio_jitter = playout_config.map(|config| JitterBuffer::new(config, playout_epoch));
+    set_session_slot(
+        receiver_control_slot(),
+        control.as_ref().map(|channel| channel.sender.clone()),
+    )?;
+    set_session_slot(
+        receiver_issued_commands_slot(),
+        control.is_some().then(BTreeMap::new),
+    )?;
+    set_session_slot(
+        receiver_datagrams_slot(),
+        datagrams.as_ref().map(|channel| channel.connection.clone()),
+    )?;
+    let mut control_active = control.is_some();
+    let (file_command_tx, mut file_commands) = mpsc::unbounded_channel();
+    let mut file_transfers = control
+        .as_ref()
+        .map(|channel| FileTransfers::new(&sink, channel.sender.clone(), &connection));
+    set_session_slot(
+        receiver_file_commands_slot(),
+        file_transfers.is_some().then_some(file_command_tx),
+    )?;
     let mut shutdown_tick = tokio::time::interval(Duration::from_millis(200));
     let mut telemetry_tick = tokio::time::interval(Duration::from_secs(1));
     shutdown_tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
@@ -1145,6 +5243,95 @@ async fn run_receiver_loop(
                 if let Some(stats) = stats_reader.network_stats() {
                     emit_quic_network_telemetry(&sink, stats);
                 }
+                emit_frame_loss_telemetry(&sink, &video_sequences);
+                if let Some(control) = control.as_ref().filter(|_| control_active) {
+                    send_receiver_reports(&control.sender, &video_sequences, &video_jitter);
+                }
+                expire_issued_commands(&sink);
+                emit_jitter_buffer_telemetry(&sink, "jitter.video", &video_jitter);
+                sink_event(
+                    &sink,
+                    UiEvent::Telemetry {
+                        name: "param_sets.prepended".to_string(),
+                        value: parameter_sets_prepended,
+                    },
+                );
+                emit_jitter_buffer_telemetry(&sink, "jitter.audio", &audio_jitter);
+                let redundancy = audio_redundancy.counters();
+                for (name, value) in [
+                    ("audio.red.recovered", redundancy.recovered),
+                    ("audio.red.duplicates", redundancy.duplicates),
+                ] {
+                    sink_event(
+                        &sink,
+                        UiEvent::Telemetry {
+                            name: name.to_string(),
+                            value,
+                        },
+                    );
+                }
+                audio_inspection.emit_telemetry(&sink, "audio.rx");
+                let gap_counters = audio_gaps.counters();
+                for (name, value) in [
+                    ("audio.gaps", gap_counters.gaps),
+                    ("audio.gap_missing_us", gap_counters.missing_us),
+                    ("audio.dtx_pauses", gap_counters.dtx_pauses),
+                    ("audio.sender_pauses", gap_counters.sender_pauses),
+                    (
+                        "audio.late_after_concealment",
+                        gap_counters.late_after_concealment,
+                    ),
+                    ("audio.concealed_packets", audio_concealed),
+                ] {
+                    sink_event(
+                        &sink,
+                        UiEvent::Telemetry {
+                            name: name.to_string(),
+                            value,
+                        },
+                    );
+                }
+                if let Some(playout) = &pcm_playout {
+                    playout.emit_telemetry(&sink);
+                }
+                if let Some(decode) = &software_video {
+                    decode.emit_telemetry(&sink);
+                }
+                emit_lip_sync_telemetry(&sink, &lip_sync);
+            }
+            _ = sleep_until_playout(next_playout_deadline(&video_jitter, &audio_jitter)) => {
+                release_jitter_buffer(&mut video_jitter, &sink);
+                release_jitter_buffer(&mut audio_jitter, &sink);
+            }
+            inbound = next_control_message(&mut control), if control_active => {
+                let inbound = fold_mute_state(inbound, peer_media_state);
+                if let Some(Ok(control::ControlMessage::MediaState(state))) = &inbound {
+                    peer_media_state = *state;
+                    audio_gaps.set_paused(state.audio_paused());
+                    for tracker in video_sequences.values_mut() {
+                        tracker.set_paused(state.video_paused());
+                    }
+                }
+                control_active = handle_control_message(
+                    &sink,
+                    inbound,
+                    control.as_ref(),
+                    false,
+                    &mut file_transfers,
+                );
+            }
+            Some(command) = file_commands.recv(), if file_transfers.is_some() => {
+                if let Some(transfers) = file_transfers.as_mut() {
+                    transfers.command(command);
+                }
+            }
+            Some((transfer_id, stream)) = next_file_stream(&mut file_transfers) => {
+                if let Some(transfers) = file_transfers.as_mut() {
+                    transfers.stream_opened(transfer_id, stream);
+                }
+            }
+            Some(inbound) = next_data_datagram(&mut datagrams), if datagrams.is_some() => {
+                handle_data_datagram(&sink, inbound);
             }
             maybe_video = inbound_video.recv() => {
                 let Some(frame) = maybe_video else {
@@ -1154,7 +5341,11 @@ async fn run_receiver_loop(
                 let stream_id = frame.stream_id;
                 let frame_index = frame.frame_index;
                 let keyframe = frame.keyframe;
-                let payload = frame.payload;
+                let (payload, prepended) =
+                    complete_with_cached_parameter_sets(stream_id, video_codec, frame.payload);
+                if prepended {
+                    parameter_sets_prepended = parameter_sets_prepended.saturating_add(1);
+                }
                 let payload_len = payload.len() as u64;
 
                 if !handshake_announced {
@@ -1176,13 +5367,58 @@ async fn run_receiver_loop(
                     );
                 }
 
-                sink_event(
+                let arrival = video_sequences
+                    .entry(stream_id)
+                    .or_insert_with(|| {
+                        let mut tracker = FrameSequenceTracker::default();
+                        tracker.set_paused(peer_media_state.video_paused());
+                        tracker
+                    })
+                    .observe(frame_index, keyframe);
+                emit_frame_arrival(&sink, stream_id, frame_index, keyframe, arrival);
+                if let Some(format) = video_formats
+                    .entry(stream_id)
+                    .or_insert_with(|| VideoFormatTracker::new(video_codec))
+                    .observe_access_unit(&payload)
+                {
+                    emit_video_stream_format(&sink, stream_id, video_codec, format);
+                }
+
+                let pts = frame.timestamp_us;
+                if let Some(decode) = software_video.as_mut() {
+                    decode.submit(&sink, stream_id, pts, &payload);
+                }
+                let output_framing = if video_codec.uses_nal_units() {
+                    receiver_video_framing_slot()
+                        .lock()
+                        .map(|guard| *guard)
+                        .unwrap_or(NalFraming::AnnexB)
+                } else {
+                    NalFraming::AnnexB
+                };
+                let payload = match convert_framing(payload, NalFraming::AnnexB, output_framing) {
+                    Some(payload) => payload,
+                    None => {
+                        sink_event(
+                            &sink,
+                            UiEvent::FrameDrop {
+                                stream_id,
+                                reason: format!(
+                                    "frame_index={frame_index} does not fit {output_framing:?}"
+                                ),
+                            },
+                        );
+                        continue;
+                    }
+                };
+                buffer_or_emit_frame(
                     &sink,
-                    UiEvent::VideoFrameReceived {
-                        data: payload,
-                        pts: frame.timestamp_us,
-                    },
+                    &mut video_jitter,
+                    stream_id,
+                    pts,
+                    UiEvent::VideoFrameReceived { data: payload, pts },
                 );
+                apply_lip_sync(&mut lip_sync, &mut video_jitter, &mut audio_jitter);
                 sink_event(
                     &sink,
                     UiEvent::Progress {
@@ -1238,26 +5474,151 @@ async fn run_receiver_loop(
                 }
 
                 if codec == AUDIO_CODEC_DEBUG_TEXT {
-                    handle_remote_debug_report_payload(
+                    if let Some(assembler) = legacy_debug_reports.as_mut() {
+                        handle_legacy_debug_report(&sink, assembler, &payload);
+                        continue;
+                    }
+                }
+
+                let packet_codec = if codec == AUDIO_CODEC_OPUS_RED {
+                    AUDIO_CODEC_OPUS
+                } else {
+                    codec
+                };
+                if packet_codec != audio_format.codec.id() {
+                    sink_event(
                         &sink,
-                        &payload,
-                        &mut remote_debug_report_assembly,
+                        UiEvent::FrameDrop {
+                            stream_id,
+                            reason: format!(
+                                "audio codec 0x{codec:02X} does not match the negotiated {}",
+                                audio_format.codec.name()
+                            ),
+                        },
                     );
                     continue;
                 }
 
-                sink_event(
-                    &sink,
-                    UiEvent::AudioFrameReceived {
-                        data: payload,
+                let packets = if codec == AUDIO_CODEC_OPUS_RED {
+                    match audio_redundancy.unwrap(pts, frames_per_packet, &payload) {
+                        Ok(packets) => packets,
+                        Err(error) => {
+                            sink_event(
+                                &sink,
+                                UiEvent::FrameDrop {
+                                    stream_id,
+                                    reason: format!("malformed redundant audio: {error}"),
+                                },
+                            );
+                            continue;
+                        }
+                    }
+                } else {
+                    vec![UnwrappedAudioPacket {
                         pts,
                         frames_per_packet,
-                    },
-                );
+                        payload,
+                        recovered: false,
+                    }]
+                };
+                for mut packet in packets {
+                    // Malformed packets are counted but still handed to the player.
+                    let mut info = None;
+                    if audio_format.codec == AudioCodec::Opus {
+                        info = audio_inspection
+                            .inspect(
+                                &sink,
+                                stream_id,
+                                false,
+                                packet.pts,
+                                &packet.payload,
+                                packet.frames_per_packet,
+                            )
+                            .ok();
+                    } else if let Ok(frames) = audio_inspection.check_frame(
+                        &audio_format,
+                        &packet.payload,
+                        packet.frames_per_packet,
+                    ) {
+                        if packet.frames_per_packet == 0 {
+                            packet.frames_per_packet = frames;
+                        }
+                    }
+                    let duration_us = match (packet.frames_per_packet, info) {
+                        (0, Some(info)) => u64::from(info.duration_us()),
+                        (frames, _) => audio_format.frame_duration_us(frames),
+                    };
+                    if duration_us > 0 {
+                        let dtx = info.is_some_and(|info| info.dtx);
+                        let arrival = audio_gaps.observe(packet.pts, duration_us, dtx);
+                        if let AudioArrival::InOrder(Some(gap)) = arrival {
+                            let concealed = match pcm_playout.as_mut() {
+                                Some(playout) => playout.conceal_gap(
+                                    &sink,
+                                    &mut audio_jitter,
+                                    stream_id,
+                                    &gap,
+                                    &packet.payload,
+                                ),
+                                None if conceal_audio_gaps => emit_concealment_placeholders(
+                                    &sink,
+                                    &mut audio_jitter,
+                                    stream_id,
+                                    &gap,
+                                    audio_format.sample_rate,
+                                ),
+                                None => 0,
+                            };
+                            emit_audio_gap(&sink, stream_id, &gap, concealed);
+                            audio_gaps.record_concealment(&gap, concealed);
+                            audio_concealed = audio_concealed.saturating_add(u64::from(concealed));
+                        }
+                        // Concealment already played in its place.
+                        if arrival == (AudioArrival::Late { concealed: true }) {
+                            continue;
+                        }
+                        if let (AudioArrival::Late { .. }, Some(playout)) =
+                            (arrival, pcm_playout.as_mut())
+                        {
+                            playout.drop_late();
+                            continue;
+                        }
+                    }
+                    match pcm_playout.as_mut() {
+                        Some(playout) => playout.deliver(
+                            &sink,
+                            &mut audio_jitter,
+                            stream_id,
+                            packet.pts,
+                            &packet.payload,
+                        ),
+                        None => buffer_or_emit_frame(
+                            &sink,
+                            &mut audio_jitter,
+                            stream_id,
+                            packet.pts,
+                            UiEvent::AudioFrameReceived {
+                                data: packet.payload,
+                                pts: packet.pts,
+                                frames_per_packet: packet.frames_per_packet,
+                            },
+                        ),
+                    }
+                }
+                apply_lip_sync(&mut lip_sync, &mut video_jitter, &mut audio_jitter);
             }
         }
     };
 
+    set_session_slot(receiver_control_slot(), None)?;
+    set_session_slot(receiver_datagrams_slot(), None)?;
+    set_session_slot(receiver_file_commands_slot(), None)?;
+    fail_issued_commands(&sink, "receiver session ended before the sender replied");
+    set_session_slot(receiver_issued_commands_slot(), None)?;
+    drop(file_transfers);
+    if let Some(control) = control {
+        control.close().await;
+    }
     sink_event(
         &sink,
         UiEvent::ConnectionState {
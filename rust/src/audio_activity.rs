@@ -0,0 +1,156 @@
+use crate::opus::OpusPacketInfo;
+
+/// Packets at or above this bitrate count as speech. Opus VBR spends far fewer
+/// bits on background noise than on voice, so packet size is a usable activity
+/// signal without decoding.
+const VOICED_BITS_PER_MS: u64 = 12;
+/// How long activity must stay below the threshold before the stream is reported
+/// silent, so pauses between words do not flicker the talk indicator.
+const SILENCE_HANG_US: u64 = 400_000;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AudioActivitySummary {
+    /// Share of the interval's audio that was voiced, 0..=100.
+    pub activity_percent: u64,
+    pub bitrate_bps: u64,
+    pub dtx_packets: u64,
+    pub bandwidth_hz: u64,
+    pub frame_duration_us: u64,
+}
+
+#[derive(Debug, Default)]
+struct ActivityInterval {
+    duration_us: u64,
+    voiced_us: u64,
+    bytes: u64,
+    dtx_packets: u64,
+    last_info: Option<OpusPacketInfo>,
+}
+
+/// Speaking/silent state and per-interval packet statistics of one Opus stream.
+/// Everything is derived from packet sizes and TOC bytes; no signal level is
+/// measured, since that would need decoding.
+#[derive(Debug, Default)]
+pub struct AudioActivityMonitor {
+    speaking: bool,
+    last_voiced_pts: Option<u64>,
+    interval: ActivityInterval,
+}
+
+impl AudioActivityMonitor {
+    /// Records one packet. Returns the new state when the stream starts or stops
+    /// speaking.
+    pub fn observe(&mut self, pts: u64, info: &OpusPacketInfo, bytes: usize) -> Option<bool> {
+        let duration_us = u64::from(info.duration_us());
+        let voiced = !info.dtx
+            && duration_us > 0
+            && (bytes as u64).saturating_mul(8_000) / duration_us >= VOICED_BITS_PER_MS;
+
+        let interval = &mut self.interval;
+        interval.duration_us = interval.duration_us.saturating_add(duration_us);
+        interval.bytes = interval.bytes.saturating_add(bytes as u64);
+        if voiced {
+            interval.voiced_us = interval.voiced_us.saturating_add(duration_us);
+        }
+        if info.dtx {
+            interval.dtx_packets = interval.dtx_packets.saturating_add(1);
+        }
+        interval.last_info = Some(*info);
+
+        if voiced {
+            self.last_voiced_pts = Some(pts);
+        }
+        let speaking = self
+            .last_voiced_pts
+            .is_some_and(|last| pts.saturating_sub(last) <= SILENCE_HANG_US);
+        if speaking == self.speaking {
+            return None;
+        }
+        self.speaking = speaking;
+        Some(speaking)
+    }
+
+    /// Returns the statistics gathered since the previous call, or `None` when no
+    /// audio arrived.
+    pub fn take_interval(&mut self) -> Option<AudioActivitySummary> {
+        let interval = std::mem::take(&mut self.interval);
+        let info = interval.last_info?;
+        if interval.duration_us == 0 {
+            return None;
+        }
+        Some(AudioActivitySummary {
+            activity_percent: interval.voiced_us * 100 / interval.duration_us,
+            bitrate_bps: interval.bytes.saturating_mul(8_000_000) / interval.duration_us,
+            dtx_packets: interval.dtx_packets,
+            bandwidth_hz: u64::from(info.bandwidth.cutoff_hz()),
+            frame_duration_us: u64::from(info.frame_duration_us),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opus::{OpusBandwidth, OpusMode};
+
+    const PACKET_US: u64 = 20_000;
+    /// Smallest 20 ms packet at the voiced bitrate threshold.
+    const VOICED_BYTES: usize = 30;
+
+    fn packet(dtx: bool) -> OpusPacketInfo {
+        OpusPacketInfo {
+            mode: OpusMode::Silk,
+            bandwidth: OpusBandwidth::Wideband,
+            stereo: false,
+            frame_duration_us: PACKET_US as u32,
+            frame_count: 1,
+            dtx,
+        }
+    }
+
+    #[test]
+    fn packets_at_the_bitrate_threshold_are_speech() {
+        let mut quiet = AudioActivityMonitor::default();
+        assert_eq!(quiet.observe(0, &packet(false), VOICED_BYTES - 1), None);
+
+        let mut voiced = AudioActivityMonitor::default();
+        assert_eq!(voiced.observe(0, &packet(false), VOICED_BYTES), Some(true));
+    }
+
+    #[test]
+    fn dtx_packets_are_never_speech() {
+        let mut monitor = AudioActivityMonitor::default();
+        assert_eq!(monitor.observe(0, &packet(true), 200), None);
+    }
+
+    #[test]
+    fn silence_is_reported_after_the_hangover() {
+        let mut monitor = AudioActivityMonitor::default();
+        assert_eq!(monitor.observe(0, &packet(false), 80), Some(true));
+        for pts in (PACKET_US..=SILENCE_HANG_US).step_by(PACKET_US as usize) {
+            assert_eq!(monitor.observe(pts, &packet(true), 1), None, "pts {pts}");
+        }
+        assert_eq!(
+            monitor.observe(SILENCE_HANG_US + PACKET_US, &packet(true), 1),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn interval_summarises_voiced_share_bitrate_and_dtx() {
+        let mut monitor = AudioActivityMonitor::default();
+        assert!(monitor.take_interval().is_none());
+
+        monitor.observe(0, &packet(false), 40);
+        monitor.observe(20_000, &packet(false), 40);
+        monitor.observe(40_000, &packet(true), 1);
+        monitor.observe(60_000, &packet(true), 1);
+        let summary = monitor.take_interval().unwrap();
+        assert_eq!(summary.activity_percent, 50);
+        assert_eq!(summary.bitrate_bps, 8_200);
+        assert_eq!(summary.dtx_packets, 2);
+        assert_eq!(summary.bandwidth_hz, 8_000);
+        assert_eq!(summary.frame_duration_us, PACKET_US);
+        assert!(monitor.take_interval().is_none());
+    }
+}
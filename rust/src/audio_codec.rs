@@ -0,0 +1,343 @@
+use crate::bitstream::BitReader;
+use crate::codec::{AUDIO_CODEC_AAC_LC, AUDIO_CODEC_PCM_S16LE};
+use crate::opus;
+use anyhow::{bail, Context};
+use sankaku_core::AUDIO_CODEC_OPUS;
+
+const OPUS_SAMPLE_RATE_HZ: u32 = 48_000;
+const AAC_OBJECT_TYPE_LC: u32 = 2;
+/// `sampling_frequency_index` values 0..=12 of ISO/IEC 14496-3 table 1.18.
+const AAC_SAMPLE_RATES: [u32; 13] = [
+    96_000, 88_200, 64_000, 48_000, 44_100, 32_000, 24_000, 22_050, 16_000, 12_000, 11_025, 8_000,
+    7_350,
+];
+/// Index that is followed by an explicit 24-bit sampling frequency.
+const AAC_EXPLICIT_FREQUENCY_INDEX: u32 = 0x0F;
+/// A raw AAC frame holds at most 6144 bits per channel.
+const AAC_MAX_FRAME_BYTES_PER_CHANNEL: usize = 768;
+const ADTS_HEADER_BYTES: usize = 7;
+const ADTS_CRC_BYTES: usize = 2;
+/// PCM configuration: sample rate (u32 BE) followed by the channel count (u8).
+const PCM_CONFIG_BYTES: usize = 5;
+const PCM_SAMPLE_RATES: std::ops::RangeInclusive<u32> = 8_000..=192_000;
+const MAX_PCM_CHANNELS: u8 = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioCodec {
+    Opus,
+    AacLc,
+    PcmS16,
+}
+
+impl AudioCodec {
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            AUDIO_CODEC_OPUS => Some(AudioCodec::Opus),
+            AUDIO_CODEC_AAC_LC => Some(AudioCodec::AacLc),
+            AUDIO_CODEC_PCM_S16LE => Some(AudioCodec::PcmS16),
+            _ => None,
+        }
+    }
+
+    pub fn id(self) -> u8 {
+        match self {
+            AudioCodec::Opus => AUDIO_CODEC_OPUS,
+            AudioCodec::AacLc => AUDIO_CODEC_AAC_LC,
+            AudioCodec::PcmS16 => AUDIO_CODEC_PCM_S16LE,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            AudioCodec::Opus => "opus",
+            AudioCodec::AacLc => "aac_lc",
+            AudioCodec::PcmS16 => "pcm_s16le",
+        }
+    }
+}
+
+/// An audio codec together with the out-of-band configuration a decoder needs:
+/// the AudioSpecificConfig for AAC-LC, sample rate and channel count for PCM.
+/// Opus describes itself in every packet and carries no configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioFormat {
+    pub codec: AudioCodec,
+    pub sample_rate: u32,
+    /// Zero for Opus, whose channel count is signalled per packet.
+    pub channels: u8,
+    /// Samples per channel in every frame, or zero when frames vary in length.
+    pub frame_samples: u32,
+    pub config: Vec<u8>,
+}
+
+impl AudioFormat {
+    pub fn opus() -> Self {
+        Self {
+            codec: AudioCodec::Opus,
+            sample_rate: OPUS_SAMPLE_RATE_HZ,
+            channels: 0,
+            frame_samples: 0,
+            config: Vec::new(),
+        }
+    }
+
+    /// Parses an AAC AudioSpecificConfig; only the AAC-LC object type is accepted.
+    /// Explicit sampling frequencies (index 15) are rejected, since ADTS headers
+    /// and most platform decoders can only express the table rates.
+    pub fn aac_lc(audio_specific_config: Vec<u8>) -> anyhow::Result<Self> {
+        let mut reader = BitReader::new(&audio_specific_config);
+        let truncated = || "AudioSpecificConfig is truncated";
+        let mut object_type = reader.read_bits(5).with_context(truncated)?;
+        if object_type == 31 {
+            object_type = 32 + reader.read_bits(6).with_context(truncated)?;
+        }
+        if object_type != AAC_OBJECT_TYPE_LC {
+            bail!("only AAC-LC (object type 2) is supported, got object type {object_type}");
+        }
+        let frequency_index = reader.read_bits(4).with_context(truncated)?;
+        if frequency_index == AAC_EXPLICIT_FREQUENCY_INDEX {
+            bail!("explicit AAC sampling frequencies are not supported");
+        }
+        let sample_rate = *AAC_SAMPLE_RATES
+            .get(frequency_index as usize)
+            .with_context(|| format!("reserved AAC sampling frequency index {frequency_index}"))?;
+        let channel_configuration = reader.read_bits(4).with_context(truncated)?;
+        let channels = aac_channel_count(channel_configuration)?;
+        let frame_samples = if reader.read_bit().with_context(truncated)? {
+            960
+        } else {
+            1024
+        };
+        Ok(Self {
+            codec: AudioCodec::AacLc,
+            sample_rate,
+            channels,
+            frame_samples,
+            config: audio_specific_config,
+        })
+    }
+
+    pub fn pcm_s16(sample_rate: u32, channels: u8) -> anyhow::Result<Self> {
+        if !PCM_SAMPLE_RATES.contains(&sample_rate) {
+            bail!("unsupported PCM sample rate: {sample_rate} Hz");
+        }
+        if channels == 0 || channels > MAX_PCM_CHANNELS {
+            bail!("unsupported PCM channel count: {channels}");
+        }
+        let mut config = Vec::with_capacity(PCM_CONFIG_BYTES);
+        config.extend_from_slice(&sample_rate.to_be_bytes());
+        config.push(channels);
+        Ok(Self {
+            codec: AudioCodec::PcmS16,
+            sample_rate,
+            channels,
+            frame_samples: 0,
+            config,
+        })
+    }
+
+    /// Rebuilds a format from a codec id and the configuration sent during
+    /// negotiation.
+    pub fn from_config(codec: u8, config: &[u8]) -> anyhow::Result<Self> {
+        match AudioCodec::from_id(codec) {
+            Some(AudioCodec::Opus) => Ok(Self::opus()),
+            Some(AudioCodec::AacLc) => Self::aac_lc(config.to_vec()),
+            Some(AudioCodec::PcmS16) => {
+                let config: [u8; PCM_CONFIG_BYTES] = config
+                    .try_into()
+                    .with_context(|| format!("PCM config must be {PCM_CONFIG_BYTES} bytes"))?;
+                let sample_rate = u32::from_be_bytes([config[0], config[1], config[2], config[3]]);
+                Self::pcm_s16(sample_rate, config[4])
+            }
+            None => bail!("unsupported audio codec: 0x{codec:02X}"),
+        }
+    }
+
+    pub fn frame_duration_us(&self, frames_per_packet: u32) -> u64 {
+        u64::from(frames_per_packet) * 1_000_000 / u64::from(self.sample_rate)
+    }
+
+    /// Checks that `payload` is one well-formed frame and returns its samples per
+    /// channel. AAC frames are expected without an ADTS header.
+    pub fn check_frame(&self, payload: &[u8]) -> anyhow::Result<u32> {
+        if payload.is_empty() {
+            bail!("{} frame is empty", self.codec.name());
+        }
+        match self.codec {
+            AudioCodec::Opus => Ok(opus::parse_packet(payload)?.samples_48k()),
+            AudioCodec::AacLc => {
+                let max_bytes = AAC_MAX_FRAME_BYTES_PER_CHANNEL * usize::from(self.channels);
+                if payload.len() > max_bytes {
+                    bail!(
+                        "AAC frame of {} bytes exceeds the {max_bytes}-byte limit for {} channels",
+                        payload.len(),
+                        self.channels
+                    );
+                }
+                Ok(self.frame_samples)
+            }
+            AudioCodec::PcmS16 => {
+                let bytes_per_frame = 2 * usize::from(self.channels);
+                if !payload.len().is_multiple_of(bytes_per_frame) {
+                    bail!(
+                        "PCM frame of {} bytes is not a whole number of {}-channel samples",
+                        payload.len(),
+                        self.channels
+                    );
+                }
+                u32::try_from(payload.len() / bytes_per_frame).context("PCM frame is too large")
+            }
+        }
+    }
+
+    /// Removes the ADTS header an AAC encoder may prepend, after checking it
+    /// against the negotiated AudioSpecificConfig. Other payloads are returned
+    /// unchanged.
+    pub fn strip_adts(&self, payload: Vec<u8>) -> anyhow::Result<Vec<u8>> {
+        if self.codec != AudioCodec::AacLc {
+            return Ok(payload);
+        }
+        if payload.len() < 2 || payload[0] != 0xFF || payload[1] & 0xF0 != 0xF0 {
+            return Ok(payload);
+        }
+        let protection_absent = payload[1] & 0x01 != 0;
+        let header_bytes = if protection_absent {
+            ADTS_HEADER_BYTES
+        } else {
+            ADTS_HEADER_BYTES + ADTS_CRC_BYTES
+        };
+        if payload.len() < header_bytes {
+            bail!("ADTS header is truncated");
+        }
+        let object_type = u32::from(payload[2] >> 6) + 1;
+        let frequency_index = usize::from((payload[2] >> 2) & 0x0F);
+        let channel_configuration = u32::from(((payload[2] & 0x01) << 2) | (payload[3] >> 6));
+        let frame_length = (usize::from(payload[3] & 0x03) << 11)
+            | (usize::from(payload[4]) << 3)
+            | usize::from(payload[5] >> 5);
+        let raw_data_blocks = usize::from(payload[6] & 0x03) + 1;
+
+        if object_type != AAC_OBJECT_TYPE_LC {
+            bail!("ADTS frame carries object type {object_type}, not AAC-LC");
+        }
+        if AAC_SAMPLE_RATES.get(frequency_index) != Some(&self.sample_rate)
+            || aac_channel_count(channel_configuration).ok() != Some(self.channels)
+        {
+            bail!("ADTS header does not match the negotiated AudioSpecificConfig");
+        }
+        if frame_length != payload.len() {
+            bail!(
+                "ADTS frame length {frame_length} does not match the {}-byte payload",
+                payload.len()
+            );
+        }
+        if raw_data_blocks != 1 {
+            bail!("ADTS frames with {raw_data_blocks} raw data blocks are not supported");
+        }
+        Ok(payload[header_bytes..].to_vec())
+    }
+}
+
+fn aac_channel_count(channel_configuration: u32) -> anyhow::Result<u8> {
+    match channel_configuration {
+        1..=6 => Ok(channel_configuration as u8),
+        7 => Ok(8),
+        _ => bail!("unsupported AAC channel configuration {channel_configuration}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitstream::test_support::BitWriter;
+
+    /// AudioSpecificConfig with a table sampling frequency.
+    fn asc(object_type: u64, frequency_index: u64, channels: u64, short_frames: bool) -> Vec<u8> {
+        let mut writer = BitWriter::default();
+        writer
+            .bits(object_type, 5)
+            .bits(frequency_index, 4)
+            .bits(channels, 4)
+            .bit(short_frames)
+            .bits(0, 2);
+        writer.finish()
+    }
+
+    /// ADTS header without CRC for an AAC-LC frame of `frame_length` bytes,
+    /// header included.
+    fn adts(frequency_index: u8, channels: u8, frame_length: usize) -> Vec<u8> {
+        vec![
+            0xFF,
+            0xF1,
+            (1 << 6) | (frequency_index << 2) | (channels >> 2),
+            ((channels & 0x03) << 6) | (frame_length >> 11) as u8,
+            (frame_length >> 3) as u8,
+            ((frame_length & 0x07) << 5) as u8 | 0x1F,
+            0xFC,
+        ]
+    }
+
+    #[test]
+    fn aac_lc_config_is_parsed() {
+        let format = AudioFormat::aac_lc(asc(2, 4, 2, false)).unwrap();
+        assert_eq!(format.codec, AudioCodec::AacLc);
+        assert_eq!(format.sample_rate, 44_100);
+        assert_eq!(format.channels, 2);
+        assert_eq!(format.frame_samples, 1024);
+        assert_eq!(format.frame_duration_us(1024), 23_219);
+
+        let short = AudioFormat::aac_lc(asc(2, 3, 7, true)).unwrap();
+        assert_eq!(short.channels, 8);
+        assert_eq!(short.frame_samples, 960);
+    }
+
+    #[test]
+    fn unsupported_aac_configs_are_rejected() {
+        // HE-AAC, a reserved frequency index, the explicit frequency escape, and
+        // channel configuration 0 (layout in a program config element).
+        assert!(AudioFormat::aac_lc(asc(5, 4, 2, false)).is_err());
+        assert!(AudioFormat::aac_lc(asc(2, 13, 2, false)).is_err());
+        assert!(AudioFormat::aac_lc(asc(2, 15, 2, false)).is_err());
+        assert!(AudioFormat::aac_lc(asc(2, 4, 0, false)).is_err());
+        assert!(AudioFormat::aac_lc(vec![0x12]).is_err());
+    }
+
+    #[test]
+    fn adts_headers_are_checked_and_stripped() {
+        let format = AudioFormat::aac_lc(asc(2, 4, 2, false)).unwrap();
+        let raw = vec![0x21, 0x10, 0x05];
+        let mut framed = adts(4, 2, ADTS_HEADER_BYTES + raw.len());
+        framed.extend_from_slice(&raw);
+        assert_eq!(format.strip_adts(framed.clone()).unwrap(), raw);
+        assert_eq!(format.strip_adts(raw.clone()).unwrap(), raw);
+
+        let mut other_rate = adts(3, 2, ADTS_HEADER_BYTES + raw.len());
+        other_rate.extend_from_slice(&raw);
+        assert!(format.strip_adts(other_rate).is_err());
+        framed.push(0);
+        assert!(format.strip_adts(framed).is_err());
+        assert!(format.strip_adts(vec![0xFF, 0xF1, 0x50]).is_err());
+    }
+
+    #[test]
+    fn pcm_config_round_trips_and_checks_frames() {
+        let format = AudioFormat::pcm_s16(16_000, 2).unwrap();
+        assert_eq!(
+            AudioFormat::from_config(AUDIO_CODEC_PCM_S16LE, &format.config).unwrap(),
+            format
+        );
+        assert_eq!(format.check_frame(&[0; 640]).unwrap(), 160);
+        assert!(format.check_frame(&[0; 642]).is_err());
+        assert!(AudioFormat::pcm_s16(4_000, 1).is_err());
+        assert!(AudioFormat::pcm_s16(48_000, 9).is_err());
+        assert!(AudioFormat::from_config(AUDIO_CODEC_PCM_S16LE, &[0; 4]).is_err());
+    }
+
+    #[test]
+    fn opus_needs_no_config() {
+        let format = AudioFormat::from_config(AUDIO_CODEC_OPUS, &[]).unwrap();
+        assert_eq!(format, AudioFormat::opus());
+        assert!(format.check_frame(&[]).is_err());
+        assert!(AudioFormat::from_config(0x7F, &[]).is_err());
+    }
+}
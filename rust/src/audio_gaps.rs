@@ -0,0 +1,279 @@
+use std::collections::VecDeque;
+use std::ops::Range;
+
+/// Most audio filled with concealment placeholders; beyond this the player should
+/// resynchronise instead of synthesising audio. Packets longer than this still get
+/// one placeholder.
+const MAX_CONCEALMENT_US: u64 = 200_000;
+/// Concealed ranges remembered so their packets are recognised if they arrive
+/// after all.
+const MAX_CONCEALED_RANGES: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AudioGap {
+    pub expected_pts: u64,
+    pub missing_us: u64,
+    /// Whole packets of the previous packet's duration that fit in the gap.
+    pub missing_packets: u32,
+    pub packet_duration_us: u64,
+}
+
+impl AudioGap {
+    /// Placeholder packets that stand in for the missing audio: one per missing
+    /// packet, covering at most `MAX_CONCEALMENT_US`.
+    pub fn concealment_packets(&self) -> u32 {
+        let limit = (MAX_CONCEALMENT_US / self.packet_duration_us.max(1)).max(1);
+        self.missing_packets
+            .min(u32::try_from(limit).unwrap_or(u32::MAX))
+    }
+
+    /// Pts of the placeholder packets.
+    pub fn concealment_pts(&self) -> impl Iterator<Item = u64> + '_ {
+        (0..u64::from(self.concealment_packets()))
+            .map(|index| self.expected_pts + index * self.packet_duration_us)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioArrival {
+    /// At or ahead of the expected pts, with the gap before it, if any.
+    InOrder(Option<AudioGap>),
+    /// Behind the expected pts: reordered or duplicated. `concealed` is set when
+    /// concealment already stood in for its pts, so it must not be played.
+    Late { concealed: bool },
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AudioGapCounters {
+    pub gaps: u64,
+    pub missing_us: u64,
+    /// Jumps after a DTX packet, where the sender paused on purpose.
+    pub dtx_pauses: u64,
+    /// Jumps while the sender announced the stream as paused, e.g. muted.
+    pub sender_pauses: u64,
+    /// Packets that arrived after concealment had already covered them.
+    pub late_after_concealment: u64,
+}
+
+/// Follows the expected pts of one audio stream and reports discontinuities.
+#[derive(Debug, Default)]
+pub struct AudioGapTracker {
+    expected_pts: Option<u64>,
+    last_duration_us: u64,
+    last_dtx: bool,
+    paused: bool,
+    pause_pending: bool,
+    concealed: VecDeque<Range<u64>>,
+    counters: AudioGapCounters,
+}
+
+impl AudioGapTracker {
+    pub fn counters(&self) -> AudioGapCounters {
+        self.counters
+    }
+
+    /// Follows the sender's announcements that it stopped sending on purpose. A
+    /// jump while paused, or the first one after resuming, is not a gap.
+    pub fn set_paused(&mut self, paused: bool) {
+        if paused {
+            self.pause_pending = true;
+        }
+        self.paused = paused;
+    }
+
+    /// Records that the first `packets` placeholders of `gap` were played.
+    pub fn record_concealment(&mut self, gap: &AudioGap, packets: u32) {
+        if packets == 0 {
+            return;
+        }
+        if self.concealed.len() == MAX_CONCEALED_RANGES {
+            self.concealed.pop_front();
+        }
+        let end = gap.expected_pts + u64::from(packets) * gap.packet_duration_us;
+        self.concealed.push_back(gap.expected_pts..end);
+    }
+
+    /// Records a packet of `duration_us` starting at `pts` and reports the gap
+    /// before it, if any. Offsets of up to half a packet either way are timestamp
+    /// jitter; packets further behind (reordered or duplicated) leave the expected
+    /// pts unchanged.
+    pub fn observe(&mut self, pts: u64, duration_us: u64, dtx: bool) -> AudioArrival {
+        let mut gap = None;
+        let paused = std::mem::replace(&mut self.pause_pending, self.paused);
+        if let Some(expected_pts) = self.expected_pts {
+            if pts.saturating_add(self.last_duration_us / 2) < expected_pts {
+                let middle = pts.saturating_add(duration_us / 2);
+                let concealed = self.concealed.iter().any(|range| range.contains(&middle));
+                if concealed {
+                    self.counters.late_after_concealment =
+                        self.counters.late_after_concealment.saturating_add(1);
+                }
+                return AudioArrival::Late { concealed };
+            }
+            let missing_us = pts.saturating_sub(expected_pts);
+            if self.last_duration_us > 0 && missing_us > self.last_duration_us / 2 {
+                if self.last_dtx {
+                    self.counters.dtx_pauses = self.counters.dtx_pauses.saturating_add(1);
+                } else if paused {
+                    self.counters.sender_pauses = self.counters.sender_pauses.saturating_add(1);
+                } else {
+                    self.counters.gaps = self.counters.gaps.saturating_add(1);
+                    self.counters.missing_us = self.counters.missing_us.saturating_add(missing_us);
+                    gap = Some(AudioGap {
+                        expected_pts,
+                        missing_us,
+                        missing_packets: u32::try_from(
+                            (missing_us + self.last_duration_us / 2) / self.last_duration_us,
+                        )
+                        .unwrap_or(u32::MAX),
+                        packet_duration_us: self.last_duration_us,
+                    });
+                }
+            }
+        }
+        self.expected_pts = Some(pts.saturating_add(duration_us));
+        self.last_duration_us = duration_us;
+        self.last_dtx = dtx;
+        AudioArrival::InOrder(gap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PACKET_US: u64 = 20_000;
+
+    fn tracker_at(pts: u64) -> AudioGapTracker {
+        let mut tracker = AudioGapTracker::default();
+        assert_eq!(
+            tracker.observe(pts, PACKET_US, false),
+            AudioArrival::InOrder(None)
+        );
+        tracker
+    }
+
+    #[test]
+    fn jitter_within_half_a_packet_is_not_a_gap() {
+        let mut tracker = tracker_at(0);
+        assert_eq!(
+            tracker.observe(29_000, PACKET_US, false),
+            AudioArrival::InOrder(None)
+        );
+        assert_eq!(
+            tracker.observe(48_000, PACKET_US, false),
+            AudioArrival::InOrder(None)
+        );
+        assert_eq!(tracker.counters().gaps, 0);
+    }
+
+    #[test]
+    fn skipped_packets_are_reported_and_concealed() {
+        let mut tracker = tracker_at(0);
+        let AudioArrival::InOrder(Some(gap)) = tracker.observe(80_000, PACKET_US, false) else {
+            panic!("expected a gap");
+        };
+        assert_eq!(
+            gap,
+            AudioGap {
+                expected_pts: 20_000,
+                missing_us: 60_000,
+                missing_packets: 3,
+                packet_duration_us: PACKET_US,
+            }
+        );
+        assert_eq!(gap.concealment_packets(), 3);
+        assert_eq!(
+            gap.concealment_pts().collect::<Vec<_>>(),
+            [20_000, 40_000, 60_000]
+        );
+        let counters = tracker.counters();
+        assert_eq!(counters.gaps, 1);
+        assert_eq!(counters.missing_us, 60_000);
+    }
+
+    #[test]
+    fn concealment_covers_at_most_200ms() {
+        let gap = |missing_packets, packet_duration_us| AudioGap {
+            expected_pts: 0,
+            missing_us: u64::from(missing_packets) * packet_duration_us,
+            missing_packets,
+            packet_duration_us,
+        };
+        assert_eq!(gap(20, 20_000).concealment_packets(), 10);
+        assert_eq!(gap(10, 60_000).concealment_packets(), 3);
+        assert_eq!(gap(2, 120_000).concealment_packets(), 1);
+        assert_eq!(gap(1, 120_000).concealment_packets(), 1);
+    }
+
+    #[test]
+    fn reordered_packets_leave_the_expected_pts_alone() {
+        let mut tracker = tracker_at(100_000);
+        assert_eq!(
+            tracker.observe(60_000, PACKET_US, false),
+            AudioArrival::Late { concealed: false }
+        );
+        assert_eq!(
+            tracker.observe(120_000, PACKET_US, false),
+            AudioArrival::InOrder(None)
+        );
+        assert_eq!(tracker.counters().gaps, 0);
+    }
+
+    #[test]
+    fn packets_arriving_after_their_concealment_are_flagged() {
+        let mut tracker = tracker_at(0);
+        let AudioArrival::InOrder(Some(gap)) = tracker.observe(80_000, PACKET_US, false) else {
+            panic!("expected a gap");
+        };
+        // Only the first two of the three missing packets were concealed.
+        tracker.record_concealment(&gap, 2);
+        assert_eq!(
+            tracker.observe(40_000, PACKET_US, false),
+            AudioArrival::Late { concealed: true }
+        );
+        assert_eq!(
+            tracker.observe(60_000, PACKET_US, false),
+            AudioArrival::Late { concealed: false }
+        );
+        assert_eq!(
+            tracker.observe(100_000, PACKET_US, false),
+            AudioArrival::InOrder(None)
+        );
+        assert_eq!(tracker.counters().late_after_concealment, 1);
+    }
+
+    #[test]
+    fn intentional_pauses_are_not_gaps() {
+        let mut tracker = tracker_at(0);
+        assert_eq!(
+            tracker.observe(20_000, PACKET_US, true),
+            AudioArrival::InOrder(None)
+        );
+        assert_eq!(
+            tracker.observe(500_000, PACKET_US, false),
+            AudioArrival::InOrder(None)
+        );
+
+        tracker.set_paused(true);
+        assert_eq!(
+            tracker.observe(900_000, PACKET_US, false),
+            AudioArrival::InOrder(None)
+        );
+        tracker.set_paused(false);
+        // The first jump after resuming still belongs to the pause.
+        assert_eq!(
+            tracker.observe(1_500_000, PACKET_US, false),
+            AudioArrival::InOrder(None)
+        );
+        assert!(matches!(
+            tracker.observe(1_600_000, PACKET_US, false),
+            AudioArrival::InOrder(Some(_))
+        ));
+
+        let counters = tracker.counters();
+        assert_eq!(counters.dtx_pauses, 1);
+        assert_eq!(counters.sender_pauses, 2);
+        assert_eq!(counters.gaps, 1);
+    }
+}
@@ -0,0 +1,307 @@
+use anyhow::{bail, Context};
+use std::collections::{BTreeSet, VecDeque};
+
+/// Most previous packets a sender may repeat in each payload.
+pub const MAX_REDUNDANCY_DEPTH: usize = 4;
+/// Per redundant block: pts offset (u32 BE, microseconds behind the primary),
+/// frames per packet (u16 BE, samples at 48 kHz) and block length (u16 BE).
+const BLOCK_HEADER_BYTES: usize = 4 + 2 + 2;
+/// Stream time behind the newest delivered packet for which the decoder remembers
+/// what it delivered. Older packets are dropped, and a primary packet this far
+/// behind the window is taken as a sender restart.
+const DELIVERY_WINDOW_US: u64 = 2_000_000;
+
+/// Redundant audio payload, adapted from RFC 2198 for a transport that already
+/// carries the primary packet's pts and frame count:
+///
+/// ```text
+/// count: u8
+/// count x { pts_offset_us: u32, frames_per_packet: u16, length: u16 }
+/// count x redundant packet, oldest first
+/// primary packet (rest of the payload)
+/// ```
+#[derive(Debug)]
+pub struct AudioRedundancyEncoder {
+    depth: usize,
+    history: VecDeque<(u64, u32, Vec<u8>)>,
+}
+
+impl AudioRedundancyEncoder {
+    pub fn new(depth: usize) -> Self {
+        let depth = depth.min(MAX_REDUNDANCY_DEPTH);
+        Self {
+            depth,
+            history: VecDeque::with_capacity(depth),
+        }
+    }
+
+    /// Wraps `payload` together with copies of the previous packets, then remembers
+    /// it for the following calls.
+    pub fn wrap(&mut self, pts: u64, frames_per_packet: u32, payload: Vec<u8>) -> Vec<u8> {
+        let blocks: Vec<(u32, u16, &[u8])> = self
+            .history
+            .iter()
+            .filter_map(|(block_pts, block_frames, block)| {
+                let offset = u32::try_from(pts.checked_sub(*block_pts)?).ok()?;
+                let frames = u16::try_from(*block_frames).ok()?;
+                u16::try_from(block.len()).ok()?;
+                (offset > 0).then_some((offset, frames, block.as_slice()))
+            })
+            .collect();
+
+        let redundant_bytes: usize = blocks.iter().map(|(_, _, block)| block.len()).sum();
+        let mut wrapped = Vec::with_capacity(
+            1 + blocks.len() * BLOCK_HEADER_BYTES + redundant_bytes + payload.len(),
+        );
+        wrapped.push(blocks.len() as u8);
+        for (offset, frames, block) in &blocks {
+            wrapped.extend_from_slice(&offset.to_be_bytes());
+            wrapped.extend_from_slice(&frames.to_be_bytes());
+            wrapped.extend_from_slice(&(block.len() as u16).to_be_bytes());
+        }
+        for (_, _, block) in &blocks {
+            wrapped.extend_from_slice(block);
+        }
+        wrapped.extend_from_slice(&payload);
+
+        if self.depth > 0 {
+            if self.history.len() == self.depth {
+                self.history.pop_front();
+            }
+            self.history.push_back((pts, frames_per_packet, payload));
+        }
+        wrapped
+    }
+}
+
+#[derive(Debug)]
+pub struct UnwrappedAudioPacket {
+    pub pts: u64,
+    pub frames_per_packet: u32,
+    pub payload: Vec<u8>,
+    /// Rebuilt from a redundant copy because its own packet never arrived.
+    pub recovered: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AudioRedundancyCounters {
+    pub recovered: u64,
+    /// Redundant or primary copies of packets that were already delivered, or
+    /// that fall behind the delivery window.
+    pub duplicates: u64,
+}
+
+/// Unbundles redundant payloads and delivers each packet once. Packets come out
+/// oldest first within a payload; a packet reordered behind newer ones is still
+/// delivered, late, as long as it is inside the delivery window.
+#[derive(Debug, Default)]
+pub struct AudioRedundancyDecoder {
+    /// Pts of delivered packets at or after `floor`.
+    delivered: BTreeSet<u64>,
+    /// Nothing older is delivered: the first packet seen, then the start of the
+    /// delivery window.
+    floor: Option<u64>,
+    counters: AudioRedundancyCounters,
+}
+
+impl AudioRedundancyDecoder {
+    pub fn counters(&self) -> AudioRedundancyCounters {
+        self.counters
+    }
+
+    /// Returns the packets in `payload` that have not been delivered yet, oldest
+    /// first. Copies from before the first delivered packet are ignored, so joining
+    /// mid-stream does not report the sender's history as recovered.
+    pub fn unwrap(
+        &mut self,
+        pts: u64,
+        frames_per_packet: u32,
+        payload: &[u8],
+    ) -> anyhow::Result<Vec<UnwrappedAudioPacket>> {
+        let (&count, rest) = payload
+            .split_first()
+            .context("redundant audio payload is empty")?;
+        let count = usize::from(count);
+        if count > MAX_REDUNDANCY_DEPTH {
+            bail!("redundant audio payload declares {count} blocks");
+        }
+        let headers = rest
+            .get(..count * BLOCK_HEADER_BYTES)
+            .context("redundant audio block headers are truncated")?;
+        let mut data = &rest[count * BLOCK_HEADER_BYTES..];
+
+        let mut packets = Vec::with_capacity(count + 1);
+        for header in headers.chunks_exact(BLOCK_HEADER_BYTES) {
+            let offset = u32::from_be_bytes([header[0], header[1], header[2], header[3]]);
+            let frames = u16::from_be_bytes([header[4], header[5]]);
+            let length = usize::from(u16::from_be_bytes([header[6], header[7]]));
+            if length > data.len() {
+                bail!("redundant audio block of {length} bytes is truncated");
+            }
+            let (block, remaining) = data.split_at(length);
+            data = remaining;
+            packets.push(UnwrappedAudioPacket {
+                pts: pts.saturating_sub(u64::from(offset)),
+                frames_per_packet: u32::from(frames),
+                payload: block.to_vec(),
+                recovered: true,
+            });
+        }
+        packets.push(UnwrappedAudioPacket {
+            pts,
+            frames_per_packet,
+            payload: data.to_vec(),
+            recovered: false,
+        });
+
+        if self
+            .floor
+            .is_some_and(|floor| pts.saturating_add(DELIVERY_WINDOW_US) < floor)
+        {
+            self.delivered.clear();
+            self.floor = None;
+        }
+        let floor = *self.floor.get_or_insert(pts);
+        packets.retain(|packet| {
+            let fresh = packet.pts >= floor && self.delivered.insert(packet.pts);
+            if fresh && packet.recovered {
+                self.counters.recovered = self.counters.recovered.saturating_add(1);
+            } else if !fresh && (packet.pts >= floor || !packet.recovered) {
+                // Redundant copies from before the first delivered packet are not
+                // duplicates, only history the receiver joined after.
+                self.counters.duplicates = self.counters.duplicates.saturating_add(1);
+            }
+            fresh
+        });
+        self.advance_window();
+        Ok(packets)
+    }
+
+    fn advance_window(&mut self) {
+        let Some(&newest) = self.delivered.last() else {
+            return;
+        };
+        let window_start = newest.saturating_sub(DELIVERY_WINDOW_US);
+        if self.floor.is_some_and(|floor| floor < window_start) {
+            self.floor = Some(window_start);
+            self.delivered = self.delivered.split_off(&window_start);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PACKET_US: u64 = 20_000;
+
+    fn packet(index: u64) -> (u64, Vec<u8>) {
+        (index * PACKET_US, vec![index as u8; 3 + index as usize])
+    }
+
+    /// Wraps packets `0..count` and returns `(pts, payload)` per packet.
+    fn wrapped(depth: usize, count: u64) -> Vec<(u64, Vec<u8>)> {
+        let mut encoder = AudioRedundancyEncoder::new(depth);
+        (0..count)
+            .map(|index| {
+                let (pts, payload) = packet(index);
+                (pts, encoder.wrap(pts, 960, payload))
+            })
+            .collect()
+    }
+
+    fn delivered(
+        decoder: &mut AudioRedundancyDecoder,
+        (pts, payload): &(u64, Vec<u8>),
+    ) -> Vec<(u64, bool)> {
+        decoder
+            .unwrap(*pts, 960, payload)
+            .unwrap()
+            .into_iter()
+            .map(|packet| {
+                assert_eq!(packet.payload, packet_payload(packet.pts));
+                assert_eq!(packet.frames_per_packet, 960);
+                (packet.pts, packet.recovered)
+            })
+            .collect()
+    }
+
+    fn packet_payload(pts: u64) -> Vec<u8> {
+        packet(pts / PACKET_US).1
+    }
+
+    #[test]
+    fn lost_packets_are_rebuilt_from_redundant_copies() {
+        let payloads = wrapped(2, 5);
+        let mut decoder = AudioRedundancyDecoder::default();
+        assert_eq!(delivered(&mut decoder, &payloads[0]), [(0, false)]);
+        assert_eq!(delivered(&mut decoder, &payloads[1]), [(20_000, false)]);
+        // Packets 2 and 3 are lost; packet 4 carries both.
+        assert_eq!(
+            delivered(&mut decoder, &payloads[4]),
+            [(40_000, true), (60_000, true), (80_000, false)]
+        );
+        let counters = decoder.counters();
+        assert_eq!(counters.recovered, 2);
+        // Packet 1 repeats packet 0.
+        assert_eq!(counters.duplicates, 1);
+    }
+
+    #[test]
+    fn joining_mid_stream_does_not_recover_history() {
+        let payloads = wrapped(2, 5);
+        let mut decoder = AudioRedundancyDecoder::default();
+        assert_eq!(delivered(&mut decoder, &payloads[3]), [(60_000, false)]);
+        assert_eq!(delivered(&mut decoder, &payloads[4]), [(80_000, false)]);
+        let counters = decoder.counters();
+        assert_eq!(counters.recovered, 0);
+        // Packet 4 repeats packet 3, which was delivered.
+        assert_eq!(counters.duplicates, 1);
+    }
+
+    #[test]
+    fn reordered_packets_are_delivered_late_instead_of_dropped() {
+        let payloads = wrapped(0, 4);
+        let mut decoder = AudioRedundancyDecoder::default();
+        assert_eq!(delivered(&mut decoder, &payloads[0]), [(0, false)]);
+        assert_eq!(delivered(&mut decoder, &payloads[2]), [(40_000, false)]);
+        assert_eq!(delivered(&mut decoder, &payloads[1]), [(20_000, false)]);
+        assert_eq!(delivered(&mut decoder, &payloads[1]), []);
+        assert_eq!(decoder.counters().duplicates, 1);
+    }
+
+    #[test]
+    fn a_large_pts_regression_restarts_delivery() {
+        let mut encoder = AudioRedundancyEncoder::new(1);
+        let mut decoder = AudioRedundancyDecoder::default();
+        let late = 10 * DELIVERY_WINDOW_US;
+        let first = encoder.wrap(late, 960, vec![1]);
+        assert_eq!(decoder.unwrap(late, 960, &first).unwrap().len(), 1);
+        // Older than anything delivered so far: too late to deliver.
+        let stale = late - PACKET_US;
+        assert!(decoder
+            .unwrap(
+                stale,
+                960,
+                &AudioRedundancyEncoder::new(0).wrap(stale, 960, vec![2])
+            )
+            .unwrap()
+            .is_empty());
+        // Far behind: the sender restarted.
+        let restarted = AudioRedundancyEncoder::new(0).wrap(0, 960, vec![3]);
+        let packets = decoder.unwrap(0, 960, &restarted).unwrap();
+        assert_eq!(packets.len(), 1);
+        assert_eq!(packets[0].payload, [3]);
+    }
+
+    #[test]
+    fn malformed_payloads_are_rejected() {
+        let mut decoder = AudioRedundancyDecoder::default();
+        assert!(decoder.unwrap(0, 960, &[]).is_err());
+        assert!(decoder.unwrap(0, 960, &[5]).is_err());
+        assert!(decoder.unwrap(0, 960, &[1, 0, 0]).is_err());
+        assert!(decoder
+            .unwrap(0, 960, &[1, 0, 0, 0x4E, 0x20, 0x03, 0xC0, 0, 9, 0xAA])
+            .is_err());
+    }
+}
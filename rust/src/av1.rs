@@ -0,0 +1,483 @@
+use crate::bitstream::BitReader;
+use crate::codec::VideoStreamFormat;
+
+pub const OBU_SEQUENCE_HEADER: u8 = 1;
+pub const OBU_TEMPORAL_DELIMITER: u8 = 2;
+pub const OBU_FRAME_HEADER: u8 = 3;
+pub const OBU_FRAME: u8 = 6;
+
+const FRAME_TYPE_KEY: u32 = 0;
+
+/// One OBU of a low-overhead bitstream format temporal unit (AV1 spec section 5).
+#[derive(Debug, Clone, Copy)]
+pub struct Obu<'a> {
+    pub obu_type: u8,
+    /// Header bytes as sent, including the extension byte when present.
+    header: &'a [u8],
+    pub payload: &'a [u8],
+}
+
+impl Obu<'_> {
+    /// Serialises the OBU with `obu_has_size_field` set, so it can be placed
+    /// anywhere in a temporal unit.
+    fn to_sized_bytes(self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.header.len() + 8 + self.payload.len());
+        out.push(self.header[0] | 0x02);
+        out.extend_from_slice(&self.header[1..]);
+        write_leb128(&mut out, self.payload.len() as u64);
+        out.extend_from_slice(self.payload);
+        out
+    }
+}
+
+fn read_leb128(data: &[u8]) -> Option<(u64, usize)> {
+    let mut value = 0u64;
+    for (index, byte) in data.iter().take(8).enumerate() {
+        value |= u64::from(byte & 0x7F) << (index * 7);
+        if byte & 0x80 == 0 {
+            return Some((value, index + 1));
+        }
+    }
+    None
+}
+
+fn write_leb128(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// Splits a temporal unit into OBUs. Returns `None` if an OBU header or size is
+/// malformed; an OBU without a size field extends to the end of the data.
+pub fn split_obus(data: &[u8]) -> Option<Vec<Obu<'_>>> {
+    let mut obus = Vec::new();
+    let mut offset = 0;
+    while offset < data.len() {
+        let first = data[offset];
+        if first & 0x80 != 0 {
+            return None;
+        }
+        let obu_type = (first >> 3) & 0x0F;
+        let header_len = if first & 0x04 != 0 { 2 } else { 1 };
+        let header = data.get(offset..offset + header_len)?;
+        offset += header_len;
+        let payload_len = if first & 0x02 != 0 {
+            let (size, consumed) = read_leb128(data.get(offset..)?)?;
+            offset += consumed;
+            usize::try_from(size).ok()?
+        } else {
+            data.len() - offset
+        };
+        let payload = data.get(offset..offset.checked_add(payload_len)?)?;
+        offset += payload_len;
+        obus.push(Obu {
+            obu_type,
+            header,
+            payload,
+        });
+    }
+    Some(obus)
+}
+
+#[derive(Debug, Clone)]
+pub struct SequenceHeader {
+    reduced_still_picture_header: bool,
+    format: VideoStreamFormat,
+}
+
+pub fn parse_sequence_header(payload: &[u8]) -> Option<SequenceHeader> {
+    let mut reader = BitReader::new(payload);
+    let seq_profile = reader.read_bits(3)?;
+    if seq_profile > 2 {
+        return None;
+    }
+    // still_picture
+    reader.skip_bits(1)?;
+    let reduced_still_picture_header = reader.read_bit()?;
+
+    let mut frame_rate = (0, 0);
+    let seq_level_idx;
+    let mut high_tier = false;
+    if reduced_still_picture_header {
+        seq_level_idx = reader.read_bits(5)?;
+    } else {
+        let mut buffer_delay_length = 0;
+        let timing_info_present = reader.read_bit()?;
+        let mut decoder_model_info_present = false;
+        if timing_info_present {
+            let num_units_in_display_tick = reader.read_bits(32)?;
+            let time_scale = reader.read_bits(32)?;
+            let ticks_per_picture = if reader.read_bit()? {
+                read_uvlc(&mut reader)?.checked_add(1)?
+            } else {
+                1
+            };
+            if num_units_in_display_tick != 0 && time_scale != 0 {
+                if let Some(den) = num_units_in_display_tick.checked_mul(ticks_per_picture) {
+                    frame_rate = (time_scale, den);
+                }
+            }
+            decoder_model_info_present = reader.read_bit()?;
+            if decoder_model_info_present {
+                buffer_delay_length = reader.read_bits(5)? as usize + 1;
+                // num_units_in_decoding_tick, buffer_removal_time_length_minus_1,
+                // frame_presentation_time_length_minus_1
+                reader.skip_bits(32 + 5 + 5)?;
+            }
+        }
+        let initial_display_delay_present = reader.read_bit()?;
+        let operating_points = reader.read_bits(5)? + 1;
+        let mut first_level = None;
+        for _ in 0..operating_points {
+            // operating_point_idc
+            reader.skip_bits(12)?;
+            let level = reader.read_bits(5)?;
+            let tier = level > 7 && reader.read_bit()?;
+            if first_level.is_none() {
+                first_level = Some(level);
+                high_tier = tier;
+            }
+            if decoder_model_info_present && reader.read_bit()? {
+                reader.skip_bits(2 * buffer_delay_length + 1)?;
+            }
+            if initial_display_delay_present && reader.read_bit()? {
+                reader.skip_bits(4)?;
+            }
+        }
+        seq_level_idx = first_level?;
+    }
+
+    let width_bits = reader.read_bits(4)? + 1;
+    let height_bits = reader.read_bits(4)? + 1;
+    let width = reader.read_bits(width_bits)?.checked_add(1)?;
+    let height = reader.read_bits(height_bits)?.checked_add(1)?;
+    if !reduced_still_picture_header && reader.read_bit()? {
+        // delta_frame_id_length_minus_2, additional_frame_id_length_minus_1
+        reader.skip_bits(4 + 3)?;
+    }
+    // use_128x128_superblock, enable_filter_intra, enable_intra_edge_filter
+    reader.skip_bits(3)?;
+    if !reduced_still_picture_header {
+        // enable_interintra_compound, enable_masked_compound, enable_warped_motion,
+        // enable_dual_filter
+        reader.skip_bits(4)?;
+        let enable_order_hint = reader.read_bit()?;
+        if enable_order_hint {
+            reader.skip_bits(2)?;
+        }
+        let force_screen_content_tools = if reader.read_bit()? {
+            2
+        } else {
+            reader.read_bits(1)?
+        };
+        if force_screen_content_tools > 0 && !reader.read_bit()? {
+            reader.skip_bits(1)?;
+        }
+        if enable_order_hint {
+            reader.skip_bits(3)?;
+        }
+    }
+    // enable_superres, enable_cdef, enable_restoration
+    reader.skip_bits(3)?;
+
+    let high_bitdepth = reader.read_bit()?;
+    let bit_depth = match (seq_profile, high_bitdepth) {
+        (2, true) => {
+            if reader.read_bit()? {
+                12
+            } else {
+                10
+            }
+        }
+        (_, true) => 10,
+        (_, false) => 8,
+    };
+    let mono_chrome = seq_profile != 1 && reader.read_bit()?;
+    let (mut primaries, mut transfer, mut matrix) = (2, 2, 2);
+    if reader.read_bit()? {
+        primaries = reader.read_bits(8)?;
+        transfer = reader.read_bits(8)?;
+        matrix = reader.read_bits(8)?;
+    }
+    let chroma_format_idc = if mono_chrome {
+        0
+    } else if primaries == 1 && transfer == 13 && matrix == 0 {
+        3
+    } else {
+        // color_range
+        reader.skip_bits(1)?;
+        match seq_profile {
+            0 => 1,
+            1 => 3,
+            _ if bit_depth == 12 => {
+                let subsampling_x = reader.read_bit()?;
+                let subsampling_y = subsampling_x && reader.read_bit()?;
+                match (subsampling_x, subsampling_y) {
+                    (true, true) => 1,
+                    (true, false) => 2,
+                    _ => 3,
+                }
+            }
+            _ => 2,
+        }
+    };
+
+    Some(SequenceHeader {
+        reduced_still_picture_header,
+        format: VideoStreamFormat {
+            width,
+            height,
+            profile_idc: seq_profile as u8,
+            high_tier,
+            level_idc: seq_level_idx as u8,
+            chroma_format_idc,
+            bit_depth_luma: bit_depth,
+            bit_depth_chroma: bit_depth,
+            frame_rate_num: frame_rate.0,
+            frame_rate_den: frame_rate.1,
+        },
+    })
+}
+
+/// Variable length unsigned integer, `uvlc()`.
+fn read_uvlc(reader: &mut BitReader) -> Option<u32> {
+    let mut leading_zeros = 0;
+    while !reader.read_bit()? {
+        leading_zeros += 1;
+        if leading_zeros >= 32 {
+            return None;
+        }
+    }
+    let value = reader.read_bits(leading_zeros)?;
+    Some(((1u64 << leading_zeros) - 1 + u64::from(value)) as u32)
+}
+
+/// Returns whether the temporal unit starts a key frame, or `None` when it carries
+/// no frame header. A sequence header in the same temporal unit takes precedence
+/// over `known_reduced_still_picture`.
+pub fn classify_temporal_unit(data: &[u8], known_reduced_still_picture: bool) -> Option<bool> {
+    let mut reduced_still_picture = known_reduced_still_picture;
+    for obu in split_obus(data)? {
+        match obu.obu_type {
+            OBU_SEQUENCE_HEADER => {
+                if let Some(header) = parse_sequence_header(obu.payload) {
+                    reduced_still_picture = header.reduced_still_picture_header;
+                }
+            }
+            OBU_FRAME_HEADER | OBU_FRAME => {
+                if reduced_still_picture {
+                    return Some(true);
+                }
+                let mut reader = BitReader::new(obu.payload);
+                let show_existing_frame = reader.read_bit()?;
+                if show_existing_frame {
+                    return Some(false);
+                }
+                return Some(reader.read_bits(2)? == FRAME_TYPE_KEY);
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Follows the sequence headers of one AV1 stream and reports format changes.
+#[derive(Debug, Default)]
+pub struct Av1FormatTracker {
+    current: Option<VideoStreamFormat>,
+}
+
+impl Av1FormatTracker {
+    pub fn observe_access_unit(&mut self, data: &[u8]) -> Option<VideoStreamFormat> {
+        let format = split_obus(data)?
+            .into_iter()
+            .rev()
+            .filter(|obu| obu.obu_type == OBU_SEQUENCE_HEADER)
+            .find_map(|obu| parse_sequence_header(obu.payload))?
+            .format;
+        if self.current.as_ref() == Some(&format) {
+            return None;
+        }
+        self.current = Some(format.clone());
+        Some(format)
+    }
+}
+
+/// Most recent sequence header OBU of one AV1 stream, the AV1 counterpart of the
+/// HEVC/H.264 parameter-set caches.
+#[derive(Debug, Default)]
+pub struct SequenceHeaderCache {
+    obu: Option<Vec<u8>>,
+    reduced_still_picture_header: bool,
+}
+
+impl SequenceHeaderCache {
+    pub fn observe_access_unit(&mut self, data: &[u8]) {
+        let Some(obus) = split_obus(data) else {
+            return;
+        };
+        for obu in obus {
+            if obu.obu_type != OBU_SEQUENCE_HEADER {
+                continue;
+            }
+            if let Some(header) = parse_sequence_header(obu.payload) {
+                self.reduced_still_picture_header = header.reduced_still_picture_header;
+                self.obu = Some(obu.to_sized_bytes());
+            }
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.obu.is_some()
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.obu.clone().unwrap_or_default()
+    }
+
+    /// Rebuilds a key frame temporal unit that lacks a sequence header, keeping the
+    /// temporal delimiter first.
+    pub fn complete_key_frame(&self, data: &[u8]) -> Option<Vec<u8>> {
+        let cached = self.obu.as_ref()?;
+        if !classify_temporal_unit(data, self.reduced_still_picture_header)? {
+            return None;
+        }
+        let obus = split_obus(data)?;
+        if obus.iter().any(|obu| obu.obu_type == OBU_SEQUENCE_HEADER) {
+            return None;
+        }
+        let mut out = Vec::with_capacity(data.len() + cached.len());
+        let (delimiters, rest): (Vec<&Obu>, Vec<&Obu>) = obus
+            .iter()
+            .partition(|obu| obu.obu_type == OBU_TEMPORAL_DELIMITER);
+        for obu in delimiters {
+            out.extend_from_slice(&obu.to_sized_bytes());
+        }
+        out.extend_from_slice(cached);
+        for obu in rest {
+            out.extend_from_slice(&obu.to_sized_bytes());
+        }
+        Some(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitstream::test_support::BitWriter;
+
+    fn obu(obu_type: u8, payload: &[u8]) -> Vec<u8> {
+        let mut out = vec![(obu_type << 3) | 0x02];
+        write_leb128(&mut out, payload.len() as u64);
+        out.extend_from_slice(payload);
+        out
+    }
+
+    /// Main profile 1920x1080 sequence header at level 4.0 with 30 fps timing.
+    fn sequence_header() -> Vec<u8> {
+        let mut writer = BitWriter::default();
+        writer.bits(0, 3).bit(false).bit(false);
+        writer
+            .bit(true)
+            .bits(1, 32)
+            .bits(30, 32)
+            .bit(false)
+            .bit(false);
+        writer
+            .bit(false)
+            .bits(0, 5)
+            .bits(0, 12)
+            .bits(8, 5)
+            .bit(false);
+        writer.bits(10, 4).bits(10, 4).bits(1919, 11).bits(1079, 11);
+        writer.bit(false).bits(0, 3).bits(0, 4);
+        // order hints on, screen content tools chosen per frame
+        writer.bit(true).bits(0, 2).bit(true).bit(true).bits(6, 3);
+        writer.bits(0, 3);
+        // 8-bit, no colour description, limited range
+        writer.bit(false).bit(false).bit(false).bit(false);
+        writer.bits(0, 4);
+        obu(OBU_SEQUENCE_HEADER, &writer.finish())
+    }
+
+    fn temporal_delimiter() -> Vec<u8> {
+        obu(OBU_TEMPORAL_DELIMITER, &[])
+    }
+
+    fn frame(frame_type: u64) -> Vec<u8> {
+        let mut writer = BitWriter::default();
+        writer.bit(false).bits(frame_type, 2).bit(true).bits(0, 12);
+        obu(OBU_FRAME, &writer.finish())
+    }
+
+    #[test]
+    fn sequence_header_parses_size_level_and_timing() {
+        let data = sequence_header();
+        let obus = split_obus(&data).unwrap();
+        let header = parse_sequence_header(obus[0].payload).unwrap();
+        assert!(!header.reduced_still_picture_header);
+        let format = header.format;
+        assert_eq!((format.width, format.height), (1920, 1080));
+        assert_eq!((format.profile_idc, format.level_idc), (0, 8));
+        assert!(!format.high_tier);
+        assert_eq!((format.bit_depth_luma, format.chroma_format_idc), (8, 1));
+        assert_eq!((format.frame_rate_num, format.frame_rate_den), (30, 1));
+    }
+
+    #[test]
+    fn split_obus_rejects_malformed_data() {
+        assert!(split_obus(&[0x80]).is_none());
+        assert!(split_obus(&[(OBU_FRAME << 3) | 0x02, 4, 0]).is_none());
+        let without_size = split_obus(&[OBU_FRAME << 3, 1, 2, 3]).unwrap();
+        assert_eq!(without_size[0].payload, [1, 2, 3]);
+    }
+
+    #[test]
+    fn temporal_units_are_classified_by_frame_type() {
+        let key = [temporal_delimiter(), sequence_header(), frame(0)].concat();
+        assert_eq!(classify_temporal_unit(&key, false), Some(true));
+        let inter = [temporal_delimiter(), frame(1)].concat();
+        assert_eq!(classify_temporal_unit(&inter, false), Some(false));
+        assert_eq!(classify_temporal_unit(&inter, true), Some(true));
+        let show_existing = obu(OBU_FRAME_HEADER, &[0x80]);
+        assert_eq!(classify_temporal_unit(&show_existing, false), Some(false));
+        assert_eq!(classify_temporal_unit(&temporal_delimiter(), false), None);
+    }
+
+    #[test]
+    fn format_tracker_reports_first_format_only() {
+        let mut tracker = Av1FormatTracker::default();
+        let key = [temporal_delimiter(), sequence_header(), frame(0)].concat();
+        assert_eq!(tracker.observe_access_unit(&key).unwrap().width, 1920);
+        assert!(tracker.observe_access_unit(&key).is_none());
+        assert!(tracker.observe_access_unit(&frame(1)).is_none());
+    }
+
+    #[test]
+    fn sequence_header_cache_completes_bare_key_frames() {
+        let (delimiter, header) = (temporal_delimiter(), sequence_header());
+        let mut cache = SequenceHeaderCache::default();
+        assert_eq!(cache.complete_key_frame(&frame(0)), None);
+        cache.observe_access_unit(&[delimiter.clone(), header.clone(), frame(0)].concat());
+        assert!(cache.is_complete());
+        assert_eq!(cache.to_bytes(), header);
+
+        let bare = [delimiter.clone(), frame(0)].concat();
+        assert_eq!(
+            cache.complete_key_frame(&bare).unwrap(),
+            [delimiter.clone(), header.clone(), frame(0)].concat()
+        );
+        let full = [delimiter.clone(), header, frame(0)].concat();
+        assert_eq!(cache.complete_key_frame(&full), None);
+        assert_eq!(
+            cache.complete_key_frame(&[delimiter, frame(1)].concat()),
+            None
+        );
+    }
+}
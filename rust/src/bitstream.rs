@@ -0,0 +1,340 @@
+/// How NAL units are delimited inside an access unit payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NalFraming {
+    /// `00 00 01` / `00 00 00 01` start codes.
+    AnnexB,
+    /// Big-endian length prefix of the given size in bytes (hvcC / avcC style).
+    LengthPrefixed(u8),
+}
+
+impl NalFraming {
+    /// Maps the bridge's `nal_length_size` flag: 0 selects Annex B, 1, 2 or 4 select a
+    /// length prefix of that many bytes.
+    pub fn from_length_size(size: u8) -> Option<Self> {
+        match size {
+            0 => Some(NalFraming::AnnexB),
+            1 | 2 | 4 => Some(NalFraming::LengthPrefixed(size)),
+            _ => None,
+        }
+    }
+}
+
+/// Splits an Annex B access unit into NAL unit payloads, without start codes.
+/// Access units inside the bridge are always Annex B: the sender converts from the
+/// framing Dart declares on ingress, and the receiver converts to its configured
+/// framing only on output, so the framing is never guessed from the bytes.
+pub fn annexb_nal_units(data: &[u8]) -> Vec<&[u8]> {
+    let mut units = Vec::new();
+    let mut start = None;
+    let mut i = 0;
+    while i + 3 <= data.len() {
+        if data[i] == 0 && data[i + 1] == 0 && data[i + 2] == 1 {
+            if let Some(begin) = start {
+                units.push(trim_trailing_zeros(&data[begin..i]));
+            }
+            i += 3;
+            start = Some(i);
+        } else {
+            i += 1;
+        }
+    }
+    if let Some(begin) = start {
+        units.push(&data[begin..]);
+    }
+    units.retain(|unit| !unit.is_empty());
+    units
+}
+
+/// Returns `None` if a length prefix points past the end of the payload.
+pub fn length_prefixed_nal_units(data: &[u8], length_size: u8) -> Option<Vec<&[u8]>> {
+    let length_size = usize::from(length_size);
+    if !(1..=4).contains(&length_size) {
+        return None;
+    }
+    let mut units = Vec::new();
+    let mut offset = 0;
+    while offset < data.len() {
+        let prefix = data.get(offset..offset + length_size)?;
+        let len = prefix
+            .iter()
+            .fold(0usize, |acc, byte| (acc << 8) | usize::from(*byte));
+        offset += length_size;
+        if len == 0 {
+            return None;
+        }
+        units.push(data.get(offset..offset + len)?);
+        offset += len;
+    }
+    Some(units)
+}
+
+/// Re-frames an access unit. Returns `None` if the input does not parse as `from`,
+/// or a NAL unit is too large for the target length prefix.
+pub fn convert_framing(data: Vec<u8>, from: NalFraming, to: NalFraming) -> Option<Vec<u8>> {
+    if from == to {
+        return Some(data);
+    }
+    let units = match from {
+        NalFraming::AnnexB => annexb_nal_units(&data),
+        NalFraming::LengthPrefixed(size) => length_prefixed_nal_units(&data, size)?,
+    };
+    write_nal_units(&units, to)
+}
+
+pub fn write_nal_units(units: &[&[u8]], framing: NalFraming) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(units.iter().map(|unit| unit.len() + 4).sum());
+    for unit in units {
+        match framing {
+            NalFraming::AnnexB => out.extend_from_slice(&[0, 0, 0, 1]),
+            NalFraming::LengthPrefixed(size) => {
+                let size = usize::from(size);
+                if size < 4 && unit.len() >> (8 * size) != 0 {
+                    return None;
+                }
+                let len = u32::try_from(unit.len()).ok()?.to_be_bytes();
+                out.extend_from_slice(&len[4 - size..]);
+            }
+        }
+        out.extend_from_slice(unit);
+    }
+    Some(out)
+}
+
+fn trim_trailing_zeros(unit: &[u8]) -> &[u8] {
+    let end = unit
+        .iter()
+        .rposition(|byte| *byte != 0)
+        .map_or(0, |pos| pos + 1);
+    &unit[..end]
+}
+
+/// MSB-first bit reader with Exp-Golomb support.
+pub struct BitReader {
+    data: Vec<u8>,
+    bit_pos: usize,
+}
+
+impl BitReader {
+    pub fn new(data: &[u8]) -> Self {
+        Self {
+            data: data.to_vec(),
+            bit_pos: 0,
+        }
+    }
+
+    /// Reads a NAL unit payload as an RBSP, removing emulation prevention bytes.
+    pub fn from_rbsp(payload: &[u8]) -> Self {
+        let mut data = Vec::with_capacity(payload.len());
+        let mut zeros = 0;
+        for &byte in payload {
+            if zeros >= 2 && byte == 0x03 {
+                zeros = 0;
+                continue;
+            }
+            zeros = if byte == 0 { zeros + 1 } else { 0 };
+            data.push(byte);
+        }
+        Self { data, bit_pos: 0 }
+    }
+
+    pub fn read_bit(&mut self) -> Option<bool> {
+        let byte = *self.data.get(self.bit_pos / 8)?;
+        let bit = (byte >> (7 - (self.bit_pos % 8))) & 1;
+        self.bit_pos += 1;
+        Some(bit == 1)
+    }
+
+    pub fn read_bits(&mut self, count: u32) -> Option<u32> {
+        debug_assert!(count <= 32);
+        let mut value = 0u32;
+        for _ in 0..count {
+            value = (value << 1) | u32::from(self.read_bit()?);
+        }
+        Some(value)
+    }
+
+    pub fn skip_bits(&mut self, count: usize) -> Option<()> {
+        let end = self.bit_pos.checked_add(count)?;
+        if end > self.data.len() * 8 {
+            return None;
+        }
+        self.bit_pos = end;
+        Some(())
+    }
+
+    /// Unsigned Exp-Golomb, `ue(v)`.
+    pub fn read_ue(&mut self) -> Option<u32> {
+        let mut leading_zeros = 0;
+        while !self.read_bit()? {
+            leading_zeros += 1;
+            if leading_zeros > 31 {
+                return None;
+            }
+        }
+        let suffix = self.read_bits(leading_zeros)?;
+        Some(((1u64 << leading_zeros) - 1 + u64::from(suffix)) as u32)
+    }
+
+    /// Signed Exp-Golomb, `se(v)`.
+    pub fn read_se(&mut self) -> Option<i32> {
+        let code = self.read_ue()?;
+        let magnitude = code.div_ceil(2) as i32;
+        Some(if code % 2 == 1 { magnitude } else { -magnitude })
+    }
+}
+
+/// Helpers for building bitstreams in parser tests.
+#[cfg(test)]
+pub mod test_support {
+    /// MSB-first bit writer with Exp-Golomb support.
+    #[derive(Debug, Default)]
+    pub struct BitWriter {
+        bytes: Vec<u8>,
+        bits: usize,
+    }
+
+    impl BitWriter {
+        pub fn bit(&mut self, bit: bool) -> &mut Self {
+            if self.bits.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            if bit {
+                *self.bytes.last_mut().unwrap() |= 0x80 >> (self.bits % 8);
+            }
+            self.bits += 1;
+            self
+        }
+
+        pub fn bits(&mut self, value: u64, count: u32) -> &mut Self {
+            for shift in (0..count).rev() {
+                self.bit(value.checked_shr(shift).unwrap_or(0) & 1 == 1);
+            }
+            self
+        }
+
+        pub fn ue(&mut self, value: u32) -> &mut Self {
+            let code = u64::from(value) + 1;
+            let len = 64 - code.leading_zeros();
+            self.bits(0, len - 1).bits(code, len)
+        }
+
+        pub fn se(&mut self, value: i32) -> &mut Self {
+            let code = if value > 0 {
+                value as u32 * 2 - 1
+            } else {
+                value.unsigned_abs() * 2
+            };
+            self.ue(code)
+        }
+
+        /// Pads the last byte with zero bits and returns the bytes.
+        pub fn finish(&mut self) -> Vec<u8> {
+            self.bits = 0;
+            std::mem::take(&mut self.bytes)
+        }
+
+        /// Appends `rbsp_trailing_bits` and returns the bytes.
+        pub fn finish_rbsp(&mut self) -> Vec<u8> {
+            self.bit(true);
+            while !self.bits.is_multiple_of(8) {
+                self.bit(false);
+            }
+            std::mem::take(&mut self.bytes)
+        }
+    }
+
+    /// Inserts emulation prevention bytes so `rbsp` can be carried in a NAL unit.
+    pub fn to_ebsp(rbsp: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(rbsp.len() + rbsp.len() / 16);
+        let mut zeros = 0;
+        for &byte in rbsp {
+            if zeros >= 2 && byte <= 3 {
+                out.push(3);
+                zeros = 0;
+            }
+            zeros = if byte == 0 { zeros + 1 } else { 0 };
+            out.push(byte);
+        }
+        out
+    }
+
+    /// Joins NAL units with four-byte start codes.
+    pub fn annexb(units: &[&[u8]]) -> Vec<u8> {
+        let mut out = Vec::new();
+        for unit in units {
+            out.extend_from_slice(&[0, 0, 0, 1]);
+            out.extend_from_slice(unit);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_support::{to_ebsp, BitWriter};
+    use super::*;
+
+    #[test]
+    fn annexb_split_handles_both_start_code_lengths() {
+        let data = [
+            0, 0, 0, 1, 0x40, 0x01, 0xAA, 0, 0, 1, 0x42, 0x01, 0, 0, 0, 1, 0x26,
+        ];
+        let units = annexb_nal_units(&data);
+        assert_eq!(units, vec![&[0x40, 0x01, 0xAA][..], &[0x42, 0x01], &[0x26]]);
+    }
+
+    #[test]
+    fn length_prefixed_units_must_walk_the_payload() {
+        assert_eq!(
+            length_prefixed_nal_units(&[0, 2, 0xAA, 0xBB, 0, 1, 0xCC], 2),
+            Some(vec![&[0xAA, 0xBB][..], &[0xCC]])
+        );
+        assert_eq!(length_prefixed_nal_units(&[0, 5, 0xAA], 2), None);
+        assert_eq!(length_prefixed_nal_units(&[0, 0, 0xAA], 2), None);
+    }
+
+    #[test]
+    fn declared_framing_is_used_even_when_bytes_look_like_start_codes() {
+        // A 4-byte prefix for a 300-byte NAL unit reads 00 00 01 2C.
+        let nal: Vec<u8> = std::iter::once(0x26)
+            .chain(std::iter::repeat_n(0x55, 299))
+            .collect();
+        let mut prefixed = 300u32.to_be_bytes().to_vec();
+        prefixed.extend_from_slice(&nal);
+        assert!(prefixed.starts_with(&[0, 0, 1]));
+
+        let annexb = convert_framing(
+            prefixed.clone(),
+            NalFraming::LengthPrefixed(4),
+            NalFraming::AnnexB,
+        )
+        .unwrap();
+        assert_eq!(annexb_nal_units(&annexb), vec![nal.as_slice()]);
+        let back = convert_framing(annexb, NalFraming::AnnexB, NalFraming::LengthPrefixed(4));
+        assert_eq!(back, Some(prefixed));
+    }
+
+    #[test]
+    fn short_length_prefix_rejects_oversized_units() {
+        let unit = vec![0x11; 300];
+        assert_eq!(
+            write_nal_units(&[&unit], NalFraming::LengthPrefixed(1)),
+            None
+        );
+        assert!(write_nal_units(&[&unit], NalFraming::LengthPrefixed(2)).is_some());
+    }
+
+    #[test]
+    fn bit_reader_reads_exp_golomb_through_emulation_prevention() {
+        let mut writer = BitWriter::default();
+        writer.bits(0, 16).ue(0).ue(7).se(-3).se(4).bits(0x5, 3);
+        let rbsp = writer.finish_rbsp();
+        let mut reader = BitReader::from_rbsp(&to_ebsp(&rbsp));
+        assert_eq!(reader.read_bits(16), Some(0));
+        assert_eq!(reader.read_ue(), Some(0));
+        assert_eq!(reader.read_ue(), Some(7));
+        assert_eq!(reader.read_se(), Some(-3));
+        assert_eq!(reader.read_se(), Some(4));
+        assert_eq!(reader.read_bits(3), Some(0x5));
+    }
+}
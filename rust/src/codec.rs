@@ -0,0 +1,184 @@
+use crate::av1::{self, Av1FormatTracker, SequenceHeaderCache};
+use crate::h264::{self, H264FormatTracker, H264ParameterSetCache};
+use crate::hevc::{self, HevcFormatTracker, ParameterSetCache};
+use sankaku_core::{AUDIO_CODEC_DEBUG_TEXT, AUDIO_CODEC_OPUS, VIDEO_CODEC_HEVC};
+
+/// Codec ids share one byte with sankaku-core, which numbers its own ids upwards
+/// from the bottom of the range. Every id the bridge defines lies in
+/// `BRIDGE_CODEC_IDS_FIRST..=BRIDGE_CODEC_IDS_LAST` so ids sankaku-core adds later
+/// cannot collide with them; the transport carries the id opaquely alongside
+/// each frame.
+const BRIDGE_CODEC_IDS_FIRST: u8 = 0xC0;
+const BRIDGE_CODEC_IDS_LAST: u8 = 0xDF;
+
+const fn is_bridge_codec_id(id: u8) -> bool {
+    id >= BRIDGE_CODEC_IDS_FIRST && id <= BRIDGE_CODEC_IDS_LAST
+}
+
+pub const VIDEO_CODEC_H264: u8 = 0xC0;
+pub const VIDEO_CODEC_AV1: u8 = 0xC1;
+
+/// Raw AAC-LC access units, whose AudioSpecificConfig travels in codec
+/// negotiation.
+pub const AUDIO_CODEC_AAC_LC: u8 = 0xC0;
+/// Interleaved signed 16-bit little-endian PCM.
+pub const AUDIO_CODEC_PCM_S16LE: u8 = 0xC1;
+/// Opus packets wrapped with redundant copies of earlier packets; see
+/// `audio_redundancy`. A separate id keeps plain Opus receivers from decoding the
+/// wrapper as audio.
+pub const AUDIO_CODEC_OPUS_RED: u8 = 0xC2;
+
+const _: () = assert!(
+    is_bridge_codec_id(VIDEO_CODEC_H264)
+        && is_bridge_codec_id(VIDEO_CODEC_AV1)
+        && is_bridge_codec_id(AUDIO_CODEC_AAC_LC)
+        && is_bridge_codec_id(AUDIO_CODEC_PCM_S16LE)
+        && is_bridge_codec_id(AUDIO_CODEC_OPUS_RED)
+        && !is_bridge_codec_id(VIDEO_CODEC_HEVC)
+        && !is_bridge_codec_id(AUDIO_CODEC_OPUS)
+        && !is_bridge_codec_id(AUDIO_CODEC_DEBUG_TEXT)
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VideoCodec {
+    Hevc,
+    H264,
+    Av1,
+}
+
+impl VideoCodec {
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            VIDEO_CODEC_HEVC => Some(VideoCodec::Hevc),
+            VIDEO_CODEC_H264 => Some(VideoCodec::H264),
+            VIDEO_CODEC_AV1 => Some(VideoCodec::Av1),
+            _ => None,
+        }
+    }
+
+    pub fn id(self) -> u8 {
+        match self {
+            VideoCodec::Hevc => VIDEO_CODEC_HEVC,
+            VideoCodec::H264 => VIDEO_CODEC_H264,
+            VideoCodec::Av1 => VIDEO_CODEC_AV1,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            VideoCodec::Hevc => "hevc",
+            VideoCodec::H264 => "h264",
+            VideoCodec::Av1 => "av1",
+        }
+    }
+
+    /// Whether access units are NAL unit sequences that Annex B / length-prefixed
+    /// framing applies to. AV1 temporal units are OBU sequences instead.
+    pub fn uses_nal_units(self) -> bool {
+        !matches!(self, VideoCodec::Av1)
+    }
+
+    /// Returns whether the access unit starts a random access point, or `None` when
+    /// it carries no picture data to classify.
+    pub fn classify_keyframe(self, data: &[u8]) -> Option<bool> {
+        match self {
+            VideoCodec::Hevc => hevc::classify_access_unit(data).map(|kind| kind.is_irap()),
+            VideoCodec::H264 => h264::classify_access_unit(data),
+            VideoCodec::Av1 => av1::classify_temporal_unit(data, false),
+        }
+    }
+}
+
+/// Decoded stream properties, independent of the codec that carried them. Profile
+/// and level use each codec's own numbering.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VideoStreamFormat {
+    pub width: u32,
+    pub height: u32,
+    pub profile_idc: u8,
+    pub high_tier: bool,
+    pub level_idc: u8,
+    pub chroma_format_idc: u8,
+    pub bit_depth_luma: u8,
+    pub bit_depth_chroma: u8,
+    pub frame_rate_num: u32,
+    pub frame_rate_den: u32,
+}
+
+pub enum VideoFormatTracker {
+    Hevc(HevcFormatTracker),
+    H264(H264FormatTracker),
+    Av1(Av1FormatTracker),
+}
+
+impl VideoFormatTracker {
+    pub fn new(codec: VideoCodec) -> Self {
+        match codec {
+            VideoCodec::Hevc => VideoFormatTracker::Hevc(HevcFormatTracker::default()),
+            VideoCodec::H264 => VideoFormatTracker::H264(H264FormatTracker::default()),
+            VideoCodec::Av1 => VideoFormatTracker::Av1(Av1FormatTracker::default()),
+        }
+    }
+
+    pub fn observe_access_unit(&mut self, data: &[u8]) -> Option<VideoStreamFormat> {
+        match self {
+            VideoFormatTracker::Hevc(tracker) => tracker.observe_access_unit(data),
+            VideoFormatTracker::H264(tracker) => tracker.observe_access_unit(data),
+            VideoFormatTracker::Av1(tracker) => tracker.observe_access_unit(data),
+        }
+    }
+}
+
+/// Out-of-band decoder configuration of one stream: VPS/SPS/PPS for HEVC, SPS/PPS
+/// for H.264 and the sequence header OBU for AV1.
+pub enum VideoParameterSetCache {
+    Hevc(ParameterSetCache),
+    H264(H264ParameterSetCache),
+    Av1(SequenceHeaderCache),
+}
+
+impl VideoParameterSetCache {
+    pub fn new(codec: VideoCodec) -> Self {
+        match codec {
+            VideoCodec::Hevc => VideoParameterSetCache::Hevc(ParameterSetCache::default()),
+            VideoCodec::H264 => VideoParameterSetCache::H264(H264ParameterSetCache::default()),
+            VideoCodec::Av1 => VideoParameterSetCache::Av1(SequenceHeaderCache::default()),
+        }
+    }
+
+    pub fn observe_access_unit(&mut self, data: &[u8]) {
+        match self {
+            VideoParameterSetCache::Hevc(cache) => cache.observe_access_unit(data),
+            VideoParameterSetCache::H264(cache) => cache.observe_access_unit(data),
+            VideoParameterSetCache::Av1(cache) => cache.observe_access_unit(data),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        match self {
+            VideoParameterSetCache::Hevc(cache) => cache.is_complete(),
+            VideoParameterSetCache::H264(cache) => cache.is_complete(),
+            VideoParameterSetCache::Av1(cache) => cache.is_complete(),
+        }
+    }
+
+    /// Annex B parameter sets for the NAL-based codecs, the bare sequence header OBU
+    /// for AV1.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            VideoParameterSetCache::Hevc(cache) => cache.to_annexb(),
+            VideoParameterSetCache::H264(cache) => cache.to_annexb(),
+            VideoParameterSetCache::Av1(cache) => cache.to_bytes(),
+        }
+    }
+
+    /// Returns the keyframe with cached configuration prepended when it arrived
+    /// without its own, or `None` when nothing needs to change.
+    pub fn complete_keyframe(&self, data: &[u8]) -> Option<Vec<u8>> {
+        match self {
+            VideoParameterSetCache::Hevc(cache) => cache.complete_irap(data),
+            VideoParameterSetCache::H264(cache) => cache.complete_idr(data),
+            VideoParameterSetCache::Av1(cache) => cache.complete_key_frame(data),
+        }
+    }
+}
@@ -0,0 +1,794 @@
+use crate::data_channel::DataMessage;
+use crate::file_transfer::FileMessage;
+use anyhow::{anyhow, bail, Context};
+use quinn::{ReadExactError, RecvStream, SendStream};
+use std::time::Duration;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tokio::task::JoinHandle;
+
+/// The control stream is a long-lived bidirectional QUIC stream the sender opens
+/// right after codec negotiation, when both peers advertised support for it. Each
+/// side first writes a header (magic and protocol version), then messages framed
+/// as `[type u8][length u32 BE][body]`. Message types a peer does not know are
+/// skipped, and bytes after the known fields of a body are ignored, so later
+/// versions can add messages and fields without breaking older peers.
+const CONTROL_MAGIC: &[u8; 4] = b"NCTL";
+const CONTROL_PROTOCOL_VERSION: u8 = 1;
+const CONTROL_HEADER_BYTES: usize = 5;
+const MESSAGE_HEADER_BYTES: usize = 5;
+pub const MAX_CONTROL_MESSAGE_BYTES: usize = 64 * 1024;
+
+const MESSAGE_KEYFRAME_REQUEST: u8 = 0x01;
+const MESSAGE_MUTE_STATE: u8 = 0x02;
+const MESSAGE_HANGUP: u8 = 0x03;
+const MESSAGE_CAPABILITIES: u8 = 0x04;
+const MESSAGE_RECEIVER_REPORT: u8 = 0x05;
+const MESSAGE_CUSTOM: u8 = 0x06;
+const MESSAGE_DATA: u8 = 0x07;
+const MESSAGE_FILE_OFFER: u8 = 0x08;
+const MESSAGE_FILE_ABORT: u8 = 0x0B;
+const MESSAGE_FILE_ACCEPT: u8 = 0x0C;
+const MESSAGE_FILE_REJECT: u8 = 0x0D;
+const MESSAGE_FILE_DONE: u8 = 0x0E;
+const MESSAGE_COMMAND: u8 = 0x0F;
+const MESSAGE_COMMAND_REPLY: u8 = 0x10;
+const MESSAGE_MEDIA_STATE: u8 = 0x11;
+const MESSAGE_DEBUG_REPORT: u8 = 0x12;
+
+const MEDIA_MICROPHONE_MUTED: u8 = 0x01;
+const MEDIA_CAMERA_OFF: u8 = 0x02;
+const MEDIA_AUDIO_ONLY: u8 = 0x04;
+const MEDIA_SCREEN_SHARE: u8 = 0x08;
+
+const COMMAND_SWITCH_CAMERA: u8 = 0x01;
+const COMMAND_SET_TORCH: u8 = 0x02;
+const COMMAND_SET_BITRATE: u8 = 0x03;
+const COMMAND_START_CAPTURE: u8 = 0x04;
+const COMMAND_STOP_CAPTURE: u8 = 0x05;
+const COMMAND_REQUEST_DEBUG_REPORT: u8 = 0x06;
+
+const COMMAND_STATUS_DONE: u8 = 0x00;
+const COMMAND_STATUS_FAILED: u8 = 0x01;
+const COMMAND_STATUS_DENIED: u8 = 0x02;
+const COMMAND_STATUS_UNSUPPORTED: u8 = 0x03;
+const COMMAND_STATUS_TIMED_OUT: u8 = 0x04;
+/// How long the receiver waits for the sender to open the control stream, and how
+/// long a closing side waits for its last messages to be acknowledged.
+pub const CONTROL_STREAM_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// quinn send priority of the control stream, below Sankaku's audio and video
+/// streams, so larger control messages such as debug reports only use bandwidth
+/// the media streams leave over.
+const CONTROL_STREAM_PRIORITY: i32 = -1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ControlMessage {
+    KeyframeRequest {
+        stream_id: u32,
+    },
+    MuteState {
+        audio_muted: bool,
+        video_muted: bool,
+    },
+    Hangup {
+        reason: String,
+    },
+    Capabilities {
+        video_codecs: Vec<u8>,
+        audio_codecs: Vec<u8>,
+        max_width: u32,
+        max_height: u32,
+    },
+    ReceiverReport(ReceiverReport),
+    Custom {
+        kind: String,
+        payload: Vec<u8>,
+    },
+    /// A message on a reliable data channel; see `data_channel`.
+    Data(DataMessage),
+    /// See `file_transfer`.
+    File(FileMessage),
+    /// Sent by the receiver; the sender answers every command with one
+    /// `CommandReply` carrying the same `command_id`.
+    Command {
+        command_id: u32,
+        command: RemoteCommand,
+    },
+    CommandReply {
+        command_id: u32,
+        status: CommandStatus,
+        detail: String,
+    },
+    /// Sent by the sender when the session starts and whenever its media state
+    /// changes.
+    MediaState(MediaState),
+    /// Diagnostics from the peer's app, saved to a file by the side receiving it.
+    /// `name` is a file name suggested by the peer.
+    DebugReport {
+        name: String,
+        report: Vec<u8>,
+    },
+}
+
+/// What the sender is deliberately not sending, so the receiver can tell a muted
+/// microphone or a switched-off camera from loss. Encoded as one flags byte;
+/// unknown flags are ignored.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MediaState {
+    pub microphone_muted: bool,
+    pub camera_off: bool,
+    pub audio_only: bool,
+    pub screen_share: bool,
+}
+
+impl MediaState {
+    /// True while no audio is expected.
+    pub fn audio_paused(&self) -> bool {
+        self.microphone_muted
+    }
+
+    /// True while no camera video is expected. Screen sharing keeps video flowing
+    /// even with the camera off.
+    pub fn video_paused(&self) -> bool {
+        self.audio_only || (self.camera_off && !self.screen_share)
+    }
+
+    /// The state after a `MuteState` message, which predates `MediaState`:
+    /// `audio_muted` is the microphone and `video_muted` the camera.
+    pub fn with_mute(self, audio_muted: bool, video_muted: bool) -> Self {
+        Self {
+            microphone_muted: audio_muted,
+            camera_off: video_muted,
+            ..self
+        }
+    }
+}
+
+/// Something the receiver asks the sender's capture side to do. A command body
+/// is its code followed by its arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteCommand {
+    SwitchCamera,
+    SetTorch {
+        enabled: bool,
+    },
+    SetBitrate {
+        bitrate_bps: u32,
+    },
+    StartCapture,
+    StopCapture,
+    RequestDebugReport,
+    /// A command from a newer peer; it is answered with `Unsupported`.
+    Unknown {
+        code: u8,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandStatus {
+    Done,
+    Failed,
+    /// The sender's policy does not allow the command.
+    Denied,
+    Unsupported,
+    /// The sender's app did not answer in time. Older peers read it as `Failed`.
+    TimedOut,
+}
+
+/// Reception statistics for one video stream, sent periodically by the receiver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReceiverReport {
+    pub stream_id: u32,
+    pub highest_frame_index: u64,
+    pub lost_frames: u64,
+    pub late_frames: u64,
+    pub awaiting_keyframe: bool,
+    pub jitter_us: u32,
+}
+
+impl ControlMessage {
+    /// Encodes the message with its type and length prefix.
+    pub fn encode(&self) -> anyhow::Result<Vec<u8>> {
+        let mut body = Vec::new();
+        let kind = match self {
+            ControlMessage::KeyframeRequest { stream_id } => {
+                body.extend_from_slice(&stream_id.to_be_bytes());
+                MESSAGE_KEYFRAME_REQUEST
+            }
+            ControlMessage::MuteState {
+                audio_muted,
+                video_muted,
+            } => {
+                body.extend_from_slice(&[u8::from(*audio_muted), u8::from(*video_muted)]);
+                MESSAGE_MUTE_STATE
+            }
+            ControlMessage::Hangup { reason } => {
+                put_string(&mut body, reason)?;
+                MESSAGE_HANGUP
+            }
+            ControlMessage::Capabilities {
+                video_codecs,
+                audio_codecs,
+                max_width,
+                max_height,
+            } => {
+                put_codecs(&mut body, video_codecs)?;
+                put_codecs(&mut body, audio_codecs)?;
+                body.extend_from_slice(&max_width.to_be_bytes());
+                body.extend_from_slice(&max_height.to_be_bytes());
+                MESSAGE_CAPABILITIES
+            }
+            ControlMessage::ReceiverReport(report) => {
+                body.extend_from_slice(&report.stream_id.to_be_bytes());
+                body.extend_from_slice(&report.highest_frame_index.to_be_bytes());
+                body.extend_from_slice(&report.lost_frames.to_be_bytes());
+                body.extend_from_slice(&report.late_frames.to_be_bytes());
+                body.push(u8::from(report.awaiting_keyframe));
+                body.extend_from_slice(&report.jitter_us.to_be_bytes());
+                MESSAGE_RECEIVER_REPORT
+            }
+            ControlMessage::Custom { kind, payload } => {
+                put_string(&mut body, kind)?;
+                body.extend_from_slice(payload);
+                MESSAGE_CUSTOM
+            }
+            ControlMessage::Data(message) => {
+                put_string(&mut body, &message.channel)?;
+                body.extend_from_slice(&message.payload);
+                MESSAGE_DATA
+            }
+            ControlMessage::File(message) => encode_file_message(&mut body, message)?,
+            ControlMessage::Command {
+                command_id,
+                command,
+            } => {
+                body.extend_from_slice(&command_id.to_be_bytes());
+                encode_command(&mut body, command);
+                MESSAGE_COMMAND
+            }
+            ControlMessage::CommandReply {
+                command_id,
+                status,
+                detail,
+            } => {
+                body.extend_from_slice(&command_id.to_be_bytes());
+                body.push(match status {
+                    CommandStatus::Done => COMMAND_STATUS_DONE,
+                    CommandStatus::Failed => COMMAND_STATUS_FAILED,
+                    CommandStatus::Denied => COMMAND_STATUS_DENIED,
+                    CommandStatus::Unsupported => COMMAND_STATUS_UNSUPPORTED,
+                    CommandStatus::TimedOut => COMMAND_STATUS_TIMED_OUT,
+                });
+                put_string(&mut body, detail)?;
+                MESSAGE_COMMAND_REPLY
+            }
+            ControlMessage::MediaState(state) => {
+                let mut flags = 0;
+                for (set, flag) in [
+                    (state.microphone_muted, MEDIA_MICROPHONE_MUTED),
+                    (state.camera_off, MEDIA_CAMERA_OFF),
+                    (state.audio_only, MEDIA_AUDIO_ONLY),
+                    (state.screen_share, MEDIA_SCREEN_SHARE),
+                ] {
+                    if set {
+                        flags |= flag;
+                    }
+                }
+                body.push(flags);
+                MESSAGE_MEDIA_STATE
+            }
+            ControlMessage::DebugReport { name, report } => {
+                put_string(&mut body, name)?;
+                body.extend_from_slice(report);
+                MESSAGE_DEBUG_REPORT
+            }
+        };
+        if body.len() > MAX_CONTROL_MESSAGE_BYTES {
+            bail!("control message exceeds {MAX_CONTROL_MESSAGE_BYTES} bytes");
+        }
+        let mut message = Vec::with_capacity(MESSAGE_HEADER_BYTES + body.len());
+        message.push(kind);
+        message.extend_from_slice(&(body.len() as u32).to_be_bytes());
+        message.extend_from_slice(&body);
+        Ok(message)
+    }
+
+    /// Decodes one message body, returning `None` for types this build does not
+    /// know.
+    fn decode(kind: u8, body: &[u8]) -> anyhow::Result<Option<Self>> {
+        let mut reader = BodyReader { rest: body };
+        let message = match kind {
+            MESSAGE_KEYFRAME_REQUEST => ControlMessage::KeyframeRequest {
+                stream_id: reader.u32()?,
+            },
+            MESSAGE_MUTE_STATE => ControlMessage::MuteState {
+                audio_muted: reader.bool()?,
+                video_muted: reader.bool()?,
+            },
+            MESSAGE_HANGUP => ControlMessage::Hangup {
+                reason: reader.string()?,
+            },
+            MESSAGE_CAPABILITIES => ControlMessage::Capabilities {
+                video_codecs: reader.codecs()?,
+                audio_codecs: reader.codecs()?,
+                max_width: reader.u32()?,
+                max_height: reader.u32()?,
+            },
+            MESSAGE_RECEIVER_REPORT => ControlMessage::ReceiverReport(ReceiverReport {
+                stream_id: reader.u32()?,
+                highest_frame_index: reader.u64()?,
+                lost_frames: reader.u64()?,
+                late_frames: reader.u64()?,
+                awaiting_keyframe: reader.bool()?,
+                jitter_us: reader.u32()?,
+            }),
+            MESSAGE_CUSTOM => ControlMessage::Custom {
+                kind: reader.string()?,
+                payload: reader.rest.to_vec(),
+            },
+            MESSAGE_DATA => ControlMessage::Data(DataMessage {
+                channel: reader.string()?,
+                payload: reader.rest.to_vec(),
+            }),
+            MESSAGE_FILE_OFFER => ControlMessage::File(FileMessage::Offer {
+                transfer_id: reader.u32()?,
+                size: reader.u64()?,
+                name: reader.string()?,
+            }),
+            MESSAGE_FILE_ABORT => ControlMessage::File(FileMessage::Abort {
+                transfer_id: reader.u32()?,
+                reason: reader.string()?,
+            }),
+            MESSAGE_FILE_ACCEPT => ControlMessage::File(FileMessage::Accept {
+                transfer_id: reader.u32()?,
+            }),
+            MESSAGE_FILE_REJECT => ControlMessage::File(FileMessage::Reject {
+                transfer_id: reader.u32()?,
+                reason: reader.string()?,
+            }),
+            MESSAGE_FILE_DONE => ControlMessage::File(FileMessage::Done {
+                transfer_id: reader.u32()?,
+            }),
+            MESSAGE_COMMAND => ControlMessage::Command {
+                command_id: reader.u32()?,
+                command: decode_command(&mut reader)?,
+            },
+            MESSAGE_COMMAND_REPLY => ControlMessage::CommandReply {
+                command_id: reader.u32()?,
+                status: match reader.take(1)?[0] {
+                    COMMAND_STATUS_DONE => CommandStatus::Done,
+                    COMMAND_STATUS_DENIED => CommandStatus::Denied,
+                    COMMAND_STATUS_UNSUPPORTED => CommandStatus::Unsupported,
+                    COMMAND_STATUS_TIMED_OUT => CommandStatus::TimedOut,
+                    _ => CommandStatus::Failed,
+                },
+                detail: reader.string()?,
+            },
+            MESSAGE_MEDIA_STATE => {
+                let flags = reader.take(1)?[0];
+                ControlMessage::MediaState(MediaState {
+                    microphone_muted: flags & MEDIA_MICROPHONE_MUTED != 0,
+                    camera_off: flags & MEDIA_CAMERA_OFF != 0,
+                    audio_only: flags & MEDIA_AUDIO_ONLY != 0,
+                    screen_share: flags & MEDIA_SCREEN_SHARE != 0,
+                })
+            }
+            MESSAGE_DEBUG_REPORT => ControlMessage::DebugReport {
+                name: reader.string()?,
+                report: reader.rest.to_vec(),
+            },
+            _ => return Ok(None),
+        };
+        Ok(Some(message))
+    }
+}
+
+/// Writes the body of a file transfer message and returns its type.
+fn encode_file_message(body: &mut Vec<u8>, message: &FileMessage) -> anyhow::Result<u8> {
+    let kind = match message {
+        FileMessage::Offer {
+            transfer_id,
+            name,
+            size,
+        } => {
+            body.extend_from_slice(&transfer_id.to_be_bytes());
+            body.extend_from_slice(&size.to_be_bytes());
+            put_string(body, name)?;
+            MESSAGE_FILE_OFFER
+        }
+        FileMessage::Abort {
+            transfer_id,
+            reason,
+        } => {
+            body.extend_from_slice(&transfer_id.to_be_bytes());
+            put_string(body, reason)?;
+            MESSAGE_FILE_ABORT
+        }
+        FileMessage::Accept { transfer_id } => {
+            body.extend_from_slice(&transfer_id.to_be_bytes());
+            MESSAGE_FILE_ACCEPT
+        }
+        FileMessage::Reject {
+            transfer_id,
+            reason,
+        } => {
+            body.extend_from_slice(&transfer_id.to_be_bytes());
+            put_string(body, reason)?;
+            MESSAGE_FILE_REJECT
+        }
+        FileMessage::Done { transfer_id } => {
+            body.extend_from_slice(&transfer_id.to_be_bytes());
+            MESSAGE_FILE_DONE
+        }
+    };
+    Ok(kind)
+}
+
+fn encode_command(body: &mut Vec<u8>, command: &RemoteCommand) {
+    match command {
+        RemoteCommand::SwitchCamera => body.push(COMMAND_SWITCH_CAMERA),
+        RemoteCommand::SetTorch { enabled } => {
+            body.extend_from_slice(&[COMMAND_SET_TORCH, u8::from(*enabled)]);
+        }
+        RemoteCommand::SetBitrate { bitrate_bps } => {
+            body.push(COMMAND_SET_BITRATE);
+            body.extend_from_slice(&bitrate_bps.to_be_bytes());
+        }
+        RemoteCommand::StartCapture => body.push(COMMAND_START_CAPTURE),
+        RemoteCommand::StopCapture => body.push(COMMAND_STOP_CAPTURE),
+        RemoteCommand::RequestDebugReport => body.push(COMMAND_REQUEST_DEBUG_REPORT),
+        RemoteCommand::Unknown { code } => body.push(*code),
+    }
+}
+
+fn decode_command(reader: &mut BodyReader<'_>) -> anyhow::Result<RemoteCommand> {
+    let command = match reader.take(1)?[0] {
+        COMMAND_SWITCH_CAMERA => RemoteCommand::SwitchCamera,
+        COMMAND_SET_TORCH => RemoteCommand::SetTorch {
+            enabled: reader.bool()?,
+        },
+        COMMAND_SET_BITRATE => RemoteCommand::SetBitrate {
+            bitrate_bps: reader.u32()?,
+        },
+        COMMAND_START_CAPTURE => RemoteCommand::StartCapture,
+        COMMAND_STOP_CAPTURE => RemoteCommand::StopCapture,
+        COMMAND_REQUEST_DEBUG_REPORT => RemoteCommand::RequestDebugReport,
+        code => RemoteCommand::Unknown { code },
+    };
+    Ok(command)
+}
+
+fn put_string(body: &mut Vec<u8>, value: &str) -> anyhow::Result<()> {
+    let len = u16::try_from(value.len()).context("control message string is too long")?;
+    body.extend_from_slice(&len.to_be_bytes());
+    body.extend_from_slice(value.as_bytes());
+    Ok(())
+}
+
+fn put_codecs(body: &mut Vec<u8>, codecs: &[u8]) -> anyhow::Result<()> {
+    let count = u8::try_from(codecs.len()).context("too many codecs in capabilities")?;
+    body.push(count);
+    body.extend_from_slice(codecs);
+    Ok(())
+}
+
+struct BodyReader<'a> {
+    rest: &'a [u8],
+}
+
+impl<'a> BodyReader<'a> {
+    fn take(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+        let (taken, rest) = self
+            .rest
+            .split_at_checked(len)
+            .context("control message is truncated")?;
+        self.rest = rest;
+        Ok(taken)
+    }
+
+    fn bool(&mut self) -> anyhow::Result<bool> {
+        Ok(self.take(1)?[0] != 0)
+    }
+
+    fn u32(&mut self) -> anyhow::Result<u32> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn u64(&mut self) -> anyhow::Result<u64> {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(self.take(8)?);
+        Ok(u64::from_be_bytes(bytes))
+    }
+
+    fn string(&mut self) -> anyhow::Result<String> {
+        let len = self.take(2)?;
+        let len = usize::from(u16::from_be_bytes([len[0], len[1]]));
+        String::from_utf8(self.take(len)?.to_vec()).context("control message string is not UTF-8")
+    }
+
+    fn codecs(&mut self) -> anyhow::Result<Vec<u8>> {
+        let count = usize::from(self.take(1)?[0]);
+        Ok(self.take(count)?.to_vec())
+    }
+}
+
+/// Queues messages for the control stream; cheap to clone.
+#[derive(Clone)]
+pub struct ControlSender {
+    outgoing: UnboundedSender<Vec<u8>>,
+}
+
+impl ControlSender {
+    pub fn send(&self, message: &ControlMessage) -> anyhow::Result<()> {
+        let encoded = message.encode()?;
+        self.outgoing
+            .send(encoded)
+            .map_err(|_| anyhow!("control stream is closed"))
+    }
+}
+
+/// Both halves of an open control stream. Reading and writing run on their own
+/// tasks; inbound messages, or the error that ended reading, arrive on `incoming`.
+/// A malformed message is reported without closing the stream.
+pub struct ControlChannel {
+    pub sender: ControlSender,
+    pub incoming: UnboundedReceiver<anyhow::Result<ControlMessage>>,
+    writer: JoinHandle<()>,
+}
+
+impl ControlChannel {
+    /// Sender side: opens the stream. The header is written immediately, since
+    /// QUIC only announces a stream to the peer once data is sent on it.
+    pub async fn open(connection: &quinn::Connection) -> anyhow::Result<Self> {
+        let (mut send, recv) = connection
+            .open_bi()
+            .await
+            .context("failed to open control stream")?;
+        send.write_all(&stream_header())
+            .await
+            .context("failed to send control stream header")?;
+        Ok(Self::spawn(send, recv))
+    }
+
+    /// Receiver side: accepts the stream the sender opened after negotiation.
+    pub async fn accept(connection: &quinn::Connection) -> anyhow::Result<Self> {
+        let (mut send, recv) =
+            match tokio::time::timeout(CONTROL_STREAM_TIMEOUT, connection.accept_bi()).await {
+                Ok(streams) => streams.context("failed to accept control stream")?,
+                Err(_) => bail!("sender did not open the control stream in time"),
+            };
+        send.write_all(&stream_header())
+            .await
+            .context("failed to send control stream header")?;
+        Ok(Self::spawn(send, recv))
+    }
+
+    fn spawn(send: SendStream, recv: RecvStream) -> Self {
+        // Fails only once the stream is closed, which the writer reports anyway.
+        let _ = send.set_priority(CONTROL_STREAM_PRIORITY);
+        let (outgoing, outbound) = mpsc::unbounded_channel();
+        let (inbound, incoming) = mpsc::unbounded_channel();
+        let writer = tokio::spawn(write_messages(send, outbound));
+        tokio::spawn(read_messages(recv, inbound));
+        Self {
+            sender: ControlSender { outgoing },
+            incoming,
+            writer,
+        }
+    }
+
+    /// Finishes the outgoing half once everything queued so far, such as a hangup,
+    /// has been delivered. Clones of the sender must already be dropped.
+    pub async fn close(self) {
+        let Self { sender, writer, .. } = self;
+        drop(sender);
+        let _ = tokio::time::timeout(CONTROL_STREAM_TIMEOUT, writer).await;
+    }
+}
+
+fn stream_header() -> [u8; CONTROL_HEADER_BYTES] {
+    let mut header = [0; CONTROL_HEADER_BYTES];
+    header[..4].copy_from_slice(CONTROL_MAGIC);
+    header[4] = CONTROL_PROTOCOL_VERSION;
+    header
+}
+
+async fn write_messages(mut send: SendStream, mut outbound: UnboundedReceiver<Vec<u8>>) {
+    while let Some(message) = outbound.recv().await {
+        if send.write_all(&message).await.is_err() {
+            return;
+        }
+    }
+    if send.finish().is_ok() {
+        let _ = send.stopped().await;
+    }
+}
+
+async fn read_messages(
+    mut recv: RecvStream,
+    inbound: UnboundedSender<anyhow::Result<ControlMessage>>,
+) {
+    if let Err(error) = read_message_loop(&mut recv, &inbound).await {
+        let _ = inbound.send(Err(error));
+    }
+}
+
+async fn read_message_loop(
+    recv: &mut RecvStream,
+    inbound: &UnboundedSender<anyhow::Result<ControlMessage>>,
+) -> anyhow::Result<()> {
+    let mut header = [0; CONTROL_HEADER_BYTES];
+    recv.read_exact(&mut header)
+        .await
+        .context("failed to read control stream header")?;
+    if &header[..4] != CONTROL_MAGIC {
+        bail!("control stream has an invalid header");
+    }
+    // Newer peers may send message types this build skips, but never a version
+    // older than the first.
+    if header[4] == 0 {
+        bail!("unsupported control protocol version: 0");
+    }
+
+    loop {
+        let mut message_header = [0; MESSAGE_HEADER_BYTES];
+        match recv.read_exact(&mut message_header).await {
+            Ok(()) => {}
+            Err(ReadExactError::FinishedEarly(0)) => return Ok(()),
+            Err(error) => return Err(error).context("failed to read control message"),
+        }
+        let kind = message_header[0];
+        let len = u32::from_be_bytes([
+            message_header[1],
+            message_header[2],
+            message_header[3],
+            message_header[4],
+        ]) as usize;
+        if len > MAX_CONTROL_MESSAGE_BYTES {
+            bail!("control message of {len} bytes exceeds {MAX_CONTROL_MESSAGE_BYTES}");
+        }
+        let mut body = vec![0; len];
+        recv.read_exact(&mut body)
+            .await
+            .context("control message is truncated")?;
+        let decoded = ControlMessage::decode(kind, &body)
+            .with_context(|| format!("malformed control message type 0x{kind:02X}"))
+            .transpose();
+        if let Some(decoded) = decoded {
+            if inbound.send(decoded).is_err() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(message: ControlMessage) -> ControlMessage {
+        let encoded = message.encode().unwrap();
+        let len = u32::from_be_bytes(encoded[1..MESSAGE_HEADER_BYTES].try_into().unwrap());
+        assert_eq!(len as usize, encoded.len() - MESSAGE_HEADER_BYTES);
+        ControlMessage::decode(encoded[0], &encoded[MESSAGE_HEADER_BYTES..])
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn messages_survive_encoding() {
+        let messages = [
+            ControlMessage::KeyframeRequest { stream_id: 7 },
+            ControlMessage::MuteState {
+                audio_muted: true,
+                video_muted: false,
+            },
+            ControlMessage::Hangup {
+                reason: "bye".to_string(),
+            },
+            ControlMessage::Capabilities {
+                video_codecs: vec![0x01, 0xB1],
+                audio_codecs: vec![0x03],
+                max_width: 1920,
+                max_height: 1080,
+            },
+            ControlMessage::ReceiverReport(ReceiverReport {
+                stream_id: 1,
+                highest_frame_index: 1 << 40,
+                lost_frames: 3,
+                late_frames: 2,
+                awaiting_keyframe: true,
+                jitter_us: 4_500,
+            }),
+            ControlMessage::Custom {
+                kind: "app.ping".to_string(),
+                payload: vec![1, 2, 3],
+            },
+            ControlMessage::Data(DataMessage {
+                channel: "chat".to_string(),
+                payload: b"hello".to_vec(),
+            }),
+            ControlMessage::File(FileMessage::Offer {
+                transfer_id: 9,
+                name: "clip.mp4".to_string(),
+                size: 1 << 33,
+            }),
+            ControlMessage::Command {
+                command_id: 4,
+                command: RemoteCommand::SetBitrate {
+                    bitrate_bps: 2_500_000,
+                },
+            },
+            ControlMessage::CommandReply {
+                command_id: 4,
+                status: CommandStatus::Denied,
+                detail: "policy".to_string(),
+            },
+            ControlMessage::CommandReply {
+                command_id: 5,
+                status: CommandStatus::TimedOut,
+                detail: String::new(),
+            },
+            ControlMessage::MediaState(MediaState {
+                microphone_muted: true,
+                camera_off: false,
+                audio_only: false,
+                screen_share: true,
+            }),
+            ControlMessage::DebugReport {
+                name: "report.log".to_string(),
+                report: b"line 1\nline 2\n".to_vec(),
+            },
+        ];
+        for message in messages {
+            assert_eq!(round_trip(message.clone()), message);
+        }
+    }
+
+    #[test]
+    fn mute_state_updates_microphone_and_camera_only() {
+        let sharing = MediaState {
+            screen_share: true,
+            ..MediaState::default()
+        };
+        let muted = sharing.with_mute(true, true);
+        assert!(muted.audio_paused());
+        assert!(muted.camera_off && muted.screen_share);
+        assert!(!muted.video_paused());
+        assert_eq!(muted.with_mute(false, false), sharing);
+    }
+
+    #[test]
+    fn unknown_types_and_trailing_fields_are_skipped() {
+        assert_eq!(ControlMessage::decode(0x7F, &[1, 2, 3]).unwrap(), None);
+        assert_eq!(
+            ControlMessage::decode(MESSAGE_KEYFRAME_REQUEST, &[0, 0, 0, 5, 0xFF]).unwrap(),
+            Some(ControlMessage::KeyframeRequest { stream_id: 5 })
+        );
+        assert_eq!(
+            ControlMessage::decode(MESSAGE_COMMAND, &[0, 0, 0, 1, 0x42]).unwrap(),
+            Some(ControlMessage::Command {
+                command_id: 1,
+                command: RemoteCommand::Unknown { code: 0x42 },
+            })
+        );
+    }
+
+    #[test]
+    fn malformed_bodies_are_rejected() {
+        assert!(ControlMessage::decode(MESSAGE_KEYFRAME_REQUEST, &[0, 0, 1]).is_err());
+        assert!(ControlMessage::decode(MESSAGE_HANGUP, &[0, 5, b'a']).is_err());
+        assert!(ControlMessage::decode(MESSAGE_HANGUP, &[0, 1, 0xFF]).is_err());
+        assert!(ControlMessage::decode(MESSAGE_FILE_OFFER, &[0, 0, 0, 1, 0xAB]).is_err());
+    }
+
+    #[test]
+    fn oversized_messages_are_not_encoded() {
+        let message = ControlMessage::Custom {
+            kind: String::new(),
+            payload: vec![0; MAX_CONTROL_MESSAGE_BYTES],
+        };
+        assert!(message.encode().is_err());
+    }
+}
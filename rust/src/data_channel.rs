@@ -0,0 +1,214 @@
+use anyhow::{anyhow, bail, Context};
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+/// Application data travels on named channels. Reliable channels are carried as
+/// `Data` messages on the control stream, so they share its ordering; unreliable
+/// channels use QUIC datagrams, which are only sent once both peers advertised
+/// them during negotiation. A datagram is `NDAT`, a version byte, the label
+/// length (u8) and label, then the payload.
+///
+/// Sankaku reads every datagram of the media connection, so data datagrams use
+/// a second connection between the same endpoints, identified by `DATA_ALPN`.
+/// The sender opens it right after the control stream.
+pub const DATA_ALPN: &[u8] = b"nomikai-ndat/1";
+const DATA_CONNECTION_TIMEOUT: Duration = Duration::from_secs(2);
+const DATAGRAM_MAGIC: &[u8; 4] = b"NDAT";
+const DATAGRAM_VERSION: u8 = 1;
+pub const MAX_CHANNEL_LABEL_BYTES: usize = u8::MAX as usize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataMessage {
+    pub channel: String,
+    pub payload: Vec<u8>,
+}
+
+pub fn validate_label(label: &str) -> anyhow::Result<()> {
+    if label.is_empty() {
+        bail!("data channel label is empty");
+    }
+    if label.len() > MAX_CHANNEL_LABEL_BYTES {
+        bail!("data channel label exceeds {MAX_CHANNEL_LABEL_BYTES} bytes");
+    }
+    Ok(())
+}
+
+fn encode_datagram(message: &DataMessage) -> anyhow::Result<Vec<u8>> {
+    validate_label(&message.channel)?;
+    let mut datagram = Vec::with_capacity(
+        DATAGRAM_MAGIC.len() + 2 + message.channel.len() + message.payload.len(),
+    );
+    datagram.extend_from_slice(DATAGRAM_MAGIC);
+    datagram.push(DATAGRAM_VERSION);
+    datagram.push(message.channel.len() as u8);
+    datagram.extend_from_slice(message.channel.as_bytes());
+    datagram.extend_from_slice(&message.payload);
+    Ok(datagram)
+}
+
+fn parse_datagram(datagram: &[u8]) -> anyhow::Result<DataMessage> {
+    let Some(rest) = datagram.strip_prefix(DATAGRAM_MAGIC) else {
+        bail!("datagram does not belong to a data channel");
+    };
+    let (&version, rest) = rest.split_first().context("data datagram is truncated")?;
+    if version != DATAGRAM_VERSION {
+        bail!("unsupported data datagram version: {version}");
+    }
+    let (&label_len, rest) = rest.split_first().context("data datagram is truncated")?;
+    let (label, payload) = rest
+        .split_at_checked(usize::from(label_len))
+        .context("data datagram label is truncated")?;
+    Ok(DataMessage {
+        channel: String::from_utf8(label.to_vec()).context("data channel label is not UTF-8")?,
+        payload: payload.to_vec(),
+    })
+}
+
+/// Sends one message as a datagram. It may be dropped in transit, and is refused
+/// up front when it does not fit in a single datagram on the current path.
+pub fn send_datagram(connection: &quinn::Connection, message: &DataMessage) -> anyhow::Result<()> {
+    let datagram = encode_datagram(message)?;
+    let max_size = connection
+        .max_datagram_size()
+        .context("peer does not accept datagrams")?;
+    if datagram.len() > max_size {
+        bail!(
+            "data message of {} bytes exceeds the {max_size}-byte datagram limit",
+            datagram.len()
+        );
+    }
+    connection
+        .send_datagram(datagram.into())
+        .map_err(|error| anyhow!("failed to send data datagram: {error}"))
+}
+
+pub struct DatagramChannel {
+    pub connection: quinn::Connection,
+    pub incoming: UnboundedReceiver<anyhow::Result<DataMessage>>,
+}
+
+impl DatagramChannel {
+    /// Sender side: opens the data connection to the receiver's endpoint.
+    /// `config` must offer only `DATA_ALPN`.
+    pub async fn connect(
+        endpoint: &quinn::Endpoint,
+        config: quinn::ClientConfig,
+        addr: SocketAddr,
+    ) -> anyhow::Result<Self> {
+        let connecting = endpoint
+            .connect_with(config, addr, "localhost")
+            .context("failed to start data connection")?;
+        let connection = match tokio::time::timeout(DATA_CONNECTION_TIMEOUT, connecting).await {
+            Ok(connection) => connection.context("failed to establish data connection")?,
+            Err(_) => bail!("receiver did not accept the data connection in time"),
+        };
+        Ok(Self::spawn(connection))
+    }
+
+    /// Receiver side: accepts the next connection on the endpoint, which must
+    /// come from the sender's address and use `DATA_ALPN`.
+    pub async fn accept(endpoint: &quinn::Endpoint, peer: IpAddr) -> anyhow::Result<Self> {
+        let accept = async {
+            let incoming = endpoint
+                .accept()
+                .await
+                .context("QUIC endpoint closed before the data connection")?;
+            let remote = incoming.remote_address();
+            if remote.ip() != peer {
+                incoming.refuse();
+                bail!("refused data connection from {remote}, expected {peer}");
+            }
+            let connection = incoming
+                .await
+                .context("failed to establish data connection")?;
+            if !uses_data_alpn(&connection) {
+                connection.close(0u32.into(), b"not a data connection");
+                bail!("connection from {remote} is not a data connection");
+            }
+            Ok(connection)
+        };
+        let connection = match tokio::time::timeout(DATA_CONNECTION_TIMEOUT, accept).await {
+            Ok(connection) => connection?,
+            Err(_) => bail!("sender did not open the data connection in time"),
+        };
+        Ok(Self::spawn(connection))
+    }
+
+    /// Reads datagrams on a task of their own until the connection closes.
+    /// Malformed datagrams are reported without stopping the reader.
+    fn spawn(connection: quinn::Connection) -> Self {
+        let (inbound, incoming) = mpsc::unbounded_channel();
+        let reader = connection.clone();
+        tokio::spawn(async move {
+            while let Ok(datagram) = reader.read_datagram().await {
+                if inbound.send(parse_datagram(&datagram)).is_err() {
+                    break;
+                }
+            }
+        });
+        Self {
+            connection,
+            incoming,
+        }
+    }
+}
+
+impl Drop for DatagramChannel {
+    /// The reader task holds a handle too, so the connection would otherwise stay
+    /// open until it idles out.
+    fn drop(&mut self) {
+        self.connection.close(0u32.into(), b"session ended");
+    }
+}
+
+fn uses_data_alpn(connection: &quinn::Connection) -> bool {
+    connection
+        .handshake_data()
+        .and_then(|data| data.downcast::<quinn::crypto::rustls::HandshakeData>().ok())
+        .and_then(|data| data.protocol)
+        .is_some_and(|protocol| protocol == DATA_ALPN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(channel: &str, payload: &[u8]) -> DataMessage {
+        DataMessage {
+            channel: channel.to_string(),
+            payload: payload.to_vec(),
+        }
+    }
+
+    #[test]
+    fn datagram_round_trips() {
+        for original in [message("cursor", b"\x01\x02\x03"), message("ping", b"")] {
+            let datagram = encode_datagram(&original).unwrap();
+            assert_eq!(&datagram[..4], DATAGRAM_MAGIC);
+            assert_eq!(parse_datagram(&datagram).unwrap(), original);
+        }
+    }
+
+    #[test]
+    fn labels_must_fit_the_length_byte() {
+        let longest = "a".repeat(MAX_CHANNEL_LABEL_BYTES);
+        assert!(encode_datagram(&message(&longest, b"x")).is_ok());
+        let oversize = "a".repeat(MAX_CHANNEL_LABEL_BYTES + 1);
+        assert!(encode_datagram(&message(&oversize, b"x")).is_err());
+        assert!(encode_datagram(&message("", b"x")).is_err());
+    }
+
+    #[test]
+    fn foreign_and_unknown_datagrams_are_rejected() {
+        assert!(parse_datagram(b"SANK\x01\x04ping").is_err());
+        assert!(parse_datagram(b"NDAT\x02\x04ping").is_err());
+        assert!(parse_datagram(b"NDAT").is_err());
+    }
+
+    #[test]
+    fn truncated_or_invalid_labels_are_rejected() {
+        assert!(parse_datagram(b"NDAT\x01\x08ping").is_err());
+        assert!(parse_datagram(b"NDAT\x01\x02\xFF\xFE").is_err());
+    }
+}
@@ -0,0 +1,257 @@
+use anyhow::{bail, Context};
+use std::collections::BTreeMap;
+
+/// Before the control stream, debug reports travelled on the audio stream as
+/// `AUDIO_CODEC_DEBUG_TEXT` frames: a BEGIN packet with the report id, chunk count,
+/// byte count and file name, CHUNK packets of up to `LEGACY_CHUNK_BYTES`, and an
+/// END packet repeating the counts. Each starts with `NRPT`, the protocol version
+/// and the packet type; integers are little-endian. A payload without the magic
+/// is plain text. Only sessions with a peer that predates the control stream use
+/// this.
+const LEGACY_MAGIC: &[u8; 4] = b"NRPT";
+const LEGACY_PROTOCOL_VERSION: u8 = 1;
+const LEGACY_PACKET_BEGIN: u8 = 0x01;
+const LEGACY_PACKET_CHUNK: u8 = 0x02;
+const LEGACY_PACKET_END: u8 = 0x03;
+const LEGACY_HEADER_BYTES: usize = 10;
+const LEGACY_CHUNK_BYTES: usize = 800;
+const DEFAULT_REPORT_NAME: &str = "nomikai_sender_report.log";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LegacyReportEvent {
+    /// A payload without the report header.
+    Text(String),
+    Started {
+        report_id: u32,
+        name: String,
+        chunks: u32,
+        bytes: u32,
+    },
+    Chunk {
+        bytes: usize,
+    },
+    Complete {
+        name: String,
+        report: Vec<u8>,
+    },
+}
+
+struct Assembly {
+    report_id: u32,
+    name: String,
+    total_chunks: u32,
+    expected_bytes: u32,
+    chunks: BTreeMap<u32, Vec<u8>>,
+}
+
+/// Reassembles legacy reports from their packets, one report at a time.
+#[derive(Default)]
+pub struct LegacyReportAssembler {
+    active: Option<Assembly>,
+}
+
+fn u16_le(bytes: &[u8], at: usize) -> anyhow::Result<u16> {
+    let field = bytes
+        .get(at..at + 2)
+        .context("debug report packet is truncated")?;
+    Ok(u16::from_le_bytes([field[0], field[1]]))
+}
+
+fn u32_le(bytes: &[u8], at: usize) -> anyhow::Result<u32> {
+    let field = bytes
+        .get(at..at + 4)
+        .context("debug report packet is truncated")?;
+    Ok(u32::from_le_bytes([field[0], field[1], field[2], field[3]]))
+}
+
+impl LegacyReportAssembler {
+    pub fn handle(&mut self, payload: &[u8]) -> anyhow::Result<LegacyReportEvent> {
+        if payload.len() < 6 || &payload[..4] != LEGACY_MAGIC {
+            return Ok(LegacyReportEvent::Text(
+                String::from_utf8_lossy(payload).into_owned(),
+            ));
+        }
+        if payload[4] != LEGACY_PROTOCOL_VERSION {
+            bail!("unsupported debug report protocol version {}", payload[4]);
+        }
+        let report_id = u32_le(payload, 6)?;
+        match payload[5] {
+            LEGACY_PACKET_BEGIN => {
+                let total_chunks = u32_le(payload, 10)?;
+                let expected_bytes = u32_le(payload, 14)?;
+                let name_len = usize::from(u16_le(payload, 18)?);
+                let name = payload
+                    .get(20..20 + name_len)
+                    .context("debug report file name is truncated")?;
+                let name = match String::from_utf8_lossy(name).into_owned() {
+                    name if name.is_empty() => DEFAULT_REPORT_NAME.to_string(),
+                    name => name,
+                };
+                self.active = Some(Assembly {
+                    report_id,
+                    name: name.clone(),
+                    total_chunks,
+                    expected_bytes,
+                    chunks: BTreeMap::new(),
+                });
+                Ok(LegacyReportEvent::Started {
+                    report_id,
+                    name,
+                    chunks: total_chunks,
+                    bytes: expected_bytes,
+                })
+            }
+            LEGACY_PACKET_CHUNK => {
+                let seq = u32_le(payload, 10)?;
+                let chunk_len = usize::from(u16_le(payload, 14)?);
+                let chunk = payload
+                    .get(16..16 + chunk_len)
+                    .with_context(|| format!("debug report chunk {seq} is truncated"))?;
+                let active = self.active_report(report_id)?;
+                if seq >= active.total_chunks {
+                    bail!("debug report chunk {seq} is beyond the announced count");
+                }
+                active.chunks.entry(seq).or_insert_with(|| chunk.to_vec());
+                Ok(LegacyReportEvent::Chunk { bytes: chunk.len() })
+            }
+            LEGACY_PACKET_END => {
+                self.active_report(report_id)?;
+                let active = self
+                    .active
+                    .take()
+                    .context("debug report has no BEGIN packet")?;
+                if active.chunks.len() != active.total_chunks as usize {
+                    bail!(
+                        "debug report {report_id} is incomplete: {}/{} chunks",
+                        active.chunks.len(),
+                        active.total_chunks
+                    );
+                }
+                let report = active.chunks.into_values().flatten().collect::<Vec<_>>();
+                if report.len() != active.expected_bytes as usize {
+                    bail!(
+                        "debug report {report_id} has {} bytes, expected {}",
+                        report.len(),
+                        active.expected_bytes
+                    );
+                }
+                Ok(LegacyReportEvent::Complete {
+                    name: active.name,
+                    report,
+                })
+            }
+            packet_type => bail!("unsupported debug report packet type 0x{packet_type:02X}"),
+        }
+    }
+
+    fn active_report(&mut self, report_id: u32) -> anyhow::Result<&mut Assembly> {
+        match self.active.as_mut() {
+            Some(active) if active.report_id == report_id => Ok(active),
+            Some(active) => bail!(
+                "debug report {report_id} does not match the active report {}",
+                active.report_id
+            ),
+            None => bail!("debug report {report_id} has no BEGIN packet"),
+        }
+    }
+}
+
+fn legacy_header(packet_type: u8, report_id: u32) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(LEGACY_HEADER_BYTES + LEGACY_CHUNK_BYTES + 6);
+    packet.extend_from_slice(LEGACY_MAGIC);
+    packet.push(LEGACY_PROTOCOL_VERSION);
+    packet.push(packet_type);
+    packet.extend_from_slice(&report_id.to_le_bytes());
+    packet
+}
+
+/// Splits a report into the BEGIN, CHUNK and END packets a peer that predates the
+/// control stream understands.
+pub fn encode_legacy_report(
+    report_id: u32,
+    name: &str,
+    report: &[u8],
+) -> anyhow::Result<Vec<Vec<u8>>> {
+    let name_len = u16::try_from(name.len()).context("debug report file name is too long")?;
+    let total_bytes = u32::try_from(report.len()).context("debug report is too large")?;
+    let chunks = report.chunks(LEGACY_CHUNK_BYTES);
+    let total_chunks = chunks.len() as u32;
+
+    let mut packets = Vec::with_capacity(chunks.len() + 2);
+    let mut begin = legacy_header(LEGACY_PACKET_BEGIN, report_id);
+    begin.extend_from_slice(&total_chunks.to_le_bytes());
+    begin.extend_from_slice(&total_bytes.to_le_bytes());
+    begin.extend_from_slice(&name_len.to_le_bytes());
+    begin.extend_from_slice(name.as_bytes());
+    packets.push(begin);
+    for (seq, chunk) in (0u32..).zip(chunks) {
+        let mut packet = legacy_header(LEGACY_PACKET_CHUNK, report_id);
+        packet.extend_from_slice(&seq.to_le_bytes());
+        packet.extend_from_slice(&(chunk.len() as u16).to_le_bytes());
+        packet.extend_from_slice(chunk);
+        packets.push(packet);
+    }
+    let mut end = legacy_header(LEGACY_PACKET_END, report_id);
+    end.extend_from_slice(&total_chunks.to_le_bytes());
+    end.extend_from_slice(&total_bytes.to_le_bytes());
+    packets.push(end);
+    Ok(packets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_reports_survive_encoding() {
+        let report: Vec<u8> = (0..2000u32).map(|byte| byte as u8).collect();
+        let packets = encode_legacy_report(7, "sender.log", &report).unwrap();
+        assert_eq!(packets.len(), 5);
+
+        let mut assembler = LegacyReportAssembler::default();
+        assert_eq!(
+            assembler.handle(&packets[0]).unwrap(),
+            LegacyReportEvent::Started {
+                report_id: 7,
+                name: "sender.log".to_string(),
+                chunks: 3,
+                bytes: 2000,
+            }
+        );
+        // Duplicated chunks are ignored.
+        for packet in [&packets[2], &packets[1], &packets[2], &packets[3]] {
+            assert!(matches!(
+                assembler.handle(packet).unwrap(),
+                LegacyReportEvent::Chunk { .. }
+            ));
+        }
+        assert_eq!(
+            assembler.handle(&packets[4]).unwrap(),
+            LegacyReportEvent::Complete {
+                name: "sender.log".to_string(),
+                report,
+            }
+        );
+    }
+
+    #[test]
+    fn incomplete_or_foreign_reports_are_rejected() {
+        let packets = encode_legacy_report(1, "", &[1; 1000]).unwrap();
+        let mut assembler = LegacyReportAssembler::default();
+        assert!(assembler.handle(&packets[1]).is_err());
+        assembler.handle(&packets[0]).unwrap();
+        let other = encode_legacy_report(2, "", &[2; 10]).unwrap();
+        assert!(assembler.handle(&other[1]).is_err());
+        assembler.handle(&packets[1]).unwrap();
+        assert!(assembler.handle(&packets[3]).is_err());
+    }
+
+    #[test]
+    fn payloads_without_the_header_are_text() {
+        let mut assembler = LegacyReportAssembler::default();
+        assert_eq!(
+            assembler.handle(b"hello\n").unwrap(),
+            LegacyReportEvent::Text("hello\n".to_string())
+        );
+    }
+}
@@ -0,0 +1,533 @@
+use anyhow::{bail, Context};
+use quinn::{RecvStream, SendStream};
+use ring::digest::{self, SHA256};
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+use tokio::task::JoinHandle;
+
+/// Offers and replies travel on the control stream. Once the peer accepts, the
+/// sending side opens a bidirectional QUIC stream for the transfer and writes
+/// `NFIL`, a version byte and the transfer id (u32 BE), then the file's bytes and
+/// their SHA-256; the other half of the stream stays unused. File bytes never
+/// share the control stream, so a transfer cannot hold up keyframe requests or
+/// other control messages. The sending side offers a file and streams it only
+/// after the peer accepts, so a peer that does not know these messages never
+/// receives file streams. Each side numbers its own outgoing transfers; messages
+/// from the sending side carry its id, and the receiving side answers with the
+/// same id.
+pub const FILE_CHUNK_BYTES: usize = 16 * 1024;
+pub const SHA256_BYTES: usize = 32;
+const FILE_STREAM_MAGIC: &[u8; 4] = b"NFIL";
+const FILE_STREAM_VERSION: u8 = 1;
+const FILE_STREAM_HEADER_BYTES: usize = 9;
+/// How long an accepted file stream may take to deliver its header.
+const FILE_STREAM_HEADER_TIMEOUT: Duration = Duration::from_secs(2);
+/// quinn send priority of file streams, below the control stream's, so file bytes
+/// only use bandwidth that media and control messages leave over.
+const FILE_STREAM_PRIORITY: i32 = -2;
+/// Longest sanitized file name, in bytes. File systems allow 255, which leaves
+/// room for the partial file's dot and `.part` and for a numbered suffix.
+const MAX_FILENAME_BYTES: usize = 200;
+/// Receiving is refused until a directory is configured; this is the default size
+/// limit for both directions.
+pub const DEFAULT_MAX_FILE_BYTES: u64 = 256 * 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileMessage {
+    /// Sending side: announces a file of `size` bytes.
+    Offer {
+        transfer_id: u32,
+        name: String,
+        size: u64,
+    },
+    /// Sending side: gives up on the transfer.
+    Abort { transfer_id: u32, reason: String },
+    /// Receiving side: open the file stream.
+    Accept { transfer_id: u32 },
+    /// Receiving side: declines the offer or stops a transfer in progress.
+    Reject { transfer_id: u32, reason: String },
+    /// Receiving side: the checksum matched and the file was saved.
+    Done { transfer_id: u32 },
+}
+
+/// Reads a local file for sending, hashing it on the way.
+pub struct OutgoingFile {
+    file: File,
+    pub name: String,
+    pub size: u64,
+    sent: u64,
+    digest: digest::Context,
+}
+
+impl OutgoingFile {
+    pub fn open(path: &Path, max_bytes: u64) -> anyhow::Result<Self> {
+        let file =
+            File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+        let metadata = file
+            .metadata()
+            .with_context(|| format!("failed to read metadata of {}", path.display()))?;
+        if !metadata.is_file() {
+            bail!("{} is not a regular file", path.display());
+        }
+        if metadata.len() > max_bytes {
+            bail!(
+                "{} is {} bytes, over the {max_bytes}-byte limit",
+                path.display(),
+                metadata.len()
+            );
+        }
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Ok(Self {
+            file,
+            name,
+            size: metadata.len(),
+            sent: 0,
+            digest: digest::Context::new(&SHA256),
+        })
+    }
+
+    pub fn sent(&self) -> u64 {
+        self.sent
+    }
+
+    /// Returns the next chunk, or `None` once `size` bytes were read.
+    pub fn next_chunk(&mut self) -> anyhow::Result<Option<Vec<u8>>> {
+        let remaining = self.size - self.sent;
+        if remaining == 0 {
+            return Ok(None);
+        }
+        let mut chunk = vec![0; remaining.min(FILE_CHUNK_BYTES as u64) as usize];
+        self.file
+            .read_exact(&mut chunk)
+            .context("file shrank or could not be read while sending")?;
+        self.digest.update(&chunk);
+        self.sent += chunk.len() as u64;
+        Ok(Some(chunk))
+    }
+
+    /// SHA-256 of the bytes read so far.
+    pub fn checksum(&self) -> [u8; SHA256_BYTES] {
+        sha256_bytes(self.digest.clone())
+    }
+}
+
+/// A file being received into `<dir>/.<name>.part`. It is renamed into place once
+/// complete and verified, and deleted if dropped before that.
+pub struct IncomingFile {
+    writer: Option<BufWriter<File>>,
+    part_path: PathBuf,
+    dir: PathBuf,
+    pub name: String,
+    pub size: u64,
+    received: u64,
+    digest: digest::Context,
+}
+
+impl IncomingFile {
+    pub fn create(dir: &Path, name: &str, size: u64) -> anyhow::Result<Self> {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create {}", dir.display()))?;
+        let name = sanitize_filename(name);
+        let (file, part_path) = create_unique(dir, &format!(".{name}"), ".part")
+            .with_context(|| format!("failed to create a partial file in {}", dir.display()))?;
+        Ok(Self {
+            writer: Some(BufWriter::new(file)),
+            part_path,
+            dir: dir.to_path_buf(),
+            name,
+            size,
+            received: 0,
+            digest: digest::Context::new(&SHA256),
+        })
+    }
+
+    pub fn received(&self) -> u64 {
+        self.received
+    }
+
+    pub fn write(&mut self, offset: u64, data: &[u8]) -> anyhow::Result<()> {
+        if offset != self.received {
+            bail!(
+                "chunk at offset {offset} does not follow the {} bytes received",
+                self.received
+            );
+        }
+        let received = self.received + data.len() as u64;
+        if received > self.size {
+            bail!("peer sent more than the {} bytes it offered", self.size);
+        }
+        let Some(writer) = self.writer.as_mut() else {
+            bail!("file is already closed");
+        };
+        writer
+            .write_all(data)
+            .with_context(|| format!("failed to write {}", self.part_path.display()))?;
+        self.digest.update(data);
+        self.received = received;
+        Ok(())
+    }
+
+    /// Checks the size and checksum, then moves the file into place and returns
+    /// its path.
+    pub fn finish(mut self, sha256: &[u8; SHA256_BYTES]) -> anyhow::Result<PathBuf> {
+        if self.received != self.size {
+            bail!(
+                "transfer ended after {} of {} bytes",
+                self.received,
+                self.size
+            );
+        }
+        let digest = std::mem::replace(&mut self.digest, digest::Context::new(&SHA256));
+        if &sha256_bytes(digest) != sha256 {
+            bail!("SHA-256 of the received file does not match");
+        }
+        let Some(writer) = self.writer.take() else {
+            bail!("file is already closed");
+        };
+        let file = writer
+            .into_inner()
+            .map_err(|error| error.into_error())
+            .with_context(|| format!("failed to flush {}", self.part_path.display()))?;
+        file.sync_all()
+            .with_context(|| format!("failed to sync {}", self.part_path.display()))?;
+        drop(file);
+        // Claims the name first, then replaces the empty claim in one rename.
+        let (claim, path) = create_final(&self.dir, &self.name)
+            .with_context(|| format!("failed to create {} in {}", self.name, self.dir.display()))?;
+        drop(claim);
+        if let Err(error) = std::fs::rename(&self.part_path, &path) {
+            let _ = std::fs::remove_file(&path);
+            return Err(error)
+                .with_context(|| format!("failed to move file into place at {}", path.display()));
+        }
+        Ok(path)
+    }
+}
+
+impl Drop for IncomingFile {
+    fn drop(&mut self) {
+        drop(self.writer.take());
+        let _ = std::fs::remove_file(&self.part_path);
+    }
+}
+
+/// Opens the stream that carries an accepted outgoing transfer and writes its
+/// header.
+pub async fn open_file_stream(
+    connection: &quinn::Connection,
+    transfer_id: u32,
+) -> anyhow::Result<SendStream> {
+    let (mut send, _) = connection
+        .open_bi()
+        .await
+        .context("failed to open file stream")?;
+    // Fails only once the stream is closed, which the header write reports anyway.
+    let _ = send.set_priority(FILE_STREAM_PRIORITY);
+    let mut header = [0; FILE_STREAM_HEADER_BYTES];
+    header[..4].copy_from_slice(FILE_STREAM_MAGIC);
+    header[4] = FILE_STREAM_VERSION;
+    header[5..].copy_from_slice(&transfer_id.to_be_bytes());
+    send.write_all(&header)
+        .await
+        .context("failed to send file stream header")?;
+    Ok(send)
+}
+
+/// Accepts the peer's file streams until the connection closes. Must only be
+/// started once the control stream is set up, since it takes every bidirectional
+/// stream the peer opens.
+pub struct FileStreams {
+    /// Each accepted stream with the transfer id from its header.
+    pub incoming: UnboundedReceiver<(u32, RecvStream)>,
+    acceptor: JoinHandle<()>,
+}
+
+impl FileStreams {
+    pub fn accept(connection: &quinn::Connection) -> Self {
+        let (streams, incoming) = mpsc::unbounded_channel();
+        let connection = connection.clone();
+        let acceptor = tokio::spawn(async move {
+            while let Ok((_, mut recv)) = connection.accept_bi().await {
+                let streams = streams.clone();
+                // Dropping a stream without a valid header stops it.
+                tokio::spawn(async move {
+                    let header = read_file_stream_header(&mut recv);
+                    if let Ok(Ok(transfer_id)) =
+                        tokio::time::timeout(FILE_STREAM_HEADER_TIMEOUT, header).await
+                    {
+                        let _ = streams.send((transfer_id, recv));
+                    }
+                });
+            }
+        });
+        Self { incoming, acceptor }
+    }
+}
+
+impl Drop for FileStreams {
+    fn drop(&mut self) {
+        self.acceptor.abort();
+    }
+}
+
+async fn read_file_stream_header(recv: &mut RecvStream) -> anyhow::Result<u32> {
+    let mut header = [0; FILE_STREAM_HEADER_BYTES];
+    recv.read_exact(&mut header)
+        .await
+        .context("failed to read file stream header")?;
+    if &header[..4] != FILE_STREAM_MAGIC || header[4] != FILE_STREAM_VERSION {
+        bail!("file stream has an invalid header");
+    }
+    Ok(u32::from_be_bytes([
+        header[5], header[6], header[7], header[8],
+    ]))
+}
+
+/// What an incoming file stream carries next.
+#[derive(Debug)]
+pub enum FileStreamItem {
+    /// The bytes at `offset`, in order.
+    Chunk { offset: u64, data: Vec<u8> },
+    /// All bytes were read; carries the SHA-256 the sender computed.
+    End { sha256: [u8; SHA256_BYTES] },
+}
+
+/// Reads the file stream of an incoming transfer of `size` bytes.
+pub struct FileStreamReader {
+    recv: RecvStream,
+    size: u64,
+    received: u64,
+}
+
+impl FileStreamReader {
+    pub fn new(recv: RecvStream, size: u64) -> Self {
+        Self {
+            recv,
+            size,
+            received: 0,
+        }
+    }
+
+    /// Returns up to `FILE_CHUNK_BYTES` of the file, then the checksum once `size`
+    /// bytes were read. Must not be called again after `End`.
+    pub async fn next(&mut self) -> anyhow::Result<FileStreamItem> {
+        let remaining = self.size - self.received;
+        if remaining == 0 {
+            let mut sha256 = [0; SHA256_BYTES];
+            self.recv
+                .read_exact(&mut sha256)
+                .await
+                .context("file stream ended before its checksum")?;
+            return Ok(FileStreamItem::End { sha256 });
+        }
+        let chunk = self
+            .recv
+            .read_chunk(remaining.min(FILE_CHUNK_BYTES as u64) as usize, true)
+            .await
+            .context("failed to read file stream")?
+            .with_context(|| {
+                format!(
+                    "file stream ended after {} of {} bytes",
+                    self.received, self.size
+                )
+            })?;
+        let offset = self.received;
+        self.received += chunk.bytes.len() as u64;
+        Ok(FileStreamItem::Chunk {
+            offset,
+            data: chunk.bytes.to_vec(),
+        })
+    }
+}
+
+/// Saves a file the peer sent whole in one message, such as a debug report, under
+/// the same naming rules as transferred files, and returns its path.
+pub fn save_file(dir: &Path, name: &str, contents: &[u8]) -> anyhow::Result<PathBuf> {
+    std::fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    let name = sanitize_filename(name);
+    let (mut file, path) = create_final(dir, &name)
+        .with_context(|| format!("failed to create {name} in {}", dir.display()))?;
+    if let Err(error) = file.write_all(contents) {
+        drop(file);
+        let _ = std::fs::remove_file(&path);
+        return Err(error).with_context(|| format!("failed to write {}", path.display()));
+    }
+    Ok(path)
+}
+
+fn sha256_bytes(digest: digest::Context) -> [u8; SHA256_BYTES] {
+    let mut bytes = [0; SHA256_BYTES];
+    bytes.copy_from_slice(digest.finish().as_ref());
+    bytes
+}
+
+/// Keeps the peer's file name from escaping the receive directory, colliding with
+/// hidden and partial files or exceeding `MAX_FILENAME_BYTES`.
+fn sanitize_filename(input: &str) -> String {
+    let name: String = input
+        .chars()
+        .map(|ch| {
+            if ch.is_alphanumeric() || matches!(ch, '.' | '_' | '-' | ' ') {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let name = name.trim_start_matches(['.', ' ']).trim_end();
+    if name.is_empty() {
+        "received_file".to_string()
+    } else {
+        truncate_filename(name)
+    }
+}
+
+/// Shortens the stem of a name longer than `MAX_FILENAME_BYTES` at a character
+/// boundary, keeping a short extension.
+fn truncate_filename(name: &str) -> String {
+    if name.len() <= MAX_FILENAME_BYTES {
+        return name.to_string();
+    }
+    let (stem, extension) = match name.rfind('.') {
+        Some(dot) if dot > 0 && name.len() - dot <= MAX_FILENAME_BYTES / 2 => name.split_at(dot),
+        _ => (name, ""),
+    };
+    let mut end = MAX_FILENAME_BYTES - extension.len();
+    while !stem.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}{extension}", stem[..end].trim_end())
+}
+
+/// Creates the file a sanitized `name` is saved as in `dir`, keeping its
+/// extension when a numbered suffix is needed.
+fn create_final(dir: &Path, name: &str) -> std::io::Result<(File, PathBuf)> {
+    match name.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => {
+            create_unique(dir, stem, &format!(".{extension}"))
+        }
+        _ => create_unique(dir, name, ""),
+    }
+}
+
+/// Creates `<dir>/<stem><extension>`, or with `-1`, `-2`, ... after the stem when
+/// taken. Each name is claimed with `create_new`, so concurrent transfers never
+/// end up with the same file.
+fn create_unique(dir: &Path, stem: &str, extension: &str) -> std::io::Result<(File, PathBuf)> {
+    let mut path = dir.join(format!("{stem}{extension}"));
+    let mut suffix = 1u32;
+    loop {
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => return Ok((file, path)),
+            Err(error) if error.kind() == ErrorKind::AlreadyExists => {
+                path = dir.join(format!("{stem}-{suffix}{extension}"));
+                suffix += 1;
+            }
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sha256_of(bytes: &[u8]) -> [u8; SHA256_BYTES] {
+        let mut digest = digest::Context::new(&SHA256);
+        digest.update(bytes);
+        sha256_bytes(digest)
+    }
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "nomikai-file-transfer-{name}-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn sanitize_filename_keeps_names_inside_the_directory() {
+        assert_eq!(sanitize_filename("report.log"), "report.log");
+        assert_eq!(sanitize_filename("../../etc/passwd"), "_.._etc_passwd");
+        assert_eq!(sanitize_filename("C:\\Users\\a.txt"), "C__Users_a.txt");
+        assert_eq!(sanitize_filename("dir/name.txt"), "dir_name.txt");
+    }
+
+    #[test]
+    fn sanitize_filename_strips_hidden_prefixes_and_falls_back() {
+        assert_eq!(sanitize_filename(".bashrc"), "bashrc");
+        assert_eq!(sanitize_filename(" . .part "), "part");
+        assert_eq!(sanitize_filename(""), "received_file");
+        assert_eq!(sanitize_filename("..."), "received_file");
+        assert_eq!(sanitize_filename("nul\0byte"), "nul_byte");
+        assert_eq!(sanitize_filename("fichier été.txt"), "fichier été.txt");
+    }
+
+    #[test]
+    fn sanitize_filename_shortens_long_names_keeping_the_extension() {
+        let long = format!("{}.log", "é".repeat(150));
+        let name = sanitize_filename(&long);
+        assert!(name.len() <= MAX_FILENAME_BYTES);
+        assert!(name.ends_with("é.log"));
+
+        let long_extension = format!("a.{}", "b".repeat(300));
+        assert_eq!(sanitize_filename(&long_extension).len(), MAX_FILENAME_BYTES);
+
+        let dir = scratch_dir("long-name");
+        let saved = save_file(&dir, &"x".repeat(65_535), b"report").unwrap();
+        assert_eq!(std::fs::read(&saved).unwrap(), b"report");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn create_unique_numbers_taken_names() {
+        let dir = scratch_dir("unique");
+        let claim = |stem| create_unique(&dir, stem, ".txt").unwrap().1;
+        assert_eq!(claim("a"), dir.join("a.txt"));
+        assert_eq!(claim("a"), dir.join("a-1.txt"));
+        assert_eq!(claim("a"), dir.join("a-2.txt"));
+        assert_eq!(claim("b"), dir.join("b.txt"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn finished_files_never_replace_each_other() {
+        let dir = scratch_dir("finish");
+        let receive = |contents: &[u8]| {
+            let mut file = IncomingFile::create(&dir, "clip.bin", contents.len() as u64).unwrap();
+            file.write(0, contents).unwrap();
+            file
+        };
+        let (first, second) = (receive(b"one"), receive(b"two"));
+        let saved = save_file(&dir, "clip.bin", b"three").unwrap();
+        let second = second.finish(&sha256_of(b"two")).unwrap();
+        let first = first.finish(&sha256_of(b"one")).unwrap();
+        assert_eq!(saved, dir.join("clip.bin"));
+        assert_eq!(second, dir.join("clip-1.bin"));
+        assert_eq!(first, dir.join("clip-2.bin"));
+        assert_eq!(std::fs::read(&first).unwrap(), b"one");
+        assert_eq!(std::fs::read(&saved).unwrap(), b"three");
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 3);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn save_file_sanitizes_and_keeps_the_extension() {
+        let dir = scratch_dir("save");
+        let first = save_file(&dir, "../report.log", b"one").unwrap();
+        let second = save_file(&dir, "../report.log", b"two").unwrap();
+        assert_eq!(first, dir.join("_report.log"));
+        assert_eq!(second, dir.join("_report-1.log"));
+        assert_eq!(std::fs::read(&second).unwrap(), b"two");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
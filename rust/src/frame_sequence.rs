@@ -0,0 +1,321 @@
+use std::collections::BTreeMap;
+
+/// How far behind the newest frame a missing index is remembered, so a late
+/// arrival can still be told apart from a duplicate.
+const MISSING_WINDOW: u64 = 512;
+
+/// Outcome of feeding one received `frame_index` into a [`FrameSequenceTracker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameArrival {
+    /// First frame on the stream, or exactly the expected next index.
+    InOrder,
+    /// One or more indices between the previous newest frame and this one never arrived.
+    Gap {
+        first_missing: u64,
+        missing: u64,
+        missing_keyframes: u64,
+    },
+    /// A frame previously counted as missing arrived after newer frames.
+    Late { behind: u64 },
+    /// The index was already seen, or is older than the reorder window.
+    Duplicate,
+    /// The index went back further than the reorder window, i.e. the sender
+    /// restarted its numbering. Tracking starts over from this frame.
+    Restarted { previous_highest: u64 },
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FrameLossCounters {
+    pub lost_keyframes: u64,
+    pub lost_delta_frames: u64,
+    pub late_keyframes: u64,
+    pub late_delta_frames: u64,
+    pub duplicates: u64,
+}
+
+impl FrameLossCounters {
+    pub fn accumulate(&mut self, other: &FrameLossCounters) {
+        self.lost_keyframes = self.lost_keyframes.saturating_add(other.lost_keyframes);
+        self.lost_delta_frames = self
+            .lost_delta_frames
+            .saturating_add(other.lost_delta_frames);
+        self.late_keyframes = self.late_keyframes.saturating_add(other.late_keyframes);
+        self.late_delta_frames = self
+            .late_delta_frames
+            .saturating_add(other.late_delta_frames);
+        self.duplicates = self.duplicates.saturating_add(other.duplicates);
+    }
+}
+
+/// Tracks the `frame_index` sequence of a single inbound stream.
+///
+/// Missing frames cannot carry their own keyframe flag, so they are classified
+/// against the keyframe cadence observed so far: an index that lands on the
+/// expected keyframe position is counted as a lost keyframe.
+#[derive(Debug, Default)]
+pub struct FrameSequenceTracker {
+    next_index: Option<u64>,
+    missing: BTreeMap<u64, bool>,
+    last_keyframe_index: Option<u64>,
+    keyframe_interval: Option<u64>,
+    awaiting_keyframe: bool,
+    paused: bool,
+    pause_pending: bool,
+    counters: FrameLossCounters,
+}
+
+impl FrameSequenceTracker {
+    pub fn observe(&mut self, frame_index: u64, keyframe: bool) -> FrameArrival {
+        let paused = std::mem::replace(&mut self.pause_pending, self.paused);
+        let arrival = match self.next_index {
+            Some(next) if frame_index.saturating_add(MISSING_WINDOW) < next => {
+                self.missing.clear();
+                self.last_keyframe_index = None;
+                self.keyframe_interval = None;
+                self.awaiting_keyframe = !keyframe;
+                FrameArrival::Restarted {
+                    previous_highest: next - 1,
+                }
+            }
+            // Indices skipped while the sender had paused the stream were never
+            // meant to arrive, but the decoding chain still restarts.
+            Some(next) if frame_index > next && paused => {
+                self.awaiting_keyframe = true;
+                FrameArrival::InOrder
+            }
+            Some(next) if frame_index > next => {
+                let missing = frame_index - next;
+                let missing_keyframes = self.predicted_keyframes_in(next, frame_index);
+                let remembered_from = next.max(frame_index.saturating_sub(MISSING_WINDOW));
+                for index in remembered_from..frame_index {
+                    let predicted_keyframe = self.is_predicted_keyframe(index);
+                    self.missing.insert(index, predicted_keyframe);
+                }
+                self.counters.lost_keyframes = self
+                    .counters
+                    .lost_keyframes
+                    .saturating_add(missing_keyframes);
+                self.counters.lost_delta_frames = self
+                    .counters
+                    .lost_delta_frames
+                    .saturating_add(missing - missing_keyframes);
+                self.awaiting_keyframe = true;
+                FrameArrival::Gap {
+                    first_missing: next,
+                    missing,
+                    missing_keyframes,
+                }
+            }
+            Some(next) if frame_index < next => {
+                let Some(predicted_keyframe) = self.missing.remove(&frame_index) else {
+                    self.counters.duplicates = self.counters.duplicates.saturating_add(1);
+                    return FrameArrival::Duplicate;
+                };
+                if predicted_keyframe {
+                    self.counters.lost_keyframes = self.counters.lost_keyframes.saturating_sub(1);
+                } else {
+                    self.counters.lost_delta_frames =
+                        self.counters.lost_delta_frames.saturating_sub(1);
+                }
+                if keyframe {
+                    self.counters.late_keyframes = self.counters.late_keyframes.saturating_add(1);
+                } else {
+                    self.counters.late_delta_frames =
+                        self.counters.late_delta_frames.saturating_add(1);
+                }
+                return FrameArrival::Late {
+                    behind: next - 1 - frame_index,
+                };
+            }
+            _ => FrameArrival::InOrder,
+        };
+
+        if keyframe {
+            if let Some(previous) = self.last_keyframe_index {
+                if frame_index > previous {
+                    self.keyframe_interval = Some(frame_index - previous);
+                }
+            }
+            self.last_keyframe_index = Some(frame_index);
+            self.awaiting_keyframe = false;
+        }
+
+        let next = frame_index.saturating_add(1);
+        self.next_index = Some(next);
+        let floor = next.saturating_sub(MISSING_WINDOW);
+        self.missing = self.missing.split_off(&floor);
+        arrival
+    }
+
+    /// True after a gap until the next keyframe arrives, i.e. while the decoding
+    /// chain is broken and concealment or a keyframe request is warranted.
+    pub fn awaiting_keyframe(&self) -> bool {
+        self.awaiting_keyframe
+    }
+
+    /// Follows the sender's announcements that it stopped sending on purpose, e.g.
+    /// with the camera off. A jump while paused, or the first one after resuming,
+    /// is not counted as loss.
+    pub fn set_paused(&mut self, paused: bool) {
+        if paused {
+            self.pause_pending = true;
+        }
+        self.paused = paused;
+    }
+
+    /// The newest `frame_index` seen so far.
+    pub fn highest_index(&self) -> Option<u64> {
+        self.next_index.map(|next| next - 1)
+    }
+
+    pub fn counters(&self) -> FrameLossCounters {
+        self.counters
+    }
+
+    fn is_predicted_keyframe(&self, index: u64) -> bool {
+        match (self.last_keyframe_index, self.keyframe_interval) {
+            (Some(anchor), Some(interval)) if index > anchor => {
+                (index - anchor).is_multiple_of(interval)
+            }
+            _ => false,
+        }
+    }
+
+    fn predicted_keyframes_in(&self, start: u64, end: u64) -> u64 {
+        let (Some(anchor), Some(interval)) = (self.last_keyframe_index, self.keyframe_interval)
+        else {
+            return 0;
+        };
+        let hits_before = |index: u64| {
+            if index <= anchor {
+                0
+            } else {
+                (index - anchor - 1) / interval
+            }
+        };
+        hits_before(end).saturating_sub(hits_before(start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn observe_all(tracker: &mut FrameSequenceTracker, frames: &[(u64, bool)]) {
+        for &(index, keyframe) in frames {
+            tracker.observe(index, keyframe);
+        }
+    }
+
+    #[test]
+    fn in_order_frames_report_no_loss() {
+        let mut tracker = FrameSequenceTracker::default();
+        for index in 0..10 {
+            assert_eq!(tracker.observe(index, index == 0), FrameArrival::InOrder);
+        }
+        let counters = tracker.counters();
+        assert_eq!(counters.lost_keyframes + counters.lost_delta_frames, 0);
+        assert_eq!(tracker.highest_index(), Some(9));
+        assert!(!tracker.awaiting_keyframe());
+    }
+
+    #[test]
+    fn gap_counts_missing_frames_and_awaits_keyframe() {
+        let mut tracker = FrameSequenceTracker::default();
+        observe_all(&mut tracker, &[(0, true), (1, false)]);
+        assert_eq!(
+            tracker.observe(5, false),
+            FrameArrival::Gap {
+                first_missing: 2,
+                missing: 3,
+                missing_keyframes: 0,
+            }
+        );
+        assert_eq!(tracker.counters().lost_delta_frames, 3);
+        assert!(tracker.awaiting_keyframe());
+        tracker.observe(6, true);
+        assert!(!tracker.awaiting_keyframe());
+    }
+
+    #[test]
+    fn gap_predicts_lost_keyframes_from_cadence() {
+        let mut tracker = FrameSequenceTracker::default();
+        for index in 0..=10 {
+            tracker.observe(index, index % 5 == 0);
+        }
+        // Index 15 is where the next keyframe was due.
+        let arrival = tracker.observe(17, false);
+        assert_eq!(
+            arrival,
+            FrameArrival::Gap {
+                first_missing: 11,
+                missing: 6,
+                missing_keyframes: 1,
+            }
+        );
+        assert_eq!(tracker.counters().lost_keyframes, 1);
+        assert_eq!(tracker.counters().lost_delta_frames, 5);
+    }
+
+    #[test]
+    fn late_frame_moves_from_lost_to_late() {
+        let mut tracker = FrameSequenceTracker::default();
+        observe_all(&mut tracker, &[(0, true), (1, false), (3, false)]);
+        assert_eq!(tracker.observe(2, false), FrameArrival::Late { behind: 1 });
+        let counters = tracker.counters();
+        assert_eq!(counters.lost_delta_frames, 0);
+        assert_eq!(counters.late_delta_frames, 1);
+    }
+
+    #[test]
+    fn repeated_index_is_duplicate() {
+        let mut tracker = FrameSequenceTracker::default();
+        observe_all(&mut tracker, &[(0, true), (1, false), (2, false)]);
+        assert_eq!(tracker.observe(1, false), FrameArrival::Duplicate);
+        assert_eq!(tracker.counters().duplicates, 1);
+    }
+
+    #[test]
+    fn sender_restart_starts_tracking_over() {
+        let mut tracker = FrameSequenceTracker::default();
+        for index in 0..2_000 {
+            tracker.observe(index, index % 30 == 0);
+        }
+        assert_eq!(
+            tracker.observe(0, true),
+            FrameArrival::Restarted {
+                previous_highest: 1_999
+            }
+        );
+        assert_eq!(tracker.observe(1, false), FrameArrival::InOrder);
+        assert_eq!(tracker.observe(2, false), FrameArrival::InOrder);
+        assert_eq!(tracker.highest_index(), Some(2));
+        assert_eq!(tracker.counters().duplicates, 0);
+    }
+
+    #[test]
+    fn restart_on_delta_frame_awaits_keyframe() {
+        let mut tracker = FrameSequenceTracker::default();
+        for index in 0..1_000 {
+            tracker.observe(index, index == 0);
+        }
+        tracker.observe(3, false);
+        assert!(tracker.awaiting_keyframe());
+    }
+
+    #[test]
+    fn jump_while_paused_is_not_loss() {
+        let mut tracker = FrameSequenceTracker::default();
+        observe_all(&mut tracker, &[(0, true), (1, false)]);
+        tracker.set_paused(true);
+        tracker.set_paused(false);
+        assert_eq!(tracker.observe(40, true), FrameArrival::InOrder);
+        let counters = tracker.counters();
+        assert_eq!(counters.lost_keyframes + counters.lost_delta_frames, 0);
+        // The pause only covers the first jump after it.
+        assert!(matches!(
+            tracker.observe(45, false),
+            FrameArrival::Gap { missing: 4, .. }
+        ));
+    }
+}
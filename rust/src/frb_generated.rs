@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 805631695;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1979931018;
 
 // Section: executor
 
@@ -45,7 +45,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__simple__announce_media_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -53,7 +53,7 @@ fn wire__crate__api__simple__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "announce_media_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -67,19 +67,28 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_microphone_muted = <bool>::sse_decode(&mut deserializer);
+            let api_camera_off = <bool>::sse_decode(&mut deserializer);
+            let api_audio_only = <bool>::sse_decode(&mut deserializer);
+            let api_screen_share = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::announce_media_state(
+                            api_microphone_muted,
+                            api_camera_off,
+                            api_audio_only,
+                            api_screen_share,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__simple__push_audio_frame_impl(
+fn wire__crate__api__simple__cancel_file_transfer_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -87,7 +96,7 @@ fn wire__crate__api__simple__push_audio_frame_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "push_audio_frame",
+            debug_name: "cancel_file_transfer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -101,19 +110,15 @@ fn wire__crate__api__simple__push_audio_frame_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_frame_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_pts = <u64>::sse_decode(&mut deserializer);
-            let api_codec = <u8>::sse_decode(&mut deserializer);
-            let api_frames_per_packet = <u32>::sse_decode(&mut deserializer);
+            let api_transfer_id = <u32>::sse_decode(&mut deserializer);
+            let api_incoming = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::push_audio_frame(
-                            api_frame_bytes,
-                            api_pts,
-                            api_codec,
-                            api_frames_per_packet,
+                        let output_ok = crate::api::simple::cancel_file_transfer(
+                            api_transfer_id,
+                            api_incoming,
                         )?;
                         Ok(output_ok)
                     })(),
@@ -122,7 +127,7 @@ fn wire__crate__api__simple__push_audio_frame_impl(
         },
     )
 }
-fn wire__crate__api__simple__push_video_frame_impl(
+fn wire__crate__api__simple__close_data_channel_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -130,7 +135,7 @@ fn wire__crate__api__simple__push_video_frame_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "push_video_frame",
+            debug_name: "close_data_channel",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -144,20 +149,12 @@ fn wire__crate__api__simple__push_video_frame_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_frame_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_is_keyframe = <bool>::sse_decode(&mut deserializer);
-            let api_pts = <u64>::sse_decode(&mut deserializer);
-            let api_codec = <u8>::sse_decode(&mut deserializer);
+            let api_label = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::push_video_frame(
-                            api_frame_bytes,
-                            api_is_keyframe,
-                            api_pts,
-                            api_codec,
-                        )?;
+                        let output_ok = crate::api::simple::close_data_channel(api_label)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -165,15 +162,15 @@ fn wire__crate__api__simple__push_video_frame_impl(
         },
     )
 }
-fn wire__crate__api__simple__start_sankaku_receiver_impl(
+fn wire__crate__api__simple__configure_file_transfer_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_sankaku_receiver",
+            debug_name: "configure_file_transfer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -187,39 +184,32 @@ fn wire__crate__api__simple__start_sankaku_receiver_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sink = <StreamSink<
-                crate::api::simple::UiEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
-            let api_bind_addr = <String>::sse_decode(&mut deserializer);
-            let api_graph_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_receive_dir = <Option<String>>::sse_decode(&mut deserializer);
+            let api_max_bytes = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
+            move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::simple::start_sankaku_receiver(
-                            api_sink,
-                            api_bind_addr,
-                            api_graph_bytes,
-                        )
-                        .await?;
+                    (move || {
+                        let output_ok = crate::api::simple::configure_file_transfer(
+                            api_receive_dir,
+                            api_max_bytes,
+                        )?;
                         Ok(output_ok)
-                    })()
-                    .await,
+                    })(),
                 )
             }
         },
     )
 }
-fn wire__crate__api__simple__start_sankaku_sender_impl(
+fn wire__crate__api__simple__configure_receiver_audio_codecs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_sankaku_sender",
+            debug_name: "configure_receiver_audio_codecs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -233,31 +223,21 @@ fn wire__crate__api__simple__start_sankaku_sender_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sink = <StreamSink<
-                crate::api::simple::UiEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
-            let api_dest = <String>::sse_decode(&mut deserializer);
-            let api_graph_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_codecs = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
+            move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::simple::start_sankaku_sender(
-                            api_sink,
-                            api_dest,
-                            api_graph_bytes,
-                        )
-                        .await?;
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::configure_receiver_audio_codecs(api_codecs)?;
                         Ok(output_ok)
-                    })()
-                    .await,
+                    })(),
                 )
             }
         },
     )
 }
-fn wire__crate__api__simple__stop_sankaku_receiver_impl(
+fn wire__crate__api__simple__configure_receiver_audio_concealment_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -265,7 +245,7 @@ fn wire__crate__api__simple__stop_sankaku_receiver_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stop_sankaku_receiver",
+            debug_name: "configure_receiver_audio_concealment",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -279,11 +259,13 @@ fn wire__crate__api__simple__stop_sankaku_receiver_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::stop_sankaku_receiver()?;
+                        let output_ok =
+                            crate::api::simple::configure_receiver_audio_concealment(api_enabled)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -291,7 +273,7 @@ fn wire__crate__api__simple__stop_sankaku_receiver_impl(
         },
     )
 }
-fn wire__crate__api__simple__stop_sankaku_sender_impl(
+fn wire__crate__api__simple__configure_receiver_jitter_buffer_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -299,7 +281,7 @@ fn wire__crate__api__simple__stop_sankaku_sender_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stop_sankaku_sender",
+            debug_name: "configure_receiver_jitter_buffer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -313,11 +295,18 @@ fn wire__crate__api__simple__stop_sankaku_sender_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            let api_min_delay_ms = <u32>::sse_decode(&mut deserializer);
+            let api_max_delay_ms = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::stop_sankaku_sender()?;
+                        let output_ok = crate::api::simple::configure_receiver_jitter_buffer(
+                            api_enabled,
+                            api_min_delay_ms,
+                            api_max_delay_ms,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -325,52 +314,1763 @@ fn wire__crate__api__simple__stop_sankaku_sender_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
+fn wire__crate__api__simple__configure_receiver_lip_sync_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "configure_receiver_lip_sync",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            let api_manual_correction_ms = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::configure_receiver_lip_sync(
+                            api_enabled,
+                            api_manual_correction_ms,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
 }
-
-impl SseDecode
-    for StreamSink<crate::api::simple::UiEvent, flutter_rust_bridge::for_generated::SseCodec>
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
-    }
+fn wire__crate__api__simple__configure_receiver_opus_decode_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "configure_receiver_opus_decode",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            let api_output_path = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::configure_receiver_opus_decode(
+                            api_enabled,
+                            api_output_path,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
 }
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
+fn wire__crate__api__simple__configure_receiver_video_codecs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "configure_receiver_video_codecs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_codecs = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::configure_receiver_video_codecs(api_codecs)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
 }
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
+fn wire__crate__api__simple__configure_receiver_video_format_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "configure_receiver_video_format",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_nal_length_size = <u8>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::configure_receiver_video_format(
+                            api_nal_length_size,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
 }
-
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
+fn wire__crate__api__simple__configure_remote_command_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "configure_remote_command_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_policy =
+                <crate::api::simple::RemoteCommandPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::configure_remote_command_policy(api_policy)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__configure_sender_aac_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "configure_sender_aac_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_audio_specific_config = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::configure_sender_aac_config(
+                            api_audio_specific_config,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__configure_sender_audio_codecs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "configure_sender_audio_codecs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_codecs = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::configure_sender_audio_codecs(api_codecs)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__configure_sender_audio_redundancy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "configure_sender_audio_redundancy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_depth = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::configure_sender_audio_redundancy(api_depth)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__configure_sender_keyframe_detection_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "configure_sender_keyframe_detection",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mode =
+                <crate::api::simple::KeyframeDetectionMode>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::configure_sender_keyframe_detection(api_mode)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__configure_sender_pcm_format_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "configure_sender_pcm_format",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sample_rate = <u32>::sse_decode(&mut deserializer);
+            let api_channels = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::configure_sender_pcm_format(
+                            api_sample_rate,
+                            api_channels,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__configure_sender_stream_latency_budget_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "configure_sender_stream_latency_budget",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stream_id = <u32>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            let api_budget_ms = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::configure_sender_stream_latency_budget(
+                            api_stream_id,
+                            api_enabled,
+                            api_budget_ms,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__configure_sender_video_codecs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "configure_sender_video_codecs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_codecs = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::configure_sender_video_codecs(api_codecs)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__configure_sender_video_latency_budget_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "configure_sender_video_latency_budget",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            let api_budget_ms = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::configure_sender_video_latency_budget(
+                            api_enabled,
+                            api_budget_ms,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__configure_sender_video_queue_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "configure_sender_video_queue",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_capacity = <u32>::sse_decode(&mut deserializer);
+            let api_policy = <crate::api::simple::VideoQueuePolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::configure_sender_video_queue(
+                            api_capacity,
+                            api_policy,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__configure_video_bytes_frame_rate_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "configure_video_bytes_frame_rate",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_frame_rate_num = <u32>::sse_decode(&mut deserializer);
+            let api_frame_rate_den = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::configure_video_bytes_frame_rate(
+                            api_frame_rate_num,
+                            api_frame_rate_den,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__congestion_action_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "congestion_action_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::CongestionAction::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__congestion_state_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "congestion_state_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::CongestionState::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__flush_video_bytes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "flush_video_bytes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_codec = <u8>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::flush_video_bytes(api_codec)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__get_cached_parameter_sets_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_cached_parameter_sets",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stream_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::get_cached_parameter_sets(api_stream_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__open_data_channel_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_data_channel",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_label = <String>::sse_decode(&mut deserializer);
+            let api_delivery =
+                <crate::api::simple::DataChannelDelivery>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::open_data_channel(api_label, api_delivery)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__push_audio_frame_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "push_audio_frame",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_frame_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_pts = <u64>::sse_decode(&mut deserializer);
+            let api_codec = <u8>::sse_decode(&mut deserializer);
+            let api_frames_per_packet = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::push_audio_frame(
+                            api_frame_bytes,
+                            api_pts,
+                            api_codec,
+                            api_frames_per_packet,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__push_video_bytes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "push_video_bytes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_chunk = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_pts = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_codec = <u8>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::push_video_bytes(api_chunk, api_pts, api_codec)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__push_video_frame_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "push_video_frame",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_frame_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_is_keyframe = <bool>::sse_decode(&mut deserializer);
+            let api_pts = <u64>::sse_decode(&mut deserializer);
+            let api_codec = <u8>::sse_decode(&mut deserializer);
+            let api_nal_length_size = <u8>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::push_video_frame(
+                            api_frame_bytes,
+                            api_is_keyframe,
+                            api_pts,
+                            api_codec,
+                            api_nal_length_size,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__remote_command_policy_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remote_command_policy_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::RemoteCommandPolicy::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__reply_remote_command_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reply_remote_command",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_command_id = <u32>::sse_decode(&mut deserializer);
+            let api_status =
+                <crate::api::simple::RemoteCommandStatus>::sse_decode(&mut deserializer);
+            let api_detail = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::reply_remote_command(
+                            api_command_id,
+                            api_status,
+                            api_detail,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__send_control_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_control_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_message = <crate::api::simple::ControlMessage>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::send_control_message(api_message)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__send_data_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_data",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_channel = <String>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::send_data(api_channel, api_data)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__send_debug_report_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_debug_report",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_report = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::send_debug_report(api_name, api_report)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__send_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::send_file(api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__send_remote_command_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_remote_command",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_command = <crate::api::simple::RemoteCommand>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::send_remote_command(api_command)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__set_receiver_lip_sync_correction_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_receiver_lip_sync_correction",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_manual_correction_ms = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::set_receiver_lip_sync_correction(
+                            api_manual_correction_ms,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__start_sankaku_receiver_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_sankaku_receiver",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::simple::UiEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_bind_addr = <String>::sse_decode(&mut deserializer);
+            let api_graph_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::start_sankaku_receiver(
+                            api_sink,
+                            api_bind_addr,
+                            api_graph_bytes,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__start_sankaku_sender_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_sankaku_sender",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::simple::UiEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_dest = <String>::sse_decode(&mut deserializer);
+            let api_graph_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::start_sankaku_sender(
+                            api_sink,
+                            api_dest,
+                            api_graph_bytes,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__start_software_video_decode_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_software_video_decode",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::simple::UiEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_format =
+                <crate::api::simple::DecodedVideoFormat>::sse_decode(&mut deserializer);
+            let api_max_width = <u32>::sse_decode(&mut deserializer);
+            let api_max_height = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::start_software_video_decode(
+                            api_sink,
+                            api_format,
+                            api_max_width,
+                            api_max_height,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__stop_sankaku_receiver_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_sankaku_receiver",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::stop_sankaku_receiver()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__stop_sankaku_sender_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_sankaku_sender",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::stop_sankaku_sender()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__stop_software_video_decode_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_software_video_decode",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::stop_software_video_decode()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::simple::UiEvent, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::simple::CongestionAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::CongestionAction::None,
+            1 => crate::api::simple::CongestionAction::ReduceBitrate,
+            2 => crate::api::simple::CongestionAction::ReduceFrameRate,
+            3 => crate::api::simple::CongestionAction::SkipFrame,
+            _ => unreachable!("Invalid variant for CongestionAction: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::CongestionState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_queueDepth = <u32>::sse_decode(deserializer);
+        let mut var_queueCapacity = <u32>::sse_decode(deserializer);
+        let mut var_estimatedSendDelayMs = <u32>::sse_decode(deserializer);
+        let mut var_recommendedAction =
+            <crate::api::simple::CongestionAction>::sse_decode(deserializer);
+        let mut var_frameQueued = <bool>::sse_decode(deserializer);
+        return crate::api::simple::CongestionState {
+            queue_depth: var_queueDepth,
+            queue_capacity: var_queueCapacity,
+            estimated_send_delay_ms: var_estimatedSendDelayMs,
+            recommended_action: var_recommendedAction,
+            frame_queued: var_frameQueued,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ControlMessage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_streamId = <u32>::sse_decode(deserializer);
+                return crate::api::simple::ControlMessage::KeyframeRequest {
+                    stream_id: var_streamId,
+                };
+            }
+            1 => {
+                let mut var_audioMuted = <bool>::sse_decode(deserializer);
+                let mut var_videoMuted = <bool>::sse_decode(deserializer);
+                return crate::api::simple::ControlMessage::MuteState {
+                    audio_muted: var_audioMuted,
+                    video_muted: var_videoMuted,
+                };
+            }
+            2 => {
+                let mut var_reason = <String>::sse_decode(deserializer);
+                return crate::api::simple::ControlMessage::Hangup { reason: var_reason };
+            }
+            3 => {
+                let mut var_videoCodecs = <Vec<u8>>::sse_decode(deserializer);
+                let mut var_audioCodecs = <Vec<u8>>::sse_decode(deserializer);
+                let mut var_maxWidth = <u32>::sse_decode(deserializer);
+                let mut var_maxHeight = <u32>::sse_decode(deserializer);
+                return crate::api::simple::ControlMessage::Capabilities {
+                    video_codecs: var_videoCodecs,
+                    audio_codecs: var_audioCodecs,
+                    max_width: var_maxWidth,
+                    max_height: var_maxHeight,
+                };
+            }
+            4 => {
+                let mut var_streamId = <u32>::sse_decode(deserializer);
+                let mut var_highestFrameIndex = <u64>::sse_decode(deserializer);
+                let mut var_lostFrames = <u64>::sse_decode(deserializer);
+                let mut var_lateFrames = <u64>::sse_decode(deserializer);
+                let mut var_awaitingKeyframe = <bool>::sse_decode(deserializer);
+                let mut var_jitterUs = <u32>::sse_decode(deserializer);
+                return crate::api::simple::ControlMessage::ReceiverReport {
+                    stream_id: var_streamId,
+                    highest_frame_index: var_highestFrameIndex,
+                    lost_frames: var_lostFrames,
+                    late_frames: var_lateFrames,
+                    awaiting_keyframe: var_awaitingKeyframe,
+                    jitter_us: var_jitterUs,
+                };
+            }
+            5 => {
+                let mut var_kind = <String>::sse_decode(deserializer);
+                let mut var_payload = <Vec<u8>>::sse_decode(deserializer);
+                return crate::api::simple::ControlMessage::Custom {
+                    kind: var_kind,
+                    payload: var_payload,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::simple::DataChannelDelivery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::DataChannelDelivery::ReliableOrdered,
+            1 => crate::api::simple::DataChannelDelivery::Unreliable,
+            _ => unreachable!("Invalid variant for DataChannelDelivery: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::DecodedVideoFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::DecodedVideoFormat::Rgba,
+            1 => crate::api::simple::DecodedVideoFormat::I420,
+            _ => unreachable!("Invalid variant for DecodedVideoFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::FileTransferOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::FileTransferOutcome::Completed,
+            1 => crate::api::simple::FileTransferOutcome::Rejected,
+            2 => crate::api::simple::FileTransferOutcome::Cancelled,
+            3 => crate::api::simple::FileTransferOutcome::Failed,
+            _ => unreachable!("Invalid variant for FileTransferOutcome: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for i16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::simple::KeyframeDetectionMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::KeyframeDetectionMode::TrustCaller,
+            1 => crate::api::simple::KeyframeDetectionMode::CrossCheck,
+            2 => crate::api::simple::KeyframeDetectionMode::Override,
+            _ => unreachable!("Invalid variant for KeyframeDetectionMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for Vec<i16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<i16>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<u8>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::simple::RemoteCommand {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::simple::RemoteCommand::SwitchCamera;
+            }
+            1 => {
+                let mut var_enabled = <bool>::sse_decode(deserializer);
+                return crate::api::simple::RemoteCommand::SetTorch {
+                    enabled: var_enabled,
+                };
+            }
+            2 => {
+                let mut var_bitrateBps = <u32>::sse_decode(deserializer);
+                return crate::api::simple::RemoteCommand::SetBitrate {
+                    bitrate_bps: var_bitrateBps,
+                };
+            }
+            3 => {
+                return crate::api::simple::RemoteCommand::StartCapture;
+            }
+            4 => {
+                return crate::api::simple::RemoteCommand::StopCapture;
+            }
+            5 => {
+                return crate::api::simple::RemoteCommand::RequestDebugReport;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::simple::RemoteCommandPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_allowSwitchCamera = <bool>::sse_decode(deserializer);
+        let mut var_allowTorch = <bool>::sse_decode(deserializer);
+        let mut var_allowBitrate = <bool>::sse_decode(deserializer);
+        let mut var_allowCapture = <bool>::sse_decode(deserializer);
+        let mut var_allowDebugReport = <bool>::sse_decode(deserializer);
+        return crate::api::simple::RemoteCommandPolicy {
+            allow_switch_camera: var_allowSwitchCamera,
+            allow_torch: var_allowTorch,
+            allow_bitrate: var_allowBitrate,
+            allow_capture: var_allowCapture,
+            allow_debug_report: var_allowDebugReport,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::RemoteCommandStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::RemoteCommandStatus::Done,
+            1 => crate::api::simple::RemoteCommandStatus::Failed,
+            2 => crate::api::simple::RemoteCommandStatus::Denied,
+            3 => crate::api::simple::RemoteCommandStatus::Unsupported,
+            4 => crate::api::simple::RemoteCommandStatus::TimedOut,
+            _ => unreachable!("Invalid variant for RemoteCommandStatus: {}", inner),
+        };
+    }
 }
 
 impl SseDecode for u32 {
@@ -482,67 +2182,766 @@ impl SseDecode for crate::api::simple::UiEvent {
                     frames_per_packet: var_framesPerPacket,
                 };
             }
-            11 => {
-                let mut var_msg = <String>::sse_decode(deserializer);
-                return crate::api::simple::UiEvent::Error { msg: var_msg };
+            11 => {
+                let mut var_streamId = <u32>::sse_decode(deserializer);
+                let mut var_pts = <u64>::sse_decode(deserializer);
+                let mut var_framesPerPacket = <u32>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::AudioConcealment {
+                    stream_id: var_streamId,
+                    pts: var_pts,
+                    frames_per_packet: var_framesPerPacket,
+                };
+            }
+            12 => {
+                let mut var_streamId = <u32>::sse_decode(deserializer);
+                let mut var_pts = <u64>::sse_decode(deserializer);
+                let mut var_sampleRate = <u32>::sse_decode(deserializer);
+                let mut var_channels = <u32>::sse_decode(deserializer);
+                let mut var_framesPerPacket = <u32>::sse_decode(deserializer);
+                let mut var_samples = <Vec<i16>>::sse_decode(deserializer);
+                let mut var_concealed = <bool>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::PcmAudioReceived {
+                    stream_id: var_streamId,
+                    pts: var_pts,
+                    sample_rate: var_sampleRate,
+                    channels: var_channels,
+                    frames_per_packet: var_framesPerPacket,
+                    samples: var_samples,
+                    concealed: var_concealed,
+                };
+            }
+            13 => {
+                let mut var_streamId = <u32>::sse_decode(deserializer);
+                let mut var_pts = <u64>::sse_decode(deserializer);
+                let mut var_width = <u32>::sse_decode(deserializer);
+                let mut var_height = <u32>::sse_decode(deserializer);
+                let mut var_format =
+                    <crate::api::simple::DecodedVideoFormat>::sse_decode(deserializer);
+                let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::DecodedVideoFrame {
+                    stream_id: var_streamId,
+                    pts: var_pts,
+                    width: var_width,
+                    height: var_height,
+                    format: var_format,
+                    data: var_data,
+                };
+            }
+            14 => {
+                let mut var_streamId = <u32>::sse_decode(deserializer);
+                let mut var_codec = <String>::sse_decode(deserializer);
+                let mut var_width = <u32>::sse_decode(deserializer);
+                let mut var_height = <u32>::sse_decode(deserializer);
+                let mut var_profileIdc = <u32>::sse_decode(deserializer);
+                let mut var_highTier = <bool>::sse_decode(deserializer);
+                let mut var_levelIdc = <u32>::sse_decode(deserializer);
+                let mut var_chromaFormatIdc = <u32>::sse_decode(deserializer);
+                let mut var_bitDepthLuma = <u32>::sse_decode(deserializer);
+                let mut var_bitDepthChroma = <u32>::sse_decode(deserializer);
+                let mut var_frameRateNum = <u32>::sse_decode(deserializer);
+                let mut var_frameRateDen = <u32>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::StreamFormat {
+                    stream_id: var_streamId,
+                    codec: var_codec,
+                    width: var_width,
+                    height: var_height,
+                    profile_idc: var_profileIdc,
+                    high_tier: var_highTier,
+                    level_idc: var_levelIdc,
+                    chroma_format_idc: var_chromaFormatIdc,
+                    bit_depth_luma: var_bitDepthLuma,
+                    bit_depth_chroma: var_bitDepthChroma,
+                    frame_rate_num: var_frameRateNum,
+                    frame_rate_den: var_frameRateDen,
+                };
+            }
+            15 => {
+                let mut var_queueDepth = <u32>::sse_decode(deserializer);
+                let mut var_queueCapacity = <u32>::sse_decode(deserializer);
+                let mut var_estimatedSendDelayMs = <u32>::sse_decode(deserializer);
+                let mut var_recommendedAction = <String>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::Congestion {
+                    queue_depth: var_queueDepth,
+                    queue_capacity: var_queueCapacity,
+                    estimated_send_delay_ms: var_estimatedSendDelayMs,
+                    recommended_action: var_recommendedAction,
+                };
+            }
+            16 => {
+                let mut var_streamId = <u32>::sse_decode(deserializer);
+                let mut var_activeLayers = <u32>::sse_decode(deserializer);
+                let mut var_totalLayers = <u32>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::TemporalLayers {
+                    stream_id: var_streamId,
+                    active_layers: var_activeLayers,
+                    total_layers: var_totalLayers,
+                };
+            }
+            17 => {
+                let mut var_streamId = <u32>::sse_decode(deserializer);
+                let mut var_expectedPts = <u64>::sse_decode(deserializer);
+                let mut var_missingUs = <u64>::sse_decode(deserializer);
+                let mut var_missingPackets = <u32>::sse_decode(deserializer);
+                let mut var_concealmentPackets = <u32>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::AudioGap {
+                    stream_id: var_streamId,
+                    expected_pts: var_expectedPts,
+                    missing_us: var_missingUs,
+                    missing_packets: var_missingPackets,
+                    concealment_packets: var_concealmentPackets,
+                };
+            }
+            18 => {
+                let mut var_streamId = <u32>::sse_decode(deserializer);
+                let mut var_local = <bool>::sse_decode(deserializer);
+                let mut var_speaking = <bool>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::AudioActivity {
+                    stream_id: var_streamId,
+                    local: var_local,
+                    speaking: var_speaking,
+                };
+            }
+            19 => {
+                let mut var_codec = <u8>::sse_decode(deserializer);
+                let mut var_codecName = <String>::sse_decode(deserializer);
+                let mut var_peerNegotiated = <bool>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::CodecNegotiated {
+                    codec: var_codec,
+                    codec_name: var_codecName,
+                    peer_negotiated: var_peerNegotiated,
+                };
+            }
+            20 => {
+                let mut var_codec = <u8>::sse_decode(deserializer);
+                let mut var_codecName = <String>::sse_decode(deserializer);
+                let mut var_sampleRate = <u32>::sse_decode(deserializer);
+                let mut var_channels = <u32>::sse_decode(deserializer);
+                let mut var_codecConfig = <Vec<u8>>::sse_decode(deserializer);
+                let mut var_peerNegotiated = <bool>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::AudioCodecNegotiated {
+                    codec: var_codec,
+                    codec_name: var_codecName,
+                    sample_rate: var_sampleRate,
+                    channels: var_channels,
+                    codec_config: var_codecConfig,
+                    peer_negotiated: var_peerNegotiated,
+                };
+            }
+            21 => {
+                let mut var_message =
+                    <crate::api::simple::ControlMessage>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::ControlMessageReceived {
+                    message: var_message,
+                };
+            }
+            22 => {
+                let mut var_channel = <String>::sse_decode(deserializer);
+                let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+                let mut var_reliable = <bool>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::DataReceived {
+                    channel: var_channel,
+                    data: var_data,
+                    reliable: var_reliable,
+                };
+            }
+            23 => {
+                let mut var_transferId = <u32>::sse_decode(deserializer);
+                let mut var_incoming = <bool>::sse_decode(deserializer);
+                let mut var_name = <String>::sse_decode(deserializer);
+                let mut var_size = <u64>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::FileTransferStarted {
+                    transfer_id: var_transferId,
+                    incoming: var_incoming,
+                    name: var_name,
+                    size: var_size,
+                };
+            }
+            24 => {
+                let mut var_transferId = <u32>::sse_decode(deserializer);
+                let mut var_incoming = <bool>::sse_decode(deserializer);
+                let mut var_bytes = <u64>::sse_decode(deserializer);
+                let mut var_size = <u64>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::FileTransferProgress {
+                    transfer_id: var_transferId,
+                    incoming: var_incoming,
+                    bytes: var_bytes,
+                    size: var_size,
+                };
+            }
+            25 => {
+                let mut var_transferId = <u32>::sse_decode(deserializer);
+                let mut var_incoming = <bool>::sse_decode(deserializer);
+                let mut var_name = <String>::sse_decode(deserializer);
+                let mut var_outcome =
+                    <crate::api::simple::FileTransferOutcome>::sse_decode(deserializer);
+                let mut var_path = <String>::sse_decode(deserializer);
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::FileTransferFinished {
+                    transfer_id: var_transferId,
+                    incoming: var_incoming,
+                    name: var_name,
+                    outcome: var_outcome,
+                    path: var_path,
+                    detail: var_detail,
+                };
+            }
+            26 => {
+                let mut var_commandId = <u32>::sse_decode(deserializer);
+                let mut var_command = <crate::api::simple::RemoteCommand>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::RemoteCommand {
+                    command_id: var_commandId,
+                    command: var_command,
+                };
+            }
+            27 => {
+                let mut var_commandId = <u32>::sse_decode(deserializer);
+                let mut var_status =
+                    <crate::api::simple::RemoteCommandStatus>::sse_decode(deserializer);
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::RemoteCommandReply {
+                    command_id: var_commandId,
+                    status: var_status,
+                    detail: var_detail,
+                };
+            }
+            28 => {
+                let mut var_microphoneMuted = <bool>::sse_decode(deserializer);
+                let mut var_cameraOff = <bool>::sse_decode(deserializer);
+                let mut var_audioOnly = <bool>::sse_decode(deserializer);
+                let mut var_screenShare = <bool>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::PeerMediaState {
+                    microphone_muted: var_microphoneMuted,
+                    camera_off: var_cameraOff,
+                    audio_only: var_audioOnly,
+                    screen_share: var_screenShare,
+                };
+            }
+            29 => {
+                let mut var_msg = <String>::sse_decode(deserializer);
+                return crate::api::simple::UiEvent::Error { msg: var_msg };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::api::simple::VideoQueuePolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::VideoQueuePolicy::DropOldestDeltaFrames,
+            1 => crate::api::simple::VideoQueuePolicy::DropUntilKeyframe,
+            2 => crate::api::simple::VideoQueuePolicy::Block,
+            _ => unreachable!("Invalid variant for VideoQueuePolicy: {}", inner),
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__simple__announce_media_state_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__simple__cancel_file_transfer_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__simple__close_data_channel_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__simple__configure_file_transfer_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        5 => wire__crate__api__simple__configure_receiver_audio_codecs_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        6 => wire__crate__api__simple__configure_receiver_audio_concealment_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        7 => wire__crate__api__simple__configure_receiver_jitter_buffer_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__simple__configure_receiver_lip_sync_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        9 => wire__crate__api__simple__configure_receiver_opus_decode_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        10 => wire__crate__api__simple__configure_receiver_video_codecs_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        11 => wire__crate__api__simple__configure_receiver_video_format_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        12 => wire__crate__api__simple__configure_remote_command_policy_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__simple__configure_sender_aac_config_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        14 => wire__crate__api__simple__configure_sender_audio_codecs_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        15 => wire__crate__api__simple__configure_sender_audio_redundancy_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        16 => wire__crate__api__simple__configure_sender_keyframe_detection_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        17 => wire__crate__api__simple__configure_sender_pcm_format_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        18 => wire__crate__api__simple__configure_sender_stream_latency_budget_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        19 => wire__crate__api__simple__configure_sender_video_codecs_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        20 => wire__crate__api__simple__configure_sender_video_latency_budget_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        21 => wire__crate__api__simple__configure_sender_video_queue_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        22 => wire__crate__api__simple__configure_video_bytes_frame_rate_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        23 => wire__crate__api__simple__congestion_action_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        24 => wire__crate__api__simple__congestion_state_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        25 => wire__crate__api__simple__flush_video_bytes_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__simple__get_cached_parameter_sets_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        27 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__simple__open_data_channel_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__simple__push_audio_frame_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__simple__push_video_bytes_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__simple__push_video_frame_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__simple__remote_command_policy_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        33 => {
+            wire__crate__api__simple__reply_remote_command_impl(port, ptr, rust_vec_len, data_len)
+        }
+        34 => {
+            wire__crate__api__simple__send_control_message_impl(port, ptr, rust_vec_len, data_len)
+        }
+        35 => wire__crate__api__simple__send_data_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__simple__send_debug_report_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__simple__send_file_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__simple__send_remote_command_impl(port, ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__simple__set_receiver_lip_sync_correction_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        40 => {
+            wire__crate__api__simple__start_sankaku_receiver_impl(port, ptr, rust_vec_len, data_len)
+        }
+        41 => {
+            wire__crate__api__simple__start_sankaku_sender_impl(port, ptr, rust_vec_len, data_len)
+        }
+        42 => wire__crate__api__simple__start_software_video_decode_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        43 => {
+            wire__crate__api__simple__stop_sankaku_receiver_impl(port, ptr, rust_vec_len, data_len)
+        }
+        44 => wire__crate__api__simple__stop_sankaku_sender_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__simple__stop_software_video_decode_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::CongestionAction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::None => 0.into_dart(),
+            Self::ReduceBitrate => 1.into_dart(),
+            Self::ReduceFrameRate => 2.into_dart(),
+            Self::SkipFrame => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::CongestionAction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::CongestionAction>
+    for crate::api::simple::CongestionAction
+{
+    fn into_into_dart(self) -> crate::api::simple::CongestionAction {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::CongestionState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.queue_depth.into_into_dart().into_dart(),
+            self.queue_capacity.into_into_dart().into_dart(),
+            self.estimated_send_delay_ms.into_into_dart().into_dart(),
+            self.recommended_action.into_into_dart().into_dart(),
+            self.frame_queued.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::CongestionState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::CongestionState>
+    for crate::api::simple::CongestionState
+{
+    fn into_into_dart(self) -> crate::api::simple::CongestionState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ControlMessage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::simple::ControlMessage::KeyframeRequest { stream_id } => {
+                [0.into_dart(), stream_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::simple::ControlMessage::MuteState {
+                audio_muted,
+                video_muted,
+            } => [
+                1.into_dart(),
+                audio_muted.into_into_dart().into_dart(),
+                video_muted.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::ControlMessage::Hangup { reason } => {
+                [2.into_dart(), reason.into_into_dart().into_dart()].into_dart()
             }
+            crate::api::simple::ControlMessage::Capabilities {
+                video_codecs,
+                audio_codecs,
+                max_width,
+                max_height,
+            } => [
+                3.into_dart(),
+                video_codecs.into_into_dart().into_dart(),
+                audio_codecs.into_into_dart().into_dart(),
+                max_width.into_into_dart().into_dart(),
+                max_height.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::ControlMessage::ReceiverReport {
+                stream_id,
+                highest_frame_index,
+                lost_frames,
+                late_frames,
+                awaiting_keyframe,
+                jitter_us,
+            } => [
+                4.into_dart(),
+                stream_id.into_into_dart().into_dart(),
+                highest_frame_index.into_into_dart().into_dart(),
+                lost_frames.into_into_dart().into_dart(),
+                late_frames.into_into_dart().into_dart(),
+                awaiting_keyframe.into_into_dart().into_dart(),
+                jitter_us.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::ControlMessage::Custom { kind, payload } => [
+                5.into_dart(),
+                kind.into_into_dart().into_dart(),
+                payload.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
             _ => {
                 unimplemented!("");
             }
         }
     }
 }
-
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ControlMessage
+{
 }
-
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ControlMessage>
+    for crate::api::simple::ControlMessage
+{
+    fn into_into_dart(self) -> crate::api::simple::ControlMessage {
+        self
     }
 }
-
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__simple__push_audio_frame_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__simple__push_video_frame_impl(port, ptr, rust_vec_len, data_len),
-        4 => {
-            wire__crate__api__simple__start_sankaku_receiver_impl(port, ptr, rust_vec_len, data_len)
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::DataChannelDelivery {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::ReliableOrdered => 0.into_dart(),
+            Self::Unreliable => 1.into_dart(),
+            _ => unreachable!(),
         }
-        5 => wire__crate__api__simple__start_sankaku_sender_impl(port, ptr, rust_vec_len, data_len),
-        6 => {
-            wire__crate__api__simple__stop_sankaku_receiver_impl(port, ptr, rust_vec_len, data_len)
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::DataChannelDelivery
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::DataChannelDelivery>
+    for crate::api::simple::DataChannelDelivery
+{
+    fn into_into_dart(self) -> crate::api::simple::DataChannelDelivery {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::DecodedVideoFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Rgba => 0.into_dart(),
+            Self::I420 => 1.into_dart(),
+            _ => unreachable!(),
         }
-        7 => wire__crate__api__simple__stop_sankaku_sender_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
     }
 }
-
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::DecodedVideoFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::DecodedVideoFormat>
+    for crate::api::simple::DecodedVideoFormat
+{
+    fn into_into_dart(self) -> crate::api::simple::DecodedVideoFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::FileTransferOutcome {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Completed => 0.into_dart(),
+            Self::Rejected => 1.into_dart(),
+            Self::Cancelled => 2.into_dart(),
+            Self::Failed => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::FileTransferOutcome
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::FileTransferOutcome>
+    for crate::api::simple::FileTransferOutcome
+{
+    fn into_into_dart(self) -> crate::api::simple::FileTransferOutcome {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::KeyframeDetectionMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::TrustCaller => 0.into_dart(),
+            Self::CrossCheck => 1.into_dart(),
+            Self::Override => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::KeyframeDetectionMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::KeyframeDetectionMode>
+    for crate::api::simple::KeyframeDetectionMode
+{
+    fn into_into_dart(self) -> crate::api::simple::KeyframeDetectionMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::RemoteCommand {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::simple::RemoteCommand::SwitchCamera => [0.into_dart()].into_dart(),
+            crate::api::simple::RemoteCommand::SetTorch { enabled } => {
+                [1.into_dart(), enabled.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::simple::RemoteCommand::SetBitrate { bitrate_bps } => {
+                [2.into_dart(), bitrate_bps.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::simple::RemoteCommand::StartCapture => [3.into_dart()].into_dart(),
+            crate::api::simple::RemoteCommand::StopCapture => [4.into_dart()].into_dart(),
+            crate::api::simple::RemoteCommand::RequestDebugReport => [5.into_dart()].into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::RemoteCommand
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::RemoteCommand>
+    for crate::api::simple::RemoteCommand
+{
+    fn into_into_dart(self) -> crate::api::simple::RemoteCommand {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::RemoteCommandPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.allow_switch_camera.into_into_dart().into_dart(),
+            self.allow_torch.into_into_dart().into_dart(),
+            self.allow_bitrate.into_into_dart().into_dart(),
+            self.allow_capture.into_into_dart().into_dart(),
+            self.allow_debug_report.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::RemoteCommandPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::RemoteCommandPolicy>
+    for crate::api::simple::RemoteCommandPolicy
+{
+    fn into_into_dart(self) -> crate::api::simple::RemoteCommandPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::RemoteCommandStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Done => 0.into_dart(),
+            Self::Failed => 1.into_dart(),
+            Self::Denied => 2.into_dart(),
+            Self::Unsupported => 3.into_dart(),
+            Self::TimedOut => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::RemoteCommandStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::RemoteCommandStatus>
+    for crate::api::simple::RemoteCommandStatus
+{
+    fn into_into_dart(self) -> crate::api::simple::RemoteCommandStatus {
+        self
     }
 }
-
-// Section: rust2dart
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::simple::UiEvent {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
@@ -566,59 +2965,303 @@ impl flutter_rust_bridge::IntoDart for crate::api::simple::UiEvent {
                 bootstrap_mode.into_into_dart().into_dart(),
             ]
             .into_dart(),
-            crate::api::simple::UiEvent::Progress {
-                stream_id,
-                frame_index,
+            crate::api::simple::UiEvent::Progress {
+                stream_id,
+                frame_index,
+                bytes,
+                frames,
+            } => [
+                4.into_dart(),
+                stream_id.into_into_dart().into_dart(),
+                frame_index.into_into_dart().into_dart(),
+                bytes.into_into_dart().into_dart(),
+                frames.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::UiEvent::Telemetry { name, value } => [
+                5.into_dart(),
+                name.into_into_dart().into_dart(),
+                value.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::UiEvent::FrameDrop { stream_id, reason } => [
+                6.into_dart(),
+                stream_id.into_into_dart().into_dart(),
+                reason.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::UiEvent::Fault { code, message } => [
+                7.into_dart(),
+                code.into_into_dart().into_dart(),
+                message.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::UiEvent::BitrateChanged { bitrate_bps } => {
+                [8.into_dart(), bitrate_bps.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::simple::UiEvent::VideoFrameReceived { data, pts } => [
+                9.into_dart(),
+                data.into_into_dart().into_dart(),
+                pts.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::UiEvent::AudioFrameReceived {
+                data,
+                pts,
+                frames_per_packet,
+            } => [
+                10.into_dart(),
+                data.into_into_dart().into_dart(),
+                pts.into_into_dart().into_dart(),
+                frames_per_packet.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::UiEvent::AudioConcealment {
+                stream_id,
+                pts,
+                frames_per_packet,
+            } => [
+                11.into_dart(),
+                stream_id.into_into_dart().into_dart(),
+                pts.into_into_dart().into_dart(),
+                frames_per_packet.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::UiEvent::PcmAudioReceived {
+                stream_id,
+                pts,
+                sample_rate,
+                channels,
+                frames_per_packet,
+                samples,
+                concealed,
+            } => [
+                12.into_dart(),
+                stream_id.into_into_dart().into_dart(),
+                pts.into_into_dart().into_dart(),
+                sample_rate.into_into_dart().into_dart(),
+                channels.into_into_dart().into_dart(),
+                frames_per_packet.into_into_dart().into_dart(),
+                samples.into_into_dart().into_dart(),
+                concealed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::UiEvent::DecodedVideoFrame {
+                stream_id,
+                pts,
+                width,
+                height,
+                format,
+                data,
+            } => [
+                13.into_dart(),
+                stream_id.into_into_dart().into_dart(),
+                pts.into_into_dart().into_dart(),
+                width.into_into_dart().into_dart(),
+                height.into_into_dart().into_dart(),
+                format.into_into_dart().into_dart(),
+                data.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::UiEvent::StreamFormat {
+                stream_id,
+                codec,
+                width,
+                height,
+                profile_idc,
+                high_tier,
+                level_idc,
+                chroma_format_idc,
+                bit_depth_luma,
+                bit_depth_chroma,
+                frame_rate_num,
+                frame_rate_den,
+            } => [
+                14.into_dart(),
+                stream_id.into_into_dart().into_dart(),
+                codec.into_into_dart().into_dart(),
+                width.into_into_dart().into_dart(),
+                height.into_into_dart().into_dart(),
+                profile_idc.into_into_dart().into_dart(),
+                high_tier.into_into_dart().into_dart(),
+                level_idc.into_into_dart().into_dart(),
+                chroma_format_idc.into_into_dart().into_dart(),
+                bit_depth_luma.into_into_dart().into_dart(),
+                bit_depth_chroma.into_into_dart().into_dart(),
+                frame_rate_num.into_into_dart().into_dart(),
+                frame_rate_den.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::UiEvent::Congestion {
+                queue_depth,
+                queue_capacity,
+                estimated_send_delay_ms,
+                recommended_action,
+            } => [
+                15.into_dart(),
+                queue_depth.into_into_dart().into_dart(),
+                queue_capacity.into_into_dart().into_dart(),
+                estimated_send_delay_ms.into_into_dart().into_dart(),
+                recommended_action.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::UiEvent::TemporalLayers {
+                stream_id,
+                active_layers,
+                total_layers,
+            } => [
+                16.into_dart(),
+                stream_id.into_into_dart().into_dart(),
+                active_layers.into_into_dart().into_dart(),
+                total_layers.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::UiEvent::AudioGap {
+                stream_id,
+                expected_pts,
+                missing_us,
+                missing_packets,
+                concealment_packets,
+            } => [
+                17.into_dart(),
+                stream_id.into_into_dart().into_dart(),
+                expected_pts.into_into_dart().into_dart(),
+                missing_us.into_into_dart().into_dart(),
+                missing_packets.into_into_dart().into_dart(),
+                concealment_packets.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::UiEvent::AudioActivity {
+                stream_id,
+                local,
+                speaking,
+            } => [
+                18.into_dart(),
+                stream_id.into_into_dart().into_dart(),
+                local.into_into_dart().into_dart(),
+                speaking.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::UiEvent::CodecNegotiated {
+                codec,
+                codec_name,
+                peer_negotiated,
+            } => [
+                19.into_dart(),
+                codec.into_into_dart().into_dart(),
+                codec_name.into_into_dart().into_dart(),
+                peer_negotiated.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::UiEvent::AudioCodecNegotiated {
+                codec,
+                codec_name,
+                sample_rate,
+                channels,
+                codec_config,
+                peer_negotiated,
+            } => [
+                20.into_dart(),
+                codec.into_into_dart().into_dart(),
+                codec_name.into_into_dart().into_dart(),
+                sample_rate.into_into_dart().into_dart(),
+                channels.into_into_dart().into_dart(),
+                codec_config.into_into_dart().into_dart(),
+                peer_negotiated.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::UiEvent::ControlMessageReceived { message } => {
+                [21.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::simple::UiEvent::DataReceived {
+                channel,
+                data,
+                reliable,
+            } => [
+                22.into_dart(),
+                channel.into_into_dart().into_dart(),
+                data.into_into_dart().into_dart(),
+                reliable.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::UiEvent::FileTransferStarted {
+                transfer_id,
+                incoming,
+                name,
+                size,
+            } => [
+                23.into_dart(),
+                transfer_id.into_into_dart().into_dart(),
+                incoming.into_into_dart().into_dart(),
+                name.into_into_dart().into_dart(),
+                size.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::simple::UiEvent::FileTransferProgress {
+                transfer_id,
+                incoming,
                 bytes,
-                frames,
+                size,
             } => [
-                4.into_dart(),
-                stream_id.into_into_dart().into_dart(),
-                frame_index.into_into_dart().into_dart(),
+                24.into_dart(),
+                transfer_id.into_into_dart().into_dart(),
+                incoming.into_into_dart().into_dart(),
                 bytes.into_into_dart().into_dart(),
-                frames.into_into_dart().into_dart(),
+                size.into_into_dart().into_dart(),
             ]
             .into_dart(),
-            crate::api::simple::UiEvent::Telemetry { name, value } => [
-                5.into_dart(),
+            crate::api::simple::UiEvent::FileTransferFinished {
+                transfer_id,
+                incoming,
+                name,
+                outcome,
+                path,
+                detail,
+            } => [
+                25.into_dart(),
+                transfer_id.into_into_dart().into_dart(),
+                incoming.into_into_dart().into_dart(),
                 name.into_into_dart().into_dart(),
-                value.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::api::simple::UiEvent::FrameDrop { stream_id, reason } => [
-                6.into_dart(),
-                stream_id.into_into_dart().into_dart(),
-                reason.into_into_dart().into_dart(),
+                outcome.into_into_dart().into_dart(),
+                path.into_into_dart().into_dart(),
+                detail.into_into_dart().into_dart(),
             ]
             .into_dart(),
-            crate::api::simple::UiEvent::Fault { code, message } => [
-                7.into_dart(),
-                code.into_into_dart().into_dart(),
-                message.into_into_dart().into_dart(),
+            crate::api::simple::UiEvent::RemoteCommand {
+                command_id,
+                command,
+            } => [
+                26.into_dart(),
+                command_id.into_into_dart().into_dart(),
+                command.into_into_dart().into_dart(),
             ]
             .into_dart(),
-            crate::api::simple::UiEvent::BitrateChanged { bitrate_bps } => {
-                [8.into_dart(), bitrate_bps.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::simple::UiEvent::VideoFrameReceived { data, pts } => [
-                9.into_dart(),
-                data.into_into_dart().into_dart(),
-                pts.into_into_dart().into_dart(),
+            crate::api::simple::UiEvent::RemoteCommandReply {
+                command_id,
+                status,
+                detail,
+            } => [
+                27.into_dart(),
+                command_id.into_into_dart().into_dart(),
+                status.into_into_dart().into_dart(),
+                detail.into_into_dart().into_dart(),
             ]
             .into_dart(),
-            crate::api::simple::UiEvent::AudioFrameReceived {
-                data,
-                pts,
-                frames_per_packet,
+            crate::api::simple::UiEvent::PeerMediaState {
+                microphone_muted,
+                camera_off,
+                audio_only,
+                screen_share,
             } => [
-                10.into_dart(),
-                data.into_into_dart().into_dart(),
-                pts.into_into_dart().into_dart(),
-                frames_per_packet.into_into_dart().into_dart(),
+                28.into_dart(),
+                microphone_muted.into_into_dart().into_dart(),
+                camera_off.into_into_dart().into_dart(),
+                audio_only.into_into_dart().into_dart(),
+                screen_share.into_into_dart().into_dart(),
             ]
             .into_dart(),
             crate::api::simple::UiEvent::Error { msg } => {
-                [11.into_dart(), msg.into_into_dart().into_dart()].into_dart()
+                [29.into_dart(), msg.into_into_dart().into_dart()].into_dart()
             }
             _ => {
                 unimplemented!("");
@@ -634,6 +3277,28 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::UiEvent>
         self
     }
 }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::VideoQueuePolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::DropOldestDeltaFrames => 0.into_dart(),
+            Self::DropUntilKeyframe => 1.into_dart(),
+            Self::Block => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::VideoQueuePolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::VideoQueuePolicy>
+    for crate::api::simple::VideoQueuePolicy
+{
+    fn into_into_dart(self) -> crate::api::simple::VideoQueuePolicy {
+        self
+    }
+}
 
 impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -665,6 +3330,186 @@ impl SseEncode for bool {
     }
 }
 
+impl SseEncode for crate::api::simple::CongestionAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::CongestionAction::None => 0,
+                crate::api::simple::CongestionAction::ReduceBitrate => 1,
+                crate::api::simple::CongestionAction::ReduceFrameRate => 2,
+                crate::api::simple::CongestionAction::SkipFrame => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::CongestionState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.queue_depth, serializer);
+        <u32>::sse_encode(self.queue_capacity, serializer);
+        <u32>::sse_encode(self.estimated_send_delay_ms, serializer);
+        <crate::api::simple::CongestionAction>::sse_encode(self.recommended_action, serializer);
+        <bool>::sse_encode(self.frame_queued, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::ControlMessage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::simple::ControlMessage::KeyframeRequest { stream_id } => {
+                <i32>::sse_encode(0, serializer);
+                <u32>::sse_encode(stream_id, serializer);
+            }
+            crate::api::simple::ControlMessage::MuteState {
+                audio_muted,
+                video_muted,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <bool>::sse_encode(audio_muted, serializer);
+                <bool>::sse_encode(video_muted, serializer);
+            }
+            crate::api::simple::ControlMessage::Hangup { reason } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(reason, serializer);
+            }
+            crate::api::simple::ControlMessage::Capabilities {
+                video_codecs,
+                audio_codecs,
+                max_width,
+                max_height,
+            } => {
+                <i32>::sse_encode(3, serializer);
+                <Vec<u8>>::sse_encode(video_codecs, serializer);
+                <Vec<u8>>::sse_encode(audio_codecs, serializer);
+                <u32>::sse_encode(max_width, serializer);
+                <u32>::sse_encode(max_height, serializer);
+            }
+            crate::api::simple::ControlMessage::ReceiverReport {
+                stream_id,
+                highest_frame_index,
+                lost_frames,
+                late_frames,
+                awaiting_keyframe,
+                jitter_us,
+            } => {
+                <i32>::sse_encode(4, serializer);
+                <u32>::sse_encode(stream_id, serializer);
+                <u64>::sse_encode(highest_frame_index, serializer);
+                <u64>::sse_encode(lost_frames, serializer);
+                <u64>::sse_encode(late_frames, serializer);
+                <bool>::sse_encode(awaiting_keyframe, serializer);
+                <u32>::sse_encode(jitter_us, serializer);
+            }
+            crate::api::simple::ControlMessage::Custom { kind, payload } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(kind, serializer);
+                <Vec<u8>>::sse_encode(payload, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::simple::DataChannelDelivery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::DataChannelDelivery::ReliableOrdered => 0,
+                crate::api::simple::DataChannelDelivery::Unreliable => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::DecodedVideoFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::DecodedVideoFormat::Rgba => 0,
+                crate::api::simple::DecodedVideoFormat::I420 => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::FileTransferOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::FileTransferOutcome::Completed => 0,
+                crate::api::simple::FileTransferOutcome::Rejected => 1,
+                crate::api::simple::FileTransferOutcome::Cancelled => 2,
+                crate::api::simple::FileTransferOutcome::Failed => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for i16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i16::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::simple::KeyframeDetectionMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::KeyframeDetectionMode::TrustCaller => 0,
+                crate::api::simple::KeyframeDetectionMode::CrossCheck => 1,
+                crate::api::simple::KeyframeDetectionMode::Override => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for Vec<i16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <i16>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -675,6 +3520,97 @@ impl SseEncode for Vec<u8> {
     }
 }
 
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<u8>>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::simple::RemoteCommand {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::simple::RemoteCommand::SwitchCamera => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::simple::RemoteCommand::SetTorch { enabled } => {
+                <i32>::sse_encode(1, serializer);
+                <bool>::sse_encode(enabled, serializer);
+            }
+            crate::api::simple::RemoteCommand::SetBitrate { bitrate_bps } => {
+                <i32>::sse_encode(2, serializer);
+                <u32>::sse_encode(bitrate_bps, serializer);
+            }
+            crate::api::simple::RemoteCommand::StartCapture => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::api::simple::RemoteCommand::StopCapture => {
+                <i32>::sse_encode(4, serializer);
+            }
+            crate::api::simple::RemoteCommand::RequestDebugReport => {
+                <i32>::sse_encode(5, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::simple::RemoteCommandPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.allow_switch_camera, serializer);
+        <bool>::sse_encode(self.allow_torch, serializer);
+        <bool>::sse_encode(self.allow_bitrate, serializer);
+        <bool>::sse_encode(self.allow_capture, serializer);
+        <bool>::sse_encode(self.allow_debug_report, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::RemoteCommandStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::RemoteCommandStatus::Done => 0,
+                crate::api::simple::RemoteCommandStatus::Failed => 1,
+                crate::api::simple::RemoteCommandStatus::Denied => 2,
+                crate::api::simple::RemoteCommandStatus::Unsupported => 3,
+                crate::api::simple::RemoteCommandStatus::TimedOut => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -766,8 +3702,236 @@ impl SseEncode for crate::api::simple::UiEvent {
                 <u64>::sse_encode(pts, serializer);
                 <u32>::sse_encode(frames_per_packet, serializer);
             }
-            crate::api::simple::UiEvent::Error { msg } => {
+            crate::api::simple::UiEvent::AudioConcealment {
+                stream_id,
+                pts,
+                frames_per_packet,
+            } => {
                 <i32>::sse_encode(11, serializer);
+                <u32>::sse_encode(stream_id, serializer);
+                <u64>::sse_encode(pts, serializer);
+                <u32>::sse_encode(frames_per_packet, serializer);
+            }
+            crate::api::simple::UiEvent::PcmAudioReceived {
+                stream_id,
+                pts,
+                sample_rate,
+                channels,
+                frames_per_packet,
+                samples,
+                concealed,
+            } => {
+                <i32>::sse_encode(12, serializer);
+                <u32>::sse_encode(stream_id, serializer);
+                <u64>::sse_encode(pts, serializer);
+                <u32>::sse_encode(sample_rate, serializer);
+                <u32>::sse_encode(channels, serializer);
+                <u32>::sse_encode(frames_per_packet, serializer);
+                <Vec<i16>>::sse_encode(samples, serializer);
+                <bool>::sse_encode(concealed, serializer);
+            }
+            crate::api::simple::UiEvent::DecodedVideoFrame {
+                stream_id,
+                pts,
+                width,
+                height,
+                format,
+                data,
+            } => {
+                <i32>::sse_encode(13, serializer);
+                <u32>::sse_encode(stream_id, serializer);
+                <u64>::sse_encode(pts, serializer);
+                <u32>::sse_encode(width, serializer);
+                <u32>::sse_encode(height, serializer);
+                <crate::api::simple::DecodedVideoFormat>::sse_encode(format, serializer);
+                <Vec<u8>>::sse_encode(data, serializer);
+            }
+            crate::api::simple::UiEvent::StreamFormat {
+                stream_id,
+                codec,
+                width,
+                height,
+                profile_idc,
+                high_tier,
+                level_idc,
+                chroma_format_idc,
+                bit_depth_luma,
+                bit_depth_chroma,
+                frame_rate_num,
+                frame_rate_den,
+            } => {
+                <i32>::sse_encode(14, serializer);
+                <u32>::sse_encode(stream_id, serializer);
+                <String>::sse_encode(codec, serializer);
+                <u32>::sse_encode(width, serializer);
+                <u32>::sse_encode(height, serializer);
+                <u32>::sse_encode(profile_idc, serializer);
+                <bool>::sse_encode(high_tier, serializer);
+                <u32>::sse_encode(level_idc, serializer);
+                <u32>::sse_encode(chroma_format_idc, serializer);
+                <u32>::sse_encode(bit_depth_luma, serializer);
+                <u32>::sse_encode(bit_depth_chroma, serializer);
+                <u32>::sse_encode(frame_rate_num, serializer);
+                <u32>::sse_encode(frame_rate_den, serializer);
+            }
+            crate::api::simple::UiEvent::Congestion {
+                queue_depth,
+                queue_capacity,
+                estimated_send_delay_ms,
+                recommended_action,
+            } => {
+                <i32>::sse_encode(15, serializer);
+                <u32>::sse_encode(queue_depth, serializer);
+                <u32>::sse_encode(queue_capacity, serializer);
+                <u32>::sse_encode(estimated_send_delay_ms, serializer);
+                <String>::sse_encode(recommended_action, serializer);
+            }
+            crate::api::simple::UiEvent::TemporalLayers {
+                stream_id,
+                active_layers,
+                total_layers,
+            } => {
+                <i32>::sse_encode(16, serializer);
+                <u32>::sse_encode(stream_id, serializer);
+                <u32>::sse_encode(active_layers, serializer);
+                <u32>::sse_encode(total_layers, serializer);
+            }
+            crate::api::simple::UiEvent::AudioGap {
+                stream_id,
+                expected_pts,
+                missing_us,
+                missing_packets,
+                concealment_packets,
+            } => {
+                <i32>::sse_encode(17, serializer);
+                <u32>::sse_encode(stream_id, serializer);
+                <u64>::sse_encode(expected_pts, serializer);
+                <u64>::sse_encode(missing_us, serializer);
+                <u32>::sse_encode(missing_packets, serializer);
+                <u32>::sse_encode(concealment_packets, serializer);
+            }
+            crate::api::simple::UiEvent::AudioActivity {
+                stream_id,
+                local,
+                speaking,
+            } => {
+                <i32>::sse_encode(18, serializer);
+                <u32>::sse_encode(stream_id, serializer);
+                <bool>::sse_encode(local, serializer);
+                <bool>::sse_encode(speaking, serializer);
+            }
+            crate::api::simple::UiEvent::CodecNegotiated {
+                codec,
+                codec_name,
+                peer_negotiated,
+            } => {
+                <i32>::sse_encode(19, serializer);
+                <u8>::sse_encode(codec, serializer);
+                <String>::sse_encode(codec_name, serializer);
+                <bool>::sse_encode(peer_negotiated, serializer);
+            }
+            crate::api::simple::UiEvent::AudioCodecNegotiated {
+                codec,
+                codec_name,
+                sample_rate,
+                channels,
+                codec_config,
+                peer_negotiated,
+            } => {
+                <i32>::sse_encode(20, serializer);
+                <u8>::sse_encode(codec, serializer);
+                <String>::sse_encode(codec_name, serializer);
+                <u32>::sse_encode(sample_rate, serializer);
+                <u32>::sse_encode(channels, serializer);
+                <Vec<u8>>::sse_encode(codec_config, serializer);
+                <bool>::sse_encode(peer_negotiated, serializer);
+            }
+            crate::api::simple::UiEvent::ControlMessageReceived { message } => {
+                <i32>::sse_encode(21, serializer);
+                <crate::api::simple::ControlMessage>::sse_encode(message, serializer);
+            }
+            crate::api::simple::UiEvent::DataReceived {
+                channel,
+                data,
+                reliable,
+            } => {
+                <i32>::sse_encode(22, serializer);
+                <String>::sse_encode(channel, serializer);
+                <Vec<u8>>::sse_encode(data, serializer);
+                <bool>::sse_encode(reliable, serializer);
+            }
+            crate::api::simple::UiEvent::FileTransferStarted {
+                transfer_id,
+                incoming,
+                name,
+                size,
+            } => {
+                <i32>::sse_encode(23, serializer);
+                <u32>::sse_encode(transfer_id, serializer);
+                <bool>::sse_encode(incoming, serializer);
+                <String>::sse_encode(name, serializer);
+                <u64>::sse_encode(size, serializer);
+            }
+            crate::api::simple::UiEvent::FileTransferProgress {
+                transfer_id,
+                incoming,
+                bytes,
+                size,
+            } => {
+                <i32>::sse_encode(24, serializer);
+                <u32>::sse_encode(transfer_id, serializer);
+                <bool>::sse_encode(incoming, serializer);
+                <u64>::sse_encode(bytes, serializer);
+                <u64>::sse_encode(size, serializer);
+            }
+            crate::api::simple::UiEvent::FileTransferFinished {
+                transfer_id,
+                incoming,
+                name,
+                outcome,
+                path,
+                detail,
+            } => {
+                <i32>::sse_encode(25, serializer);
+                <u32>::sse_encode(transfer_id, serializer);
+                <bool>::sse_encode(incoming, serializer);
+                <String>::sse_encode(name, serializer);
+                <crate::api::simple::FileTransferOutcome>::sse_encode(outcome, serializer);
+                <String>::sse_encode(path, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::api::simple::UiEvent::RemoteCommand {
+                command_id,
+                command,
+            } => {
+                <i32>::sse_encode(26, serializer);
+                <u32>::sse_encode(command_id, serializer);
+                <crate::api::simple::RemoteCommand>::sse_encode(command, serializer);
+            }
+            crate::api::simple::UiEvent::RemoteCommandReply {
+                command_id,
+                status,
+                detail,
+            } => {
+                <i32>::sse_encode(27, serializer);
+                <u32>::sse_encode(command_id, serializer);
+                <crate::api::simple::RemoteCommandStatus>::sse_encode(status, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::api::simple::UiEvent::PeerMediaState {
+                microphone_muted,
+                camera_off,
+                audio_only,
+                screen_share,
+            } => {
+                <i32>::sse_encode(28, serializer);
+                <bool>::sse_encode(microphone_muted, serializer);
+                <bool>::sse_encode(camera_off, serializer);
+                <bool>::sse_encode(audio_only, serializer);
+                <bool>::sse_encode(screen_share, serializer);
+            }
+            crate::api::simple::UiEvent::Error { msg } => {
+                <i32>::sse_encode(29, serializer);
                 <String>::sse_encode(msg, serializer);
             }
             _ => {
@@ -782,10 +3946,20 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::api::simple::VideoQueuePolicy {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::VideoQueuePolicy::DropOldestDeltaFrames => 0,
+                crate::api::simple::VideoQueuePolicy::DropUntilKeyframe => 1,
+                crate::api::simple::VideoQueuePolicy::Block => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
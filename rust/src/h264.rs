@@ -0,0 +1,433 @@
+use crate::bitstream::{annexb_nal_units, write_nal_units, BitReader, NalFraming};
+use crate::codec::VideoStreamFormat;
+use std::collections::BTreeMap;
+
+pub const NAL_SLICE: u8 = 1;
+pub const NAL_IDR_SLICE: u8 = 5;
+pub const NAL_SPS: u8 = 7;
+pub const NAL_PPS: u8 = 8;
+pub const NAL_AUD: u8 = 9;
+
+/// `nal_unit_type` from the one-byte H.264 NAL unit header.
+pub fn nal_unit_type(nal: &[u8]) -> Option<u8> {
+    nal.first().map(|header| header & 0x1F)
+}
+
+/// Returns whether the access unit is an IDR picture, or `None` when it carries no
+/// coded slices.
+pub fn classify_access_unit(data: &[u8]) -> Option<bool> {
+    annexb_nal_units(data)
+        .into_iter()
+        .filter_map(nal_unit_type)
+        .find(|nal_type| (NAL_SLICE..=NAL_IDR_SLICE).contains(nal_type))
+        .map(|nal_type| nal_type == NAL_IDR_SLICE)
+}
+
+#[derive(Debug, Clone)]
+pub struct SpsInfo {
+    pub sps_id: u8,
+    format: VideoStreamFormat,
+}
+
+fn skip_scaling_list(reader: &mut BitReader, size: usize) -> Option<()> {
+    let mut last_scale = 8i32;
+    let mut next_scale = 8i32;
+    for _ in 0..size {
+        if next_scale != 0 {
+            let delta_scale = reader.read_se()?;
+            next_scale = (last_scale + delta_scale + 256) % 256;
+        }
+        if next_scale != 0 {
+            last_scale = next_scale;
+        }
+    }
+    Some(())
+}
+
+pub fn parse_sps(nal: &[u8]) -> Option<SpsInfo> {
+    let mut reader = BitReader::from_rbsp(nal.get(1..)?);
+    let profile_idc = reader.read_bits(8)?;
+    // constraint_set flags and reserved bits
+    reader.skip_bits(8)?;
+    let level_idc = reader.read_bits(8)?;
+    let sps_id = reader.read_ue()?;
+    if sps_id > 31 {
+        return None;
+    }
+
+    let mut chroma_format_idc = 1;
+    let mut separate_colour_plane = false;
+    let mut bit_depth_luma = 8;
+    let mut bit_depth_chroma = 8;
+    if matches!(
+        profile_idc,
+        100 | 110 | 122 | 244 | 44 | 83 | 86 | 118 | 128 | 138 | 139 | 134 | 135
+    ) {
+        chroma_format_idc = reader.read_ue()?;
+        if chroma_format_idc > 3 {
+            return None;
+        }
+        if chroma_format_idc == 3 {
+            separate_colour_plane = reader.read_bit()?;
+        }
+        bit_depth_luma = reader.read_ue()?.checked_add(8)?;
+        bit_depth_chroma = reader.read_ue()?.checked_add(8)?;
+        // qpprime_y_zero_transform_bypass_flag
+        reader.skip_bits(1)?;
+        if reader.read_bit()? {
+            let lists = if chroma_format_idc == 3 { 12 } else { 8 };
+            for index in 0..lists {
+                if reader.read_bit()? {
+                    skip_scaling_list(&mut reader, if index < 6 { 16 } else { 64 })?;
+                }
+            }
+        }
+    }
+    if bit_depth_luma > 14 || bit_depth_chroma > 14 {
+        return None;
+    }
+
+    // log2_max_frame_num_minus4
+    reader.read_ue()?;
+    match reader.read_ue()? {
+        0 => {
+            reader.read_ue()?;
+        }
+        1 => {
+            reader.skip_bits(1)?;
+            reader.read_se()?;
+            reader.read_se()?;
+            let cycle = reader.read_ue()?;
+            if cycle > 255 {
+                return None;
+            }
+            for _ in 0..cycle {
+                reader.read_se()?;
+            }
+        }
+        _ => {}
+    }
+    // max_num_ref_frames, gaps_in_frame_num_value_allowed_flag
+    reader.read_ue()?;
+    reader.skip_bits(1)?;
+    let width_in_mbs = reader.read_ue()?.checked_add(1)?;
+    let height_in_map_units = reader.read_ue()?.checked_add(1)?;
+    let frame_mbs_only = reader.read_bit()?;
+    if !frame_mbs_only {
+        reader.skip_bits(1)?;
+    }
+    // direct_8x8_inference_flag
+    reader.skip_bits(1)?;
+
+    let field_factor = if frame_mbs_only { 1 } else { 2 };
+    let mut width = width_in_mbs.checked_mul(16)?;
+    let mut height = height_in_map_units.checked_mul(16 * field_factor)?;
+    if reader.read_bit()? {
+        let left = reader.read_ue()?;
+        let right = reader.read_ue()?;
+        let top = reader.read_ue()?;
+        let bottom = reader.read_ue()?;
+        let chroma_array_type = if separate_colour_plane {
+            0
+        } else {
+            chroma_format_idc
+        };
+        let (crop_x, crop_y) = match chroma_array_type {
+            0 => (1, field_factor),
+            1 => (2, 2 * field_factor),
+            2 => (2, field_factor),
+            _ => (1, field_factor),
+        };
+        width = width.saturating_sub(crop_x * left.saturating_add(right));
+        height = height.saturating_sub(crop_y * top.saturating_add(bottom));
+    }
+
+    let (frame_rate_num, frame_rate_den) = if reader.read_bit()? {
+        parse_vui_frame_rate(&mut reader).unwrap_or((0, 0))
+    } else {
+        (0, 0)
+    };
+
+    Some(SpsInfo {
+        sps_id: sps_id as u8,
+        format: VideoStreamFormat {
+            width,
+            height,
+            profile_idc: profile_idc as u8,
+            high_tier: false,
+            level_idc: level_idc as u8,
+            chroma_format_idc: chroma_format_idc as u8,
+            bit_depth_luma: bit_depth_luma as u8,
+            bit_depth_chroma: bit_depth_chroma as u8,
+            frame_rate_num,
+            frame_rate_den,
+        },
+    })
+}
+
+/// Frame rate from the VUI timing info; H.264 ticks count fields, so one frame is
+/// two ticks.
+fn parse_vui_frame_rate(reader: &mut BitReader) -> Option<(u32, u32)> {
+    if reader.read_bit()? && reader.read_bits(8)? == 255 {
+        reader.skip_bits(32)?;
+    }
+    if reader.read_bit()? {
+        reader.skip_bits(1)?;
+    }
+    if reader.read_bit()? {
+        reader.skip_bits(3 + 1)?;
+        if reader.read_bit()? {
+            reader.skip_bits(24)?;
+        }
+    }
+    if reader.read_bit()? {
+        reader.read_ue()?;
+        reader.read_ue()?;
+    }
+    if !reader.read_bit()? {
+        return Some((0, 0));
+    }
+    let num_units_in_tick = reader.read_bits(32)?;
+    let time_scale = reader.read_bits(32)?;
+    if num_units_in_tick == 0 || time_scale == 0 {
+        return Some((0, 0));
+    }
+    Some((time_scale, num_units_in_tick.checked_mul(2)?))
+}
+
+fn parse_pps_ids(nal: &[u8]) -> Option<(u8, u8)> {
+    let mut reader = BitReader::from_rbsp(nal.get(1..)?);
+    let pps_id = reader.read_ue()?;
+    let sps_id = reader.read_ue()?;
+    if pps_id > 255 || sps_id > 31 {
+        return None;
+    }
+    Some((pps_id as u8, sps_id as u8))
+}
+
+/// Follows the SPS/PPS of one H.264 stream and reports format changes.
+#[derive(Debug, Default)]
+pub struct H264FormatTracker {
+    sps: BTreeMap<u8, SpsInfo>,
+    active_sps_id: Option<u8>,
+    current: Option<VideoStreamFormat>,
+}
+
+impl H264FormatTracker {
+    pub fn observe_access_unit(&mut self, data: &[u8]) -> Option<VideoStreamFormat> {
+        for nal in annexb_nal_units(data) {
+            match nal_unit_type(nal) {
+                Some(NAL_SPS) => {
+                    if let Some(sps) = parse_sps(nal) {
+                        self.active_sps_id.get_or_insert(sps.sps_id);
+                        self.sps.insert(sps.sps_id, sps);
+                    }
+                }
+                Some(NAL_PPS) => {
+                    if let Some((_, sps_id)) = parse_pps_ids(nal) {
+                        self.active_sps_id = Some(sps_id);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let format = self.sps.get(&self.active_sps_id?)?.format.clone();
+        if self.current.as_ref() == Some(&format) {
+            return None;
+        }
+        self.current = Some(format.clone());
+        Some(format)
+    }
+}
+
+/// Most recent SPS/PPS NAL units of one H.264 stream, keyed by their ids.
+#[derive(Debug, Default)]
+pub struct H264ParameterSetCache {
+    sps: BTreeMap<u8, Vec<u8>>,
+    pps: BTreeMap<u8, Vec<u8>>,
+}
+
+impl H264ParameterSetCache {
+    pub fn observe_access_unit(&mut self, data: &[u8]) {
+        for nal in annexb_nal_units(data) {
+            match nal_unit_type(nal) {
+                Some(NAL_SPS) => {
+                    if let Some(sps) = parse_sps(nal) {
+                        self.sps.insert(sps.sps_id, nal.to_vec());
+                    }
+                }
+                Some(NAL_PPS) => {
+                    if let Some((pps_id, _)) = parse_pps_ids(nal) {
+                        self.pps.insert(pps_id, nal.to_vec());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        !self.sps.is_empty() && !self.pps.is_empty()
+    }
+
+    pub fn to_annexb(&self) -> Vec<u8> {
+        let units: Vec<&[u8]> = self
+            .sps
+            .values()
+            .chain(self.pps.values())
+            .map(Vec::as_slice)
+            .collect();
+        write_nal_units(&units, NalFraming::AnnexB).unwrap_or_default()
+    }
+
+    /// Rebuilds an IDR access unit that lacks SPS or PPS, keeping an AUD first.
+    pub fn complete_idr(&self, data: &[u8]) -> Option<Vec<u8>> {
+        if !classify_access_unit(data)? || !self.is_complete() {
+            return None;
+        }
+        let nals = annexb_nal_units(data);
+        let has_type = |wanted: u8| nals.iter().any(|nal| nal_unit_type(nal) == Some(wanted));
+        let (has_sps, has_pps) = (has_type(NAL_SPS), has_type(NAL_PPS));
+        if has_sps && has_pps {
+            return None;
+        }
+
+        let mut ordered: Vec<&[u8]> = Vec::with_capacity(nals.len() + 2);
+        ordered.extend(
+            nals.iter()
+                .filter(|nal| nal_unit_type(nal) == Some(NAL_AUD)),
+        );
+        for (nal_type, present, cached) in
+            [(NAL_SPS, has_sps, &self.sps), (NAL_PPS, has_pps, &self.pps)]
+        {
+            if present {
+                ordered.extend(
+                    nals.iter()
+                        .filter(|nal| nal_unit_type(nal) == Some(nal_type)),
+                );
+            } else {
+                ordered.extend(cached.values().map(Vec::as_slice));
+            }
+        }
+        ordered.extend(
+            nals.iter()
+                .filter(|nal| !matches!(nal_unit_type(nal), Some(NAL_SPS | NAL_PPS | NAL_AUD))),
+        );
+        write_nal_units(&ordered, NalFraming::AnnexB)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitstream::test_support::{annexb, to_ebsp, BitWriter};
+
+    fn nal(nal_type: u8, rbsp: &[u8]) -> Vec<u8> {
+        let mut nal = vec![0x60 | nal_type];
+        nal.extend_from_slice(&to_ebsp(rbsp));
+        nal
+    }
+
+    /// Baseline 1920x1080 SPS with VUI timing for 29.97 fps.
+    fn baseline_sps(sps_id: u32) -> Vec<u8> {
+        let mut writer = BitWriter::default();
+        writer.bits(66, 8).bits(0, 8).bits(40, 8).ue(sps_id);
+        // frame_num bits, pic_order_cnt_type 2, one reference frame, no gaps
+        writer.ue(0).ue(2).ue(1).bit(false);
+        writer.ue(119).ue(67).bit(true).bit(true);
+        writer.bit(true).ue(0).ue(0).ue(0).ue(4);
+        writer.bit(true).bits(0, 4);
+        writer.bit(true).bits(1001, 32).bits(60_000, 32).bit(true);
+        nal(NAL_SPS, &writer.finish_rbsp())
+    }
+
+    /// High 10 SPS without cropping or VUI.
+    fn high10_sps() -> Vec<u8> {
+        let mut writer = BitWriter::default();
+        writer.bits(110, 8).bits(0, 8).bits(51, 8).ue(0);
+        writer.ue(1).ue(2).ue(2).bit(false).bit(false);
+        writer.ue(0).ue(2).ue(1).bit(false);
+        writer.ue(79).ue(44).bit(true).bit(true);
+        writer.bit(false).bit(false);
+        nal(NAL_SPS, &writer.finish_rbsp())
+    }
+
+    fn pps(pps_id: u32, sps_id: u32) -> Vec<u8> {
+        let mut writer = BitWriter::default();
+        writer.ue(pps_id).ue(sps_id).bits(0, 5);
+        nal(NAL_PPS, &writer.finish_rbsp())
+    }
+
+    const IDR: [u8; 3] = [0x65, 0x88, 0x84];
+    const NON_IDR: [u8; 3] = [0x41, 0x9A, 0x02];
+
+    #[test]
+    fn sps_parses_cropped_size_and_vui_frame_rate() {
+        let info = parse_sps(&baseline_sps(2)).unwrap();
+        assert_eq!(info.sps_id, 2);
+        let format = info.format;
+        assert_eq!((format.width, format.height), (1920, 1080));
+        assert_eq!((format.profile_idc, format.level_idc), (66, 40));
+        assert_eq!(format.chroma_format_idc, 1);
+        assert_eq!(
+            (format.frame_rate_num, format.frame_rate_den),
+            (60_000, 2002)
+        );
+    }
+
+    #[test]
+    fn high_profile_sps_reads_chroma_format_and_bit_depth() {
+        let format = parse_sps(&high10_sps()).unwrap().format;
+        assert_eq!((format.width, format.height), (1280, 720));
+        assert_eq!(format.profile_idc, 110);
+        assert_eq!((format.bit_depth_luma, format.bit_depth_chroma), (10, 10));
+        assert_eq!((format.frame_rate_num, format.frame_rate_den), (0, 0));
+    }
+
+    #[test]
+    fn truncated_sps_is_rejected() {
+        assert!(parse_sps(&baseline_sps(0)[..6]).is_none());
+    }
+
+    #[test]
+    fn access_units_are_classified_by_first_slice() {
+        let (sps, pps) = (baseline_sps(0), pps(0, 0));
+        assert_eq!(
+            classify_access_unit(&annexb(&[&sps, &pps, &IDR])),
+            Some(true)
+        );
+        assert_eq!(classify_access_unit(&annexb(&[&NON_IDR])), Some(false));
+        assert_eq!(classify_access_unit(&annexb(&[&sps, &pps])), None);
+    }
+
+    #[test]
+    fn format_tracker_reports_first_format_and_changes_only() {
+        let mut tracker = H264FormatTracker::default();
+        let first = annexb(&[&baseline_sps(0), &pps(0, 0), &IDR]);
+        let format = tracker.observe_access_unit(&first).unwrap();
+        assert_eq!(format.height, 1080);
+        assert!(tracker.observe_access_unit(&first).is_none());
+
+        let format = tracker
+            .observe_access_unit(&annexb(&[&high10_sps(), &pps(0, 0)]))
+            .unwrap();
+        assert_eq!(format.bit_depth_luma, 10);
+    }
+
+    #[test]
+    fn parameter_set_cache_completes_bare_idr_frames() {
+        let (sps, pps) = (baseline_sps(0), pps(0, 0));
+        let aud = [0x09, 0xF0];
+        let mut cache = H264ParameterSetCache::default();
+        assert!(!cache.is_complete());
+        cache.observe_access_unit(&annexb(&[&sps, &pps, &IDR]));
+        assert!(cache.is_complete());
+        assert_eq!(cache.to_annexb(), annexb(&[&sps, &pps]));
+
+        let completed = cache.complete_idr(&annexb(&[&aud, &IDR])).unwrap();
+        assert_eq!(completed, annexb(&[&aud, &sps, &pps, &IDR]));
+        assert_eq!(cache.complete_idr(&annexb(&[&sps, &pps, &IDR])), None);
+        assert_eq!(cache.complete_idr(&annexb(&[&NON_IDR])), None);
+    }
+}
@@ -0,0 +1,996 @@
+use crate::bitstream::{annexb_nal_units, write_nal_units, BitReader, NalFraming};
+use crate::codec::VideoStreamFormat;
+use std::collections::BTreeMap;
+
+pub const NAL_TSA_N: u8 = 2;
+pub const NAL_STSA_R: u8 = 5;
+pub const NAL_BLA_W_LP: u8 = 16;
+pub const NAL_BLA_N_LP: u8 = 18;
+pub const NAL_IDR_W_RADL: u8 = 19;
+pub const NAL_IDR_N_LP: u8 = 20;
+pub const NAL_CRA: u8 = 21;
+/// Last value of the IRAP range; 22 and 23 are reserved IRAP types.
+pub const NAL_IRAP_RESERVED_23: u8 = 23;
+pub const NAL_VPS: u8 = 32;
+pub const NAL_SPS: u8 = 33;
+pub const NAL_PPS: u8 = 34;
+pub const NAL_AUD: u8 = 35;
+pub const NAL_PREFIX_SEI: u8 = 39;
+
+/// `nal_unit_type` from the two-byte HEVC NAL unit header.
+pub fn nal_unit_type(nal: &[u8]) -> Option<u8> {
+    if nal.len() < 2 {
+        return None;
+    }
+    Some((nal[0] >> 1) & 0x3F)
+}
+
+/// `TemporalId` from the two-byte HEVC NAL unit header, or `None` when
+/// `nuh_temporal_id_plus1` holds the forbidden value 0.
+pub fn temporal_id(nal: &[u8]) -> Option<u8> {
+    if nal.len() < 2 {
+        return None;
+    }
+    (nal[1] & 0x07).checked_sub(1)
+}
+
+/// Type and temporal layer of the first VCL NAL unit of an access unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VclHeader {
+    pub nal_type: u8,
+    pub temporal_id: u8,
+}
+
+impl VclHeader {
+    pub fn is_irap(self) -> bool {
+        (NAL_BLA_W_LP..=NAL_IRAP_RESERVED_23).contains(&self.nal_type)
+    }
+
+    /// TSA and STSA pictures are where a decoder may start decoding a higher
+    /// temporal layer.
+    pub fn is_temporal_switch_point(self) -> bool {
+        (NAL_TSA_N..=NAL_STSA_R).contains(&self.nal_type)
+    }
+}
+
+pub fn first_vcl_header(data: &[u8]) -> Option<VclHeader> {
+    annexb_nal_units(data)
+        .into_iter()
+        .find(|nal| nal_unit_type(nal).is_some_and(|nal_type| nal_type < NAL_VPS))
+        .and_then(|nal| {
+            Some(VclHeader {
+                nal_type: nal_unit_type(nal)?,
+                temporal_id: temporal_id(nal)?,
+            })
+        })
+}
+
+/// Random-access class of an access unit, taken from its VCL NAL unit types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PictureKind {
+    Idr,
+    Cra,
+    Bla,
+    /// Reserved IRAP types 22/23, still decodable as a random access point.
+    ReservedIrap,
+    /// TRAIL, TSA, STSA, RADL, RASL and reserved non-IRAP types.
+    NonIrap,
+}
+
+impl PictureKind {
+    pub fn is_irap(self) -> bool {
+        !matches!(self, PictureKind::NonIrap)
+    }
+}
+
+/// Classifies an access unit by its first VCL NAL unit. Returns `None` when the
+/// payload contains no VCL NAL units at all (parameter sets or SEI only).
+pub fn classify_access_unit(data: &[u8]) -> Option<PictureKind> {
+    annexb_nal_units(data)
+        .into_iter()
+        .filter_map(nal_unit_type)
+        .find(|nal_type| *nal_type < NAL_VPS)
+        .map(|nal_type| match nal_type {
+            NAL_BLA_W_LP..=NAL_BLA_N_LP => PictureKind::Bla,
+            NAL_IDR_W_RADL | NAL_IDR_N_LP => PictureKind::Idr,
+            NAL_CRA => PictureKind::Cra,
+            22..=NAL_IRAP_RESERVED_23 => PictureKind::ReservedIrap,
+            _ => PictureKind::NonIrap,
+        })
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ProfileTierLevel {
+    profile_idc: u8,
+    high_tier: bool,
+    level_idc: u8,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TimingInfo {
+    num_units_in_tick: u32,
+    time_scale: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct VpsInfo {
+    pub vps_id: u8,
+    timing: Option<TimingInfo>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SpsInfo {
+    pub sps_id: u8,
+    pub vps_id: u8,
+    ptl: ProfileTierLevel,
+    chroma_format_idc: u8,
+    width: u32,
+    height: u32,
+    bit_depth_luma: u8,
+    bit_depth_chroma: u8,
+    timing: Option<TimingInfo>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PpsInfo {
+    pub pps_id: u8,
+    pub sps_id: u8,
+}
+
+fn parse_profile_tier_level(
+    reader: &mut BitReader,
+    max_sub_layers_minus1: u32,
+) -> Option<ProfileTierLevel> {
+    let _profile_space = reader.read_bits(2)?;
+    let high_tier = reader.read_bit()?;
+    let profile_idc = reader.read_bits(5)? as u8;
+    // compatibility flags (32), source flags (4), constraint/reserved bits (44)
+    reader.skip_bits(32 + 4 + 43 + 1)?;
+    let level_idc = reader.read_bits(8)? as u8;
+
+    let mut sub_layer_flags = Vec::with_capacity(max_sub_layers_minus1 as usize);
+    for _ in 0..max_sub_layers_minus1 {
+        let profile_present = reader.read_bit()?;
+        let level_present = reader.read_bit()?;
+        sub_layer_flags.push((profile_present, level_present));
+    }
+    if max_sub_layers_minus1 > 0 {
+        reader.skip_bits(2 * (8 - max_sub_layers_minus1 as usize))?;
+    }
+    for (profile_present, level_present) in sub_layer_flags {
+        if profile_present {
+            reader.skip_bits(88)?;
+        }
+        if level_present {
+            reader.skip_bits(8)?;
+        }
+    }
+
+    Some(ProfileTierLevel {
+        profile_idc,
+        high_tier,
+        level_idc,
+    })
+}
+
+fn parse_timing_info(reader: &mut BitReader) -> Option<Option<TimingInfo>> {
+    if !reader.read_bit()? {
+        return Some(None);
+    }
+    let num_units_in_tick = reader.read_bits(32)?;
+    let time_scale = reader.read_bits(32)?;
+    if num_units_in_tick == 0 || time_scale == 0 {
+        return Some(None);
+    }
+    Some(Some(TimingInfo {
+        num_units_in_tick,
+        time_scale,
+    }))
+}
+
+pub fn parse_vps(nal: &[u8]) -> Option<VpsInfo> {
+    let mut reader = BitReader::from_rbsp(nal.get(2..)?);
+    let vps_id = reader.read_bits(4)? as u8;
+    // base_layer_internal, base_layer_available, max_layers_minus1
+    reader.skip_bits(1 + 1 + 6)?;
+    let max_sub_layers_minus1 = reader.read_bits(3)?;
+    // temporal_id_nesting, reserved_0xffff_16bits
+    reader.skip_bits(1 + 16)?;
+    parse_profile_tier_level(&mut reader, max_sub_layers_minus1)?;
+
+    let ordering_info_present = reader.read_bit()?;
+    let first = if ordering_info_present {
+        0
+    } else {
+        max_sub_layers_minus1
+    };
+    for _ in first..=max_sub_layers_minus1 {
+        reader.read_ue()?;
+        reader.read_ue()?;
+        reader.read_ue()?;
+    }
+
+    let max_layer_id = reader.read_bits(6)? as usize;
+    let num_layer_sets_minus1 = reader.read_ue()? as usize;
+    reader.skip_bits(num_layer_sets_minus1.checked_mul(max_layer_id + 1)?)?;
+
+    let timing = parse_timing_info(&mut reader)?;
+    Some(VpsInfo { vps_id, timing })
+}
+
+fn skip_scaling_list_data(reader: &mut BitReader) -> Option<()> {
+    for size_id in 0..4u32 {
+        let step = if size_id == 3 { 3 } else { 1 };
+        for _matrix_id in (0..6).step_by(step) {
+            if !reader.read_bit()? {
+                reader.read_ue()?;
+                continue;
+            }
+            let coef_num = 64.min(1 << (4 + (size_id << 1)));
+            if size_id > 1 {
+                reader.read_se()?;
+            }
+            for _ in 0..coef_num {
+                reader.read_se()?;
+            }
+        }
+    }
+    Some(())
+}
+
+/// Skips one `st_ref_pic_set` and returns its NumDeltaPocs.
+fn skip_short_term_ref_pic_set(
+    reader: &mut BitReader,
+    index: usize,
+    num_delta_pocs: &[u32],
+) -> Option<u32> {
+    let inter_rps_prediction = index != 0 && reader.read_bit()?;
+    if inter_rps_prediction {
+        // delta_rps_sign, abs_delta_rps_minus1; the SPS form always references index - 1.
+        reader.read_bit()?;
+        reader.read_ue()?;
+        let reference = *num_delta_pocs.get(index - 1)?;
+        let mut count = 0;
+        for _ in 0..=reference {
+            let used_by_curr_pic = reader.read_bit()?;
+            let use_delta = used_by_curr_pic || reader.read_bit()?;
+            if use_delta {
+                count += 1;
+            }
+        }
+        return Some(count);
+    }
+
+    let num_negative = reader.read_ue()?;
+    let num_positive = reader.read_ue()?;
+    if num_negative > 16 || num_positive > 16 {
+        return None;
+    }
+    for _ in 0..num_negative + num_positive {
+        reader.read_ue()?;
+        reader.read_bit()?;
+    }
+    Some(num_negative + num_positive)
+}
+
+fn parse_vui_timing(reader: &mut BitReader) -> Option<Option<TimingInfo>> {
+    if reader.read_bit()? {
+        let aspect_ratio_idc = reader.read_bits(8)?;
+        if aspect_ratio_idc == 255 {
+            reader.skip_bits(32)?;
+        }
+    }
+    if reader.read_bit()? {
+        reader.skip_bits(1)?;
+    }
+    if reader.read_bit()? {
+        // video_format, video_full_range_flag
+        reader.skip_bits(3 + 1)?;
+        if reader.read_bit()? {
+            reader.skip_bits(24)?;
+        }
+    }
+    if reader.read_bit()? {
+        reader.read_ue()?;
+        reader.read_ue()?;
+    }
+    // neutral_chroma_indication, field_seq, frame_field_info_present
+    reader.skip_bits(3)?;
+    if reader.read_bit()? {
+        for _ in 0..4 {
+            reader.read_ue()?;
+        }
+    }
+    parse_timing_info(reader)
+}
+
+pub fn parse_sps(nal: &[u8]) -> Option<SpsInfo> {
+    let mut reader = BitReader::from_rbsp(nal.get(2..)?);
+    let vps_id = reader.read_bits(4)? as u8;
+    let max_sub_layers_minus1 = reader.read_bits(3)?;
+    reader.skip_bits(1)?;
+    let ptl = parse_profile_tier_level(&mut reader, max_sub_layers_minus1)?;
+    let sps_id = reader.read_ue()?;
+    let chroma_format_idc = reader.read_ue()?;
+    if chroma_format_idc > 3 || sps_id > 15 {
+        return None;
+    }
+    let separate_colour_plane = chroma_format_idc == 3 && reader.read_bit()?;
+    let pic_width = reader.read_ue()?;
+    let pic_height = reader.read_ue()?;
+
+    let (mut width, mut height) = (pic_width, pic_height);
+    if reader.read_bit()? {
+        let left = reader.read_ue()?;
+        let right = reader.read_ue()?;
+        let top = reader.read_ue()?;
+        let bottom = reader.read_ue()?;
+        let chroma_array_type = if separate_colour_plane {
+            0
+        } else {
+            chroma_format_idc
+        };
+        let sub_width = if matches!(chroma_array_type, 1 | 2) {
+            2
+        } else {
+            1
+        };
+        let sub_height = if chroma_array_type == 1 { 2 } else { 1 };
+        width = pic_width.saturating_sub(sub_width * left.saturating_add(right));
+        height = pic_height.saturating_sub(sub_height * top.saturating_add(bottom));
+    }
+
+    let bit_depth_luma = reader.read_ue()?.checked_add(8)?;
+    let bit_depth_chroma = reader.read_ue()?.checked_add(8)?;
+    let log2_max_poc_lsb = reader.read_ue()?.checked_add(4)?;
+    if bit_depth_luma > 16 || bit_depth_chroma > 16 || log2_max_poc_lsb > 16 {
+        return None;
+    }
+
+    let ordering_info_present = reader.read_bit()?;
+    let first = if ordering_info_present {
+        0
+    } else {
+        max_sub_layers_minus1
+    };
+    for _ in first..=max_sub_layers_minus1 {
+        reader.read_ue()?;
+        reader.read_ue()?;
+        reader.read_ue()?;
+    }
+    for _ in 0..6 {
+        reader.read_ue()?;
+    }
+    if reader.read_bit()? && reader.read_bit()? {
+        skip_scaling_list_data(&mut reader)?;
+    }
+    // amp_enabled, sample_adaptive_offset_enabled
+    reader.skip_bits(2)?;
+    if reader.read_bit()? {
+        reader.skip_bits(4 + 4)?;
+        reader.read_ue()?;
+        reader.read_ue()?;
+        reader.skip_bits(1)?;
+    }
+
+    let num_short_term_ref_pic_sets = reader.read_ue()? as usize;
+    if num_short_term_ref_pic_sets > 64 {
+        return None;
+    }
+    let mut num_delta_pocs = Vec::with_capacity(num_short_term_ref_pic_sets);
+    for index in 0..num_short_term_ref_pic_sets {
+        let count = skip_short_term_ref_pic_set(&mut reader, index, &num_delta_pocs)?;
+        num_delta_pocs.push(count);
+    }
+    if reader.read_bit()? {
+        let num_long_term = reader.read_ue()?;
+        for _ in 0..num_long_term {
+            reader.skip_bits(log2_max_poc_lsb as usize + 1)?;
+        }
+    }
+    // temporal_mvp_enabled, strong_intra_smoothing_enabled
+    reader.skip_bits(2)?;
+    let timing = if reader.read_bit()? {
+        parse_vui_timing(&mut reader).flatten()
+    } else {
+        None
+    };
+
+    Some(SpsInfo {
+        sps_id: sps_id as u8,
+        vps_id,
+        ptl,
+        chroma_format_idc: chroma_format_idc as u8,
+        width,
+        height,
+        bit_depth_luma: bit_depth_luma as u8,
+        bit_depth_chroma: bit_depth_chroma as u8,
+        timing,
+    })
+}
+
+pub fn parse_pps(nal: &[u8]) -> Option<PpsInfo> {
+    let mut reader = BitReader::from_rbsp(nal.get(2..)?);
+    let pps_id = reader.read_ue()?;
+    let sps_id = reader.read_ue()?;
+    if pps_id > 63 || sps_id > 15 {
+        return None;
+    }
+    Some(PpsInfo {
+        pps_id: pps_id as u8,
+        sps_id: sps_id as u8,
+    })
+}
+
+/// Follows the parameter sets of one stream and reports when the effective
+/// [`VideoStreamFormat`] first becomes known or changes.
+#[derive(Debug, Default)]
+pub struct HevcFormatTracker {
+    vps: BTreeMap<u8, VpsInfo>,
+    sps: BTreeMap<u8, SpsInfo>,
+    active_sps_id: Option<u8>,
+    current: Option<VideoStreamFormat>,
+}
+
+impl HevcFormatTracker {
+    pub fn observe_access_unit(&mut self, data: &[u8]) -> Option<VideoStreamFormat> {
+        for nal in annexb_nal_units(data) {
+            match nal_unit_type(nal) {
+                Some(NAL_VPS) => {
+                    if let Some(vps) = parse_vps(nal) {
+                        self.vps.insert(vps.vps_id, vps);
+                    }
+                }
+                Some(NAL_SPS) => {
+                    if let Some(sps) = parse_sps(nal) {
+                        self.active_sps_id.get_or_insert(sps.sps_id);
+                        self.sps.insert(sps.sps_id, sps);
+                    }
+                }
+                Some(NAL_PPS) => {
+                    if let Some(pps) = parse_pps(nal) {
+                        self.active_sps_id = Some(pps.sps_id);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let format = self.resolve()?;
+        if self.current.as_ref() == Some(&format) {
+            return None;
+        }
+        self.current = Some(format.clone());
+        Some(format)
+    }
+
+    fn resolve(&self) -> Option<VideoStreamFormat> {
+        let sps = self.sps.get(&self.active_sps_id?)?;
+        let timing = sps
+            .timing
+            .or_else(|| self.vps.get(&sps.vps_id).and_then(|vps| vps.timing));
+        let (frame_rate_num, frame_rate_den) = timing
+            .map(|timing| (timing.time_scale, timing.num_units_in_tick))
+            .unwrap_or((0, 0));
+        Some(VideoStreamFormat {
+            width: sps.width,
+            height: sps.height,
+            profile_idc: sps.ptl.profile_idc,
+            high_tier: sps.ptl.high_tier,
+            level_idc: sps.ptl.level_idc,
+            chroma_format_idc: sps.chroma_format_idc,
+            bit_depth_luma: sps.bit_depth_luma,
+            bit_depth_chroma: sps.bit_depth_chroma,
+            frame_rate_num,
+            frame_rate_den,
+        })
+    }
+}
+
+/// Most recent VPS/SPS/PPS NAL units of one stream, keyed by their ids.
+#[derive(Debug, Default)]
+pub struct ParameterSetCache {
+    vps: BTreeMap<u8, Vec<u8>>,
+    sps: BTreeMap<u8, Vec<u8>>,
+    pps: BTreeMap<u8, Vec<u8>>,
+}
+
+impl ParameterSetCache {
+    pub fn observe_access_unit(&mut self, data: &[u8]) {
+        for nal in annexb_nal_units(data) {
+            match nal_unit_type(nal) {
+                Some(NAL_VPS) => {
+                    if let Some(vps) = parse_vps(nal) {
+                        self.vps.insert(vps.vps_id, nal.to_vec());
+                    }
+                }
+                Some(NAL_SPS) => {
+                    if let Some(sps) = parse_sps(nal) {
+                        self.sps.insert(sps.sps_id, nal.to_vec());
+                    }
+                }
+                Some(NAL_PPS) => {
+                    if let Some(pps) = parse_pps(nal) {
+                        self.pps.insert(pps.pps_id, nal.to_vec());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        !self.vps.is_empty() && !self.sps.is_empty() && !self.pps.is_empty()
+    }
+
+    /// All cached parameter sets as an Annex B blob, in VPS, SPS, PPS order.
+    pub fn to_annexb(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        for nal in self
+            .vps
+            .values()
+            .chain(self.sps.values())
+            .chain(self.pps.values())
+        {
+            out.extend_from_slice(&[0, 0, 0, 1]);
+            out.extend_from_slice(nal);
+        }
+        out
+    }
+
+    /// Rebuilds an IRAP access unit that is missing VPS, SPS or PPS so it carries a
+    /// complete set ahead of its slices. Parameter sets already in the access unit
+    /// take precedence over cached ones. Returns `None` when nothing needs changing.
+    pub fn complete_irap(&self, data: &[u8]) -> Option<Vec<u8>> {
+        if !classify_access_unit(data)?.is_irap() || !self.is_complete() {
+            return None;
+        }
+        let nals = annexb_nal_units(data);
+        let has_type = |wanted: u8| nals.iter().any(|nal| nal_unit_type(nal) == Some(wanted));
+        let (has_vps, has_sps, has_pps) = (has_type(NAL_VPS), has_type(NAL_SPS), has_type(NAL_PPS));
+        if has_vps && has_sps && has_pps {
+            return None;
+        }
+
+        let mut ordered: Vec<&[u8]> = Vec::with_capacity(nals.len() + 3);
+        ordered.extend(
+            nals.iter()
+                .filter(|nal| nal_unit_type(nal) == Some(NAL_AUD)),
+        );
+        for (nal_type, present, cached) in [
+            (NAL_VPS, has_vps, &self.vps),
+            (NAL_SPS, has_sps, &self.sps),
+            (NAL_PPS, has_pps, &self.pps),
+        ] {
+            if present {
+                ordered.extend(
+                    nals.iter()
+                        .filter(|nal| nal_unit_type(nal) == Some(nal_type)),
+                );
+            } else {
+                ordered.extend(cached.values().map(Vec::as_slice));
+            }
+        }
+        ordered.extend(
+            nals.iter()
+                .filter(|nal| !matches!(nal_unit_type(nal), Some(NAL_VPS..=NAL_AUD))),
+        );
+        write_nal_units(&ordered, NalFraming::AnnexB)
+    }
+}
+
+/// A complete access unit cut from an elementary stream, in Annex B framing.
+#[derive(Debug)]
+pub struct AccessUnit {
+    pub data: Vec<u8>,
+    pub keyframe: bool,
+    /// Stream byte offset of the start code that opens the access unit.
+    pub offset: u64,
+}
+
+/// Reassembles access units from arbitrary chunks of an Annex B elementary stream.
+///
+/// A new access unit starts at an AUD, at a parameter set or prefix SEI that follows
+/// slice data, or at a slice with `first_slice_segment_in_pic_flag` set.
+#[derive(Debug, Default)]
+pub struct AccessUnitSplitter {
+    pending: Vec<u8>,
+    /// Stream byte offset of `pending[0]`.
+    pending_offset: u64,
+    /// Offset in `pending` just past the start code of the NAL unit being collected.
+    nal_start: Option<usize>,
+    current: Vec<u8>,
+    current_offset: u64,
+    current_has_vcl: bool,
+    current_keyframe: bool,
+}
+
+impl AccessUnitSplitter {
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<AccessUnit> {
+        let mut completed = Vec::new();
+        // Everything before the last two bytes was already scanned on a previous push.
+        let mut i = self
+            .pending
+            .len()
+            .saturating_sub(2)
+            .max(self.nal_start.unwrap_or(0));
+        let mut pending = std::mem::take(&mut self.pending);
+        pending.extend_from_slice(chunk);
+
+        while i + 3 <= pending.len() {
+            if pending[i] != 0 || pending[i + 1] != 0 || pending[i + 2] != 1 {
+                i += 1;
+                continue;
+            }
+            if let Some(begin) = self.nal_start {
+                // A four-byte start code leaves a zero behind; it is not NAL data.
+                let nal = &pending[begin..i];
+                let end = nal
+                    .iter()
+                    .rposition(|byte| *byte != 0)
+                    .map_or(0, |pos| pos + 1);
+                let nal_offset = self.pending_offset + (begin - 3) as u64;
+                self.push_nal(&nal[..end], nal_offset, &mut completed);
+            }
+            i += 3;
+            self.nal_start = Some(i);
+        }
+
+        let drained = match self.nal_start {
+            Some(begin) => {
+                self.nal_start = Some(3);
+                begin - 3
+            }
+            None => pending.len().saturating_sub(2),
+        };
+        pending.drain(..drained);
+        self.pending_offset += drained as u64;
+        self.pending = pending;
+        completed
+    }
+
+    /// Emits whatever is buffered as the final access unit(s) of the stream.
+    pub fn flush(&mut self) -> Vec<AccessUnit> {
+        let pending = std::mem::take(&mut self.pending);
+        let mut completed = Vec::new();
+        if let Some(begin) = self.nal_start.take() {
+            let nal_offset = self.pending_offset + (begin - 3) as u64;
+            self.push_nal(&pending[begin..], nal_offset, &mut completed);
+        }
+        self.pending_offset += pending.len() as u64;
+        completed.extend(self.take_current());
+        completed
+    }
+
+    fn push_nal(&mut self, nal: &[u8], nal_offset: u64, completed: &mut Vec<AccessUnit>) {
+        let Some(nal_type) = nal_unit_type(nal) else {
+            return;
+        };
+        let is_vcl = nal_type < NAL_VPS;
+        let starts_access_unit = match nal_type {
+            NAL_AUD => true,
+            NAL_VPS | NAL_SPS | NAL_PPS | NAL_PREFIX_SEI | 41..=44 | 48..=55 => {
+                self.current_has_vcl
+            }
+            _ if is_vcl => self.current_has_vcl && nal.get(2).is_some_and(|byte| byte & 0x80 != 0),
+            _ => false,
+        };
+        if starts_access_unit {
+            completed.extend(self.take_current());
+        }
+        if self.current.is_empty() {
+            self.current_offset = nal_offset;
+        }
+        if is_vcl {
+            self.current_has_vcl = true;
+            self.current_keyframe |= (16..=NAL_IRAP_RESERVED_23).contains(&nal_type);
+        }
+        self.current.extend_from_slice(&[0, 0, 0, 1]);
+        self.current.extend_from_slice(nal);
+    }
+
+    fn take_current(&mut self) -> Option<AccessUnit> {
+        let has_vcl = std::mem::take(&mut self.current_has_vcl);
+        let keyframe = std::mem::take(&mut self.current_keyframe);
+        let data = std::mem::take(&mut self.current);
+        has_vcl.then_some(AccessUnit {
+            data,
+            keyframe,
+            offset: self.current_offset,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitstream::test_support::{annexb, to_ebsp, BitWriter};
+
+    fn nal(nal_type: u8, rbsp: &[u8]) -> Vec<u8> {
+        let mut nal = vec![nal_type << 1, 1];
+        nal.extend_from_slice(&to_ebsp(rbsp));
+        nal
+    }
+
+    fn write_ptl(writer: &mut BitWriter, profile_idc: u8, level_idc: u8) {
+        writer
+            .bits(0, 2)
+            .bit(false)
+            .bits(u64::from(profile_idc), 5)
+            .bits(0, 80)
+            .bits(u64::from(level_idc), 8);
+    }
+
+    fn vps(timing: Option<(u32, u32)>) -> Vec<u8> {
+        let mut writer = BitWriter::default();
+        writer.bits(0, 4).bit(true).bit(true).bits(0, 6).bits(0, 3);
+        writer.bit(true).bits(0xFFFF, 16);
+        write_ptl(&mut writer, 1, 93);
+        writer.bit(true).ue(1).ue(0).ue(0);
+        writer.bits(0, 6).ue(0);
+        match timing {
+            Some((num_units_in_tick, time_scale)) => writer
+                .bit(true)
+                .bits(u64::from(num_units_in_tick), 32)
+                .bits(u64::from(time_scale), 32)
+                .bit(false),
+            None => writer.bit(false),
+        };
+        writer.bit(false);
+        nal(NAL_VPS, &writer.finish_rbsp())
+    }
+
+    struct SpsParams {
+        sps_id: u32,
+        width: u32,
+        height: u32,
+        crop_bottom: u32,
+        bit_depth_luma: u32,
+        timing: Option<(u32, u32)>,
+    }
+
+    const SPS_1080P: SpsParams = SpsParams {
+        sps_id: 0,
+        width: 1920,
+        height: 1088,
+        crop_bottom: 4,
+        bit_depth_luma: 8,
+        timing: Some((1001, 60_000)),
+    };
+
+    fn sps(params: &SpsParams) -> Vec<u8> {
+        let mut writer = BitWriter::default();
+        writer.bits(0, 4).bits(0, 3).bit(true);
+        write_ptl(&mut writer, 2, 123);
+        writer.ue(params.sps_id).ue(1);
+        writer.ue(params.width).ue(params.height);
+        if params.crop_bottom > 0 {
+            writer.bit(true).ue(0).ue(0).ue(0).ue(params.crop_bottom);
+        } else {
+            writer.bit(false);
+        }
+        writer
+            .ue(params.bit_depth_luma - 8)
+            .ue(params.bit_depth_luma - 8)
+            .ue(4);
+        writer.bit(true).ue(1).ue(0).ue(0);
+        for value in [0, 1, 0, 1, 1, 1] {
+            writer.ue(value);
+        }
+        // scaling lists off, AMP and SAO on, no PCM
+        writer.bit(false).bit(true).bit(true).bit(false);
+        // one short-term RPS with a single negative picture
+        writer.ue(1).ue(1).ue(0).ue(0).bit(true);
+        writer.bit(false).bit(true).bit(true);
+        match params.timing {
+            Some((num_units_in_tick, time_scale)) => {
+                writer.bit(true);
+                writer.bit(false).bit(false).bit(false).bit(false);
+                writer.bits(0, 3).bit(false);
+                writer
+                    .bit(true)
+                    .bits(u64::from(num_units_in_tick), 32)
+                    .bits(u64::from(time_scale), 32)
+                    .bit(false);
+            }
+            None => {
+                writer.bit(false);
+            }
+        }
+        writer.bit(false);
+        nal(NAL_SPS, &writer.finish_rbsp())
+    }
+
+    fn pps(pps_id: u32, sps_id: u32) -> Vec<u8> {
+        let mut writer = BitWriter::default();
+        writer.ue(pps_id).ue(sps_id).bits(0, 7);
+        nal(NAL_PPS, &writer.finish_rbsp())
+    }
+
+    #[test]
+    fn sps_parses_cropped_size_profile_and_timing() {
+        let info = parse_sps(&sps(&SPS_1080P)).unwrap();
+        assert_eq!((info.width, info.height), (1920, 1080));
+        assert_eq!(info.ptl.profile_idc, 2);
+        assert_eq!(info.ptl.level_idc, 123);
+        assert_eq!(info.chroma_format_idc, 1);
+        assert_eq!(info.bit_depth_luma, 8);
+        let timing = info.timing.unwrap();
+        assert_eq!(
+            (timing.time_scale, timing.num_units_in_tick),
+            (60_000, 1001)
+        );
+    }
+
+    #[test]
+    fn vps_and_pps_ids_parse() {
+        let vps_info = parse_vps(&vps(Some((1, 30)))).unwrap();
+        assert_eq!(vps_info.vps_id, 0);
+        assert_eq!(vps_info.timing.unwrap().time_scale, 30);
+        let pps_info = parse_pps(&pps(3, 0)).unwrap();
+        assert_eq!((pps_info.pps_id, pps_info.sps_id), (3, 0));
+    }
+
+    #[test]
+    fn truncated_sps_is_rejected() {
+        let nal = sps(&SPS_1080P);
+        assert!(parse_sps(&nal[..8]).is_none());
+    }
+
+    #[test]
+    fn format_tracker_reports_first_format_and_changes_only() {
+        let mut tracker = HevcFormatTracker::default();
+        let parameter_sets = annexb(&[&vps(None), &sps(&SPS_1080P), &pps(0, 0)]);
+        let format = tracker.observe_access_unit(&parameter_sets).unwrap();
+        assert_eq!((format.width, format.height), (1920, 1080));
+        assert_eq!(
+            (format.frame_rate_num, format.frame_rate_den),
+            (60_000, 1001)
+        );
+        assert!(tracker.observe_access_unit(&parameter_sets).is_none());
+
+        let ten_bit = SpsParams {
+            bit_depth_luma: 10,
+            ..SPS_1080P
+        };
+        let format = tracker
+            .observe_access_unit(&annexb(&[&sps(&ten_bit)]))
+            .unwrap();
+        assert_eq!(format.bit_depth_luma, 10);
+    }
+
+    #[test]
+    fn format_tracker_falls_back_to_vps_timing() {
+        let mut tracker = HevcFormatTracker::default();
+        let no_timing = SpsParams {
+            timing: None,
+            crop_bottom: 0,
+            ..SPS_1080P
+        };
+        let format = tracker
+            .observe_access_unit(&annexb(&[&vps(Some((1, 25))), &sps(&no_timing)]))
+            .unwrap();
+        assert_eq!((format.width, format.height), (1920, 1088));
+        assert_eq!((format.frame_rate_num, format.frame_rate_den), (25, 1));
+    }
+
+    fn slice(nal_type: u8) -> Vec<u8> {
+        vec![nal_type << 1, 1, 0x80, 0x11]
+    }
+
+    #[test]
+    fn access_units_are_classified_by_first_vcl_nal_unit() {
+        let classify = |nal_type: u8| {
+            classify_access_unit(&annexb(&[&vps(None), &sps(&SPS_1080P), &slice(nal_type)]))
+        };
+        assert_eq!(classify(NAL_IDR_W_RADL), Some(PictureKind::Idr));
+        assert_eq!(classify(NAL_IDR_N_LP), Some(PictureKind::Idr));
+        assert_eq!(classify(NAL_CRA), Some(PictureKind::Cra));
+        assert_eq!(classify(NAL_BLA_W_LP), Some(PictureKind::Bla));
+        assert_eq!(classify(22), Some(PictureKind::ReservedIrap));
+        assert_eq!(classify(1), Some(PictureKind::NonIrap));
+        assert!(!classify(1).unwrap().is_irap());
+        assert!(classify(NAL_CRA).unwrap().is_irap());
+    }
+
+    #[test]
+    fn parameter_sets_alone_are_not_classified() {
+        let data = annexb(&[&vps(None), &sps(&SPS_1080P), &pps(0, 0)]);
+        assert_eq!(classify_access_unit(&data), None);
+    }
+
+    #[test]
+    fn parameter_set_cache_completes_bare_irap_frames() {
+        let (vps, sps, pps) = (vps(None), sps(&SPS_1080P), pps(0, 0));
+        let aud = vec![NAL_AUD << 1, 1, 0x50];
+        let idr = slice(NAL_IDR_W_RADL);
+        let mut cache = ParameterSetCache::default();
+        assert!(!cache.is_complete());
+        cache.observe_access_unit(&annexb(&[&vps, &sps, &pps, &idr]));
+        assert!(cache.is_complete());
+        assert_eq!(cache.to_annexb(), annexb(&[&vps, &sps, &pps]));
+
+        let completed = cache.complete_irap(&annexb(&[&aud, &idr])).unwrap();
+        assert_eq!(completed, annexb(&[&aud, &vps, &sps, &pps, &idr]));
+    }
+
+    #[test]
+    fn parameter_set_cache_leaves_complete_and_non_irap_frames_alone() {
+        let (vps, sps, pps) = (vps(None), sps(&SPS_1080P), pps(0, 0));
+        let mut cache = ParameterSetCache::default();
+        cache.observe_access_unit(&annexb(&[&vps, &sps, &pps]));
+        let full = annexb(&[&vps, &sps, &pps, &slice(NAL_CRA)]);
+        assert_eq!(cache.complete_irap(&full), None);
+        assert_eq!(cache.complete_irap(&annexb(&[&slice(1)])), None);
+    }
+
+    #[test]
+    fn parameter_sets_in_the_frame_take_precedence() {
+        let (vps, pps) = (vps(None), pps(0, 0));
+        let mut cache = ParameterSetCache::default();
+        cache.observe_access_unit(&annexb(&[&vps, &sps(&SPS_1080P), &pps]));
+        let ten_bit = sps(&SpsParams {
+            bit_depth_luma: 10,
+            ..SPS_1080P
+        });
+        let idr = slice(NAL_IDR_N_LP);
+        let completed = cache.complete_irap(&annexb(&[&ten_bit, &idr])).unwrap();
+        assert_eq!(completed, annexb(&[&vps, &ten_bit, &pps, &idr]));
+    }
+
+    fn split_in_chunks(stream: &[u8], chunk_len: usize) -> Vec<AccessUnit> {
+        let mut splitter = AccessUnitSplitter::default();
+        let mut units: Vec<AccessUnit> = stream
+            .chunks(chunk_len)
+            .flat_map(|chunk| splitter.push(chunk))
+            .collect();
+        units.extend(splitter.flush());
+        units
+    }
+
+    #[test]
+    fn splitter_cuts_at_first_slices_and_parameter_sets() {
+        let (vps, sps, pps) = (vps(None), sps(&SPS_1080P), pps(0, 0));
+        let first = annexb(&[&vps, &sps, &pps, &slice(NAL_IDR_W_RADL)]);
+        let second = annexb(&[&slice(1), &[2, 1, 0x00, 0x22]]);
+        let third = annexb(&[&vps, &slice(NAL_CRA)]);
+        let stream = [first.clone(), second.clone(), third.clone()].concat();
+
+        for chunk_len in [1, 2, 3, 7, stream.len()] {
+            let units = split_in_chunks(&stream, chunk_len);
+            let data: Vec<&[u8]> = units.iter().map(|unit| unit.data.as_slice()).collect();
+            assert_eq!(data, [&first[..], &second[..], &third[..]], "{chunk_len}");
+            let keyframes: Vec<bool> = units.iter().map(|unit| unit.keyframe).collect();
+            assert_eq!(keyframes, [true, false, true]);
+            let offsets: Vec<u64> = units.iter().map(|unit| unit.offset).collect();
+            assert_eq!(
+                offsets,
+                [
+                    1,
+                    first.len() as u64 + 1,
+                    (first.len() + second.len()) as u64 + 1
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn splitter_holds_the_last_access_unit_until_flush() {
+        let mut splitter = AccessUnitSplitter::default();
+        let frame = annexb(&[&slice(NAL_IDR_N_LP)]);
+        assert!(splitter.push(&frame).is_empty());
+        let units = splitter.flush();
+        assert_eq!(units.len(), 1);
+        assert_eq!(units[0].data, frame);
+        assert!(splitter.flush().is_empty());
+    }
+
+    #[test]
+    fn splitter_drops_leading_units_without_slices() {
+        let aud = vec![NAL_AUD << 1, 1, 0x50];
+        let stream = annexb(&[&aud, &aud, &slice(1)]);
+        let units = split_in_chunks(&stream, 4);
+        assert_eq!(units.len(), 1);
+        assert_eq!(units[0].data, annexb(&[&aud, &slice(1)]));
+    }
+}
@@ -0,0 +1,482 @@
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+/// What a full [`VideoIngressQueue`] does with the next frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Evict the oldest queued delta frame, or the oldest frame if only keyframes
+    /// are queued, along with the delta frames that depend on it.
+    DropOldestDelta,
+    /// Drop the incoming frame and every delta frame after it until a keyframe is
+    /// pushed; that keyframe evicts the stale frames ahead of it if needed.
+    DropUntilKeyframe,
+    /// Make the pushing thread wait for space.
+    Block,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropReason {
+    /// Evicted from a full queue to make room for a newer frame.
+    Evicted,
+    /// Rejected while waiting for a keyframe after an overflow.
+    AwaitingKeyframe,
+}
+
+impl DropReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DropReason::Evicted => "queue_full_evicted",
+            DropReason::AwaitingKeyframe => "queue_full_awaiting_keyframe",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushOutcome {
+    Queued,
+    /// Rejected by the overflow policy; reported through `take_drops`.
+    Dropped,
+    Closed,
+}
+
+/// What the capture side should do to relieve the queue, from mildest to most
+/// drastic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Backpressure {
+    None,
+    ReduceBitrate,
+    ReduceFrameRate,
+    SkipFrame,
+}
+
+/// Queue occupancy (percent) and estimated send delay at which each
+/// [`Backpressure`] level is recommended.
+const BACKPRESSURE_THRESHOLDS: [(Backpressure, usize, Duration); 3] = [
+    (Backpressure::SkipFrame, 90, Duration::from_millis(1000)),
+    (
+        Backpressure::ReduceFrameRate,
+        50,
+        Duration::from_millis(500),
+    ),
+    (Backpressure::ReduceBitrate, 25, Duration::from_millis(200)),
+];
+
+#[derive(Debug, Clone, Copy)]
+pub struct CongestionSnapshot {
+    pub depth: usize,
+    pub capacity: usize,
+    /// Time to drain the queued bytes at the transport's target bitrate; zero
+    /// until a bitrate is known.
+    pub send_delay: Duration,
+    pub backpressure: Backpressure,
+}
+
+#[derive(Debug)]
+pub struct QueuedFrame {
+    pub payload: Vec<u8>,
+    /// Resolved by the sender's keyframe detection mode before queueing, so it
+    /// may differ from the caller's flag.
+    pub keyframe: bool,
+    pub pts: u64,
+    pub codec: u8,
+    pub queued_at: Instant,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DroppedFrame {
+    pub pts: u64,
+    pub keyframe: bool,
+    pub reason: DropReason,
+}
+
+/// Keeps reports for the consumer to turn into events; beyond this only the
+/// counters grow.
+const MAX_PENDING_DROP_REPORTS: usize = 256;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IngressDropCounters {
+    pub evicted: u64,
+    pub awaiting_keyframe: u64,
+    pub unreported: u64,
+}
+
+#[derive(Default)]
+struct QueueState {
+    frames: VecDeque<QueuedFrame>,
+    queued_bytes: usize,
+    closed: bool,
+    skipping_until_keyframe: bool,
+    newest_pts: Option<u64>,
+    pending_drops: Vec<DroppedFrame>,
+    counters: IngressDropCounters,
+}
+
+impl QueueState {
+    fn record_drop(&mut self, frame: &QueuedFrame, reason: DropReason) {
+        match reason {
+            DropReason::Evicted => self.counters.evicted = self.counters.evicted.saturating_add(1),
+            DropReason::AwaitingKeyframe => {
+                self.counters.awaiting_keyframe = self.counters.awaiting_keyframe.saturating_add(1)
+            }
+        }
+        if self.pending_drops.len() < MAX_PENDING_DROP_REPORTS {
+            self.pending_drops.push(DroppedFrame {
+                pts: frame.pts,
+                keyframe: frame.keyframe,
+                reason,
+            });
+        } else {
+            self.counters.unreported = self.counters.unreported.saturating_add(1);
+        }
+    }
+
+    /// Evicts one frame and the queued delta frames after it up to the next
+    /// keyframe, since they reference it. When no keyframe follows, frames pushed
+    /// later depend on it too, so the queue skips until the next keyframe.
+    fn evict_one(&mut self, prefer_delta: bool) {
+        let index = if prefer_delta {
+            self.frames
+                .iter()
+                .position(|frame| !frame.keyframe)
+                .unwrap_or(0)
+        } else {
+            0
+        };
+        let Some(frame) = self.frames.remove(index) else {
+            return;
+        };
+        self.queued_bytes = self.queued_bytes.saturating_sub(frame.payload.len());
+        self.record_drop(&frame, DropReason::Evicted);
+        while let Some(dependent) = self.frames.get(index) {
+            if dependent.keyframe {
+                return;
+            }
+            if let Some(dependent) = self.frames.remove(index) {
+                self.queued_bytes = self.queued_bytes.saturating_sub(dependent.payload.len());
+                self.record_drop(&dependent, DropReason::Evicted);
+            }
+        }
+        self.skipping_until_keyframe = true;
+    }
+}
+
+/// Bounded single-consumer queue between the capture-side `push_video_frame`
+/// callers and the sender loop.
+pub struct VideoIngressQueue {
+    capacity: usize,
+    policy: OverflowPolicy,
+    state: Mutex<QueueState>,
+    space: Condvar,
+    ready: Notify,
+    target_bitrate_bps: AtomicU32,
+}
+
+impl VideoIngressQueue {
+    pub fn new(capacity: usize, policy: OverflowPolicy) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            policy,
+            state: Mutex::new(QueueState::default()),
+            space: Condvar::new(),
+            ready: Notify::new(),
+            target_bitrate_bps: AtomicU32::new(0),
+        }
+    }
+
+    /// Queues a frame according to the overflow policy.
+    pub fn push(&self, frame: QueuedFrame) -> PushOutcome {
+        let Ok(mut state) = self.state.lock() else {
+            return PushOutcome::Closed;
+        };
+        if self.policy == OverflowPolicy::Block {
+            state = match self.space.wait_while(state, |state| {
+                !state.closed && state.frames.len() >= self.capacity
+            }) {
+                Ok(state) => state,
+                Err(_) => return PushOutcome::Closed,
+            };
+        }
+        if state.closed {
+            return PushOutcome::Closed;
+        }
+        state.newest_pts = Some(state.newest_pts.map_or(frame.pts, |pts| pts.max(frame.pts)));
+
+        match self.policy {
+            OverflowPolicy::Block => {}
+            OverflowPolicy::DropOldestDelta => {
+                while state.frames.len() >= self.capacity {
+                    state.evict_one(true);
+                }
+                if frame.keyframe {
+                    state.skipping_until_keyframe = false;
+                } else if state.skipping_until_keyframe {
+                    state.record_drop(&frame, DropReason::AwaitingKeyframe);
+                    return PushOutcome::Dropped;
+                }
+            }
+            OverflowPolicy::DropUntilKeyframe => {
+                if frame.keyframe {
+                    while state.frames.len() >= self.capacity {
+                        state.evict_one(false);
+                    }
+                    state.skipping_until_keyframe = false;
+                } else if state.skipping_until_keyframe || state.frames.len() >= self.capacity {
+                    state.skipping_until_keyframe = true;
+                    state.record_drop(&frame, DropReason::AwaitingKeyframe);
+                    return PushOutcome::Dropped;
+                }
+            }
+        }
+
+        state.queued_bytes = state.queued_bytes.saturating_add(frame.payload.len());
+        state.frames.push_back(frame);
+        drop(state);
+        self.ready.notify_one();
+        PushOutcome::Queued
+    }
+
+    /// Waits for the next frame. Frames queued before `close` are still delivered;
+    /// `None` means the queue is closed and empty.
+    pub async fn pop(&self) -> Option<QueuedFrame> {
+        loop {
+            {
+                let mut state = self.state.lock().ok()?;
+                if let Some(frame) = state.frames.pop_front() {
+                    state.queued_bytes = state.queued_bytes.saturating_sub(frame.payload.len());
+                    drop(state);
+                    self.space.notify_one();
+                    return Some(frame);
+                }
+                if state.closed {
+                    return None;
+                }
+            }
+            self.ready.notified().await;
+        }
+    }
+
+    /// Rejects further pushes and wakes any blocked producer and the consumer.
+    pub fn close(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.closed = true;
+        }
+        self.space.notify_all();
+        self.ready.notify_one();
+    }
+
+    pub fn depth(&self) -> usize {
+        self.state
+            .lock()
+            .map(|state| state.frames.len())
+            .unwrap_or(0)
+    }
+
+    /// Highest pts pushed so far, including frames the overflow policy dropped.
+    pub fn newest_pts(&self) -> Option<u64> {
+        self.state.lock().ok().and_then(|state| state.newest_pts)
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Rate used to turn queued bytes into an estimated send delay.
+    pub fn set_target_bitrate(&self, bitrate_bps: u32) {
+        self.target_bitrate_bps
+            .store(bitrate_bps, Ordering::Relaxed);
+    }
+
+    pub fn congestion(&self) -> CongestionSnapshot {
+        let (depth, queued_bytes) = self
+            .state
+            .lock()
+            .map(|state| (state.frames.len(), state.queued_bytes))
+            .unwrap_or_default();
+        let bitrate_bps = u64::from(self.target_bitrate_bps.load(Ordering::Relaxed));
+        let send_delay = (queued_bytes as u64)
+            .saturating_mul(8_000_000)
+            .checked_div(bitrate_bps)
+            .map_or(Duration::ZERO, Duration::from_micros);
+        let occupancy_percent = depth * 100 / self.capacity;
+        let backpressure = BACKPRESSURE_THRESHOLDS
+            .iter()
+            .find(|(_, percent, delay)| occupancy_percent >= *percent || send_delay >= *delay)
+            .map_or(Backpressure::None, |(level, _, _)| *level);
+        CongestionSnapshot {
+            depth,
+            capacity: self.capacity,
+            send_delay,
+            backpressure,
+        }
+    }
+
+    pub fn take_drops(&self) -> Vec<DroppedFrame> {
+        self.state
+            .lock()
+            .map(|mut state| std::mem::take(&mut state.pending_drops))
+            .unwrap_or_default()
+    }
+
+    pub fn drop_counters(&self) -> IngressDropCounters {
+        self.state
+            .lock()
+            .map(|state| state.counters)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn frame(pts: u64, keyframe: bool) -> QueuedFrame {
+        QueuedFrame {
+            payload: vec![0; 1000],
+            keyframe,
+            pts,
+            codec: 1,
+            queued_at: Instant::now(),
+        }
+    }
+
+    fn queued_pts(queue: &VideoIngressQueue) -> Vec<u64> {
+        let state = queue.state.lock().unwrap();
+        state.frames.iter().map(|frame| frame.pts).collect()
+    }
+
+    #[test]
+    fn drop_oldest_delta_keeps_keyframes() {
+        let queue = VideoIngressQueue::new(4, OverflowPolicy::DropOldestDelta);
+        for (pts, keyframe) in [(0, true), (1, false), (2, true), (3, false), (4, false)] {
+            assert_eq!(queue.push(frame(pts, keyframe)), PushOutcome::Queued);
+        }
+        // Frame 1 is evicted; frame 2 starts a new chain, so nothing else goes.
+        assert_eq!(queued_pts(&queue), [0, 2, 3, 4]);
+        let drops = queue.take_drops();
+        assert_eq!(drops.len(), 1);
+        assert_eq!((drops[0].pts, drops[0].reason), (1, DropReason::Evicted));
+        assert!(queue.take_drops().is_empty());
+        assert_eq!(queue.drop_counters().evicted, 1);
+    }
+
+    #[test]
+    fn drop_oldest_delta_evicts_dependent_frames_and_skips_until_a_keyframe() {
+        let queue = VideoIngressQueue::new(3, OverflowPolicy::DropOldestDelta);
+        for (pts, keyframe) in [(0, true), (1, false), (2, false)] {
+            queue.push(frame(pts, keyframe));
+        }
+        // Evicting frame 1 breaks the chain through frame 2 and the incoming frame.
+        assert_eq!(queue.push(frame(3, false)), PushOutcome::Dropped);
+        assert_eq!(queue.push(frame(4, false)), PushOutcome::Dropped);
+        assert_eq!(queue.push(frame(5, true)), PushOutcome::Queued);
+        assert_eq!(queue.push(frame(6, false)), PushOutcome::Queued);
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let popped: Vec<_> = (0..3)
+            .map(|_| runtime.block_on(queue.pop()).unwrap().pts)
+            .collect();
+        assert_eq!(popped, [0, 5, 6]);
+
+        let counters = queue.drop_counters();
+        assert_eq!((counters.evicted, counters.awaiting_keyframe), (2, 2));
+    }
+
+    #[test]
+    fn drop_oldest_delta_evicts_keyframes_when_nothing_else_is_queued() {
+        let queue = VideoIngressQueue::new(2, OverflowPolicy::DropOldestDelta);
+        for pts in 0..3 {
+            queue.push(frame(pts, true));
+        }
+        assert_eq!(queued_pts(&queue), [1, 2]);
+    }
+
+    #[test]
+    fn drop_until_keyframe_skips_deltas_after_an_overflow() {
+        let queue = VideoIngressQueue::new(2, OverflowPolicy::DropUntilKeyframe);
+        queue.push(frame(0, true));
+        queue.push(frame(1, false));
+        assert_eq!(queue.push(frame(2, false)), PushOutcome::Dropped);
+        // Space alone does not end the skip; only a keyframe does.
+        queue.state.lock().unwrap().frames.pop_back();
+        assert_eq!(queue.push(frame(3, false)), PushOutcome::Dropped);
+        assert_eq!(queue.push(frame(4, true)), PushOutcome::Queued);
+        assert_eq!(queue.push(frame(5, false)), PushOutcome::Dropped);
+        assert_eq!(queued_pts(&queue), [0, 4]);
+
+        let counters = queue.drop_counters();
+        assert_eq!((counters.awaiting_keyframe, counters.evicted), (3, 0));
+        assert_eq!(queue.newest_pts(), Some(5));
+    }
+
+    #[test]
+    fn block_waits_for_the_consumer() {
+        let queue = Arc::new(VideoIngressQueue::new(1, OverflowPolicy::Block));
+        queue.push(frame(0, true));
+        let producer = {
+            let queue = Arc::clone(&queue);
+            std::thread::spawn(move || queue.push(frame(1, false)))
+        };
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(queue.depth(), 1);
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        assert_eq!(runtime.block_on(queue.pop()).unwrap().pts, 0);
+        assert_eq!(producer.join().unwrap(), PushOutcome::Queued);
+        assert_eq!(runtime.block_on(queue.pop()).unwrap().pts, 1);
+    }
+
+    #[test]
+    fn close_releases_blocked_producers_and_drains_the_consumer() {
+        let queue = Arc::new(VideoIngressQueue::new(1, OverflowPolicy::Block));
+        queue.push(frame(0, true));
+        let producer = {
+            let queue = Arc::clone(&queue);
+            std::thread::spawn(move || queue.push(frame(1, false)))
+        };
+        std::thread::sleep(Duration::from_millis(20));
+        queue.close();
+        assert_eq!(producer.join().unwrap(), PushOutcome::Closed);
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        assert_eq!(runtime.block_on(queue.pop()).unwrap().pts, 0);
+        assert!(runtime.block_on(queue.pop()).is_none());
+    }
+
+    #[test]
+    fn congestion_follows_occupancy_and_send_delay() {
+        let queue = VideoIngressQueue::new(10, OverflowPolicy::DropOldestDelta);
+        assert_eq!(queue.congestion().backpressure, Backpressure::None);
+        for pts in 0..3 {
+            queue.push(frame(pts, false));
+        }
+        let snapshot = queue.congestion();
+        assert_eq!(snapshot.depth, 3);
+        assert_eq!(snapshot.send_delay, Duration::ZERO);
+        assert_eq!(snapshot.backpressure, Backpressure::ReduceBitrate);
+
+        // 3000 bytes at 24 kbit/s take one second to send.
+        queue.set_target_bitrate(24_000);
+        let snapshot = queue.congestion();
+        assert_eq!(snapshot.send_delay, Duration::from_secs(1));
+        assert_eq!(snapshot.backpressure, Backpressure::SkipFrame);
+
+        for pts in 3..6 {
+            queue.push(frame(pts, false));
+        }
+        queue.set_target_bitrate(0);
+        assert_eq!(
+            queue.congestion().backpressure,
+            Backpressure::ReduceFrameRate
+        );
+    }
+}
@@ -0,0 +1,306 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::time::{Duration, Instant};
+
+/// Multiple of the smoothed inter-arrival jitter used as the playout delay target.
+const JITTER_DELAY_FACTOR: f64 = 3.0;
+/// Transit samples after which the minimum-transit baseline is refreshed, so clock
+/// drift between sender and receiver does not accumulate into the playout delay.
+const BASELINE_WINDOW: u32 = 256;
+/// pts jumps larger than this are treated as a sender restart.
+const DISCONTINUITY_US: u64 = 5_000_000;
+const MAX_BUFFERED_FRAMES: usize = 512;
+
+#[derive(Debug, Clone, Copy)]
+pub struct JitterBufferConfig {
+    pub min_delay: Duration,
+    pub max_delay: Duration,
+}
+
+/// Reorders frames by pts and releases them on the local clock after an adaptive
+/// playout delay derived from RFC 3550 style inter-arrival jitter.
+pub struct JitterBuffer<T> {
+    config: JitterBufferConfig,
+    epoch: Instant,
+    frames: BTreeMap<(u64, u64), T>,
+    /// Frames buffered before a pts discontinuity, released ahead of the new
+    /// timeline without waiting for a playout time.
+    flushed: VecDeque<(u64, T)>,
+    next_seq: u64,
+    last_transit_us: Option<i64>,
+    jitter_us: f64,
+    baseline_transit_us: Option<i64>,
+    window_min_transit_us: Option<i64>,
+    window_samples: u32,
+    target_delay: Duration,
+    hold: Duration,
+    last_pushed_pts: Option<u64>,
+    last_released_pts: Option<u64>,
+    late_drops: u64,
+    overflow_drops: u64,
+}
+
+impl<T> JitterBuffer<T> {
+    /// Buffers sharing an `epoch` have directly comparable playout offsets.
+    pub fn new(config: JitterBufferConfig, epoch: Instant) -> Self {
+        Self {
+            config,
+            epoch,
+            frames: BTreeMap::new(),
+            flushed: VecDeque::new(),
+            next_seq: 0,
+            last_transit_us: None,
+            jitter_us: 0.0,
+            baseline_transit_us: None,
+            window_min_transit_us: None,
+            window_samples: 0,
+            target_delay: config.min_delay,
+            hold: Duration::ZERO,
+            last_pushed_pts: None,
+            last_released_pts: None,
+            late_drops: 0,
+            overflow_drops: 0,
+        }
+    }
+
+    /// Buffers a frame. Returns `false` when the frame arrived after a later pts was
+    /// already released and had to be dropped.
+    pub fn push(&mut self, pts: u64, arrival: Instant, item: T) -> bool {
+        if let Some(previous) = self.last_pushed_pts {
+            if pts.abs_diff(previous) > DISCONTINUITY_US {
+                self.reset_timeline();
+            }
+        }
+        self.last_pushed_pts = Some(pts);
+        self.observe_transit(pts, arrival);
+
+        if self
+            .last_released_pts
+            .is_some_and(|released| pts <= released)
+        {
+            self.late_drops = self.late_drops.saturating_add(1);
+            return false;
+        }
+
+        self.frames.insert((pts, self.next_seq), item);
+        self.next_seq = self.next_seq.wrapping_add(1);
+        if self.frames.len() > MAX_BUFFERED_FRAMES {
+            self.frames.pop_first();
+            self.overflow_drops = self.overflow_drops.saturating_add(1);
+        }
+        true
+    }
+
+    /// Local instant at which the oldest buffered frame becomes due.
+    pub fn next_release_at(&self) -> Option<Instant> {
+        if !self.flushed.is_empty() {
+            return Some(self.epoch);
+        }
+        let (&(pts, _), _) = self.frames.first_key_value()?;
+        Some(self.playout_at(pts))
+    }
+
+    pub fn pop_ready(&mut self, now: Instant) -> Option<(u64, T)> {
+        if let Some(frame) = self.flushed.pop_front() {
+            return Some(frame);
+        }
+        if self.next_release_at()? > now {
+            return None;
+        }
+        let ((pts, _), item) = self.frames.pop_first()?;
+        self.last_released_pts = Some(pts);
+        Some((pts, item))
+    }
+
+    pub fn depth(&self) -> usize {
+        self.frames.len() + self.flushed.len()
+    }
+
+    pub fn target_delay(&self) -> Duration {
+        self.target_delay
+    }
+
+    pub fn jitter(&self) -> Duration {
+        Duration::from_micros(self.jitter_us as u64)
+    }
+
+    /// Local clock minus pts at which frames are currently released, excluding any
+    /// hold-back. `None` until the first frame has been observed.
+    pub fn playout_offset_us(&self) -> Option<i64> {
+        let baseline = self.baseline_transit_us?;
+        Some(baseline.saturating_add(self.target_delay.as_micros() as i64))
+    }
+
+    /// Extra delay applied on top of the adaptive target, used for A/V alignment.
+    pub fn set_hold(&mut self, hold: Duration) {
+        self.hold = hold;
+    }
+
+    pub fn late_drops(&self) -> u64 {
+        self.late_drops
+    }
+
+    pub fn overflow_drops(&self) -> u64 {
+        self.overflow_drops
+    }
+
+    fn observe_transit(&mut self, pts: u64, arrival: Instant) {
+        let arrival_us = arrival.saturating_duration_since(self.epoch).as_micros() as i64;
+        let transit_us = arrival_us.saturating_sub(pts as i64);
+
+        if let Some(previous) = self.last_transit_us {
+            let delta = transit_us.saturating_sub(previous).unsigned_abs() as f64;
+            self.jitter_us += (delta - self.jitter_us) / 16.0;
+        }
+        self.last_transit_us = Some(transit_us);
+
+        let baseline = self.baseline_transit_us.get_or_insert(transit_us);
+        if transit_us < *baseline {
+            *baseline = transit_us;
+        }
+        let window_min = self.window_min_transit_us.get_or_insert(transit_us);
+        if transit_us < *window_min {
+            *window_min = transit_us;
+        }
+        self.window_samples += 1;
+        if self.window_samples >= BASELINE_WINDOW {
+            self.baseline_transit_us = self.window_min_transit_us.take();
+            self.window_samples = 0;
+        }
+
+        let target_us = (self.jitter_us * JITTER_DELAY_FACTOR) as u64;
+        self.target_delay =
+            Duration::from_micros(target_us).clamp(self.config.min_delay, self.config.max_delay);
+    }
+
+    fn playout_at(&self, pts: u64) -> Instant {
+        let baseline = self.baseline_transit_us.unwrap_or_default();
+        let due_us = (pts as i64)
+            .saturating_add(baseline)
+            .saturating_add(self.target_delay.as_micros() as i64)
+            .saturating_add(self.hold.as_micros() as i64);
+        self.epoch + Duration::from_micros(due_us.max(0) as u64)
+    }
+
+    /// Starts a new timeline after a pts jump. Frames of the old timeline would
+    /// be scheduled against the new baseline, so they are flushed instead.
+    fn reset_timeline(&mut self) {
+        let frames = std::mem::take(&mut self.frames);
+        self.flushed
+            .extend(frames.into_iter().map(|((pts, _), item)| (pts, item)));
+        self.last_transit_us = None;
+        self.baseline_transit_us = None;
+        self.window_min_transit_us = None;
+        self.window_samples = 0;
+        self.last_released_pts = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME_US: u64 = 20_000;
+    const CONFIG: JitterBufferConfig = JitterBufferConfig {
+        min_delay: Duration::from_millis(20),
+        max_delay: Duration::from_millis(200),
+    };
+
+    fn at(epoch: Instant, us: u64) -> Instant {
+        epoch + Duration::from_micros(us)
+    }
+
+    fn drain(buffer: &mut JitterBuffer<()>, now: Instant) -> Vec<u64> {
+        std::iter::from_fn(|| buffer.pop_ready(now))
+            .map(|(pts, ())| pts)
+            .collect()
+    }
+
+    #[test]
+    fn frames_are_released_in_pts_order() {
+        let epoch = Instant::now();
+        let mut buffer = JitterBuffer::new(CONFIG, epoch);
+        assert!(buffer.push(0, at(epoch, 0), ()));
+        assert!(buffer.push(2 * FRAME_US, at(epoch, 2 * FRAME_US), ()));
+        assert!(buffer.push(FRAME_US, at(epoch, 2 * FRAME_US), ()));
+        assert_eq!(
+            drain(&mut buffer, at(epoch, 1_000_000)),
+            [0, FRAME_US, 2 * FRAME_US]
+        );
+    }
+
+    #[test]
+    fn frames_are_held_for_the_playout_delay() {
+        let epoch = Instant::now();
+        let mut buffer = JitterBuffer::new(CONFIG, epoch);
+        buffer.push(0, at(epoch, 0), ());
+        assert_eq!(buffer.next_release_at(), Some(at(epoch, 20_000)));
+        assert!(buffer.pop_ready(at(epoch, 19_000)).is_none());
+        assert_eq!(buffer.pop_ready(at(epoch, 20_000)), Some((0, ())));
+    }
+
+    #[test]
+    fn frames_behind_the_released_pts_are_dropped() {
+        let epoch = Instant::now();
+        let mut buffer = JitterBuffer::new(CONFIG, epoch);
+        buffer.push(0, at(epoch, 0), ());
+        buffer.push(2 * FRAME_US, at(epoch, 2 * FRAME_US), ());
+        assert_eq!(drain(&mut buffer, at(epoch, 1_000_000)).len(), 2);
+
+        assert!(!buffer.push(FRAME_US, at(epoch, 3 * FRAME_US), ()));
+        assert_eq!(buffer.late_drops(), 1);
+        assert_eq!(buffer.depth(), 0);
+    }
+
+    #[test]
+    fn target_delay_is_clamped_to_the_configured_range() {
+        let epoch = Instant::now();
+        let mut steady = JitterBuffer::new(CONFIG, epoch);
+        for i in 0..32 {
+            steady.push(i * FRAME_US, at(epoch, i * FRAME_US), ());
+        }
+        assert_eq!(steady.target_delay(), CONFIG.min_delay);
+
+        let mut bursty = JitterBuffer::new(CONFIG, epoch);
+        for i in 0..64 {
+            let late_us = (i % 2) * 100_000;
+            bursty.push(i * FRAME_US, at(epoch, i * FRAME_US + late_us), ());
+        }
+        assert!(bursty.jitter() > Duration::from_millis(80));
+        assert_eq!(bursty.target_delay(), CONFIG.max_delay);
+    }
+
+    #[test]
+    fn overflow_drops_the_oldest_frame() {
+        let epoch = Instant::now();
+        let mut buffer = JitterBuffer::new(CONFIG, epoch);
+        for i in 0..=MAX_BUFFERED_FRAMES as u64 {
+            buffer.push(i * FRAME_US, at(epoch, i * FRAME_US), ());
+        }
+        assert_eq!(buffer.depth(), MAX_BUFFERED_FRAMES);
+        assert_eq!(buffer.overflow_drops(), 1);
+        assert_eq!(
+            buffer.pop_ready(at(epoch, 60_000_000)),
+            Some((FRAME_US, ()))
+        );
+    }
+
+    #[test]
+    fn discontinuity_releases_frames_of_the_old_timeline() {
+        let epoch = Instant::now();
+        let mut buffer = JitterBuffer::new(CONFIG, epoch);
+        buffer.push(0, at(epoch, 0), ());
+        buffer.push(FRAME_US, at(epoch, FRAME_US), ());
+
+        let restarted_pts = 10_000_000;
+        assert!(buffer.push(restarted_pts, at(epoch, 2 * FRAME_US), ()));
+        assert_eq!(buffer.depth(), 3);
+        assert_eq!(buffer.next_release_at(), Some(epoch));
+        assert_eq!(drain(&mut buffer, epoch), [0, FRAME_US]);
+
+        assert_eq!(buffer.next_release_at(), Some(at(epoch, 3 * FRAME_US)));
+        assert_eq!(
+            buffer.pop_ready(at(epoch, 3 * FRAME_US)),
+            Some((restarted_pts, ()))
+        );
+    }
+}
@@ -1,2 +1,28 @@
 pub mod api;
+mod audio_activity;
+mod audio_codec;
+mod audio_gaps;
+mod audio_redundancy;
+mod av1;
+mod bitstream;
+mod codec;
+mod control;
+mod data_channel;
+mod debug_report;
+mod file_transfer;
+mod frame_sequence;
 mod frb_generated;
+mod h264;
+mod hevc;
+mod ingress_queue;
+mod jitter_buffer;
+mod lip_sync;
+mod negotiation;
+mod opus;
+#[cfg(feature = "opus-decode")]
+mod opus_decode;
+mod send_delay;
+mod stale_frames;
+mod temporal_layers;
+#[cfg(feature = "hevc-decode")]
+mod video_decode;
@@ -0,0 +1,136 @@
+use std::time::Duration;
+
+/// Skew changes smaller than this are ignored so playout does not wobble with
+/// every jitter estimate update.
+const SKEW_HYSTERESIS_US: i64 = 5_000;
+/// Upper bound on how long either stream may be held back.
+const MAX_HOLD_US: i64 = 1_000_000;
+
+/// Hold-back applied to each stream so that equal pts are released together.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SyncHold {
+    pub video: Duration,
+    pub audio: Duration,
+}
+
+/// Aligns the audio and video playout timelines.
+///
+/// Each stream reports its playout offset (local arrival clock minus pts, plus its
+/// own jitter delay). Whichever stream would play a given pts earlier is ahead and
+/// is held back by the difference. The manual correction is added on top; positive
+/// values delay audio relative to video.
+#[derive(Debug, Default)]
+pub struct LipSync {
+    applied_skew_us: Option<i64>,
+    hold: SyncHold,
+}
+
+impl LipSync {
+    /// Returns the new hold-back when it changed by more than the hysteresis.
+    pub fn update(
+        &mut self,
+        video_offset_us: Option<i64>,
+        audio_offset_us: Option<i64>,
+        manual_correction_us: i64,
+    ) -> Option<SyncHold> {
+        let (Some(video_offset_us), Some(audio_offset_us)) = (video_offset_us, audio_offset_us)
+        else {
+            return None;
+        };
+        let skew_us = video_offset_us
+            .saturating_sub(audio_offset_us)
+            .saturating_add(manual_correction_us)
+            .clamp(-MAX_HOLD_US, MAX_HOLD_US);
+        if self
+            .applied_skew_us
+            .is_some_and(|applied| applied.abs_diff(skew_us) < SKEW_HYSTERESIS_US as u64)
+        {
+            return None;
+        }
+        self.applied_skew_us = Some(skew_us);
+        self.hold = if skew_us >= 0 {
+            SyncHold {
+                video: Duration::ZERO,
+                audio: Duration::from_micros(skew_us as u64),
+            }
+        } else {
+            SyncHold {
+                video: Duration::from_micros(skew_us.unsigned_abs()),
+                audio: Duration::ZERO,
+            }
+        };
+        Some(self.hold)
+    }
+
+    pub fn hold(&self) -> SyncHold {
+        self.hold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hold(video_ms: u64, audio_ms: u64) -> SyncHold {
+        SyncHold {
+            video: Duration::from_millis(video_ms),
+            audio: Duration::from_millis(audio_ms),
+        }
+    }
+
+    #[test]
+    fn nothing_is_held_until_both_offsets_are_known() {
+        let mut sync = LipSync::default();
+        assert_eq!(sync.update(Some(40_000), None, 0), None);
+        assert_eq!(sync.update(None, Some(40_000), 0), None);
+        assert_eq!(sync.hold(), SyncHold::default());
+    }
+
+    #[test]
+    fn the_stream_that_plays_earlier_is_held_back() {
+        let mut sync = LipSync::default();
+        assert_eq!(
+            sync.update(Some(80_000), Some(30_000), 0),
+            Some(hold(0, 50))
+        );
+        assert_eq!(
+            sync.update(Some(30_000), Some(80_000), 0),
+            Some(hold(50, 0))
+        );
+        assert_eq!(sync.hold(), hold(50, 0));
+    }
+
+    #[test]
+    fn hold_is_capped_at_one_second() {
+        let mut sync = LipSync::default();
+        assert_eq!(
+            sync.update(Some(5_000_000), Some(0), 0),
+            Some(hold(0, 1_000))
+        );
+        assert_eq!(
+            sync.update(Some(0), Some(5_000_000), 0),
+            Some(hold(1_000, 0))
+        );
+    }
+
+    #[test]
+    fn small_skew_changes_are_ignored() {
+        let mut sync = LipSync::default();
+        assert_eq!(sync.update(Some(50_000), Some(0), 0), Some(hold(0, 50)));
+        assert_eq!(sync.update(Some(54_000), Some(0), 0), None);
+        assert_eq!(sync.update(Some(46_000), Some(0), 0), None);
+        assert_eq!(sync.hold(), hold(0, 50));
+        assert_eq!(sync.update(Some(55_000), Some(0), 0), Some(hold(0, 55)));
+    }
+
+    #[test]
+    fn positive_manual_correction_delays_audio() {
+        let mut sync = LipSync::default();
+        assert_eq!(sync.update(Some(0), Some(0), 30_000), Some(hold(0, 30)));
+        assert_eq!(sync.update(Some(0), Some(0), -30_000), Some(hold(30, 0)));
+        assert_eq!(
+            sync.update(Some(20_000), Some(0), -20_000),
+            Some(hold(0, 0))
+        );
+    }
+}
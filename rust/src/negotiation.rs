@@ -0,0 +1,515 @@
+use crate::audio_codec::AudioFormat;
+use anyhow::{bail, Context};
+use std::time::Duration;
+
+/// Codec negotiation runs on the first bidirectional QUIC stream, before the
+/// Sankaku transport is set up, and only between peers that both advertised
+/// `NEGOTIATION_ALPN` during the handshake. A peer that only offers
+/// `SANKAKU_ALPN` predates negotiation: it is recognised as soon as the
+/// connection is up, and no stream is opened or accepted that Sankaku could
+/// expect for itself. The sender writes an offer listing codec ids in
+/// preference order; the receiver answers with the first one it can decode, or
+/// declines. Trailing bytes in either message are ignored so later protocol
+/// versions can append sections.
+///
+/// The audio section follows the video section in both messages. Offered audio
+/// codecs carry the configuration their decoder needs; a message without the
+/// section comes from a peer that predates audio negotiation and only sends or
+/// accepts Opus.
+///
+/// A features byte follows the audio section: the sender lists the optional
+/// session features it supports and the receiver answers with those it also
+/// supports. Without it, no optional features are used.
+pub const NEGOTIATION_ALPN: &[u8] = b"nomikai-ncod/1";
+pub const SANKAKU_ALPN: &[u8] = b"sankaku-rt";
+const NEGOTIATION_MAGIC: &[u8; 4] = b"NCOD";
+const NEGOTIATION_PROTOCOL_VERSION: u8 = 1;
+const NEGOTIATION_PACKET_OFFER: u8 = 0x01;
+const NEGOTIATION_PACKET_ANSWER: u8 = 0x02;
+const ANSWER_STATUS_ACCEPTED: u8 = 0x00;
+const ANSWER_STATUS_DECLINED: u8 = 0x01;
+const MAX_MESSAGE_BYTES: usize = 1024;
+/// The sender opens a control stream after negotiation; see `control`.
+const SESSION_FEATURE_CONTROL_STREAM: u8 = 0x01;
+/// Both peers accept unreliable data channel datagrams; see `data_channel`.
+const SESSION_FEATURE_DATAGRAMS: u8 = 0x02;
+/// The receiver unwraps `AUDIO_CODEC_OPUS_RED` packets; see `audio_redundancy`.
+const SESSION_FEATURE_AUDIO_REDUNDANCY: u8 = 0x04;
+const SUPPORTED_SESSION_FEATURES: u8 =
+    SESSION_FEATURE_CONTROL_STREAM | SESSION_FEATURE_DATAGRAMS | SESSION_FEATURE_AUDIO_REDUNDANCY;
+
+/// How long either side waits for a peer that advertised negotiation.
+const NEGOTIATION_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NegotiationOutcome {
+    /// Both sides agreed on this video codec id.
+    Agreed(u8),
+    /// The peer understood the protocol but shares no codec with us. Carries the
+    /// codec ids the peer offered, empty on the sender side.
+    Declined(Vec<u8>),
+    /// The peer did not advertise negotiation; it is assumed to be a
+    /// pre-negotiation build that only speaks HEVC.
+    NoPeerSupport,
+}
+
+/// One offered audio codec and its decoder configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioCodecOffer {
+    pub codec: u8,
+    pub config: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AudioNegotiationOutcome {
+    Agreed(AudioCodecOffer),
+    /// Carries the codec ids the peer offered, empty on the sender side.
+    Declined(Vec<u8>),
+    /// The peer did not negotiate audio; it is assumed to only speak Opus.
+    NoPeerSupport,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionNegotiation {
+    pub video: NegotiationOutcome,
+    pub audio: AudioNegotiationOutcome,
+    /// Both peers support the control stream.
+    pub control_stream: bool,
+    /// Both peers accept data channel datagrams.
+    pub datagrams: bool,
+    /// The receiver understands redundant audio payloads.
+    pub audio_redundancy: bool,
+}
+
+impl SessionNegotiation {
+    fn no_peer_support() -> Self {
+        Self {
+            video: NegotiationOutcome::NoPeerSupport,
+            audio: AudioNegotiationOutcome::NoPeerSupport,
+            control_stream: false,
+            datagrams: false,
+            audio_redundancy: false,
+        }
+    }
+}
+
+/// ALPN protocols both endpoints advertise, most preferred first. The server
+/// picks the first of its own protocols the client also offers.
+pub fn alpn_protocols() -> Vec<Vec<u8>> {
+    vec![NEGOTIATION_ALPN.to_vec(), SANKAKU_ALPN.to_vec()]
+}
+
+fn peer_negotiates(connection: &quinn::Connection) -> bool {
+    connection
+        .handshake_data()
+        .and_then(|data| data.downcast::<quinn::crypto::rustls::HandshakeData>().ok())
+        .and_then(|data| data.protocol)
+        .is_some_and(|protocol| protocol == NEGOTIATION_ALPN)
+}
+
+fn message_header(packet_type: u8) -> Vec<u8> {
+    let mut message = Vec::with_capacity(16);
+    message.extend_from_slice(NEGOTIATION_MAGIC);
+    message.push(NEGOTIATION_PROTOCOL_VERSION);
+    message.push(packet_type);
+    message
+}
+
+/// Returns the message body after the header, or an error if the header does not
+/// match.
+fn parse_message_header(message: &[u8], packet_type: u8) -> anyhow::Result<&[u8]> {
+    if message.len() < 6 || &message[..4] != NEGOTIATION_MAGIC {
+        bail!("codec negotiation message has an invalid header");
+    }
+    if message[4] != NEGOTIATION_PROTOCOL_VERSION {
+        bail!(
+            "unsupported codec negotiation protocol version: {}",
+            message[4]
+        );
+    }
+    if message[5] != packet_type {
+        bail!(
+            "unexpected codec negotiation packet type 0x{:02X}",
+            message[5]
+        );
+    }
+    Ok(&message[6..])
+}
+
+fn encode_offer(video_codecs: &[u8], audio_codecs: &[AudioCodecOffer]) -> anyhow::Result<Vec<u8>> {
+    let count = u8::try_from(video_codecs.len()).context("too many video codecs offered")?;
+    let mut message = message_header(NEGOTIATION_PACKET_OFFER);
+    message.push(count);
+    message.extend_from_slice(video_codecs);
+
+    let count = u8::try_from(audio_codecs.len()).context("too many audio codecs offered")?;
+    message.push(count);
+    for offer in audio_codecs {
+        let config_len =
+            u16::try_from(offer.config.len()).context("audio codec config is too large")?;
+        message.push(offer.codec);
+        message.extend_from_slice(&config_len.to_be_bytes());
+        message.extend_from_slice(&offer.config);
+    }
+    message.push(SUPPORTED_SESSION_FEATURES);
+    if message.len() > MAX_MESSAGE_BYTES {
+        bail!("codec offer exceeds {MAX_MESSAGE_BYTES} bytes");
+    }
+    Ok(message)
+}
+
+/// Returns the offered video codecs, the offered audio codecs when the sender
+/// negotiates audio, and the session features it supports.
+fn parse_offer(message: &[u8]) -> anyhow::Result<(Vec<u8>, Option<Vec<AudioCodecOffer>>, u8)> {
+    let body = parse_message_header(message, NEGOTIATION_PACKET_OFFER)?;
+    let (&count, rest) = body.split_first().context("codec offer is empty")?;
+    let codecs = rest
+        .get(..usize::from(count))
+        .context("codec offer is truncated")?;
+    let Some((&audio_count, mut rest)) = rest[usize::from(count)..].split_first() else {
+        return Ok((codecs.to_vec(), None, 0));
+    };
+
+    let mut audio = Vec::with_capacity(usize::from(audio_count));
+    for _ in 0..audio_count {
+        let (header, after_header) = rest
+            .split_at_checked(3)
+            .context("audio codec offer is truncated")?;
+        let config_len = usize::from(u16::from_be_bytes([header[1], header[2]]));
+        let (config, after_config) = after_header
+            .split_at_checked(config_len)
+            .context("audio codec config is truncated")?;
+        audio.push(AudioCodecOffer {
+            codec: header[0],
+            config: config.to_vec(),
+        });
+        rest = after_config;
+    }
+    let features = rest.first().copied().unwrap_or(0);
+    Ok((codecs.to_vec(), Some(audio), features))
+}
+
+fn push_answer_status(message: &mut Vec<u8>, codec: Option<u8>) {
+    match codec {
+        Some(codec) => message.extend_from_slice(&[ANSWER_STATUS_ACCEPTED, codec]),
+        None => message.extend_from_slice(&[ANSWER_STATUS_DECLINED, 0]),
+    }
+}
+
+fn encode_answer(video_codec: Option<u8>, audio_codec: Option<u8>, features: u8) -> Vec<u8> {
+    let mut message = message_header(NEGOTIATION_PACKET_ANSWER);
+    push_answer_status(&mut message, video_codec);
+    push_answer_status(&mut message, audio_codec);
+    message.push(features);
+    message
+}
+
+fn parse_answer_status(status: &[u8]) -> anyhow::Result<Option<u8>> {
+    match status {
+        [ANSWER_STATUS_ACCEPTED, codec, ..] => Ok(Some(*codec)),
+        [ANSWER_STATUS_DECLINED, ..] => Ok(None),
+        _ => bail!("codec answer is malformed"),
+    }
+}
+
+/// Returns the chosen video codec, the chosen audio codec when the receiver
+/// negotiates audio, and the session features both sides support.
+fn parse_answer(message: &[u8]) -> anyhow::Result<(Option<u8>, Option<Option<u8>>, u8)> {
+    let body = parse_message_header(message, NEGOTIATION_PACKET_ANSWER)?;
+    let video = parse_answer_status(body)?;
+    let audio = match body.get(2..) {
+        Some(status) if !status.is_empty() => Some(parse_answer_status(status)?),
+        _ => None,
+    };
+    let features = body.get(4).copied().unwrap_or(0);
+    Ok((video, audio, features))
+}
+
+/// Sender side: offers codecs in preference order and waits for the answer.
+pub async fn offer_codecs(
+    connection: &quinn::Connection,
+    video_codecs: &[u8],
+    audio_codecs: &[AudioCodecOffer],
+) -> anyhow::Result<SessionNegotiation> {
+    if !peer_negotiates(connection) {
+        return Ok(SessionNegotiation::no_peer_support());
+    }
+    let offer = encode_offer(video_codecs, audio_codecs)?;
+    let (mut send, mut recv) = connection
+        .open_bi()
+        .await
+        .context("failed to open codec negotiation stream")?;
+    send.write_all(&offer)
+        .await
+        .context("failed to send codec offer")?;
+    send.finish()
+        .context("failed to finish codec offer stream")?;
+
+    let answer = tokio::time::timeout(NEGOTIATION_TIMEOUT, recv.read_to_end(MAX_MESSAGE_BYTES))
+        .await
+        .context("timed out waiting for codec answer")?
+        .context("failed to read codec answer")?;
+    resolve_answer(&answer, video_codecs, audio_codecs)
+}
+
+/// Sender side: turns the receiver's answer into the negotiated session, rejecting
+/// an answer that names a codec which was not offered.
+fn resolve_answer(
+    answer: &[u8],
+    video_codecs: &[u8],
+    audio_codecs: &[AudioCodecOffer],
+) -> anyhow::Result<SessionNegotiation> {
+    let (video_answer, audio_answer, features) = parse_answer(answer)?;
+    let video = match video_answer {
+        Some(codec) if video_codecs.contains(&codec) => NegotiationOutcome::Agreed(codec),
+        Some(codec) => bail!("receiver chose codec 0x{codec:02X}, which was not offered"),
+        None => NegotiationOutcome::Declined(Vec::new()),
+    };
+    let audio = match audio_answer {
+        Some(Some(codec)) => {
+            let offer = audio_codecs
+                .iter()
+                .find(|offer| offer.codec == codec)
+                .with_context(|| {
+                    format!("receiver chose audio codec 0x{codec:02X}, which was not offered")
+                })?;
+            AudioNegotiationOutcome::Agreed(offer.clone())
+        }
+        Some(None) => AudioNegotiationOutcome::Declined(Vec::new()),
+        None => AudioNegotiationOutcome::NoPeerSupport,
+    };
+    Ok(SessionNegotiation {
+        video,
+        audio,
+        control_stream: features & SESSION_FEATURE_CONTROL_STREAM != 0,
+        datagrams: features & SESSION_FEATURE_DATAGRAMS != 0,
+        audio_redundancy: features & SESSION_FEATURE_AUDIO_REDUNDANCY != 0,
+    })
+}
+
+/// The sender's most preferred audio offer that is supported and carries a usable
+/// configuration.
+fn choose_audio_codec(offered: &[AudioCodecOffer], supported: &[u8]) -> Option<AudioCodecOffer> {
+    offered
+        .iter()
+        .find(|offer| {
+            supported.contains(&offer.codec)
+                && AudioFormat::from_config(offer.codec, &offer.config).is_ok()
+        })
+        .cloned()
+}
+
+/// Receiver side: waits for an offer and picks the sender's most preferred codecs
+/// that also appear in `video_supported` and `audio_supported`. An audio offer
+/// whose configuration is invalid or unsupported is skipped, so the next offered
+/// codec, usually Opus, is used instead.
+pub async fn answer_codecs(
+    connection: &quinn::Connection,
+    video_supported: &[u8],
+    audio_supported: &[u8],
+) -> anyhow::Result<SessionNegotiation> {
+    if !peer_negotiates(connection) {
+        return Ok(SessionNegotiation::no_peer_support());
+    }
+    let (mut send, mut recv) = tokio::time::timeout(NEGOTIATION_TIMEOUT, connection.accept_bi())
+        .await
+        .context("timed out waiting for codec negotiation stream")?
+        .context("failed to accept codec negotiation stream")?;
+    let offer = tokio::time::timeout(NEGOTIATION_TIMEOUT, recv.read_to_end(MAX_MESSAGE_BYTES))
+        .await
+        .context("timed out waiting for codec offer")?
+        .context("failed to read codec offer")?;
+    let (answer, negotiation) = choose_codecs(&offer, video_supported, audio_supported)?;
+
+    send.write_all(&answer)
+        .await
+        .context("failed to send codec answer")?;
+    send.finish()
+        .context("failed to finish codec answer stream")?;
+    Ok(negotiation)
+}
+
+/// Receiver side: picks codecs from the sender's offer and returns the answer to
+/// send along with the negotiated session.
+fn choose_codecs(
+    offer: &[u8],
+    video_supported: &[u8],
+    audio_supported: &[u8],
+) -> anyhow::Result<(Vec<u8>, SessionNegotiation)> {
+    let (offered_video, offered_audio, offered_features) = parse_offer(offer)?;
+    let features = offered_features & SUPPORTED_SESSION_FEATURES;
+    let chosen_video = offered_video
+        .iter()
+        .copied()
+        .find(|codec| video_supported.contains(codec));
+    let chosen_audio = offered_audio
+        .as_ref()
+        .and_then(|offered| choose_audio_codec(offered, audio_supported));
+    let answer = encode_answer(
+        chosen_video,
+        chosen_audio.as_ref().map(|offer| offer.codec),
+        features,
+    );
+
+    let video = match chosen_video {
+        Some(codec) => NegotiationOutcome::Agreed(codec),
+        None => NegotiationOutcome::Declined(offered_video),
+    };
+    let audio = match (chosen_audio, offered_audio) {
+        (Some(offer), _) => AudioNegotiationOutcome::Agreed(offer),
+        (None, Some(offered)) => {
+            AudioNegotiationOutcome::Declined(offered.iter().map(|offer| offer.codec).collect())
+        }
+        (None, None) => AudioNegotiationOutcome::NoPeerSupport,
+    };
+    Ok((
+        answer,
+        SessionNegotiation {
+            video,
+            audio,
+            control_stream: features & SESSION_FEATURE_CONTROL_STREAM != 0,
+            datagrams: features & SESSION_FEATURE_DATAGRAMS != 0,
+            audio_redundancy: features & SESSION_FEATURE_AUDIO_REDUNDANCY != 0,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::{AUDIO_CODEC_AAC_LC, VIDEO_CODEC_AV1, VIDEO_CODEC_H264};
+    use sankaku_core::{AUDIO_CODEC_OPUS, VIDEO_CODEC_HEVC};
+
+    fn offer(codec: u8, config: &[u8]) -> AudioCodecOffer {
+        AudioCodecOffer {
+            codec,
+            config: config.to_vec(),
+        }
+    }
+
+    #[test]
+    fn offers_survive_encoding() {
+        let audio = [
+            offer(AUDIO_CODEC_AAC_LC, &[0x12, 0x10]),
+            offer(AUDIO_CODEC_OPUS, &[]),
+        ];
+        let message = encode_offer(&[0xC0, 0x01], &audio).unwrap();
+        let (video, parsed_audio, features) = parse_offer(&message).unwrap();
+        assert_eq!(video, [0xC0, 0x01]);
+        assert_eq!(parsed_audio.as_deref(), Some(&audio[..]));
+        assert_eq!(features, SUPPORTED_SESSION_FEATURES);
+    }
+
+    #[test]
+    fn answers_survive_encoding() {
+        let message = encode_answer(Some(VIDEO_CODEC_H264), Some(AUDIO_CODEC_OPUS), 0x03);
+        assert_eq!(
+            parse_answer(&message).unwrap(),
+            (Some(VIDEO_CODEC_H264), Some(Some(AUDIO_CODEC_OPUS)), 0x03)
+        );
+        let message = encode_answer(None, None, 0);
+        assert_eq!(parse_answer(&message).unwrap(), (None, Some(None), 0));
+    }
+
+    #[test]
+    fn offer_without_audio_section_parses_as_video_only() {
+        let mut message = message_header(NEGOTIATION_PACKET_OFFER);
+        message.extend_from_slice(&[2, VIDEO_CODEC_H264, VIDEO_CODEC_HEVC]);
+        assert_eq!(
+            parse_offer(&message).unwrap(),
+            (vec![VIDEO_CODEC_H264, VIDEO_CODEC_HEVC], None, 0)
+        );
+    }
+
+    #[test]
+    fn answer_without_audio_status_or_features_parses_as_video_only() {
+        let mut message = message_header(NEGOTIATION_PACKET_ANSWER);
+        push_answer_status(&mut message, Some(VIDEO_CODEC_HEVC));
+        assert_eq!(
+            parse_answer(&message).unwrap(),
+            (Some(VIDEO_CODEC_HEVC), None, 0)
+        );
+    }
+
+    #[test]
+    fn receiver_declines_video_it_cannot_decode() {
+        let offer = encode_offer(&[VIDEO_CODEC_AV1, VIDEO_CODEC_H264], &[]).unwrap();
+        let (answer, negotiation) = choose_codecs(&offer, &[VIDEO_CODEC_HEVC], &[]).unwrap();
+        assert_eq!(
+            negotiation.video,
+            NegotiationOutcome::Declined(vec![VIDEO_CODEC_AV1, VIDEO_CODEC_H264])
+        );
+
+        let sender = resolve_answer(&answer, &[VIDEO_CODEC_AV1, VIDEO_CODEC_H264], &[]).unwrap();
+        assert_eq!(sender.video, NegotiationOutcome::Declined(Vec::new()));
+    }
+
+    #[test]
+    fn receiver_picks_the_senders_most_preferred_supported_codec() {
+        let audio = [offer(AUDIO_CODEC_OPUS, &[])];
+        let offer = encode_offer(
+            &[VIDEO_CODEC_AV1, VIDEO_CODEC_H264, VIDEO_CODEC_HEVC],
+            &audio,
+        )
+        .unwrap();
+        let (answer, negotiation) = choose_codecs(
+            &offer,
+            &[VIDEO_CODEC_HEVC, VIDEO_CODEC_H264],
+            &[AUDIO_CODEC_OPUS],
+        )
+        .unwrap();
+        assert_eq!(
+            negotiation.video,
+            NegotiationOutcome::Agreed(VIDEO_CODEC_H264)
+        );
+
+        let sender = resolve_answer(
+            &answer,
+            &[VIDEO_CODEC_AV1, VIDEO_CODEC_H264, VIDEO_CODEC_HEVC],
+            &audio,
+        )
+        .unwrap();
+        assert_eq!(sender, negotiation);
+    }
+
+    #[test]
+    fn answer_naming_an_unoffered_codec_is_rejected() {
+        let answer = encode_answer(Some(VIDEO_CODEC_AV1), None, 0);
+        assert!(resolve_answer(&answer, &[VIDEO_CODEC_HEVC], &[]).is_err());
+
+        let answer = encode_answer(Some(VIDEO_CODEC_HEVC), Some(AUDIO_CODEC_AAC_LC), 0);
+        assert!(resolve_answer(
+            &answer,
+            &[VIDEO_CODEC_HEVC],
+            &[offer(AUDIO_CODEC_OPUS, &[])]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn unusable_aac_config_falls_back_to_the_next_offer() {
+        let supported = [AUDIO_CODEC_AAC_LC, AUDIO_CODEC_OPUS];
+        let valid = offer(AUDIO_CODEC_AAC_LC, &[0x12, 0x10]);
+        assert_eq!(
+            choose_audio_codec(&[valid.clone(), offer(AUDIO_CODEC_OPUS, &[])], &supported),
+            Some(valid)
+        );
+        // HE-AAC object type, then an explicit 24-bit sampling frequency.
+        for config in [&[0x2A, 0x10][..], &[0x17, 0x80, 0x56, 0x22, 0x10]] {
+            assert_eq!(
+                choose_audio_codec(
+                    &[
+                        offer(AUDIO_CODEC_AAC_LC, config),
+                        offer(AUDIO_CODEC_OPUS, &[])
+                    ],
+                    &supported
+                ),
+                Some(offer(AUDIO_CODEC_OPUS, &[]))
+            );
+        }
+        assert_eq!(
+            choose_audio_codec(&[offer(AUDIO_CODEC_AAC_LC, &[])], &supported),
+            None
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use anyhow::bail;
+
+/// Opus always counts frames per packet at 48 kHz, whatever the coded bandwidth.
+const SAMPLES_PER_MS: u32 = 48;
+/// Longest duration a single packet may carry (RFC 6716 section 3.2.5).
+const MAX_PACKET_DURATION_US: u32 = 120_000;
+/// DTX packets carry at most the TOC byte and a frame-count byte.
+const MAX_DTX_PACKET_BYTES: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpusMode {
+    Silk,
+    Hybrid,
+    Celt,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpusBandwidth {
+    Narrowband,
+    Mediumband,
+    Wideband,
+    SuperWideband,
+    Fullband,
+}
+
+impl OpusBandwidth {
+    /// Audio bandwidth of the mode, in Hz.
+    pub fn cutoff_hz(self) -> u32 {
+        match self {
+            OpusBandwidth::Narrowband => 4_000,
+            OpusBandwidth::Mediumband => 6_000,
+            OpusBandwidth::Wideband => 8_000,
+            OpusBandwidth::SuperWideband => 12_000,
+            OpusBandwidth::Fullband => 20_000,
+        }
+    }
+}
+
+/// What the TOC byte (and, for code 3 packets, the frame-count byte) says about an
+/// Opus packet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpusPacketInfo {
+    pub mode: OpusMode,
+    pub bandwidth: OpusBandwidth,
+    pub stereo: bool,
+    pub frame_duration_us: u32,
+    pub frame_count: u8,
+    /// Discontinuous-transmission packet: no coded audio, the decoder plays comfort
+    /// noise or silence.
+    pub dtx: bool,
+}
+
+impl OpusPacketInfo {
+    pub fn duration_us(&self) -> u32 {
+        self.frame_duration_us * u32::from(self.frame_count)
+    }
+
+    /// Samples per channel at 48 kHz, the unit of `frames_per_packet`.
+    pub fn samples_48k(&self) -> u32 {
+        self.duration_us() * SAMPLES_PER_MS / 1000
+    }
+}
+
+/// Parses the TOC byte of an Opus packet (RFC 6716 section 3.1) and checks the
+/// framing rules that can be verified without decoding.
+pub fn parse_packet(packet: &[u8]) -> anyhow::Result<OpusPacketInfo> {
+    let Some(&toc) = packet.first() else {
+        bail!("opus packet is empty");
+    };
+    let config = toc >> 3;
+    let (mode, bandwidth, frame_duration_us) = match config {
+        0..=11 => {
+            let bandwidth = match config / 4 {
+                0 => OpusBandwidth::Narrowband,
+                1 => OpusBandwidth::Mediumband,
+                _ => OpusBandwidth::Wideband,
+            };
+            let duration = [10_000, 20_000, 40_000, 60_000][usize::from(config % 4)];
+            (OpusMode::Silk, bandwidth, duration)
+        }
+        12..=15 => {
+            let bandwidth = if config < 14 {
+                OpusBandwidth::SuperWideband
+            } else {
+                OpusBandwidth::Fullband
+            };
+            let duration = [10_000, 20_000][usize::from(config % 2)];
+            (OpusMode::Hybrid, bandwidth, duration)
+        }
+        _ => {
+            let bandwidth = match (config - 16) / 4 {
+                0 => OpusBandwidth::Narrowband,
+                1 => OpusBandwidth::Wideband,
+                2 => OpusBandwidth::SuperWideband,
+                _ => OpusBandwidth::Fullband,
+            };
+            let duration = [2_500, 5_000, 10_000, 20_000][usize::from(config % 4)];
+            (OpusMode::Celt, bandwidth, duration)
+        }
+    };
+
+    let frame_count = match toc & 0x03 {
+        0 => 1,
+        1 => {
+            if !(packet.len() - 1).is_multiple_of(2) {
+                bail!("opus code 1 packet has frames of unequal size");
+            }
+            2
+        }
+        2 => {
+            if packet.len() < 2 {
+                bail!("opus code 2 packet is missing its frame length");
+            }
+            2
+        }
+        _ => {
+            let Some(&count_byte) = packet.get(1) else {
+                bail!("opus code 3 packet is missing its frame count");
+            };
+            let count = count_byte & 0x3F;
+            if count == 0 {
+                bail!("opus code 3 packet declares zero frames");
+            }
+            count
+        }
+    };
+    let info = OpusPacketInfo {
+        mode,
+        bandwidth,
+        stereo: toc & 0x04 != 0,
+        frame_duration_us,
+        frame_count,
+        dtx: packet.len() <= MAX_DTX_PACKET_BYTES,
+    };
+    if info.duration_us() > MAX_PACKET_DURATION_US {
+        bail!(
+            "opus packet lasts {}us, more than the 120ms maximum",
+            info.duration_us()
+        );
+    }
+    Ok(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn toc(config: u8, stereo: bool, code: u8) -> u8 {
+        (config << 3) | (u8::from(stereo) << 2) | code
+    }
+
+    #[test]
+    fn toc_config_selects_mode_bandwidth_and_duration() {
+        let silk = parse_packet(&[toc(9, false, 0), 0xAA, 0xBB]).unwrap();
+        assert_eq!(silk.mode, OpusMode::Silk);
+        assert_eq!(silk.bandwidth, OpusBandwidth::Wideband);
+        assert_eq!(silk.frame_duration_us, 20_000);
+        assert!(!silk.stereo);
+
+        let hybrid = parse_packet(&[toc(14, true, 0), 0xAA, 0xBB]).unwrap();
+        assert_eq!(hybrid.mode, OpusMode::Hybrid);
+        assert_eq!(hybrid.bandwidth, OpusBandwidth::Fullband);
+        assert_eq!(hybrid.frame_duration_us, 10_000);
+        assert!(hybrid.stereo);
+
+        let celt = parse_packet(&[toc(31, false, 0), 0xAA, 0xBB]).unwrap();
+        assert_eq!(celt.mode, OpusMode::Celt);
+        assert_eq!(celt.bandwidth, OpusBandwidth::Fullband);
+        assert_eq!(celt.frame_duration_us, 20_000);
+        assert_eq!(celt.samples_48k(), 960);
+    }
+
+    #[test]
+    fn frame_count_codes_are_checked() {
+        assert_eq!(
+            parse_packet(&[toc(1, false, 1), 1, 2, 3, 4])
+                .unwrap()
+                .frame_count,
+            2
+        );
+        assert!(parse_packet(&[toc(1, false, 1), 1, 2, 3]).is_err());
+        assert!(parse_packet(&[toc(1, false, 2)]).is_err());
+
+        let code3 = parse_packet(&[toc(16, false, 3), 3, 0xAA, 0xBB]).unwrap();
+        assert_eq!(code3.frame_count, 3);
+        assert_eq!(code3.duration_us(), 7_500);
+        assert!(parse_packet(&[toc(16, false, 3), 0, 0xAA]).is_err());
+        assert!(parse_packet(&[toc(16, false, 3)]).is_err());
+    }
+
+    #[test]
+    fn packets_over_120ms_are_rejected() {
+        // Six 20 ms frames is the longest packet allowed; seven is not.
+        assert!(parse_packet(&[toc(1, false, 3), 6, 0xAA, 0xBB]).is_ok());
+        assert!(parse_packet(&[toc(1, false, 3), 7, 0xAA, 0xBB]).is_err());
+    }
+
+    #[test]
+    fn tiny_packets_are_dtx() {
+        assert!(parse_packet(&[toc(1, false, 0)]).unwrap().dtx);
+        assert!(!parse_packet(&[toc(1, false, 0), 0xAA, 0xBB]).unwrap().dtx);
+        assert!(parse_packet(&[]).is_err());
+    }
+}
@@ -0,0 +1,324 @@
+use anyhow::{anyhow, bail, Context};
+use audiopus::coder::Decoder;
+use audiopus::packet::Packet;
+use audiopus::{Channels, MutSignals, SampleRate};
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender, TryRecvError, TrySendError};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Decoded audio is always 48 kHz interleaved stereo; mono packets are upmixed by
+/// the decoder, so the output format never changes mid-stream.
+pub const PCM_SAMPLE_RATE_HZ: u32 = 48_000;
+pub const PCM_CHANNELS: u8 = 2;
+/// 120 ms at 48 kHz, the longest duration one Opus packet can carry.
+const MAX_PACKET_SAMPLES: usize = 5_760;
+/// Blocks waiting for the PCM writer thread, about a second of 20 ms packets.
+/// Blocks decoded while the queue is full are dropped.
+const PCM_WRITE_QUEUE_BLOCKS: usize = 50;
+/// How long the writer waits before retrying when the reader has not drained
+/// the pipe.
+const PCM_WRITE_RETRY: Duration = Duration::from_millis(5);
+
+/// Interleaved 16-bit samples for `pts` onwards.
+#[derive(Debug)]
+pub struct PcmBlock {
+    pub pts: u64,
+    pub samples: Vec<i16>,
+    /// Synthesised by the decoder for a lost packet rather than decoded from it.
+    pub concealed: bool,
+}
+
+impl PcmBlock {
+    /// Samples per channel.
+    pub fn frames(&self) -> u32 {
+        (self.samples.len() / usize::from(PCM_CHANNELS)) as u32
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OpusDecodeCounters {
+    pub decoded: u64,
+    /// Lost packets rebuilt from the in-band FEC of the packet that followed them.
+    pub fec_recovered: u64,
+    /// Lost packets extrapolated by the decoder's packet loss concealment.
+    pub plc_packets: u64,
+    pub errors: u64,
+}
+
+/// libopus decoder for one received stream.
+pub struct OpusPcmDecoder {
+    decoder: Decoder,
+    buffer: Vec<i16>,
+    counters: OpusDecodeCounters,
+}
+
+impl OpusPcmDecoder {
+    pub fn new() -> anyhow::Result<Self> {
+        let decoder = Decoder::new(SampleRate::Hz48000, Channels::Stereo)
+            .map_err(|error| anyhow!("failed to create opus decoder: {error}"))?;
+        Ok(Self {
+            decoder,
+            buffer: vec![0; MAX_PACKET_SAMPLES * usize::from(PCM_CHANNELS)],
+            counters: OpusDecodeCounters::default(),
+        })
+    }
+
+    pub fn counters(&self) -> OpusDecodeCounters {
+        self.counters
+    }
+
+    pub fn decode(&mut self, pts: u64, packet: &[u8]) -> anyhow::Result<PcmBlock> {
+        let block = self
+            .run(Some(packet), MAX_PACKET_SAMPLES, false)
+            .context("failed to decode opus packet")
+            .map(|samples| PcmBlock {
+                pts,
+                samples,
+                concealed: false,
+            });
+        self.count(&block, |counters| &mut counters.decoded);
+        block
+    }
+
+    /// Rebuilds `frames` lost samples per channel starting at `pts`. When
+    /// `next_packet` (the packet that arrived after the loss) is given, its FEC data
+    /// is used, which only covers the packet immediately before it; otherwise the
+    /// decoder extrapolates from the audio it last produced.
+    pub fn conceal(
+        &mut self,
+        pts: u64,
+        frames: u32,
+        next_packet: Option<&[u8]>,
+    ) -> anyhow::Result<PcmBlock> {
+        let frames = (frames as usize).min(MAX_PACKET_SAMPLES);
+        let block = self
+            .run(next_packet, frames, next_packet.is_some())
+            .context("opus packet loss concealment failed")
+            .map(|samples| PcmBlock {
+                pts,
+                samples,
+                concealed: true,
+            });
+        self.count(&block, |counters| match next_packet {
+            Some(_) => &mut counters.fec_recovered,
+            None => &mut counters.plc_packets,
+        });
+        block
+    }
+
+    fn run(&mut self, packet: Option<&[u8]>, frames: usize, fec: bool) -> anyhow::Result<Vec<i16>> {
+        let packet = packet
+            .map(Packet::try_from)
+            .transpose()
+            .map_err(|error| anyhow!("{error}"))?;
+        let output = &mut self.buffer[..frames * usize::from(PCM_CHANNELS)];
+        let signals = MutSignals::try_from(output).map_err(|error| anyhow!("{error}"))?;
+        let decoded = self
+            .decoder
+            .decode(packet, signals, fec)
+            .map_err(|error| anyhow!("{error}"))?;
+        Ok(self.buffer[..decoded * usize::from(PCM_CHANNELS)].to_vec())
+    }
+
+    fn count<T>(
+        &mut self,
+        result: &anyhow::Result<T>,
+        counter: impl FnOnce(&mut OpusDecodeCounters) -> &mut u64,
+    ) {
+        let counter = match result {
+            Ok(_) => counter(&mut self.counters),
+            Err(_) => &mut self.counters.errors,
+        };
+        *counter = counter.saturating_add(1);
+    }
+}
+
+/// Writes raw s16le PCM to a file or FIFO on its own thread, so a slow or stalled
+/// reader never blocks the receiver loop.
+pub struct PcmFileWriter {
+    queue: SyncSender<Vec<u8>>,
+    failure: Receiver<std::io::Error>,
+    stopped: Arc<AtomicBool>,
+    dropped: u64,
+}
+
+impl PcmFileWriter {
+    /// Opens `path` without blocking: a FIFO must already have a reader attached,
+    /// otherwise opening fails instead of waiting for one.
+    pub fn open(path: &Path) -> anyhow::Result<Self> {
+        let file = open_nonblocking(path)
+            .with_context(|| format!("failed to open PCM output {}", path.display()))?;
+        let (queue, blocks) = mpsc::sync_channel::<Vec<u8>>(PCM_WRITE_QUEUE_BLOCKS);
+        let (report_failure, failure) = mpsc::channel();
+        let stopped = Arc::new(AtomicBool::new(false));
+        let thread_stopped = Arc::clone(&stopped);
+        // Detached: it exits once the queue closes or `stopped` is set, and never
+        // needs to be joined from the receiver loop.
+        std::thread::Builder::new()
+            .name("pcm-output".to_string())
+            .spawn(move || {
+                let mut file = file;
+                for block in blocks {
+                    if let Err(error) = write_block(&mut file, &block, &thread_stopped) {
+                        let _ = report_failure.send(error);
+                        break;
+                    }
+                }
+            })
+            .context("failed to start PCM output thread")?;
+        Ok(Self {
+            queue,
+            failure,
+            stopped,
+            dropped: 0,
+        })
+    }
+
+    /// Queues `samples` for writing. A full queue drops the block; an error means
+    /// the output failed and nothing more will be written.
+    pub fn write(&mut self, samples: &[i16]) -> anyhow::Result<()> {
+        match self.failure.try_recv() {
+            Ok(error) => return Err(anyhow!(error).context("PCM output failed")),
+            Err(TryRecvError::Disconnected) => bail!("PCM output stopped"),
+            Err(TryRecvError::Empty) => {}
+        }
+        let bytes = samples
+            .iter()
+            .flat_map(|sample| sample.to_le_bytes())
+            .collect();
+        match self.queue.try_send(bytes) {
+            Ok(()) => Ok(()),
+            Err(TrySendError::Full(_)) => {
+                self.dropped = self.dropped.saturating_add(1);
+                Ok(())
+            }
+            Err(TrySendError::Disconnected(_)) => bail!("PCM output stopped"),
+        }
+    }
+
+    /// Blocks dropped because the writer fell behind.
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+}
+
+impl Drop for PcmFileWriter {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(unix)]
+fn open_nonblocking(path: &Path) -> std::io::Result<std::fs::File> {
+    use std::os::unix::fs::OpenOptionsExt;
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .custom_flags(libc::O_NONBLOCK)
+        .open(path)
+        .map_err(|error| match error.raw_os_error() {
+            Some(libc::ENXIO) => std::io::Error::new(
+                std::io::ErrorKind::NotConnected,
+                "FIFO has no reader attached",
+            ),
+            _ => error,
+        })
+}
+
+#[cfg(not(unix))]
+fn open_nonblocking(path: &Path) -> std::io::Result<std::fs::File> {
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+}
+
+/// Writes all of `bytes`, waiting for a non-blocking FIFO to drain rather than
+/// splitting a sample across a dropped block.
+fn write_block(
+    file: &mut std::fs::File,
+    mut bytes: &[u8],
+    stopped: &AtomicBool,
+) -> std::io::Result<()> {
+    while !bytes.is_empty() {
+        match file.write(bytes) {
+            Ok(0) => return Err(std::io::ErrorKind::WriteZero.into()),
+            Ok(written) => bytes = &bytes[written..],
+            Err(error) if error.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => {
+                if stopped.load(Ordering::Relaxed) {
+                    return Ok(());
+                }
+                std::thread::sleep(PCM_WRITE_RETRY);
+            }
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "opus-decode"))]
+mod tests {
+    use super::*;
+    use audiopus::coder::Encoder;
+    use audiopus::Application;
+
+    /// 20 ms per channel at 48 kHz.
+    const PACKET_FRAMES: usize = 960;
+
+    fn encoded_tone() -> Vec<u8> {
+        let encoder = Encoder::new(SampleRate::Hz48000, Channels::Stereo, Application::Audio)
+            .expect("opus encoder");
+        let pcm: Vec<i16> = (0..PACKET_FRAMES)
+            .flat_map(|frame| {
+                let phase = frame as f32 * 440.0 * std::f32::consts::TAU / 48_000.0;
+                let sample = (phase.sin() * 8_000.0) as i16;
+                [sample, sample]
+            })
+            .collect();
+        let mut packet = vec![0; 4_000];
+        let len = encoder.encode(&pcm, &mut packet).expect("opus encode");
+        packet.truncate(len);
+        packet
+    }
+
+    #[test]
+    fn encoded_packet_decodes_to_one_block() {
+        let mut decoder = OpusPcmDecoder::new().unwrap();
+        let block = decoder.decode(40_000, &encoded_tone()).unwrap();
+        assert_eq!(block.pts, 40_000);
+        assert!(!block.concealed);
+        assert_eq!(block.frames() as usize, PACKET_FRAMES);
+        assert_eq!(
+            block.samples.len(),
+            PACKET_FRAMES * usize::from(PCM_CHANNELS)
+        );
+        assert!(block.samples.iter().any(|&sample| sample != 0));
+        assert_eq!(decoder.counters().decoded, 1);
+        assert_eq!(decoder.counters().errors, 0);
+    }
+
+    #[test]
+    fn malformed_packet_is_counted_as_an_error() {
+        let mut decoder = OpusPcmDecoder::new().unwrap();
+        // Frame count code 3 without the frame count byte.
+        assert!(decoder.decode(0, &[0x03]).is_err());
+        assert!(decoder.decode(0, &[]).is_err());
+        let counters = decoder.counters();
+        assert_eq!(counters.errors, 2);
+        assert_eq!(counters.decoded, 0);
+    }
+
+    #[test]
+    fn lost_packet_is_concealed() {
+        let mut decoder = OpusPcmDecoder::new().unwrap();
+        decoder.decode(0, &encoded_tone()).unwrap();
+        let block = decoder.conceal(20_000, PACKET_FRAMES as u32, None).unwrap();
+        assert!(block.concealed);
+        assert_eq!(block.frames() as usize, PACKET_FRAMES);
+        assert_eq!(decoder.counters().plc_packets, 1);
+    }
+}
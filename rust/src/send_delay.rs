@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy)]
+pub struct SendDelaySummary {
+    pub samples: u64,
+    pub average: Duration,
+    pub max: Duration,
+}
+
+/// Time frames of one sender stream spend between ingress and the transport,
+/// summarised per telemetry interval.
+#[derive(Debug, Default)]
+pub struct SendDelayStats {
+    samples: u64,
+    total: Duration,
+    max: Duration,
+}
+
+impl SendDelayStats {
+    pub fn record(&mut self, delay: Duration) {
+        self.samples = self.samples.saturating_add(1);
+        self.total = self.total.saturating_add(delay);
+        self.max = self.max.max(delay);
+    }
+
+    /// Returns the interval's summary and starts a new interval, or `None` when
+    /// nothing was sent.
+    pub fn take(&mut self) -> Option<SendDelaySummary> {
+        let stats = std::mem::take(self);
+        let samples = u32::try_from(stats.samples).unwrap_or(u32::MAX);
+        (samples > 0).then(|| SendDelaySummary {
+            samples: stats.samples,
+            average: stats.total / samples,
+            max: stats.max,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_interval_has_no_summary() {
+        let mut stats = SendDelayStats::default();
+        assert!(stats.take().is_none());
+    }
+
+    #[test]
+    fn summary_reports_average_and_max() {
+        let mut stats = SendDelayStats::default();
+        for ms in [2, 4, 12] {
+            stats.record(Duration::from_millis(ms));
+        }
+        let summary = stats.take().unwrap();
+        assert_eq!(summary.samples, 3);
+        assert_eq!(summary.average, Duration::from_millis(6));
+        assert_eq!(summary.max, Duration::from_millis(12));
+    }
+
+    #[test]
+    fn take_starts_a_new_interval() {
+        let mut stats = SendDelayStats::default();
+        stats.record(Duration::from_millis(30));
+        assert!(stats.take().is_some());
+        assert!(stats.take().is_none());
+
+        stats.record(Duration::from_millis(5));
+        let summary = stats.take().unwrap();
+        assert_eq!(summary.samples, 1);
+        assert_eq!(summary.max, Duration::from_millis(5));
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Decision for one dequeued video frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaleVerdict {
+    Send,
+    /// Over budget, but a keyframe restarts the decoding chain, so it is sent.
+    SendStaleKeyframe,
+    /// Delta frame older than the budget.
+    DiscardStale,
+    /// Delta frame that references an earlier discarded frame.
+    DiscardDependent,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StaleFrameCounters {
+    pub discarded_stale: u64,
+    pub discarded_dependent: u64,
+    pub stale_keyframes_sent: u64,
+}
+
+/// Discards frames whose pts trails the newest queued pts by more than a latency
+/// budget. Once a delta frame is discarded, every following delta frame is
+/// discarded too until the next keyframe, so the receiver never sees a frame whose
+/// references are missing. Without a budget nothing is stale, but a chain that is
+/// already broken still runs to the next keyframe.
+#[derive(Debug)]
+pub struct StaleFrameFilter {
+    budget: Option<Duration>,
+    skipping_until_keyframe: bool,
+    counters: StaleFrameCounters,
+}
+
+impl StaleFrameFilter {
+    pub fn new(budget: Option<Duration>) -> Self {
+        Self {
+            budget,
+            skipping_until_keyframe: false,
+            counters: StaleFrameCounters::default(),
+        }
+    }
+
+    pub fn set_budget(&mut self, budget: Option<Duration>) {
+        self.budget = budget;
+    }
+
+    pub fn check(&mut self, pts: u64, keyframe: bool, newest_pts: u64) -> StaleVerdict {
+        let lag = newest_pts.saturating_sub(pts);
+        let stale = self
+            .budget
+            .is_some_and(|budget| u128::from(lag) > budget.as_micros());
+        if keyframe {
+            self.skipping_until_keyframe = false;
+            if stale {
+                self.counters.stale_keyframes_sent =
+                    self.counters.stale_keyframes_sent.saturating_add(1);
+                return StaleVerdict::SendStaleKeyframe;
+            }
+            return StaleVerdict::Send;
+        }
+        if self.skipping_until_keyframe {
+            self.counters.discarded_dependent = self.counters.discarded_dependent.saturating_add(1);
+            return StaleVerdict::DiscardDependent;
+        }
+        if stale {
+            self.skipping_until_keyframe = true;
+            self.counters.discarded_stale = self.counters.discarded_stale.saturating_add(1);
+            return StaleVerdict::DiscardStale;
+        }
+        StaleVerdict::Send
+    }
+
+    pub fn counters(&self) -> StaleFrameCounters {
+        self.counters
+    }
+}
+
+/// One [`StaleFrameFilter`] per stream, each following its own budget. A stream
+/// gets a filter once it first has a budget.
+#[derive(Debug, Default)]
+pub struct StaleFrameFilters {
+    filters: BTreeMap<u32, StaleFrameFilter>,
+}
+
+impl StaleFrameFilters {
+    pub fn check(
+        &mut self,
+        stream_id: u32,
+        budget: Option<Duration>,
+        pts: u64,
+        keyframe: bool,
+        newest_pts: u64,
+    ) -> StaleVerdict {
+        let filter = match self.filters.get_mut(&stream_id) {
+            Some(filter) => filter,
+            None if budget.is_some() => self
+                .filters
+                .entry(stream_id)
+                .or_insert_with(|| StaleFrameFilter::new(budget)),
+            None => return StaleVerdict::Send,
+        };
+        filter.set_budget(budget);
+        filter.check(pts, keyframe, newest_pts)
+    }
+
+    pub fn counters(&self) -> impl Iterator<Item = (u32, StaleFrameCounters)> + '_ {
+        self.filters
+            .iter()
+            .map(|(stream_id, filter)| (*stream_id, filter.counters()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUDGET: Option<Duration> = Some(Duration::from_millis(100));
+
+    #[test]
+    fn stale_delta_discards_its_dependents_until_the_next_keyframe() {
+        let mut filter = StaleFrameFilter::new(BUDGET);
+        assert_eq!(filter.check(0, true, 50_000), StaleVerdict::Send);
+        assert_eq!(
+            filter.check(33_000, false, 200_000),
+            StaleVerdict::DiscardStale
+        );
+        // Back within budget, but its reference is gone.
+        assert_eq!(
+            filter.check(180_000, false, 200_000),
+            StaleVerdict::DiscardDependent
+        );
+        assert_eq!(filter.check(200_000, true, 200_000), StaleVerdict::Send);
+        assert_eq!(filter.check(233_000, false, 233_000), StaleVerdict::Send);
+
+        let counters = filter.counters();
+        assert_eq!(counters.discarded_stale, 1);
+        assert_eq!(counters.discarded_dependent, 1);
+        assert_eq!(counters.stale_keyframes_sent, 0);
+    }
+
+    #[test]
+    fn stale_keyframes_are_still_sent() {
+        let mut filter = StaleFrameFilter::new(BUDGET);
+        assert_eq!(
+            filter.check(0, true, 500_000),
+            StaleVerdict::SendStaleKeyframe
+        );
+        assert_eq!(filter.counters().stale_keyframes_sent, 1);
+    }
+
+    #[test]
+    fn clearing_the_budget_keeps_a_broken_chain_broken() {
+        let mut filter = StaleFrameFilter::new(BUDGET);
+        assert_eq!(filter.check(0, false, 500_000), StaleVerdict::DiscardStale);
+        filter.set_budget(None);
+        assert_eq!(
+            filter.check(10_000, false, 500_000),
+            StaleVerdict::DiscardDependent
+        );
+        assert_eq!(filter.check(20_000, true, 500_000), StaleVerdict::Send);
+        assert_eq!(filter.check(30_000, false, 500_000), StaleVerdict::Send);
+    }
+
+    #[test]
+    fn streams_follow_their_own_budgets() {
+        let mut filters = StaleFrameFilters::default();
+        assert_eq!(
+            filters.check(1, None, 0, false, 500_000),
+            StaleVerdict::Send
+        );
+        assert_eq!(
+            filters.check(2, BUDGET, 0, false, 500_000),
+            StaleVerdict::DiscardStale
+        );
+        assert_eq!(
+            filters.check(2, BUDGET, 10_000, false, 10_000),
+            StaleVerdict::DiscardDependent
+        );
+        assert_eq!(
+            filters.check(1, None, 10_000, false, 500_000),
+            StaleVerdict::Send
+        );
+
+        let counters: Vec<_> = filters.counters().collect();
+        assert_eq!(counters.len(), 1);
+        assert_eq!(counters[0].0, 2);
+        assert_eq!(counters[0].1.discarded_stale, 1);
+        assert_eq!(counters[0].1.discarded_dependent, 1);
+    }
+}
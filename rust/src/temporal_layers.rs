@@ -0,0 +1,265 @@
+use crate::hevc::VclHeader;
+use std::collections::VecDeque;
+
+/// HEVC allows at most seven temporal sub-layers.
+const MAX_TEMPORAL_LAYERS: usize = 7;
+/// Stream time over which per-layer bitrates are measured.
+const RATE_WINDOW_US: u64 = 1_000_000;
+/// Layer decisions wait until the window covers at least this much stream time.
+const MIN_RATE_SPAN_US: u64 = 500_000;
+/// A layer comes back only once the target leaves this much headroom (percent of
+/// the resulting rate), so the count does not flap around the threshold.
+const UPSWITCH_HEADROOM_PERCENT: u64 = 115;
+/// Minimum stream time between enabling layers after the previous change.
+const MIN_UPSWITCH_INTERVAL_US: u64 = 1_000_000;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TemporalLayerCounters {
+    pub dropped: u64,
+    pub layer_changes: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LayerSample {
+    pts: u64,
+    layer: u8,
+    bytes: usize,
+}
+
+/// Drops whole HEVC temporal sub-layers, highest first, while the transport's
+/// target bitrate is below the stream's measured bitrate. Removing the top layers
+/// never breaks the references of the remaining ones, so with a dyadic hierarchy
+/// each step halves the frame rate (60 -> 30 -> 15 fps). Layers are re-enabled at
+/// an IRAP picture, or one at a time at a TSA/STSA picture of the layer coming back.
+#[derive(Debug, Default)]
+pub struct TemporalLayerShaper {
+    samples: VecDeque<LayerSample>,
+    newest_pts: u64,
+    total_layers: u8,
+    /// `None` sends every layer seen so far.
+    limit: Option<u8>,
+    last_change_pts: u64,
+    counters: TemporalLayerCounters,
+}
+
+impl TemporalLayerShaper {
+    pub fn active_layers(&self) -> u8 {
+        self.limit
+            .map_or(self.total_layers, |limit| limit.min(self.total_layers))
+    }
+
+    pub fn total_layers(&self) -> u8 {
+        self.total_layers
+    }
+
+    /// `(active, total)` layer counts.
+    pub fn layers(&self) -> (u8, u8) {
+        (self.active_layers(), self.total_layers)
+    }
+
+    pub fn counters(&self) -> TemporalLayerCounters {
+        self.counters
+    }
+
+    /// Measured bitrate of all layers, including the ones being dropped; zero until
+    /// enough of the stream has been seen.
+    pub fn stream_bitrate_bps(&self) -> u64 {
+        self.layer_rates().map_or(0, |rates| rates.iter().sum())
+    }
+
+    /// Records the access unit and returns whether it should be sent.
+    pub fn admit(
+        &mut self,
+        pts: u64,
+        header: VclHeader,
+        bytes: usize,
+        target_bitrate_bps: u32,
+    ) -> bool {
+        let layer = header.temporal_id.min(MAX_TEMPORAL_LAYERS as u8 - 1);
+        self.total_layers = self.total_layers.max(layer + 1);
+        self.record(pts, layer, bytes);
+        self.update_limit(header, target_bitrate_bps);
+
+        let admitted = layer < self.active_layers();
+        if !admitted {
+            self.counters.dropped = self.counters.dropped.saturating_add(1);
+        }
+        admitted
+    }
+
+    fn record(&mut self, pts: u64, layer: u8, bytes: usize) {
+        self.newest_pts = self.newest_pts.max(pts);
+        self.samples.push_back(LayerSample { pts, layer, bytes });
+        while self
+            .samples
+            .front()
+            .is_some_and(|sample| self.newest_pts.saturating_sub(sample.pts) > RATE_WINDOW_US)
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    fn layer_rates(&self) -> Option<[u64; MAX_TEMPORAL_LAYERS]> {
+        let oldest_pts = self.samples.iter().map(|sample| sample.pts).min()?;
+        let span_us = self.newest_pts.saturating_sub(oldest_pts);
+        if span_us < MIN_RATE_SPAN_US {
+            return None;
+        }
+        let mut rates = [0u64; MAX_TEMPORAL_LAYERS];
+        for sample in &self.samples {
+            rates[usize::from(sample.layer)] =
+                rates[usize::from(sample.layer)].saturating_add(sample.bytes as u64);
+        }
+        for rate in &mut rates {
+            *rate = rate.saturating_mul(8_000_000) / span_us;
+        }
+        Some(rates)
+    }
+
+    fn update_limit(&mut self, header: VclHeader, target_bitrate_bps: u32) {
+        let total = self.total_layers;
+        if total <= 1 {
+            return;
+        }
+        let Some(rates) = self.layer_rates() else {
+            return;
+        };
+        // No estimate yet means nothing to shape against.
+        let target = match target_bitrate_bps {
+            0 => u64::MAX,
+            bps => u64::from(bps),
+        };
+        let rate_of = |layers: u8| -> u64 { rates[..usize::from(layers)].iter().sum() };
+        let active = self.active_layers();
+
+        let fitting = (1..=total)
+            .rev()
+            .find(|layers| rate_of(*layers) <= target)
+            .unwrap_or(1);
+        if fitting < active {
+            self.set_active_layers(fitting);
+            return;
+        }
+
+        if active == total
+            || self.newest_pts.saturating_sub(self.last_change_pts) < MIN_UPSWITCH_INTERVAL_US
+        {
+            return;
+        }
+        let Some(affordable) = (active + 1..=total).rev().find(|layers| {
+            rate_of(*layers).saturating_mul(UPSWITCH_HEADROOM_PERCENT) / 100 <= target
+        }) else {
+            return;
+        };
+        if header.is_irap() {
+            self.set_active_layers(affordable);
+        } else if header.is_temporal_switch_point() && header.temporal_id == active {
+            self.set_active_layers(active + 1);
+        }
+    }
+
+    fn set_active_layers(&mut self, layers: u8) {
+        self.limit = (layers < self.total_layers).then_some(layers);
+        self.last_change_pts = self.newest_pts;
+        self.counters.layer_changes = self.counters.layer_changes.saturating_add(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hevc::{NAL_IDR_W_RADL, NAL_TSA_N};
+
+    const FRAME_US: u64 = 16_667;
+    const FRAME_BYTES: usize = 10_000;
+    /// Dyadic 60 fps hierarchy: layer 0 and 1 at 15 fps each, layer 2 at 30 fps,
+    /// so the layers measure about 1.2, 1.2 and 2.4 Mbit/s.
+    const PATTERN: [u8; 4] = [0, 2, 1, 2];
+    const NAL_TRAIL_R: u8 = 1;
+
+    fn header(nal_type: u8, temporal_id: u8) -> VclHeader {
+        VclHeader {
+            nal_type,
+            temporal_id,
+        }
+    }
+
+    /// Feeds trailing pictures `range` and returns the temporal ids that were sent.
+    fn feed(
+        shaper: &mut TemporalLayerShaper,
+        range: std::ops::Range<u64>,
+        target_bps: u32,
+    ) -> Vec<u8> {
+        range
+            .filter_map(|n| {
+                let temporal_id = PATTERN[(n % 4) as usize];
+                shaper
+                    .admit(
+                        n * FRAME_US,
+                        header(NAL_TRAIL_R, temporal_id),
+                        FRAME_BYTES,
+                        target_bps,
+                    )
+                    .then_some(temporal_id)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_layer_is_sent_while_the_target_covers_the_stream() {
+        let mut shaper = TemporalLayerShaper::default();
+        let sent = feed(&mut shaper, 0..120, 10_000_000);
+        assert_eq!(sent.len(), 120);
+        assert_eq!(shaper.layers(), (3, 3));
+        assert_eq!(shaper.counters().dropped, 0);
+        let stream_bps = shaper.stream_bitrate_bps();
+        assert!((4_500_000..5_000_000).contains(&stream_bps), "{stream_bps}");
+    }
+
+    #[test]
+    fn highest_layers_are_dropped_first_under_a_low_target() {
+        let mut shaper = TemporalLayerShaper::default();
+        feed(&mut shaper, 0..60, 3_000_000);
+        assert_eq!(shaper.layers(), (2, 3));
+        let sent = feed(&mut shaper, 60..120, 3_000_000);
+        assert!(sent.iter().all(|temporal_id| *temporal_id < 2));
+        assert_eq!(sent.len(), 30);
+
+        feed(&mut shaper, 120..180, 1_500_000);
+        assert_eq!(shaper.layers(), (1, 3));
+        let sent = feed(&mut shaper, 180..240, 1_500_000);
+        assert!(sent.iter().all(|temporal_id| *temporal_id == 0));
+        assert_eq!(shaper.counters().layer_changes, 2);
+    }
+
+    #[test]
+    fn dropped_layers_return_only_at_switch_points_or_irap() {
+        let mut shaper = TemporalLayerShaper::default();
+        feed(&mut shaper, 0..60, 1_500_000);
+        assert_eq!(shaper.layers(), (1, 3));
+
+        // Plenty of room again, but trailing pictures are no place to switch up.
+        feed(&mut shaper, 60..180, 10_000_000);
+        assert_eq!(shaper.layers(), (1, 3));
+
+        // A TSA picture brings back its own layer only.
+        assert!(shaper.admit(
+            180 * FRAME_US,
+            header(NAL_TSA_N, 1),
+            FRAME_BYTES,
+            10_000_000
+        ));
+        assert_eq!(shaper.layers(), (2, 3));
+
+        // An IRAP restores everything affordable once the interval has passed.
+        feed(&mut shaper, 181..260, 10_000_000);
+        assert_eq!(shaper.layers(), (2, 3));
+        assert!(shaper.admit(
+            260 * FRAME_US,
+            header(NAL_IDR_W_RADL, 0),
+            FRAME_BYTES,
+            10_000_000
+        ));
+        assert_eq!(shaper.layers(), (3, 3));
+    }
+}
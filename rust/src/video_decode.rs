@@ -0,0 +1,462 @@
+use crate::hevc::{classify_access_unit, PictureKind};
+use anyhow::{anyhow, Context};
+use ffmpeg_next::codec::{self, threading};
+use ffmpeg_next::software::scaling;
+use ffmpeg_next::util::format::Pixel;
+use ffmpeg_next::{decoder, frame, Packet};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Access units that waited this long for the decoder, or pictures that finish
+/// decoding this long after their access unit arrived, are counted and discarded
+/// instead of being handed to Flutter.
+pub const MAX_DECODE_LATENESS: Duration = Duration::from_millis(250);
+/// Access units waiting for the decoder thread. Units arriving while the queue is
+/// full are dropped, and decoding resumes at the next IRAP.
+const DECODE_QUEUE_UNITS: usize = 8;
+/// Slice threads per decoder. Frame threading would add about one frame of
+/// output delay per thread, which at 30 fps on a many-core desktop exceeds
+/// `MAX_DECODE_LATENESS` on its own; slice threading adds none, so a picture's
+/// lateness is only its queueing and decode time.
+const DECODE_SLICE_THREADS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputPixels {
+    /// Packed 8-bit RGBA, `width * 4` bytes per row.
+    Rgba,
+    /// Planar 4:2:0: the Y plane followed by the U and V planes, without padding.
+    I420,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DecodeTarget {
+    pub pixels: OutputPixels,
+    /// Pictures larger than this are downscaled to fit, keeping their aspect
+    /// ratio. Zero leaves that dimension unbounded.
+    pub max_width: u32,
+    pub max_height: u32,
+}
+
+impl DecodeTarget {
+    fn output_size(&self, width: u32, height: u32) -> (u32, u32) {
+        let scale_w = match self.max_width {
+            0 => 1.0,
+            max => (f64::from(max) / f64::from(width)).min(1.0),
+        };
+        let scale_h = match self.max_height {
+            0 => 1.0,
+            max => (f64::from(max) / f64::from(height)).min(1.0),
+        };
+        let scale = scale_w.min(scale_h);
+        // Chroma subsampling needs even dimensions.
+        let even = |value: u32| ((f64::from(value) * scale) as u32 & !1).max(2);
+        (even(width), even(height))
+    }
+}
+
+#[derive(Debug)]
+pub struct DecodedPicture {
+    pub pts: u64,
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+/// libavcodec HEVC decoder with conversion to the requested output format.
+pub struct HevcSoftwareDecoder {
+    decoder: decoder::Video,
+    target: DecodeTarget,
+    decoded: frame::Video,
+    scaler: Option<(ScalerKey, scaling::Context)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ScalerKey {
+    format: Pixel,
+    width: u32,
+    height: u32,
+}
+
+impl HevcSoftwareDecoder {
+    pub fn new(target: DecodeTarget) -> anyhow::Result<Self> {
+        ffmpeg_next::init().context("failed to initialise ffmpeg")?;
+        let codec = decoder::find(codec::Id::HEVC).context("ffmpeg has no HEVC decoder")?;
+        let mut context = codec::Context::new_with_codec(codec);
+        context.set_flags(codec::Flags::LOW_DELAY);
+        context.set_threading(threading::Config {
+            kind: threading::Type::Slice,
+            count: DECODE_SLICE_THREADS,
+            ..Default::default()
+        });
+        let decoder = context
+            .decoder()
+            .video()
+            .context("failed to open HEVC decoder")?;
+        Ok(Self {
+            decoder,
+            target,
+            decoded: frame::Video::empty(),
+            scaler: None,
+        })
+    }
+
+    /// Queues one Annex B access unit.
+    pub fn send(&mut self, pts: u64, access_unit: &[u8]) -> anyhow::Result<()> {
+        let mut packet = Packet::copy(access_unit);
+        packet.set_pts(Some(pts as i64));
+        self.decoder
+            .send_packet(&packet)
+            .context("HEVC decoder rejected access unit")
+    }
+
+    /// Takes the next decoded picture, returning its pts, or `None` when the
+    /// decoder needs more input. The picture stays in the decoder until `convert`.
+    pub fn receive(&mut self) -> anyhow::Result<Option<u64>> {
+        match self.decoder.receive_frame(&mut self.decoded) {
+            Ok(()) => Ok(Some(self.decoded.pts().unwrap_or(0).max(0) as u64)),
+            Err(ffmpeg_next::Error::Other {
+                errno: ffmpeg_next::error::EAGAIN,
+            })
+            | Err(ffmpeg_next::Error::Eof) => Ok(None),
+            Err(error) => Err(anyhow!(error).context("HEVC decoding failed")),
+        }
+    }
+
+    /// Converts the last received picture to the target format and size.
+    pub fn convert(&mut self) -> anyhow::Result<DecodedPicture> {
+        let key = ScalerKey {
+            format: self.decoded.format(),
+            width: self.decoded.width(),
+            height: self.decoded.height(),
+        };
+        let (width, height) = self.target.output_size(key.width, key.height);
+        let output_format = match self.target.pixels {
+            OutputPixels::Rgba => Pixel::RGBA,
+            OutputPixels::I420 => Pixel::YUV420P,
+        };
+        if self.scaler.as_ref().map(|(current, _)| *current) != Some(key) {
+            let scaler = scaling::Context::get(
+                key.format,
+                key.width,
+                key.height,
+                output_format,
+                width,
+                height,
+                scaling::Flags::BILINEAR,
+            )
+            .context("failed to create picture scaler")?;
+            self.scaler = Some((key, scaler));
+        }
+        let Some((_, scaler)) = self.scaler.as_mut() else {
+            unreachable!("scaler was created above");
+        };
+        let mut output = frame::Video::empty();
+        scaler
+            .run(&self.decoded, &mut output)
+            .context("failed to convert decoded picture")?;
+        Ok(DecodedPicture {
+            pts: self.decoded.pts().unwrap_or(0).max(0) as u64,
+            width,
+            height,
+            data: pack_planes(&output, self.target.pixels, width, height),
+        })
+    }
+}
+
+/// Copies the planes of `frame` without their row padding.
+fn pack_planes(frame: &frame::Video, pixels: OutputPixels, width: u32, height: u32) -> Vec<u8> {
+    let (width, height) = (width as usize, height as usize);
+    let planes: &[(usize, usize)] = match pixels {
+        OutputPixels::Rgba => &[(width * 4, height)],
+        OutputPixels::I420 => &[
+            (width, height),
+            (width / 2, height / 2),
+            (width / 2, height / 2),
+        ],
+    };
+    let mut data = Vec::with_capacity(planes.iter().map(|(row, rows)| row * rows).sum());
+    for (index, &(row_bytes, rows)) in planes.iter().enumerate() {
+        let stride = frame.stride(index);
+        let plane = frame.data(index);
+        for row in 0..rows {
+            data.extend_from_slice(&plane[row * stride..row * stride + row_bytes]);
+        }
+    }
+    data
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct VideoDecodeStats {
+    pub decoded: u64,
+    pub dropped_late: u64,
+    /// Access units dropped because the decode queue was full.
+    pub dropped_backlog: u64,
+    /// Access units skipped after a drop, until the next IRAP.
+    pub skipped_until_irap: u64,
+    pub errors: u64,
+    /// Decode time per access unit since the previous `take_stats`.
+    pub decode_samples: u64,
+    pub decode_total: Duration,
+    pub decode_max: Duration,
+}
+
+struct QueuedAccessUnit {
+    pts: u64,
+    data: Vec<u8>,
+    arrived_at: Instant,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Admission {
+    Decode,
+    DropLate,
+    SkipUntilIrap,
+}
+
+/// Decides which access units reach the decoder. Dropping a unit breaks the
+/// reference chain of the pictures after it, so once one is dropped everything up
+/// to the next IRAP is skipped too. A new worker starts out waiting for an IRAP,
+/// since it may join the stream mid-GOP.
+#[derive(Debug)]
+struct IrapGate {
+    awaiting_irap: bool,
+}
+
+impl Default for IrapGate {
+    fn default() -> Self {
+        Self {
+            awaiting_irap: true,
+        }
+    }
+}
+
+impl IrapGate {
+    /// Units without a picture (parameter sets, SEI) always pass. An IRAP is
+    /// decoded even when late, since it is where decoding resumes.
+    fn admit(&mut self, kind: Option<PictureKind>, waited: Duration) -> Admission {
+        match kind {
+            None => Admission::Decode,
+            Some(kind) if kind.is_irap() => {
+                self.awaiting_irap = false;
+                Admission::Decode
+            }
+            Some(_) if self.awaiting_irap => Admission::SkipUntilIrap,
+            Some(_) if waited > MAX_DECODE_LATENESS => {
+                self.awaiting_irap = true;
+                Admission::DropLate
+            }
+            Some(_) => Admission::Decode,
+        }
+    }
+
+    fn resync(&mut self) {
+        self.awaiting_irap = true;
+    }
+}
+
+/// Runs a decoder on its own thread so software decoding never stalls the
+/// receiver loop. Pictures go to `deliver` on the decoder thread. Dropping the
+/// worker detaches the thread, which stops before its next access unit.
+pub struct VideoDecodeWorker {
+    queue: SyncSender<QueuedAccessUnit>,
+    stats: Arc<Mutex<VideoDecodeStats>>,
+    /// Set when `submit` drops a unit, so the thread resumes at the next IRAP.
+    resync: Arc<AtomicBool>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl VideoDecodeWorker {
+    pub fn spawn(
+        target: DecodeTarget,
+        mut deliver: impl FnMut(DecodedPicture) + Send + 'static,
+        mut report_error: impl FnMut(anyhow::Error) + Send + 'static,
+    ) -> anyhow::Result<Self> {
+        let mut decoder = HevcSoftwareDecoder::new(target)?;
+        let (queue, inbound) = mpsc::sync_channel::<QueuedAccessUnit>(DECODE_QUEUE_UNITS);
+        let stats = Arc::new(Mutex::new(VideoDecodeStats::default()));
+        let resync = Arc::new(AtomicBool::new(false));
+        let stopped = Arc::new(AtomicBool::new(false));
+        let thread_stats = Arc::clone(&stats);
+        let thread_resync = Arc::clone(&resync);
+        let thread_stopped = Arc::clone(&stopped);
+        std::thread::Builder::new()
+            .name("hevc-decode".to_string())
+            .spawn(move || {
+                let mut arrivals: BTreeMap<u64, Instant> = BTreeMap::new();
+                let mut gate = IrapGate::default();
+                for unit in inbound {
+                    if thread_stopped.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    if thread_resync.swap(false, Ordering::Relaxed) {
+                        gate.resync();
+                    }
+                    let admission =
+                        gate.admit(classify_access_unit(&unit.data), unit.arrived_at.elapsed());
+                    if admission != Admission::Decode {
+                        arrivals.clear();
+                        let Ok(mut stats) = thread_stats.lock() else {
+                            break;
+                        };
+                        let counter = match admission {
+                            Admission::DropLate => &mut stats.dropped_late,
+                            _ => &mut stats.skipped_until_irap,
+                        };
+                        *counter = counter.saturating_add(1);
+                        continue;
+                    }
+                    arrivals.insert(unit.pts, unit.arrived_at);
+                    let started = Instant::now();
+                    let result = decode_access_unit(
+                        &mut decoder,
+                        &unit,
+                        &mut arrivals,
+                        &thread_stats,
+                        &mut deliver,
+                    );
+                    let elapsed = started.elapsed();
+                    let Ok(mut stats) = thread_stats.lock() else {
+                        break;
+                    };
+                    stats.decode_samples = stats.decode_samples.saturating_add(1);
+                    stats.decode_total = stats.decode_total.saturating_add(elapsed);
+                    stats.decode_max = stats.decode_max.max(elapsed);
+                    if let Err(error) = result {
+                        stats.errors = stats.errors.saturating_add(1);
+                        drop(stats);
+                        report_error(error);
+                    }
+                }
+            })
+            .context("failed to start decoder thread")?;
+        Ok(Self {
+            queue,
+            stats,
+            resync,
+            stopped,
+        })
+    }
+
+    /// Queues an access unit without blocking. When the decoder has fallen behind
+    /// by a full queue, the unit is dropped and decoding resumes at the next IRAP.
+    pub fn submit(&self, pts: u64, access_unit: Vec<u8>) {
+        let unit = QueuedAccessUnit {
+            pts,
+            data: access_unit,
+            arrived_at: Instant::now(),
+        };
+        if let Err(TrySendError::Full(_)) = self.queue.try_send(unit) {
+            self.resync.store(true, Ordering::Relaxed);
+            if let Ok(mut stats) = self.stats.lock() {
+                stats.dropped_backlog = stats.dropped_backlog.saturating_add(1);
+            }
+        }
+    }
+
+    /// Returns the counters and resets the decode timing window.
+    pub fn take_stats(&self) -> VideoDecodeStats {
+        let Ok(mut stats) = self.stats.lock() else {
+            return VideoDecodeStats::default();
+        };
+        let snapshot = *stats;
+        stats.decode_samples = 0;
+        stats.decode_total = Duration::ZERO;
+        stats.decode_max = Duration::ZERO;
+        snapshot
+    }
+}
+
+impl Drop for VideoDecodeWorker {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+}
+
+fn decode_access_unit(
+    decoder: &mut HevcSoftwareDecoder,
+    unit: &QueuedAccessUnit,
+    arrivals: &mut BTreeMap<u64, Instant>,
+    stats: &Mutex<VideoDecodeStats>,
+    deliver: &mut impl FnMut(DecodedPicture),
+) -> anyhow::Result<()> {
+    decoder.send(unit.pts, &unit.data)?;
+    while let Some(pts) = decoder.receive()? {
+        let arrived_at = arrivals.remove(&pts).unwrap_or(unit.arrived_at);
+        // Pictures come out in display order, so anything older was never output.
+        *arrivals = arrivals.split_off(&pts);
+        let late = arrived_at.elapsed() > MAX_DECODE_LATENESS;
+        if let Ok(mut stats) = stats.lock() {
+            if late {
+                stats.dropped_late = stats.dropped_late.saturating_add(1);
+            } else {
+                stats.decoded = stats.decoded.saturating_add(1);
+            }
+        }
+        if !late {
+            deliver(decoder.convert()?);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ON_TIME: Duration = Duration::ZERO;
+    const LATE: Duration = Duration::from_secs(1);
+
+    #[test]
+    fn decoding_starts_and_resumes_at_an_irap() {
+        let mut gate = IrapGate::default();
+        assert_eq!(
+            gate.admit(Some(PictureKind::NonIrap), ON_TIME),
+            Admission::SkipUntilIrap
+        );
+        assert_eq!(gate.admit(None, ON_TIME), Admission::Decode);
+        assert_eq!(
+            gate.admit(Some(PictureKind::Cra), ON_TIME),
+            Admission::Decode
+        );
+        assert_eq!(
+            gate.admit(Some(PictureKind::NonIrap), ON_TIME),
+            Admission::Decode
+        );
+
+        gate.resync();
+        assert_eq!(
+            gate.admit(Some(PictureKind::NonIrap), ON_TIME),
+            Admission::SkipUntilIrap
+        );
+        assert_eq!(
+            gate.admit(Some(PictureKind::Idr), ON_TIME),
+            Admission::Decode
+        );
+    }
+
+    #[test]
+    fn late_units_are_dropped_before_decoding_until_the_next_irap() {
+        let mut gate = IrapGate::default();
+        assert_eq!(
+            gate.admit(Some(PictureKind::Idr), ON_TIME),
+            Admission::Decode
+        );
+        assert_eq!(
+            gate.admit(Some(PictureKind::NonIrap), LATE),
+            Admission::DropLate
+        );
+        assert_eq!(
+            gate.admit(Some(PictureKind::NonIrap), ON_TIME),
+            Admission::SkipUntilIrap
+        );
+        // The IRAP is where decoding resumes, so it is decoded even when late.
+        assert_eq!(gate.admit(Some(PictureKind::Idr), LATE), Admission::Decode);
+        assert_eq!(
+            gate.admit(Some(PictureKind::NonIrap), ON_TIME),
+            Admission::Decode
+        );
+    }
+}